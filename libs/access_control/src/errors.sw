@@ -0,0 +1,9 @@
+library;
+
+/// Error log for when access is denied.
+pub enum AccessControlError {
+    /// Emitted when the initial admin has already been granted.
+    AlreadyInitialized: (),
+    /// Emitted when the caller does not have the required role.
+    MissingRole: (),
+}
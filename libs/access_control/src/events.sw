@@ -0,0 +1,27 @@
+library;
+
+/// Logged when the admin role of a role is changed.
+pub struct RoleAdminChanged {
+    /// The role which has a new admin role.
+    role: b256,
+    /// The admin role which previously controlled the role.
+    previous_admin_role: b256,
+    /// The admin role which now controls the role.
+    new_admin_role: b256,
+}
+
+/// Logged when a role is granted to a user.
+pub struct RoleGranted {
+    /// The role which has been granted.
+    role: b256,
+    /// The user which has been granted the role.
+    account: Identity,
+}
+
+/// Logged when a role is revoked from a user.
+pub struct RoleRevoked {
+    /// The role which has been revoked.
+    role: b256,
+    /// The user which the role has been revoked from.
+    account: Identity,
+}
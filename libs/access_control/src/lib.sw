@@ -0,0 +1,330 @@
+library;
+
+pub mod errors;
+pub mod events;
+
+use errors::AccessControlError;
+use events::{RoleAdminChanged, RoleGranted, RoleRevoked};
+use std::{auth::msg_sender, hash::sha256, storage::storage_api::{read, write}};
+
+/// The admin role of every role which has not been given another admin role.
+///
+/// # Additional Information
+///
+/// Users with this role may grant and revoke any role which uses it as the admin role, including itself.
+pub const DEFAULT_ADMIN_ROLE: b256 = 0x0000000000000000000000000000000000000000000000000000000000000000;
+
+/// The roles of many users held in storage, along with the admin role which controls each role.
+///
+/// # Additional Information
+///
+/// The granted roles are kept in a `StorageMap<(b256, Identity), bool>` at the storage key of the access control.
+/// The admin roles are kept in a `StorageMap<b256, b256>` and whether the initial admin has been granted in a
+/// `bool`, each at a slot derived from the slot of the access control.
+pub struct AccessControl {}
+
+impl StorageKey<AccessControl> {
+    /// Returns whether a user has been granted a role.
+    ///
+    /// # Arguments
+    ///
+    /// * `role`: [b256] - The role to check.
+    /// * `account`: [Identity] - The user to check.
+    ///
+    /// # Returns
+    ///
+    /// * [bool] - `true` if `account` has `role`, otherwise `false`.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use access_control::AccessControl;
+    ///
+    /// storage {
+    ///     access: AccessControl = AccessControl {},
+    /// }
+    ///
+    /// fn foo(role: b256, account: Identity) {
+    ///     assert(!storage.access.has_role(role, account));
+    /// }
+    /// ```
+    #[storage(read)]
+    pub fn has_role(self, role: b256, account: Identity) -> bool {
+        self.roles().get((role, account)).try_read().unwrap_or(false)
+    }
+
+    /// Returns the admin role which controls a role.
+    ///
+    /// # Arguments
+    ///
+    /// * `role`: [b256] - The role of which to query the admin role.
+    ///
+    /// # Returns
+    ///
+    /// * [b256] - The admin role of `role`, or `DEFAULT_ADMIN_ROLE` if none has been set.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use access_control::{AccessControl, DEFAULT_ADMIN_ROLE};
+    ///
+    /// storage {
+    ///     access: AccessControl = AccessControl {},
+    /// }
+    ///
+    /// fn foo(role: b256) {
+    ///     assert(storage.access.role_admin(role) == DEFAULT_ADMIN_ROLE);
+    /// }
+    /// ```
+    #[storage(read)]
+    pub fn role_admin(self, role: b256) -> b256 {
+        self.role_admins().get(role).try_read().unwrap_or(DEFAULT_ADMIN_ROLE)
+    }
+
+    /// Ensures that the sender has been granted a role.
+    ///
+    /// # Arguments
+    ///
+    /// * `role`: [b256] - The role which the sender must have.
+    ///
+    /// # Reverts
+    ///
+    /// * When the sender does not have `role`.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use access_control::AccessControl;
+    ///
+    /// const MINTER_ROLE: b256 = 0x0000000000000000000000000000000000000000000000000000000000000001;
+    ///
+    /// storage {
+    ///     access: AccessControl = AccessControl {},
+    /// }
+    ///
+    /// fn foo() {
+    ///     storage.access.require_role(MINTER_ROLE);
+    ///     // Mint here
+    /// }
+    /// ```
+    #[storage(read)]
+    pub fn require_role(self, role: b256) {
+        require(
+            self.has_role(role, msg_sender().unwrap()),
+            AccessControlError::MissingRole,
+        );
+    }
+}
+
+impl StorageKey<AccessControl> {
+    /// Grants `DEFAULT_ADMIN_ROLE` to the initial admin.
+    ///
+    /// # Additional Information
+    ///
+    /// This does not check the role of the sender. It is intended to be called once in a constructor, after
+    /// which only the admins may grant roles.
+    ///
+    /// # Arguments
+    ///
+    /// * `admin`: [Identity] - The user which will be granted `DEFAULT_ADMIN_ROLE`.
+    ///
+    /// # Reverts
+    ///
+    /// * When the initial admin has already been granted.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    /// * Writes: `2`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use access_control::{AccessControl, DEFAULT_ADMIN_ROLE};
+    ///
+    /// storage {
+    ///     access: AccessControl = AccessControl {},
+    /// }
+    ///
+    /// fn foo(admin: Identity) {
+    ///     storage.access.initialize(admin);
+    ///     assert(storage.access.has_role(DEFAULT_ADMIN_ROLE, admin));
+    /// }
+    /// ```
+    #[storage(read, write)]
+    pub fn initialize(self, admin: Identity) {
+        let initialized_slot = self.initialized_slot();
+        require(
+            !read::<bool>(initialized_slot, 0).unwrap_or(false),
+            AccessControlError::AlreadyInitialized,
+        );
+
+        write(initialized_slot, 0, true);
+        self.set_role(DEFAULT_ADMIN_ROLE, admin, true);
+    }
+
+    /// Grants a role to a user.
+    ///
+    /// # Arguments
+    ///
+    /// * `role`: [b256] - The role to grant.
+    /// * `account`: [Identity] - The user which will be granted `role`.
+    ///
+    /// # Reverts
+    ///
+    /// * When the sender does not have the admin role of `role`.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `2`
+    /// * Writes: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use access_control::AccessControl;
+    ///
+    /// const MINTER_ROLE: b256 = 0x0000000000000000000000000000000000000000000000000000000000000001;
+    ///
+    /// storage {
+    ///     access: AccessControl = AccessControl {},
+    /// }
+    ///
+    /// fn foo(account: Identity) {
+    ///     storage.access.grant_role(MINTER_ROLE, account);
+    ///     assert(storage.access.has_role(MINTER_ROLE, account));
+    /// }
+    /// ```
+    #[storage(read, write)]
+    pub fn grant_role(self, role: b256, account: Identity) {
+        self.require_role(self.role_admin(role));
+        self.set_role(role, account, true);
+    }
+
+    /// Revokes a role from a user.
+    ///
+    /// # Arguments
+    ///
+    /// * `role`: [b256] - The role to revoke.
+    /// * `account`: [Identity] - The user which `role` will be revoked from.
+    ///
+    /// # Reverts
+    ///
+    /// * When the sender does not have the admin role of `role`.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `2`
+    /// * Writes: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use access_control::AccessControl;
+    ///
+    /// const MINTER_ROLE: b256 = 0x0000000000000000000000000000000000000000000000000000000000000001;
+    ///
+    /// storage {
+    ///     access: AccessControl = AccessControl {},
+    /// }
+    ///
+    /// fn foo(account: Identity) {
+    ///     storage.access.revoke_role(MINTER_ROLE, account);
+    ///     assert(!storage.access.has_role(MINTER_ROLE, account));
+    /// }
+    /// ```
+    #[storage(read, write)]
+    pub fn revoke_role(self, role: b256, account: Identity) {
+        self.require_role(self.role_admin(role));
+        self.set_role(role, account, false);
+    }
+
+    /// Sets the admin role which controls a role.
+    ///
+    /// # Arguments
+    ///
+    /// * `role`: [b256] - The role of which to set the admin role.
+    /// * `admin_role`: [b256] - The role which will be able to grant and revoke `role`.
+    ///
+    /// # Reverts
+    ///
+    /// * When the sender does not have the current admin role of `role`.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `2`
+    /// * Writes: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use access_control::AccessControl;
+    ///
+    /// const MINTER_ROLE: b256 = 0x0000000000000000000000000000000000000000000000000000000000000001;
+    /// const MINTER_ADMIN_ROLE: b256 = 0x0000000000000000000000000000000000000000000000000000000000000002;
+    ///
+    /// storage {
+    ///     access: AccessControl = AccessControl {},
+    /// }
+    ///
+    /// fn foo() {
+    ///     storage.access.set_role_admin(MINTER_ROLE, MINTER_ADMIN_ROLE);
+    ///     assert(storage.access.role_admin(MINTER_ROLE) == MINTER_ADMIN_ROLE);
+    /// }
+    /// ```
+    #[storage(read, write)]
+    pub fn set_role_admin(self, role: b256, admin_role: b256) {
+        let previous_admin_role = self.role_admin(role);
+        self.require_role(previous_admin_role);
+
+        self.role_admins().insert(role, admin_role);
+
+        log(RoleAdminChanged {
+            role,
+            previous_admin_role,
+            new_admin_role: admin_role,
+        });
+    }
+}
+
+impl StorageKey<AccessControl> {
+    /// Records whether a user has a role without checking the role of the sender.
+    #[storage(write)]
+    fn set_role(self, role: b256, account: Identity, granted: bool) {
+        self.roles().insert((role, account), granted);
+
+        if granted {
+            log(RoleGranted { role, account });
+        } else {
+            log(RoleRevoked { role, account });
+        }
+    }
+
+    /// Returns the `StorageMap` which holds whether every user has every role.
+    fn roles(self) -> StorageKey<StorageMap<(b256, Identity), bool>> {
+        StorageKey::new(self.slot, self.offset, self.field_id)
+    }
+
+    /// Returns the `StorageMap` which holds the admin role of every role.
+    fn role_admins(self) -> StorageKey<StorageMap<b256, b256>> {
+        let slot = sha256((self.slot, "role_admins"));
+        StorageKey::new(slot, 0, slot)
+    }
+
+    /// Returns the slot which holds whether the initial admin has been granted.
+    fn initialized_slot(self) -> b256 {
+        sha256((self.slot, "initialized"))
+    }
+}
@@ -0,0 +1,15 @@
+library;
+
+/// Error log for when a swap or deposit cannot be priced.
+pub enum AmmError {
+    /// Emitted when the amount given to a swap or deposit is zero.
+    InsufficientInputAmount: (),
+    /// Emitted when the amount requested from a swap is zero.
+    InsufficientOutputAmount: (),
+    /// Emitted when a reserve is zero or too small to provide the requested amount.
+    InsufficientLiquidity: (),
+    /// Emitted when the fee is not less than `BPS_DIVISOR`.
+    InvalidFee: (),
+    /// Emitted when the result does not fit in a `u64`.
+    Overflow: (),
+}
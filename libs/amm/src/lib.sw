@@ -0,0 +1,177 @@
+library;
+
+pub mod errors;
+
+use errors::AmmError;
+use math::bps::BPS_DIVISOR;
+use std::{u128::U128, u256::U256};
+
+/// Computes the amount of tokens received for swapping into a constant-product pool.
+///
+/// # Additional Information
+///
+/// The fee is taken from `amount_in` and the remainder is swapped such that the product of the reserves
+/// does not decrease, rounding the output down. Products of two values are computed in 128 bits and the
+/// product of three values in 256 bits, so any `u64` amounts and reserves may be used.
+///
+/// # Arguments
+///
+/// * `amount_in`: [u64] - The amount of tokens given to the pool.
+/// * `reserve_in`: [u64] - The reserve of the token given to the pool.
+/// * `reserve_out`: [u64] - The reserve of the token received from the pool.
+/// * `fee_bps`: [u64] - The fee of the pool in basis points.
+///
+/// # Returns
+///
+/// * [u64] - The largest amount of tokens which may be taken from the pool in return for `amount_in`.
+///
+/// # Reverts
+///
+/// * When `amount_in` is zero.
+/// * When either reserve is zero.
+/// * When `fee_bps` is not less than `BPS_DIVISOR`.
+///
+/// # Examples
+///
+/// ```sway
+/// use amm::get_amount_out;
+///
+/// fn foo() {
+///     // A 0.3% fee
+///     let amount_out = get_amount_out(10_000, 1_000_000, 2_000_000, 30);
+///     assert(amount_out == 19_743);
+/// }
+/// ```
+pub fn get_amount_out(
+    amount_in: u64,
+    reserve_in: u64,
+    reserve_out: u64,
+    fee_bps: u64,
+) -> u64 {
+    require(amount_in != 0, AmmError::InsufficientInputAmount);
+    require(
+        reserve_in != 0 && reserve_out != 0,
+        AmmError::InsufficientLiquidity,
+    );
+    require(fee_bps < BPS_DIVISOR, AmmError::InvalidFee);
+
+    let fee_multiplier = U128::from((0, BPS_DIVISOR - fee_bps));
+    let amount_in_with_fee = U128::from((0, amount_in)) * fee_multiplier;
+    let numerator = to_u256(amount_in_with_fee) * U256::from((0, 0, 0, reserve_out));
+    let scaled_reserve_in = U128::from((0, reserve_in)) * U128::from((0, BPS_DIVISOR));
+    let denominator = scaled_reserve_in + amount_in_with_fee;
+
+    // The output is always less than `reserve_out`
+    (numerator / to_u256(denominator)).d
+}
+
+/// Computes the amount of tokens which must be swapped into a constant-product pool to receive an amount.
+///
+/// # Additional Information
+///
+/// This is the inverse of `get_amount_out`, rounding the input up, so swapping the returned amount
+/// always yields at least `amount_out`. Products of two values are computed in 128 bits and the product
+/// of three values in 256 bits, so any `u64` amounts and reserves may be used.
+///
+/// # Arguments
+///
+/// * `amount_out`: [u64] - The amount of tokens to receive from the pool.
+/// * `reserve_in`: [u64] - The reserve of the token given to the pool.
+/// * `reserve_out`: [u64] - The reserve of the token received from the pool.
+/// * `fee_bps`: [u64] - The fee of the pool in basis points.
+///
+/// # Returns
+///
+/// * [u64] - The smallest amount of tokens which must be given to the pool to receive `amount_out`.
+///
+/// # Reverts
+///
+/// * When `amount_out` is zero.
+/// * When either reserve is zero or `amount_out` is not less than `reserve_out`.
+/// * When `fee_bps` is not less than `BPS_DIVISOR`.
+/// * When the required input does not fit in a `u64`.
+///
+/// # Examples
+///
+/// ```sway
+/// use amm::get_amount_in;
+///
+/// fn foo() {
+///     // A 0.3% fee
+///     let amount_in = get_amount_in(19_743, 1_000_000, 2_000_000, 30);
+///     assert(amount_in == 10_000);
+/// }
+/// ```
+pub fn get_amount_in(
+    amount_out: u64,
+    reserve_in: u64,
+    reserve_out: u64,
+    fee_bps: u64,
+) -> u64 {
+    require(amount_out != 0, AmmError::InsufficientOutputAmount);
+    require(
+        reserve_in != 0 && amount_out < reserve_out,
+        AmmError::InsufficientLiquidity,
+    );
+    require(fee_bps < BPS_DIVISOR, AmmError::InvalidFee);
+
+    let product = U128::from((0, reserve_in)) * U128::from((0, amount_out));
+    let numerator = to_u256(product) * U256::from((0, 0, 0, BPS_DIVISOR));
+    let fee_multiplier = U128::from((0, BPS_DIVISOR - fee_bps));
+    let denominator = U128::from((0, reserve_out - amount_out)) * fee_multiplier;
+
+    let amount_in = numerator / to_u256(denominator);
+    let fits = amount_in.a == 0 && amount_in.b == 0 && amount_in.c == 0;
+    require(fits && amount_in.d != u64::max(), AmmError::Overflow);
+    amount_in.d + 1
+}
+
+/// Computes the amount of a token which matches an amount of the other token at the ratio of the reserves.
+///
+/// # Additional Information
+///
+/// This is used to deposit liquidity without changing the price of the pool. The result is rounded down.
+///
+/// # Arguments
+///
+/// * `amount_a`: [u64] - The amount of the first token.
+/// * `reserve_a`: [u64] - The reserve of the first token.
+/// * `reserve_b`: [u64] - The reserve of the second token.
+///
+/// # Returns
+///
+/// * [u64] - `amount_a * reserve_b / reserve_a`.
+///
+/// # Reverts
+///
+/// * When `amount_a` is zero.
+/// * When either reserve is zero.
+/// * When the result does not fit in a `u64`.
+///
+/// # Examples
+///
+/// ```sway
+/// use amm::quote;
+///
+/// fn foo() {
+///     let amount_b = quote(1_000, 1_000_000, 2_000_000);
+///     assert(amount_b == 2_000);
+/// }
+/// ```
+pub fn quote(amount_a: u64, reserve_a: u64, reserve_b: u64) -> u64 {
+    require(amount_a != 0, AmmError::InsufficientInputAmount);
+    require(
+        reserve_a != 0 && reserve_b != 0,
+        AmmError::InsufficientLiquidity,
+    );
+
+    let product = U128::from((0, amount_a)) * U128::from((0, reserve_b));
+    let amount_b = product / U128::from((0, reserve_a));
+    require(amount_b.upper == 0, AmmError::Overflow);
+    amount_b.lower
+}
+
+/// Widens a `U128` to a `U256`.
+fn to_u256(value: U128) -> U256 {
+    U256::from((0, 0, value.upper, value.lower))
+}
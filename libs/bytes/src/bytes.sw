@@ -0,0 +1,571 @@
+library;
+
+pub mod errors;
+pub mod parse;
+
+use errors::BytesError;
+use std::{bytes::Bytes as RawBytes, hash::sha256, storage::storage_vec::*};
+
+/// A dynamically sized buffer of bytes held in memory.
+pub struct Bytes {
+    /// The underlying tightly packed bytes.
+    bytes: RawBytes,
+}
+
+impl Bytes {
+    /// Creates a new empty `Bytes` buffer.
+    ///
+    /// # Returns
+    ///
+    /// * [Bytes] - The newly created buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use bytes::Bytes;
+    ///
+    /// fn foo() {
+    ///     let bytes = Bytes::new();
+    ///     assert(bytes.is_empty());
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            bytes: RawBytes::new(),
+        }
+    }
+
+    /// Appends a byte to the end of the buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `byte`: [u8] - The byte to append.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use bytes::Bytes;
+    ///
+    /// fn foo() {
+    ///     let mut bytes = Bytes::new();
+    ///     bytes.push(5u8);
+    ///     assert(bytes.get(0).unwrap() == 5u8);
+    /// }
+    /// ```
+    pub fn push(ref mut self, byte: u8) {
+        self.bytes.push(byte);
+    }
+
+    /// Appends all bytes of another buffer to the end of the buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [Bytes] - The buffer whose bytes are appended. It is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use bytes::Bytes;
+    ///
+    /// fn foo() {
+    ///     let mut bytes = Bytes::new();
+    ///     bytes.push(1u8);
+    ///     let mut other = Bytes::new();
+    ///     other.push(2u8);
+    ///
+    ///     bytes.append(other);
+    ///     assert(bytes.len() == 2);
+    /// }
+    /// ```
+    pub fn append(ref mut self, other: Self) {
+        let mut index = 0;
+        while index < other.len() {
+            self.bytes.push(other.bytes.get(index).unwrap());
+            index += 1;
+        }
+    }
+
+    /// Returns the byte at `index`.
+    ///
+    /// # Arguments
+    ///
+    /// * `index`: [u64] - The position of the byte.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<u8>] - The byte at `index`, or `None` if `index` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use bytes::Bytes;
+    ///
+    /// fn foo() {
+    ///     let mut bytes = Bytes::new();
+    ///     bytes.push(5u8);
+    ///     assert(bytes.get(0).unwrap() == 5u8);
+    ///     assert(bytes.get(1).is_none());
+    /// }
+    /// ```
+    pub fn get(self, index: u64) -> Option<u8> {
+        self.bytes.get(index)
+    }
+
+    /// Returns the number of bytes in the buffer.
+    ///
+    /// # Returns
+    ///
+    /// * [u64] - The number of bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use bytes::Bytes;
+    ///
+    /// fn foo() {
+    ///     let mut bytes = Bytes::new();
+    ///     bytes.push(5u8);
+    ///     assert(bytes.len() == 1);
+    /// }
+    /// ```
+    pub fn len(self) -> u64 {
+        self.bytes.len()
+    }
+
+    /// Checks whether the buffer contains no bytes.
+    ///
+    /// # Returns
+    ///
+    /// * [bool] - `true` if the buffer is empty, otherwise `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use bytes::Bytes;
+    ///
+    /// fn foo() {
+    ///     let bytes = Bytes::new();
+    ///     assert(bytes.is_empty());
+    /// }
+    /// ```
+    pub fn is_empty(self) -> bool {
+        self.bytes.len() == 0
+    }
+
+    /// Copies the bytes from `start` up to, but not including, `end` into a new buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `start`: [u64] - The position of the first byte to copy.
+    /// * `end`: [u64] - The position after the last byte to copy.
+    ///
+    /// # Returns
+    ///
+    /// * [Bytes] - A new buffer holding the bytes in the range.
+    ///
+    /// # Reverts
+    ///
+    /// * When `start` is greater than `end`.
+    /// * When `end` is greater than the length of the buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use bytes::Bytes;
+    ///
+    /// fn foo() {
+    ///     let mut bytes = Bytes::new();
+    ///     bytes.push(1u8);
+    ///     bytes.push(2u8);
+    ///     bytes.push(3u8);
+    ///
+    ///     let slice = bytes.slice(1, 3);
+    ///     assert(slice.len() == 2);
+    ///     assert(slice.get(0).unwrap() == 2u8);
+    /// }
+    /// ```
+    pub fn slice(self, start: u64, end: u64) -> Self {
+        require(start <= end && end <= self.len(), BytesError::IndexOutOfBounds);
+
+        let mut slice = Self::new();
+        let mut index = start;
+        while index < end {
+            slice.push(self.bytes.get(index).unwrap());
+            index += 1;
+        }
+        slice
+    }
+
+    /// Converts the buffer into a `b256`.
+    ///
+    /// # Additional Information
+    ///
+    /// The bytes fill the `b256` from its most significant byte.
+    /// Buffers shorter than 32 bytes are padded with zeros at the end and longer buffers are truncated to their first 32 bytes.
+    ///
+    /// # Returns
+    ///
+    /// * [b256] - The first 32 bytes of the buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use bytes::Bytes;
+    ///
+    /// fn foo() {
+    ///     let mut bytes = Bytes::new();
+    ///     bytes.push(1u8);
+    ///     assert(bytes.to_b256() == 0x0100000000000000000000000000000000000000000000000000000000000000);
+    /// }
+    /// ```
+    pub fn to_b256(self) -> b256 {
+        let mut value = 0x0000000000000000000000000000000000000000000000000000000000000000;
+        let len = if self.len() < 32 { self.len() } else { 32 };
+        if len > 0 {
+            self.bytes.buf.ptr().copy_bytes_to(__addr_of(value), len);
+        }
+        value
+    }
+
+    /// Computes the SHA-256 hash of the bytes in the buffer.
+    ///
+    /// # Returns
+    ///
+    /// * [b256] - The SHA-256 hash of the buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use bytes::Bytes;
+    ///
+    /// fn foo() {
+    ///     let bytes = Bytes::new();
+    ///     assert(bytes.sha256() == 0xe3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855);
+    /// }
+    /// ```
+    pub fn sha256(self) -> b256 {
+        sha256(self.bytes)
+    }
+}
+
+impl From<RawBytes> for Bytes {
+    /// Wraps the standard library's `Bytes` so it can be used with this library.
+    fn from(bytes: RawBytes) -> Self {
+        Self { bytes }
+    }
+
+    /// Unwraps the buffer into the standard library's `Bytes`, such as to build a `std::string::String`.
+    fn into(self) -> RawBytes {
+        self.bytes
+    }
+}
+
+/// A dynamically sized buffer of bytes held in storage.
+///
+/// # Additional Information
+///
+/// The bytes are kept in a `StorageVec<u8>` at the storage key of the buffer.
+pub struct StorageBytes {}
+
+impl StorageKey<StorageBytes> {
+    /// Returns the `StorageVec<u8>` which holds the bytes.
+    fn vec(self) -> StorageKey<StorageVec<u8>> {
+        StorageKey::new(self.slot, self.offset, self.field_id)
+    }
+
+    /// Appends a byte to the end of the stored buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `byte`: [u8] - The byte to append.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    /// * Writes: `2`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use bytes::StorageBytes;
+    ///
+    /// storage {
+    ///     bytes: StorageBytes = StorageBytes {},
+    /// }
+    ///
+    /// fn foo() {
+    ///     storage.bytes.push(5u8);
+    ///     assert(storage.bytes.get(0).unwrap() == 5u8);
+    /// }
+    /// ```
+    #[storage(read, write)]
+    pub fn push(self, byte: u8) {
+        self.vec().push(byte);
+    }
+
+    /// Appends all bytes of an in memory buffer to the end of the stored buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [Bytes] - The buffer whose bytes are appended.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `other.len()`
+    /// * Writes: `other.len() * 2`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use bytes::{Bytes, StorageBytes};
+    ///
+    /// storage {
+    ///     bytes: StorageBytes = StorageBytes {},
+    /// }
+    ///
+    /// fn foo() {
+    ///     let mut other = Bytes::new();
+    ///     other.push(1u8);
+    ///     other.push(2u8);
+    ///
+    ///     storage.bytes.append(other);
+    ///     assert(storage.bytes.len() == 2);
+    /// }
+    /// ```
+    #[storage(read, write)]
+    pub fn append(self, other: Bytes) {
+        let mut index = 0;
+        while index < other.len() {
+            self.vec().push(other.get(index).unwrap());
+            index += 1;
+        }
+    }
+
+    /// Returns the stored byte at `index`.
+    ///
+    /// # Arguments
+    ///
+    /// * `index`: [u64] - The position of the byte.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<u8>] - The byte at `index`, or `None` if `index` is out of bounds.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `2`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use bytes::StorageBytes;
+    ///
+    /// storage {
+    ///     bytes: StorageBytes = StorageBytes {},
+    /// }
+    ///
+    /// fn foo() {
+    ///     storage.bytes.push(5u8);
+    ///     assert(storage.bytes.get(0).unwrap() == 5u8);
+    ///     assert(storage.bytes.get(1).is_none());
+    /// }
+    /// ```
+    #[storage(read)]
+    pub fn get(self, index: u64) -> Option<u8> {
+        match self.vec().get(index) {
+            Option::Some(byte) => Option::Some(byte.read()),
+            Option::None => Option::None,
+        }
+    }
+
+    /// Returns the number of bytes in the stored buffer.
+    ///
+    /// # Returns
+    ///
+    /// * [u64] - The number of bytes.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use bytes::StorageBytes;
+    ///
+    /// storage {
+    ///     bytes: StorageBytes = StorageBytes {},
+    /// }
+    ///
+    /// fn foo() {
+    ///     assert(storage.bytes.len() == 0);
+    /// }
+    /// ```
+    #[storage(read)]
+    pub fn len(self) -> u64 {
+        self.vec().len()
+    }
+
+    /// Removes all bytes from the stored buffer.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Writes: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use bytes::StorageBytes;
+    ///
+    /// storage {
+    ///     bytes: StorageBytes = StorageBytes {},
+    /// }
+    ///
+    /// fn foo() {
+    ///     storage.bytes.push(5u8);
+    ///     storage.bytes.clear();
+    ///     assert(storage.bytes.len() == 0);
+    /// }
+    /// ```
+    #[storage(write)]
+    pub fn clear(self) {
+        self.vec().clear();
+    }
+
+    /// Copies the stored bytes from `start` up to, but not including, `end` into memory.
+    ///
+    /// # Arguments
+    ///
+    /// * `start`: [u64] - The position of the first byte to copy.
+    /// * `end`: [u64] - The position after the last byte to copy.
+    ///
+    /// # Returns
+    ///
+    /// * [Bytes] - An in memory buffer holding the bytes in the range.
+    ///
+    /// # Reverts
+    ///
+    /// * When `start` is greater than `end`.
+    /// * When `end` is greater than the length of the stored buffer.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `(end - start) * 2 + 1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use bytes::StorageBytes;
+    ///
+    /// storage {
+    ///     bytes: StorageBytes = StorageBytes {},
+    /// }
+    ///
+    /// fn foo() {
+    ///     storage.bytes.push(1u8);
+    ///     storage.bytes.push(2u8);
+    ///
+    ///     let slice = storage.bytes.slice(1, 2);
+    ///     assert(slice.get(0).unwrap() == 2u8);
+    /// }
+    /// ```
+    #[storage(read)]
+    pub fn slice(self, start: u64, end: u64) -> Bytes {
+        require(start <= end && end <= self.len(), BytesError::IndexOutOfBounds);
+
+        let mut slice = Bytes::new();
+        let mut index = start;
+        while index < end {
+            slice.push(self.vec().get(index).unwrap().read());
+            index += 1;
+        }
+        slice
+    }
+
+    /// Copies all stored bytes into memory.
+    ///
+    /// # Returns
+    ///
+    /// * [Bytes] - An in memory buffer holding all stored bytes.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `self.len() * 2 + 1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use bytes::StorageBytes;
+    ///
+    /// storage {
+    ///     bytes: StorageBytes = StorageBytes {},
+    /// }
+    ///
+    /// fn foo() {
+    ///     storage.bytes.push(1u8);
+    ///     let bytes = storage.bytes.load();
+    ///     assert(bytes.len() == 1);
+    /// }
+    /// ```
+    #[storage(read)]
+    pub fn load(self) -> Bytes {
+        self.slice(0, self.len())
+    }
+
+    /// Converts the stored buffer into a `b256`, padding or truncating it to 32 bytes.
+    ///
+    /// # Returns
+    ///
+    /// * [b256] - The first 32 bytes of the stored buffer.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `self.len() * 2 + 1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use bytes::StorageBytes;
+    ///
+    /// storage {
+    ///     bytes: StorageBytes = StorageBytes {},
+    /// }
+    ///
+    /// fn foo() {
+    ///     storage.bytes.push(1u8);
+    ///     assert(storage.bytes.to_b256() == 0x0100000000000000000000000000000000000000000000000000000000000000);
+    /// }
+    /// ```
+    #[storage(read)]
+    pub fn to_b256(self) -> b256 {
+        self.load().to_b256()
+    }
+
+    /// Computes the SHA-256 hash of the stored bytes.
+    ///
+    /// # Returns
+    ///
+    /// * [b256] - The SHA-256 hash of the stored buffer.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `self.len() * 2 + 1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use bytes::StorageBytes;
+    ///
+    /// storage {
+    ///     bytes: StorageBytes = StorageBytes {},
+    /// }
+    ///
+    /// fn foo() {
+    ///     assert(storage.bytes.sha256() == 0xe3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855);
+    /// }
+    /// ```
+    #[storage(read)]
+    pub fn sha256(self) -> b256 {
+        self.load().sha256()
+    }
+}
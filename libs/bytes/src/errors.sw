@@ -0,0 +1,7 @@
+library;
+
+/// Error log for when a byte buffer is accessed incorrectly.
+pub enum BytesError {
+    /// Emitted when a range does not lie within the buffer.
+    IndexOutOfBounds: (),
+}
@@ -0,0 +1,108 @@
+library;
+
+use ::Bytes;
+
+/// The ASCII code of the `0` character.
+const ASCII_ZERO: u8 = 48u8;
+/// The ASCII code of the `9` character.
+const ASCII_NINE: u8 = 57u8;
+
+/// Parses the ASCII decimal representation of a `u64`.
+///
+/// # Additional Information
+///
+/// Only the digits `0` to `9` are accepted. Signs, whitespace and separators are rejected.
+/// Leading zeros are allowed.
+///
+/// # Arguments
+///
+/// * `input`: [Bytes] - The ASCII digits to parse.
+///
+/// # Returns
+///
+/// * [Option<u64>] - The parsed number, or `None` if `input` is empty, contains a byte which is not a digit, or overflows a `u64`.
+///
+/// # Examples
+///
+/// ```sway
+/// use bytes::{Bytes, parse::parse_u64};
+///
+/// fn foo() {
+///     let mut input = Bytes::new();
+///     input.push(52u8);
+///     input.push(50u8);
+///     assert(parse_u64(input).unwrap() == 42);
+/// }
+/// ```
+pub fn parse_u64(input: Bytes) -> Option<u64> {
+    if input.is_empty() {
+        return Option::None;
+    }
+
+    let mut result = 0;
+    let mut index = 0;
+    while index < input.len() {
+        let byte = input.get(index).unwrap();
+        if byte < ASCII_ZERO || byte > ASCII_NINE {
+            return Option::None;
+        }
+
+        // result * 10 + digit <= u64::max() is equivalent to result <= (u64::max() - digit) / 10
+        let digit = (byte - ASCII_ZERO).as_u64();
+        if result > (u64::max() - digit) / 10 {
+            return Option::None;
+        }
+        result = result * 10 + digit;
+        index += 1;
+    }
+
+    Option::Some(result)
+}
+
+/// Formats a `u64` as its minimal ASCII decimal representation.
+///
+/// # Additional Information
+///
+/// The result has no leading zeros, except for `0` which is formatted as a single `0`.
+///
+/// # Arguments
+///
+/// * `n`: [u64] - The number to format.
+///
+/// # Returns
+///
+/// * [Bytes] - The ASCII digits of `n`.
+///
+/// # Examples
+///
+/// ```sway
+/// use bytes::parse::{format_u64, parse_u64};
+///
+/// fn foo() {
+///     let digits = format_u64(42);
+///     assert(digits.len() == 2);
+///     assert(parse_u64(digits).unwrap() == 42);
+/// }
+/// ```
+pub fn format_u64(n: u64) -> Bytes {
+    let mut result = Bytes::new();
+    if n == 0 {
+        result.push(ASCII_ZERO);
+        return result;
+    }
+
+    // The digits are produced from the least significant one
+    let mut reversed = Bytes::new();
+    let mut value = n;
+    while value > 0 {
+        reversed.push(ASCII_ZERO + asm(digit: value % 10) { digit: u8 });
+        value = value / 10;
+    }
+
+    let mut index = reversed.len();
+    while index > 0 {
+        index -= 1;
+        result.push(reversed.get(index).unwrap());
+    }
+    result
+}
@@ -0,0 +1,136 @@
+library;
+
+use std::storage::storage_vec::*;
+
+/// An indexable, growable list of elements, which collections are built on.
+///
+/// # Additional Information
+///
+/// Collections such as heaps and queues only need to push, pop and index their elements, so writing them
+/// against a `Backend` allows the same collection to be kept in contract storage with a `StorageBackend` or in
+/// memory with a `MemBackend`. Storage access is part of a function's signature, so every function declares the
+/// storage access a `StorageBackend` needs and a collection on any `Backend` can only be used within a contract.
+pub trait Backend<T> {
+    /// Overwrites the element at `index` with `value`. Reverts when `index` is out of bounds.
+    #[storage(read, write)]
+    fn set(ref mut self, index: u64, value: T);
+    /// Returns the element at `index`. Reverts when `index` is out of bounds.
+    #[storage(read)]
+    fn get(self, index: u64) -> T;
+    /// Appends `value` to the end of the backend.
+    #[storage(read, write)]
+    fn push(ref mut self, value: T);
+    /// Removes the last element and returns it, or `None` if the backend is empty.
+    #[storage(read, write)]
+    fn pop(ref mut self) -> Option<T>;
+    /// Returns the number of elements.
+    #[storage(read)]
+    fn len(self) -> u64;
+}
+
+/// A `Backend` held in contract storage.
+///
+/// # Additional Information
+///
+/// The elements are kept in a `StorageVec<T>` at the storage key of the backend, so a `StorageBackend<T>`
+/// has the same storage layout as a `StorageVec<T>`.
+pub struct StorageBackend<T> {}
+
+impl<T> Backend<T> for StorageKey<StorageBackend<T>> {
+    #[storage(read, write)]
+    fn set(ref mut self, index: u64, value: T) {
+        self.vec().set(index, value);
+    }
+
+    #[storage(read)]
+    fn get(self, index: u64) -> T {
+        self.vec().get(index).unwrap().read()
+    }
+
+    #[storage(read, write)]
+    fn push(ref mut self, value: T) {
+        self.vec().push(value);
+    }
+
+    #[storage(read, write)]
+    fn pop(ref mut self) -> Option<T> {
+        self.vec().pop()
+    }
+
+    #[storage(read)]
+    fn len(self) -> u64 {
+        self.vec().len()
+    }
+}
+
+impl<T> StorageKey<StorageBackend<T>> {
+    /// Returns the `StorageVec` which holds the elements.
+    fn vec(self) -> StorageKey<StorageVec<T>> {
+        StorageKey::new(self.slot, self.offset, self.field_id)
+    }
+}
+
+/// A `Backend` held in memory.
+///
+/// # Additional Information
+///
+/// The elements are kept in a `Vec<T>`, so a collection on a `MemBackend` only lives for the duration of a
+/// call, such as to compute a result without writing it to storage. Its functions carry the storage access
+/// declared by `Backend` but never access storage.
+pub struct MemBackend<T> {
+    /// The underlying vector which stores the elements.
+    vec: Vec<T>,
+}
+
+impl<T> MemBackend<T> {
+    /// Creates a new, empty `MemBackend`.
+    ///
+    /// # Returns
+    ///
+    /// * [MemBackend<T>] - The newly created `MemBackend` struct.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use collection_backend::{Backend, MemBackend};
+    ///
+    /// #[storage(read, write)]
+    /// fn foo() {
+    ///     let mut backend = MemBackend::<u64>::new();
+    ///     backend.push(5);
+    ///     assert(backend.get(0) == 5);
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            vec: Vec::new(),
+        }
+    }
+}
+
+impl<T> Backend<T> for MemBackend<T> {
+    #[storage(read, write)]
+    fn set(ref mut self, index: u64, value: T) {
+        self.vec.set(index, value);
+    }
+
+    #[storage(read)]
+    fn get(self, index: u64) -> T {
+        self.vec.get(index).unwrap()
+    }
+
+    #[storage(read, write)]
+    fn push(ref mut self, value: T) {
+        self.vec.push(value);
+    }
+
+    #[storage(read, write)]
+    fn pop(ref mut self) -> Option<T> {
+        self.vec.pop()
+    }
+
+    #[storage(read)]
+    fn len(self) -> u64 {
+        self.vec.len()
+    }
+}
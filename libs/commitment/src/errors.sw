@@ -0,0 +1,17 @@
+library;
+
+/// Error log for when a commitment cannot be submitted or revealed.
+pub enum CommitmentError {
+    /// Emitted when a committer submits a second commitment.
+    AlreadyCommitted: (),
+    /// Emitted when a committer reveals a second time.
+    AlreadyRevealed: (),
+    /// Emitted when a commitment is submitted at or after the cutoff block height.
+    CommitPeriodOver: (),
+    /// Emitted when the revealed value and nonce do not match the commitment.
+    InvalidReveal: (),
+    /// Emitted when a committer reveals without having submitted a commitment.
+    NotCommitted: (),
+    /// Emitted when a commitment is revealed before the cutoff block height.
+    RevealPeriodNotStarted: (),
+}
@@ -0,0 +1,260 @@
+library;
+
+pub mod errors;
+
+use errors::CommitmentError;
+use std::{block::height, hash::sha256};
+
+/// Returns the commitment to a value, which hides the value until it is revealed with the nonce.
+///
+/// # Additional Information
+///
+/// The nonce should be random and kept secret until the reveal, otherwise a value with few possibilities can be
+/// found by hashing each of them.
+///
+/// # Arguments
+///
+/// * `value`: [b256] - The value to commit to.
+/// * `nonce`: [b256] - The secret which blinds the value.
+///
+/// # Returns
+///
+/// * [b256] - The SHA-256 hash of `value` followed by `nonce`.
+///
+/// # Examples
+///
+/// ```sway
+/// use commitment::commit;
+///
+/// fn foo(value: b256, nonce: b256) {
+///     let commitment = commit(value, nonce);
+///     assert(commitment != commit(value, value));
+/// }
+/// ```
+pub fn commit(value: b256, nonce: b256) -> b256 {
+    sha256((value, nonce))
+}
+
+/// Returns whether a value and nonce open a commitment.
+///
+/// # Arguments
+///
+/// * `commitment`: [b256] - The commitment which was made.
+/// * `value`: [b256] - The value which is revealed.
+/// * `nonce`: [b256] - The nonce which is revealed.
+///
+/// # Returns
+///
+/// * [bool] - `true` if `commitment` is the commitment to `value` with `nonce`, otherwise `false`.
+///
+/// # Examples
+///
+/// ```sway
+/// use commitment::{commit, verify_commitment};
+///
+/// fn foo(value: b256, nonce: b256) {
+///     let commitment = commit(value, nonce);
+///     assert(verify_commitment(commitment, value, nonce));
+/// }
+/// ```
+pub fn verify_commitment(commitment: b256, value: b256, nonce: b256) -> bool {
+    commitment == commit(value, nonce)
+}
+
+/// The commitments of many committers held in storage, which may each be submitted and revealed once.
+///
+/// # Additional Information
+///
+/// The commitments are kept in a `StorageMap<Identity, b256>` at the storage key of the store. Whether each
+/// committer has revealed is kept in a `StorageMap<Identity, bool>` at a slot derived from the slot of the store.
+/// Commitments are submitted before a cutoff block height and revealed from it onwards, where the cutoff is given
+/// by the contract on every call.
+pub struct CommitStore {}
+
+impl StorageKey<CommitStore> {
+    /// Returns the commitment submitted by a committer.
+    ///
+    /// # Arguments
+    ///
+    /// * `committer`: [Identity] - The committer of which to query the commitment.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<b256>] - The commitment of `committer` or `None` if it has not submitted one.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use commitment::CommitStore;
+    ///
+    /// storage {
+    ///     commits: CommitStore = CommitStore {},
+    /// }
+    ///
+    /// fn foo(committer: Identity) {
+    ///     assert(storage.commits.commitment_of(committer).is_none());
+    /// }
+    /// ```
+    #[storage(read)]
+    pub fn commitment_of(self, committer: Identity) -> Option<b256> {
+        self.commitments().get(committer).try_read()
+    }
+
+    /// Returns whether a committer has revealed its commitment.
+    ///
+    /// # Arguments
+    ///
+    /// * `committer`: [Identity] - The committer to check.
+    ///
+    /// # Returns
+    ///
+    /// * [bool] - `true` if `committer` has revealed, otherwise `false`.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use commitment::CommitStore;
+    ///
+    /// storage {
+    ///     commits: CommitStore = CommitStore {},
+    /// }
+    ///
+    /// fn foo(committer: Identity) {
+    ///     assert(!storage.commits.has_revealed(committer));
+    /// }
+    /// ```
+    #[storage(read)]
+    pub fn has_revealed(self, committer: Identity) -> bool {
+        self.revealed().get(committer).try_read().unwrap_or(false)
+    }
+
+    /// Stores the commitment of a committer.
+    ///
+    /// # Additional Information
+    ///
+    /// The store does not check who calls it, so the contract should pass the sender as the `committer`.
+    ///
+    /// # Arguments
+    ///
+    /// * `committer`: [Identity] - The committer which makes the commitment.
+    /// * `commitment`: [b256] - The commitment, as returned by `commit`.
+    /// * `cutoff`: [u64] - The block height from which commitments are no longer accepted.
+    ///
+    /// # Reverts
+    ///
+    /// * When the current block height is at or above `cutoff`.
+    /// * When `committer` has already submitted a commitment.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    /// * Writes: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use commitment::{commit, CommitStore};
+    ///
+    /// storage {
+    ///     commits: CommitStore = CommitStore {},
+    /// }
+    ///
+    /// fn foo(value: b256, nonce: b256) {
+    ///     let sender = msg_sender().unwrap();
+    ///     storage.commits.submit(sender, commit(value, nonce), 1000);
+    ///     assert(storage.commits.commitment_of(sender).is_some());
+    /// }
+    /// ```
+    #[storage(read, write)]
+    pub fn submit(self, committer: Identity, commitment: b256, cutoff: u64) {
+        require(height().as_u64() < cutoff, CommitmentError::CommitPeriodOver);
+        require(
+            self.commitment_of(committer).is_none(),
+            CommitmentError::AlreadyCommitted,
+        );
+
+        self.commitments().insert(committer, commitment);
+    }
+
+    /// Opens the commitment of a committer with its value and nonce.
+    ///
+    /// # Additional Information
+    ///
+    /// The commitment stays in storage after it is revealed, so a committer can never commit again.
+    ///
+    /// # Arguments
+    ///
+    /// * `committer`: [Identity] - The committer which opens its commitment.
+    /// * `value`: [b256] - The value which was committed to.
+    /// * `nonce`: [b256] - The nonce which was committed with.
+    /// * `cutoff`: [u64] - The block height from which commitments may be revealed.
+    ///
+    /// # Reverts
+    ///
+    /// * When the current block height is below `cutoff`.
+    /// * When `committer` has not submitted a commitment.
+    /// * When `committer` has already revealed.
+    /// * When `value` and `nonce` do not match the commitment.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `2`
+    /// * Writes: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use commitment::CommitStore;
+    ///
+    /// storage {
+    ///     commits: CommitStore = CommitStore {},
+    /// }
+    ///
+    /// fn foo(value: b256, nonce: b256) {
+    ///     let sender = msg_sender().unwrap();
+    ///     storage.commits.reveal(sender, value, nonce, 1000);
+    ///     assert(storage.commits.has_revealed(sender));
+    /// }
+    /// ```
+    #[storage(read, write)]
+    pub fn reveal(self, committer: Identity, value: b256, nonce: b256, cutoff: u64) {
+        require(
+            height().as_u64() >= cutoff,
+            CommitmentError::RevealPeriodNotStarted,
+        );
+
+        let commitment = self.commitment_of(committer);
+        require(commitment.is_some(), CommitmentError::NotCommitted);
+        require(
+            !self.has_revealed(committer),
+            CommitmentError::AlreadyRevealed,
+        );
+        require(
+            verify_commitment(commitment.unwrap(), value, nonce),
+            CommitmentError::InvalidReveal,
+        );
+
+        self.revealed().insert(committer, true);
+    }
+}
+
+impl StorageKey<CommitStore> {
+    /// Returns the `StorageMap` which holds the commitment of every committer.
+    fn commitments(self) -> StorageKey<StorageMap<Identity, b256>> {
+        StorageKey::new(self.slot, self.offset, self.field_id)
+    }
+
+    /// Returns the `StorageMap` which holds whether every committer has revealed.
+    fn revealed(self) -> StorageKey<StorageMap<Identity, bool>> {
+        let slot = sha256((self.slot, "revealed"));
+        StorageKey::new(slot, 0, slot)
+    }
+}
@@ -0,0 +1,586 @@
+library;
+
+use ownership::{
+    errors::AccessError,
+    events::{OwnershipSet, OwnershipTransferStarted, OwnershipTransferred},
+};
+use pausable::{errors::PauseError, events::{Paused, Unpaused}};
+use std::auth::msg_sender;
+
+/// The ownership and pause state of a contract.
+///
+/// # Additional Information
+///
+/// All fields are kept together at the storage key of the `ContractBase`, so a contract only declares a single
+/// storage field for both its ownership and its pause state. Sway derives the storage slot of every field of a
+/// `storage` block from the name of the field, so the `ContractBase` does not collide with the other storage of
+/// the contract and no explicit slot has to be chosen.
+pub struct ContractBase {
+    /// The owner of the contract, or `None` before the ownership is set.
+    owner: Option<Identity>,
+    /// Whether guarded functions of the contract are halted.
+    paused: bool,
+    /// The identity which may accept the ownership, or `None` if no transfer is pending.
+    pending_owner: Option<Identity>,
+}
+
+impl ContractBase {
+    /// Returns a `ContractBase` without an owner which is not paused.
+    ///
+    /// # Returns
+    ///
+    /// * [ContractBase] - The `ContractBase` whose ownership is yet to be set.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use contract_base::ContractBase;
+    ///
+    /// storage {
+    ///     base: ContractBase = ContractBase::new(),
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            owner: Option::None,
+            paused: false,
+            pending_owner: Option::None,
+        }
+    }
+
+    /// Returns a `ContractBase` owned by an identity which is not paused.
+    ///
+    /// # Arguments
+    ///
+    /// * `owner`: [Identity] - The owner of the contract.
+    ///
+    /// # Returns
+    ///
+    /// * [ContractBase] - The `ContractBase` owned by `owner`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use contract_base::ContractBase;
+    ///
+    /// storage {
+    ///     base: ContractBase = ContractBase::initialized(Identity::Address(Address::from(ZERO_B256))),
+    /// }
+    /// ```
+    pub fn initialized(owner: Identity) -> Self {
+        Self {
+            owner: Option::Some(owner),
+            paused: false,
+            pending_owner: Option::None,
+        }
+    }
+}
+
+impl StorageKey<ContractBase> {
+    /// Returns the owner of the contract.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<Identity>] - The owner of the contract, or `None` before the ownership is set.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use contract_base::ContractBase;
+    ///
+    /// storage {
+    ///     base: ContractBase = ContractBase::new(),
+    /// }
+    ///
+    /// fn foo() {
+    ///     assert(storage.base.owner().is_none());
+    /// }
+    /// ```
+    #[storage(read)]
+    pub fn owner(self) -> Option<Identity> {
+        self.read().owner
+    }
+
+    /// Returns the pending owner of a two-step ownership transfer.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<Identity>] - The `Identity` which may accept the ownership, or `None` if no transfer is pending.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use contract_base::ContractBase;
+    ///
+    /// storage {
+    ///     base: ContractBase = ContractBase::new(),
+    /// }
+    ///
+    /// fn foo() {
+    ///     assert(storage.base.pending_owner().is_none());
+    /// }
+    /// ```
+    #[storage(read)]
+    pub fn pending_owner(self) -> Option<Identity> {
+        self.read().pending_owner
+    }
+
+    /// Returns whether the contract is paused.
+    ///
+    /// # Returns
+    ///
+    /// * [bool] - `true` if the contract is paused, otherwise `false`.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use contract_base::ContractBase;
+    ///
+    /// storage {
+    ///     base: ContractBase = ContractBase::new(),
+    /// }
+    ///
+    /// fn foo() {
+    ///     assert(!storage.base.is_paused());
+    /// }
+    /// ```
+    #[storage(read)]
+    pub fn is_paused(self) -> bool {
+        self.read().paused
+    }
+}
+
+impl StorageKey<ContractBase> {
+    /// Ensures that the sender is the owner.
+    ///
+    /// # Reverts
+    ///
+    /// * When the sender is not the owner.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use contract_base::ContractBase;
+    ///
+    /// storage {
+    ///     base: ContractBase = ContractBase::new(),
+    /// }
+    ///
+    /// fn foo() {
+    ///     storage.base.only_owner();
+    ///     // Do stuff here
+    /// }
+    /// ```
+    #[storage(read)]
+    pub fn only_owner(self) {
+        require(is_owner(self.read()), AccessError::NotOwner);
+    }
+
+    /// Ensures that the contract is not paused.
+    ///
+    /// # Reverts
+    ///
+    /// * When the contract is paused.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use contract_base::ContractBase;
+    ///
+    /// storage {
+    ///     base: ContractBase = ContractBase::new(),
+    /// }
+    ///
+    /// fn foo() {
+    ///     storage.base.require_not_paused();
+    ///     // Do stuff here
+    /// }
+    /// ```
+    #[storage(read)]
+    pub fn require_not_paused(self) {
+        require(!self.read().paused, PauseError::Paused);
+    }
+
+    /// Ensures that the contract is paused.
+    ///
+    /// # Reverts
+    ///
+    /// * When the contract is not paused.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use contract_base::ContractBase;
+    ///
+    /// storage {
+    ///     base: ContractBase = ContractBase::new(),
+    /// }
+    ///
+    /// fn foo() {
+    ///     storage.base.require_paused();
+    ///     // Do recovery here
+    /// }
+    /// ```
+    #[storage(read)]
+    pub fn require_paused(self) {
+        require(self.read().paused, PauseError::NotPaused);
+    }
+
+    /// Ensures that the sender is the owner and that the contract is not paused.
+    ///
+    /// # Additional Information
+    ///
+    /// Guards functions which only the owner may call during normal operation, such as changing parameters.
+    ///
+    /// # Reverts
+    ///
+    /// * When the sender is not the owner.
+    /// * When the contract is paused.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use contract_base::ContractBase;
+    ///
+    /// storage {
+    ///     base: ContractBase = ContractBase::new(),
+    /// }
+    ///
+    /// fn foo() {
+    ///     storage.base.only_owner_and_not_paused();
+    ///     // Do stuff here
+    /// }
+    /// ```
+    #[storage(read)]
+    pub fn only_owner_and_not_paused(self) {
+        let base = self.read();
+        require(is_owner(base), AccessError::NotOwner);
+        require(!base.paused, PauseError::Paused);
+    }
+
+    /// Ensures that the sender is the owner and that the contract is paused.
+    ///
+    /// # Additional Information
+    ///
+    /// Guards functions which only the owner may call during an emergency, such as migrating funds.
+    ///
+    /// # Reverts
+    ///
+    /// * When the sender is not the owner.
+    /// * When the contract is not paused.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use contract_base::ContractBase;
+    ///
+    /// storage {
+    ///     base: ContractBase = ContractBase::new(),
+    /// }
+    ///
+    /// fn foo() {
+    ///     storage.base.only_owner_and_paused();
+    ///     // Do recovery here
+    /// }
+    /// ```
+    #[storage(read)]
+    pub fn only_owner_and_paused(self) {
+        let base = self.read();
+        require(is_owner(base), AccessError::NotOwner);
+        require(base.paused, PauseError::NotPaused);
+    }
+
+    /// Ensures that the sender is the owner or that the contract is paused.
+    ///
+    /// # Additional Information
+    ///
+    /// Guards functions which the owner may always call and which anyone may call during an emergency,
+    /// such as withdrawing deposits.
+    ///
+    /// # Reverts
+    ///
+    /// * When the sender is not the owner and the contract is not paused.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use contract_base::ContractBase;
+    ///
+    /// storage {
+    ///     base: ContractBase = ContractBase::new(),
+    /// }
+    ///
+    /// fn foo() {
+    ///     storage.base.only_owner_or_paused();
+    ///     // Do withdrawal here
+    /// }
+    /// ```
+    #[storage(read)]
+    pub fn only_owner_or_paused(self) {
+        let base = self.read();
+        require(base.paused || is_owner(base), AccessError::NotOwner);
+    }
+}
+
+impl StorageKey<ContractBase> {
+    /// Sets the passed identity as the initial owner.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_owner`: [Identity] - The `Identity` that will be the first owner.
+    ///
+    /// # Reverts
+    ///
+    /// * When ownership has been set before.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    /// * Writes: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use contract_base::ContractBase;
+    ///
+    /// storage {
+    ///     base: ContractBase = ContractBase::new(),
+    /// }
+    ///
+    /// fn foo(owner: Identity) {
+    ///     storage.base.set_ownership(owner);
+    ///     assert(storage.base.owner().unwrap() == owner);
+    /// }
+    /// ```
+    #[storage(read, write)]
+    pub fn set_ownership(self, new_owner: Identity) {
+        let mut base = self.read();
+        require(base.owner.is_none(), AccessError::CannotReinitialized);
+
+        base.owner = Option::Some(new_owner);
+        self.write(base);
+
+        log(OwnershipSet { new_owner });
+    }
+
+    /// Starts a two-step transfer of the ownership to the passed identity.
+    ///
+    /// # Additional Information
+    ///
+    /// The ownership is only transferred once `new_owner` calls `accept_ownership()`. Until then
+    /// the current owner keeps the ownership and may start a transfer to another identity, which
+    /// replaces the pending one.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_owner`: [Identity] - The `Identity` that may accept the ownership.
+    ///
+    /// # Reverts
+    ///
+    /// * When the sender is not the owner.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    /// * Writes: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use contract_base::ContractBase;
+    ///
+    /// storage {
+    ///     base: ContractBase = ContractBase::new(),
+    /// }
+    ///
+    /// fn foo(new_owner: Identity) {
+    ///     storage.base.start_ownership_transfer(new_owner);
+    ///     assert(storage.base.pending_owner().unwrap() == new_owner);
+    /// }
+    /// ```
+    #[storage(read, write)]
+    pub fn start_ownership_transfer(self, new_owner: Identity) {
+        let mut base = self.read();
+        require(is_owner(base), AccessError::NotOwner);
+
+        base.pending_owner = Option::Some(new_owner);
+        self.write(base);
+
+        log(OwnershipTransferStarted {
+            pending_owner: new_owner,
+            previous_owner: msg_sender().unwrap(),
+        });
+    }
+
+    /// Completes a two-step ownership transfer, making the sender the owner.
+    ///
+    /// # Reverts
+    ///
+    /// * When the sender is not the pending owner.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    /// * Writes: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use contract_base::ContractBase;
+    ///
+    /// storage {
+    ///     base: ContractBase = ContractBase::new(),
+    /// }
+    ///
+    /// fn foo() {
+    ///     // Called by the identity passed to `start_ownership_transfer()`
+    ///     storage.base.accept_ownership();
+    ///     assert(storage.base.owner().unwrap() == msg_sender().unwrap());
+    ///     assert(storage.base.pending_owner().is_none());
+    /// }
+    /// ```
+    #[storage(read, write)]
+    pub fn accept_ownership(self) {
+        let mut base = self.read();
+        let sender = msg_sender().unwrap();
+        let is_pending_owner = match base.pending_owner {
+            Option::Some(pending_owner) => pending_owner == sender,
+            Option::None => false,
+        };
+        require(is_pending_owner, AccessError::NotPendingOwner);
+
+        let previous_owner = base.owner.unwrap();
+        base.owner = Option::Some(sender);
+        base.pending_owner = Option::None;
+        self.write(base);
+
+        log(OwnershipTransferred {
+            new_owner: sender,
+            previous_owner,
+        });
+    }
+
+    /// Pauses the contract.
+    ///
+    /// # Reverts
+    ///
+    /// * When the sender is not the owner.
+    /// * When the contract is already paused.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    /// * Writes: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use contract_base::ContractBase;
+    ///
+    /// storage {
+    ///     base: ContractBase = ContractBase::new(),
+    /// }
+    ///
+    /// fn foo() {
+    ///     storage.base.pause();
+    ///     assert(storage.base.is_paused());
+    /// }
+    /// ```
+    #[storage(read, write)]
+    pub fn pause(self) {
+        let mut base = self.read();
+        require(is_owner(base), AccessError::NotOwner);
+        require(!base.paused, PauseError::Paused);
+
+        base.paused = true;
+        self.write(base);
+
+        log(Paused {
+            caller: msg_sender().unwrap(),
+        });
+    }
+
+    /// Unpauses the contract.
+    ///
+    /// # Reverts
+    ///
+    /// * When the sender is not the owner.
+    /// * When the contract is not paused.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    /// * Writes: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use contract_base::ContractBase;
+    ///
+    /// storage {
+    ///     base: ContractBase = ContractBase::new(),
+    /// }
+    ///
+    /// fn foo() {
+    ///     storage.base.unpause();
+    ///     assert(!storage.base.is_paused());
+    /// }
+    /// ```
+    #[storage(read, write)]
+    pub fn unpause(self) {
+        let mut base = self.read();
+        require(is_owner(base), AccessError::NotOwner);
+        require(base.paused, PauseError::NotPaused);
+
+        base.paused = false;
+        self.write(base);
+
+        log(Unpaused {
+            caller: msg_sender().unwrap(),
+        });
+    }
+}
+
+/// Returns whether the sender is the owner of a `ContractBase`.
+fn is_owner(base: ContractBase) -> bool {
+    match base.owner {
+        Option::Some(owner) => owner == msg_sender().unwrap(),
+        Option::None => false,
+    }
+}
@@ -345,7 +345,7 @@ impl IFP128 {
     /// fn foo() {
     ///     let ifp128 = IFP128::from_uint(128);
     ///     let floor = ifp128.floor();
-    ///     assert(floor.underlying == UFP64::from(128).floor());
+    ///     assert(floor.underlying == UFP64::from(128).trunc());
     /// }
     /// ```
     pub fn floor(self) -> Self {
@@ -401,7 +401,7 @@ impl IFP128 {
     /// fn foo() {
     ///     let ifp128 = IFP128::from_uint(128);
     ///     let ceil = ifp128.ceil();
-    ///     assert(ceil.underlying = UFP64::from(128).ceil());
+    ///     assert(ceil.underlying == UFP64::from_uint(UFP64::from(128).ceil()));
     /// }
     /// ```
     pub fn ceil(self) -> Self {
@@ -409,9 +409,9 @@ impl IFP128 {
         let mut non_negative = self.non_negative;
 
         if self.non_negative {
-            underlying = self.underlying.ceil();
+            underlying = UFP64::from_uint(self.underlying.ceil());
         } else {
-            let ceil = self.underlying.ceil();
+            let ceil = UFP64::from_uint(self.underlying.ceil());
             if ceil != self.underlying {
                 underlying = ceil + UFP64::from(1);
                 if ceil == UFP64::from(1) {
@@ -453,8 +453,8 @@ impl IFP128 {
         if self.non_negative {
             underlying = self.underlying.round();
         } else {
-            let floor = self.underlying.floor();
-            let ceil = self.underlying.ceil();
+            let floor = self.underlying.trunc();
+            let ceil = UFP64::from_uint(self.underlying.ceil());
             let diff_self_floor = self.underlying - floor;
             let diff_ceil_self = ceil - self.underlying;
             let underlying = if diff_self_floor > diff_ceil_self {
@@ -495,10 +495,10 @@ impl Power for IFP128 {
     fn pow(self, exponent: Self) -> Self {
         let non_negative = if !self.non_negative {
             // roots of negative numbers are complex numbers which we lack for now
-            assert(exponent.underlying.floor() == exponent.underlying);
+            assert(exponent.underlying.trunc() == exponent.underlying);
 
             let div_2 = exponent.underlying / UFP64::from(2);
-            div_2.floor() == div_2
+            div_2.trunc() == div_2
         } else {
             true
         };
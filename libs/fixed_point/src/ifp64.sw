@@ -401,7 +401,7 @@ impl IFP64 {
     /// fn foo() {
     ///     let ifp64 = IFP64::from_uint(128u32);
     ///     let ceil = ifp64.ceil();
-    ///     assert(ceil.underlying = UFP32::from(128u32).ceil());
+    ///     assert(ceil.underlying == UFP32::from_uint(UFP32::from(128u32).ceil()));
     /// }
     /// ```
     pub fn ceil(self) -> Self {
@@ -409,9 +409,9 @@ impl IFP64 {
         let mut non_negative = self.non_negative;
 
         if self.non_negative {
-            underlying = self.underlying.ceil();
+            underlying = UFP32::from_uint(self.underlying.ceil());
         } else {
-            let ceil = self.underlying.ceil();
+            let ceil = UFP32::from_uint(self.underlying.ceil());
             if ceil != self.underlying {
                 underlying = ceil + UFP32::from(1u32);
                 if ceil == UFP32::from(1u32) {
@@ -453,8 +453,8 @@ impl IFP64 {
         if self.non_negative {
             underlying = self.underlying.round();
         } else {
-            let floor = self.underlying.floor();
-            let ceil = self.underlying.ceil();
+            let floor = self.underlying.trunc();
+            let ceil = UFP32::from_uint(self.underlying.ceil());
             let diff_self_floor = self.underlying - floor;
             let diff_ceil_self = ceil - self.underlying;
             let underlying = if diff_self_floor > diff_ceil_self {
@@ -495,10 +495,10 @@ impl Power for IFP64 {
     fn pow(self, exponent: Self) -> Self {
         let non_negative = if !self.non_negative {
             // roots of negative numbers are complex numbers which we lack for now
-            assert(exponent.underlying.floor() == exponent.underlying);
+            assert(exponent.underlying.trunc() == exponent.underlying);
 
             let div_2 = exponent.underlying / UFP32::from(2u32);
-            div_2.floor() == div_2
+            div_2.trunc() == div_2
         } else {
             true
         };
@@ -141,6 +141,66 @@ impl UFP128 {
             value: U128::from((uint, 0)),
         }
     }
+
+    /// Creates UFP128 that corresponds to the fraction `numerator / denominator`.
+    ///
+    /// # Additional Information
+    ///
+    /// The result is rounded down to the nearest representable value.
+    ///
+    /// # Arguments
+    ///
+    /// * `numerator`: [u64] - The numerator of the fraction.
+    /// * `denominator`: [u64] - The denominator of the fraction.
+    ///
+    /// # Returns
+    ///
+    /// * [UFP128] - The newly created `UFP128` type.
+    ///
+    /// # Reverts
+    ///
+    /// * When `denominator` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use fixed_point::UFP128;
+    /// use std::u128::U128;
+    ///
+    /// fn foo() {
+    ///     let ufp128 = UFP128::from_rational(1, 2);
+    ///     assert(ufp128.underlying == U128::from((0, 9223372036854775808)));
+    /// }
+    /// ```
+    pub fn from_rational(numerator: u64, denominator: u64) -> Self {
+        assert(denominator != 0);
+
+        // A u64 numerator shifted by 64 bits divided by a non-zero u64
+        // always fits in 128 bits
+        let numerator_u256 = U256::from((0, 0, numerator, 0));
+        let res_u256 = numerator_u256 / U256::from((0, 0, 0, denominator));
+        Self::from((res_u256.c, res_u256.d))
+    }
+
+    /// Returns the integer part of `self` as an unsigned integer, discarding the fractional part.
+    ///
+    /// # Returns
+    ///
+    /// * [u64] - The integer part of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use fixed_point::UFP128;
+    ///
+    /// fn foo() {
+    ///     let ufp128 = UFP128::from_rational(7, 2);
+    ///     assert(ufp128.to_u64() == 3);
+    /// }
+    /// ```
+    pub fn to_u64(self) -> u64 {
+        self.value.upper
+    }
 }
 
 impl core::ops::Eq for UFP128 {
@@ -255,7 +315,7 @@ impl UFP128 {
     ///
     /// # Returns
     ///
-    /// * [UFP128] - The newly created `UFP128` type.
+    /// * [u64] - The largest integer less than or equal to `self`.
     ///
     /// # Examples
     ///
@@ -264,19 +324,22 @@ impl UFP128 {
     ///
     /// fn foo() {
     ///     let ufp128 = UFP128::from_uint(128);
-    ///     let floor = ufp128.floor();
-    ///     assert(floor.underlying == U128::from((0,0)));
+    ///     assert(ufp128.floor() == 128);
     /// }
     /// ```
-    pub fn floor(self) -> Self {
-        Self::from((self.value.upper, 0))
+    pub fn floor(self) -> u64 {
+        self.value.upper
     }
 
     /// Returns the smallest integer greater than or equal to `self`.
     ///
     /// # Returns
     ///
-    /// * [UFP64] - The newly created `UFP64` type.
+    /// * [u64] - The smallest integer greater than or equal to `self`.
+    ///
+    /// # Reverts
+    ///
+    /// * When the integer part of `self` is `u64::max()` and `self` has a fractional part, as the result does not fit in a `u64`.
     ///
     /// # Examples
     ///
@@ -284,17 +347,15 @@ impl UFP128 {
     /// use fixed_point::UFP128;
     ///
     /// fn foo() {
-    ///     let ufp128 = UFP128::from_uint(128);
-    ///     let ceil = ufp128.ceil();
-    ///     assert(ceil.underlying = U128::from((4294967296, 0)));
+    ///     let ufp128 = UFP128::from((1, 1));
+    ///     assert(ufp128.ceil() == 2);
     /// }
     /// ```
-    pub fn ceil(self) -> Self {
-        let val = self.value;
-        if val.lower == 0 {
-            return Self::from((val.upper, 0));
+    pub fn ceil(self) -> u64 {
+        if self.value.lower == 0 {
+            self.value.upper
         } else {
-            return Self::from((val.upper + 1, 0));
+            self.value.upper + 1
         }
     }
 }
@@ -318,8 +379,8 @@ impl UFP128 {
     /// }
     /// ```
     pub fn round(self) -> Self {
-        let floor = self.floor();
-        let ceil = self.ceil();
+        let floor = Self::from((self.floor(), 0));
+        let ceil = Self::from((self.ceil(), 0));
         let diff_self_floor = self - floor;
         let diff_ceil_self = ceil - self;
         if diff_self_floor < diff_ceil_self {
@@ -377,10 +438,29 @@ impl UFP128 {
 }
 
 impl Root for UFP128 {
+    /// Square root of a UFP128, rounded down.
     fn sqrt(self) -> Self {
-        let numerator_root = self.value.sqrt();
-        let numerator = numerator_root * U128::from((0, 2 << 32));
-        Self::from((numerator.upper, numerator.lower))
+        if self == Self::zero() {
+            return self;
+        }
+
+        // The square root of the underlying value shifted by 64 bits
+        // keeps the fixed-point structure, so we compute it on U256
+        let value_u256 = U256::from((0, 0, self.value.upper, self.value.lower)) << 64;
+
+        // Start from a value which is never below the result,
+        // based on the integer square root of the underlying value
+        let root_u128 = self.value.sqrt() + U128::from((0, 1));
+        let mut root = U256::from((0, 0, root_u128.upper, root_u128.lower)) << 32;
+
+        // Newton-Raphson iterations decrease until the result is reached
+        let mut next = (root + value_u256 / root) >> 1;
+        while next < root {
+            root = next;
+            next = (root + value_u256 / root) >> 1;
+        }
+
+        Self::from((root.c, root.d))
     }
 }
 
@@ -306,7 +306,7 @@ impl UFP32 {
     ///
     /// # Returns
     ///
-    /// * [UFP32] - The newly created `UFP32` type.
+    /// * [u32] - The largest integer less than or equal to `self`.
     ///
     /// # Examples
     ///
@@ -315,12 +315,11 @@ impl UFP32 {
     ///
     /// fn foo() {
     ///     let ufp32 = UFP32::from_uint(128u32);
-    ///     let floor = ufp32.floor();
-    ///     assert(floor.underlying == 0);
+    ///     assert(ufp32.floor() == 128u32);
     /// }
     /// ```
-    pub fn floor(self) -> Self {
-        return self.trunc();
+    pub fn floor(self) -> u32 {
+        self.value >> 16
     }
 
     /// Returns the fractional part of `self`.
@@ -356,7 +355,7 @@ impl UFP32 {
     ///
     /// # Returns
     ///
-    /// * [UFP32] - The newly created `UFP32` type.
+    /// * [u32] - The smallest integer greater than or equal to `self`.
     ///
     /// # Examples
     ///
@@ -365,16 +364,16 @@ impl UFP32 {
     ///
     /// fn foo() {
     ///     let ufp32 = UFP32::from_uint(128u32);
-    ///     let ceil = ufp32.ceil();
-    ///     assert(ceil.underlying = 65536u32);
+    ///     assert(ufp32.ceil() == 128u32);
     /// }
     /// ```
-    pub fn ceil(self) -> Self {
-        if self.fract().value != 0u32 {
-            let res = self.trunc() + UFP32::from_uint(1u32);
-            return res;
+    pub fn ceil(self) -> u32 {
+        // The integer part is at most 2 ^ 16 - 1 so adding one never overflows
+        if self.trunc() != self {
+            self.floor() + 1u32
+        } else {
+            self.floor()
         }
-        return self;
     }
 }
 
@@ -397,8 +396,8 @@ impl UFP32 {
     /// }
     /// ```
     pub fn round(self) -> Self {
-        let floor = self.floor();
-        let ceil = self.ceil();
+        let floor = self.trunc();
+        let ceil = UFP32::from_uint(self.ceil());
         let diff_self_floor = self - floor;
         let diff_ceil_self = ceil - self;
 
@@ -1,5 +1,6 @@
 library;
 // A wrapper library around the u64 type for mathematical functions operating with unsigned 64-bit fixed point numbers.
+use math::isqrt_u128;
 use std::{math::{Exponent, Power, Root}, u128::U128};
 
 /// The 64-bit unsigned fixed point number type.
@@ -127,6 +128,28 @@ impl UFP64 {
     pub fn zero() -> Self {
         Self { value: 0 }
     }
+
+    /// The value one of this type.
+    ///
+    /// # Returns
+    ///
+    /// * [UFP64] - The newly created `UFP64` type.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use fixed_point::UFP64;
+    ///
+    /// fn foo() {
+    ///     let ufp64 = UFP64::one();
+    ///     assert(ufp64 == UFP64::from_uint(1));
+    /// }
+    /// ```
+    pub fn one() -> Self {
+        Self {
+            value: Self::denominator(),
+        }
+    }
 }
 
 impl core::ops::Eq for UFP64 {
@@ -238,6 +261,53 @@ impl UFP64 {
             value: Self::denominator() * uint,
         }
     }
+
+    /// Creates UFP64 that corresponds to the fraction `numerator / denominator`.
+    ///
+    /// # Additional Information
+    ///
+    /// The result is rounded down to the nearest representable value.
+    ///
+    /// # Arguments
+    ///
+    /// * `numerator`: [u64] - The numerator of the fraction.
+    /// * `denominator`: [u64] - The denominator of the fraction.
+    ///
+    /// # Returns
+    ///
+    /// * [UFP64] - The newly created `UFP64` type.
+    ///
+    /// # Reverts
+    ///
+    /// * When `denominator` is zero.
+    /// * When the result does not fit in a `UFP64`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use fixed_point::UFP64;
+    ///
+    /// fn foo() {
+    ///     let ufp64 = UFP64::from_rational(1, 2);
+    ///     assert(ufp64.underlying == 2147483648);
+    /// }
+    /// ```
+    pub fn from_rational(numerator: u64, denominator: u64) -> Self {
+        assert(denominator != 0);
+
+        // Conversion to U128 done to ensure no overflow happens
+        // when shifting the numerator into the fixed-point structure
+        let numerator_u128 = U128::from((0, numerator)) << 32;
+        let res_u128 = numerator_u128 / U128::from((0, denominator));
+
+        if res_u128.upper != 0 {
+            // panic on overflow
+            revert(0);
+        }
+        Self {
+            value: res_u128.lower,
+        }
+    }
 }
 
 impl UFP64 {
@@ -306,7 +376,7 @@ impl UFP64 {
     ///
     /// # Returns
     ///
-    /// * [UFP64] - The newly created `UFP64` type.
+    /// * [u64] - The largest integer less than or equal to `self`.
     ///
     /// # Examples
     ///
@@ -314,13 +384,12 @@ impl UFP64 {
     /// use fixed_point::UFP64;
     ///
     /// fn foo() {
-    ///     let ufp64 = UFP64::from_uint(128);
-    ///     let floor = ufp64.floor();
-    ///     assert(floor.underlying == 0);
+    ///     let ufp64 = UFP64::from_rational(7, 2);
+    ///     assert(ufp64.floor() == 3);
     /// }
     /// ```
-    pub fn floor(self) -> Self {
-        return self.trunc();
+    pub fn floor(self) -> u64 {
+        self.value >> 32
     }
 
     /// Returns the fractional part of `self`.
@@ -356,7 +425,7 @@ impl UFP64 {
     ///
     /// # Returns
     ///
-    /// * [UFP64] - The newly created `UFP64` type.
+    /// * [u64] - The smallest integer greater than or equal to `self`.
     ///
     /// # Examples
     ///
@@ -364,17 +433,17 @@ impl UFP64 {
     /// use fixed_point::UFP64;
     ///
     /// fn foo() {
-    ///     let ufp64 = UFP64::from_uint(128);
-    ///     let ceil = ufp64.ceil();
-    ///     assert(ceil.underlying = 4294967296);
+    ///     let ufp64 = UFP64::from_rational(7, 2);
+    ///     assert(ufp64.ceil() == 4);
     /// }
     /// ```
-    pub fn ceil(self) -> Self {
+    pub fn ceil(self) -> u64 {
+        // The integer part is at most 2 ^ 32 - 1 so adding one never overflows
         if self.fract().value != 0 {
-            let res = self.trunc() + UFP64::from_uint(1);
-            return res;
+            self.floor() + 1
+        } else {
+            self.floor()
         }
-        return self;
     }
 }
 
@@ -397,8 +466,8 @@ impl UFP64 {
     /// }
     /// ```
     pub fn round(self) -> Self {
-        let floor = self.floor();
-        let ceil = self.ceil();
+        let floor = self.trunc();
+        let ceil = UFP64::from_uint(self.ceil());
         let diff_self_floor = self - floor;
         let diff_ceil_self = ceil - self;
 
@@ -409,17 +478,66 @@ impl UFP64 {
             return ceil;
         }
     }
+
+    /// Converts `self` to the nearest integer. Round half-way cases up.
+    ///
+    /// # Returns
+    ///
+    /// * [u64] - The nearest integer to `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use fixed_point::UFP64;
+    ///
+    /// fn foo() {
+    ///     let ufp64 = UFP64::from_rational(1, 2);
+    ///     assert(ufp64.to_u64_round() == 1);
+    ///
+    ///     let ufp64 = UFP64::from_rational(1, 3);
+    ///     assert(ufp64.to_u64_round() == 0);
+    /// }
+    /// ```
+    pub fn to_u64_round(self) -> u64 {
+        let integer = self.value >> 32;
+        let fraction = self.value & (Self::denominator() - 1);
+
+        // The integer part is at most 2 ^ 32 - 1 so adding one never overflows
+        if fraction >= Self::denominator() >> 1 {
+            integer + 1
+        } else {
+            integer
+        }
+    }
 }
 
 impl Root for UFP64 {
-    /// Sqaure root for UFP64
+    /// Square root for UFP64, rounded down to the nearest representable value.
+    ///
+    /// # Additional Information
+    ///
+    /// The result is within one unit in the last place of the exact square root.
+    /// Uses Newton-Raphson iterations on a `U128`, which always terminate, including for the smallest inputs.
+    ///
+    /// # Returns
+    ///
+    /// * [UFP64] - The square root of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use fixed_point::ufp64::UFP64;
+    ///
+    /// fn foo() {
+    ///     let root = UFP64::from_uint(4).sqrt();
+    ///     assert(root == UFP64::from_uint(2));
+    /// }
+    /// ```
     fn sqrt(self) -> Self {
-        let nominator_root = self.value.sqrt();
-        // Need to multiply over 2 ^ 16, as the square root of the denominator 
-        // is also taken and we need to ensure that the denominator is constant
-        let nominator = nominator_root << 16;
+        // sqrt(value / 2 ^ 32) * 2 ^ 32 == sqrt(value * 2 ^ 32), which is at most 2 ^ 48
+        let scaled = U128::from((self.value >> 32, self.value << 32));
         Self {
-            value: nominator,
+            value: isqrt_u128(scaled),
         }
     }
 }
@@ -0,0 +1,243 @@
+library;
+
+use collection_backend::Backend;
+
+/// A binary min-heap on any `Backend`, which orders its items by priority.
+///
+/// # Additional Information
+///
+/// Each item is a `(priority, data)` tuple. The item at index `i` of the backend has its children at indices
+/// `2 * i + 1` and `2 * i + 2` and its priority is never greater than the priorities of its children, so the item
+/// with the lowest priority is always at index `0`. Items with equal priorities are not returned in any particular order.
+/// A heap on a `StorageBackend` persists between calls, while a heap on a `MemBackend` only lives for the duration of a call.
+pub struct BackendHeap<B> {
+    /// The backend which holds the items in heap order.
+    backend: B,
+}
+
+impl<B> BackendHeap<B>
+where
+    B: Backend<(u64, b256)>,
+{
+    /// Creates a heap on a backend.
+    ///
+    /// # Additional Information
+    ///
+    /// The items already in `backend` must be in heap order, which holds for any backend that is empty or was
+    /// only modified by a `BackendHeap`. A heap in storage is recreated on the same `StorageBackend` in every call.
+    ///
+    /// # Arguments
+    ///
+    /// * `backend`: [B] - The backend which holds the items of the heap.
+    ///
+    /// # Returns
+    ///
+    /// * [BackendHeap<B>] - The newly created `BackendHeap` struct.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use collection_backend::{MemBackend, StorageBackend};
+    /// use heap::backend_heap::BackendHeap;
+    ///
+    /// storage {
+    ///     heap: StorageBackend<(u64, b256)> = StorageBackend {},
+    /// }
+    ///
+    /// #[storage(read)]
+    /// fn foo() {
+    ///     let stored_heap = BackendHeap::new(storage.heap);
+    ///     let heap = BackendHeap::new(MemBackend::new());
+    ///     assert(heap.len() == stored_heap.len());
+    /// }
+    /// ```
+    pub fn new(backend: B) -> Self {
+        Self { backend }
+    }
+
+    /// Adds an item to the heap.
+    ///
+    /// # Additional Information
+    ///
+    /// The item is appended to the end of the heap and is then sifted up until its parent has a priority no greater than its own.
+    ///
+    /// # Arguments
+    ///
+    /// * `priority`: [u64] - The priority of the item, where lower values are popped first.
+    /// * `data`: [b256] - The data of the item.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: At most `2 + 3 * log2(self.len())` on a `StorageBackend`
+    /// * Writes: At most `3 + log2(self.len())` on a `StorageBackend`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use collection_backend::MemBackend;
+    /// use heap::backend_heap::BackendHeap;
+    ///
+    /// #[storage(read, write)]
+    /// fn foo() {
+    ///     let mut heap = BackendHeap::new(MemBackend::new());
+    ///     heap.push(5, b256::min());
+    ///     heap.push(1, b256::max());
+    ///     assert(heap.peek().unwrap().0 == 1);
+    /// }
+    /// ```
+    #[storage(read, write)]
+    pub fn push(ref mut self, priority: u64, data: b256) {
+        let mut index = self.backend.len();
+        self.backend.push((priority, data));
+
+        while index > 0 {
+            let parent = (index - 1) / 2;
+            let parent_item = self.backend.get(parent);
+            if !(priority < parent_item.0) {
+                break;
+            }
+
+            self.backend.set(index, parent_item);
+            index = parent;
+        }
+
+        self.backend.set(index, (priority, data));
+    }
+
+    /// Removes the item with the lowest priority from the heap and returns it.
+    ///
+    /// # Additional Information
+    ///
+    /// The last item of the heap takes the place of the removed item and is then sifted down until
+    /// both of its children have a priority no lower than its own.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<(u64, b256)>] - The `(priority, data)` of the item with the lowest priority or `None` if the heap is empty.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: At most `6 + 5 * log2(self.len())` on a `StorageBackend`
+    /// * Writes: At most `2 + log2(self.len())` on a `StorageBackend`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use collection_backend::MemBackend;
+    /// use heap::backend_heap::BackendHeap;
+    ///
+    /// #[storage(read, write)]
+    /// fn foo() {
+    ///     let mut heap = BackendHeap::new(MemBackend::new());
+    ///     heap.push(5, b256::min());
+    ///     heap.push(1, b256::max());
+    ///     assert(heap.pop().unwrap().0 == 1);
+    ///     assert(heap.pop().unwrap().0 == 5);
+    ///     assert(heap.pop().is_none());
+    /// }
+    /// ```
+    #[storage(read, write)]
+    pub fn pop(ref mut self) -> Option<(u64, b256)> {
+        let len = self.backend.len();
+        if len == 0 {
+            return Option::None;
+        }
+
+        let min = self.backend.get(0);
+        let last = self.backend.pop().unwrap();
+        let len = len - 1;
+        if len == 0 {
+            return Option::Some(min);
+        }
+
+        let mut index = 0;
+        while true {
+            let left = 2 * index + 1;
+            if left >= len {
+                break;
+            }
+
+            let mut child = left;
+            let mut child_item = self.backend.get(left);
+            let right = left + 1;
+            if right < len {
+                let right_item = self.backend.get(right);
+                if right_item.0 < child_item.0 {
+                    child = right;
+                    child_item = right_item;
+                }
+            }
+
+            if !(child_item.0 < last.0) {
+                break;
+            }
+
+            self.backend.set(index, child_item);
+            index = child;
+        }
+
+        self.backend.set(index, last);
+        Option::Some(min)
+    }
+
+    /// Returns the item with the lowest priority without removing it from the heap.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<(u64, b256)>] - The `(priority, data)` of the item with the lowest priority or `None` if the heap is empty.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `3` on a `StorageBackend`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use collection_backend::MemBackend;
+    /// use heap::backend_heap::BackendHeap;
+    ///
+    /// #[storage(read, write)]
+    /// fn foo() {
+    ///     let mut heap = BackendHeap::new(MemBackend::new());
+    ///     assert(heap.peek().is_none());
+    ///     heap.push(5, b256::min());
+    ///     assert(heap.peek().unwrap().0 == 5);
+    /// }
+    /// ```
+    #[storage(read)]
+    pub fn peek(self) -> Option<(u64, b256)> {
+        if self.backend.len() == 0 {
+            return Option::None;
+        }
+
+        Option::Some(self.backend.get(0))
+    }
+
+    /// Returns the number of items in the heap.
+    ///
+    /// # Returns
+    ///
+    /// * [u64] - The number of items which have been pushed and not yet popped.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1` on a `StorageBackend`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use collection_backend::MemBackend;
+    /// use heap::backend_heap::BackendHeap;
+    ///
+    /// #[storage(read, write)]
+    /// fn foo() {
+    ///     let mut heap = BackendHeap::new(MemBackend::new());
+    ///     heap.push(5, b256::min());
+    ///     assert(heap.len() == 1);
+    /// }
+    /// ```
+    #[storage(read)]
+    pub fn len(self) -> u64 {
+        self.backend.len()
+    }
+}
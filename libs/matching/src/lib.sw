@@ -0,0 +1,121 @@
+library;
+
+use signed_integers::i64::I64;
+
+/// Returns the assignment of rows to columns of a 4x4 cost matrix with the lowest total cost.
+///
+/// # Additional Information
+///
+/// The assignment is found with the Hungarian algorithm, which keeps a potential for every row and column and
+/// adds one row at a time along the path of lowest reduced cost. All of its state is held in fixed size arrays,
+/// so nothing is allocated. Costs may be negative, such as when they are the negated profits of matching orders.
+/// When more than one assignment has the lowest total cost, any of them may be returned.
+///
+/// # Arguments
+///
+/// * `cost`: [[[I64; 4]; 4]] - The cost of assigning the row to the column, indexed as `cost[row][column]`.
+///
+/// # Returns
+///
+/// * [([u8; 4], I64)] - The column assigned to each row and the total cost of the assignment.
+///
+/// # Reverts
+///
+/// * When the costs are so large in magnitude that the potentials of the algorithm or the total cost overflow.
+///
+/// # Examples
+///
+/// ```sway
+/// use matching::min_cost_assignment_4x4;
+/// use signed_integers::i64::I64;
+///
+/// fn foo() {
+///     let row = [I64::from(1u64), I64::from(2u64), I64::from(3u64), I64::from(4u64)];
+///     let (assignment, total) = min_cost_assignment_4x4([row, row, row, row]);
+///     assert(total == I64::from(10u64));
+/// }
+/// ```
+pub fn min_cost_assignment_4x4(cost: [[I64; 4]; 4]) -> ([u8; 4], I64) {
+    let zero = I64::new();
+    let infinity = I64::max();
+
+    // Index 0 of the columns is a virtual column, which holds the row being added
+    let mut row_potential = [zero; 5];
+    let mut column_potential = [zero; 5];
+    let mut row_of_column = [0; 5];
+    let mut previous_column = [0; 5];
+
+    let mut row = 1;
+    while row <= 4 {
+        row_of_column[0] = row;
+        let mut column = 0;
+        let mut min_reduced_cost = [infinity; 5];
+        let mut visited = [false; 5];
+
+        // Grow the tree of visited columns until it reaches a column without a row
+        while true {
+            visited[column] = true;
+            let current_row = row_of_column[column];
+            let mut delta = infinity;
+            let mut next_column = 0;
+
+            let mut j = 1;
+            while j <= 4 {
+                if !visited[j] {
+                    let reduced_cost = cost[current_row - 1][j - 1] - row_potential[current_row] - column_potential[j];
+                    if reduced_cost < min_reduced_cost[j] {
+                        min_reduced_cost[j] = reduced_cost;
+                        previous_column[j] = column;
+                    }
+                    if min_reduced_cost[j] < delta {
+                        delta = min_reduced_cost[j];
+                        next_column = j;
+                    }
+                }
+                j = j + 1;
+            }
+
+            let mut j = 0;
+            while j <= 4 {
+                if visited[j] {
+                    row_potential[row_of_column[j]] = row_potential[row_of_column[j]] + delta;
+                    column_potential[j] = column_potential[j] - delta;
+                } else {
+                    min_reduced_cost[j] = min_reduced_cost[j] - delta;
+                }
+                j = j + 1;
+            }
+
+            column = next_column;
+            if row_of_column[column] == 0 {
+                break;
+            }
+        }
+
+        // Shift the rows along the path back to the virtual column
+        while true {
+            let previous = previous_column[column];
+            row_of_column[column] = row_of_column[previous];
+            column = previous;
+            if column == 0 {
+                break;
+            }
+        }
+
+        row = row + 1;
+    }
+
+    let mut assignment = [0u8; 4];
+    let mut total = zero;
+    let mut j = 1;
+    let mut assigned_column = 0u8;
+    while j <= 4 {
+        let assigned_row = row_of_column[j] - 1;
+        assignment[assigned_row] = assigned_column;
+        total = total + cost[assigned_row][j - 1];
+        j = j + 1;
+        assigned_column = assigned_column + 1;
+    }
+
+    (assignment, total)
+}
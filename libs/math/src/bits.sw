@@ -0,0 +1,222 @@
+library;
+
+/// Counts the number of set bits in a `u64`.
+///
+/// # Additional Information
+///
+/// The FuelVM has no instruction to count bits, so the bits are summed in parallel within each byte.
+///
+/// # Arguments
+///
+/// * `x`: [u64] - The value of which to count the bits.
+///
+/// # Returns
+///
+/// * [u32] - The number of bits which are one.
+///
+/// # Examples
+///
+/// ```sway
+/// use math::bits::popcount_u64;
+///
+/// fn foo() {
+///     assert(popcount_u64(0b1011) == 3);
+///     assert(popcount_u64(u64::max()) == 64);
+/// }
+/// ```
+pub fn popcount_u64(x: u64) -> u32 {
+    // Count the bits of each 2-bit, 4-bit and 8-bit field
+    let mut count = x - ((x >> 1) & 0x5555555555555555);
+    count = (count & 0x3333333333333333) + ((count >> 2) & 0x3333333333333333);
+    count = (count + (count >> 4)) & 0x0f0f0f0f0f0f0f0f;
+
+    // Sum the bytes into the lowest byte. Shifting avoids the overflow of a multiplication.
+    count = count + (count >> 8);
+    count = count + (count >> 16);
+    count = count + (count >> 32);
+    asm(ptr: count & 0x7f) { ptr: u32 }
+}
+
+/// Counts the number of zero bits above the highest set bit of a `u64`.
+///
+/// # Arguments
+///
+/// * `x`: [u64] - The value of which to count the bits.
+///
+/// # Returns
+///
+/// * [u32] - The number of leading zero bits, or `64` if `x` is zero.
+///
+/// # Examples
+///
+/// ```sway
+/// use math::bits::leading_zeros_u64;
+///
+/// fn foo() {
+///     assert(leading_zeros_u64(1) == 63);
+///     assert(leading_zeros_u64(0) == 64);
+/// }
+/// ```
+pub fn leading_zeros_u64(x: u64) -> u32 {
+    if x == 0 {
+        return 64;
+    }
+
+    // Binary search for the highest set bit
+    let mut value = x;
+    let mut count = 0u32;
+    if value >> 32 == 0 {
+        count += 32;
+        value = value << 32;
+    }
+    if value >> 48 == 0 {
+        count += 16;
+        value = value << 16;
+    }
+    if value >> 56 == 0 {
+        count += 8;
+        value = value << 8;
+    }
+    if value >> 60 == 0 {
+        count += 4;
+        value = value << 4;
+    }
+    if value >> 62 == 0 {
+        count += 2;
+        value = value << 2;
+    }
+    if value >> 63 == 0 {
+        count += 1;
+    }
+    count
+}
+
+/// Counts the number of zero bits below the lowest set bit of a `u64`.
+///
+/// # Arguments
+///
+/// * `x`: [u64] - The value of which to count the bits.
+///
+/// # Returns
+///
+/// * [u32] - The number of trailing zero bits, or `64` if `x` is zero.
+///
+/// # Examples
+///
+/// ```sway
+/// use math::bits::trailing_zeros_u64;
+///
+/// fn foo() {
+///     assert(trailing_zeros_u64(8) == 3);
+///     assert(trailing_zeros_u64(0) == 64);
+/// }
+/// ```
+pub fn trailing_zeros_u64(x: u64) -> u32 {
+    if x == 0 {
+        return 64;
+    }
+
+    // Binary search for the lowest set bit
+    let mut value = x;
+    let mut count = 0u32;
+    if value & 0xffffffff == 0 {
+        count += 32;
+        value = value >> 32;
+    }
+    if value & 0xffff == 0 {
+        count += 16;
+        value = value >> 16;
+    }
+    if value & 0xff == 0 {
+        count += 8;
+        value = value >> 8;
+    }
+    if value & 0xf == 0 {
+        count += 4;
+        value = value >> 4;
+    }
+    if value & 0x3 == 0 {
+        count += 2;
+        value = value >> 2;
+    }
+    if value & 0x1 == 0 {
+        count += 1;
+    }
+    count
+}
+
+/// Counts the number of set bits in a `u32`.
+///
+/// # Arguments
+///
+/// * `x`: [u32] - The value of which to count the bits.
+///
+/// # Returns
+///
+/// * [u32] - The number of bits which are one.
+///
+/// # Examples
+///
+/// ```sway
+/// use math::bits::popcount_u32;
+///
+/// fn foo() {
+///     assert(popcount_u32(0b1011u32) == 3);
+///     assert(popcount_u32(u32::max()) == 32);
+/// }
+/// ```
+pub fn popcount_u32(x: u32) -> u32 {
+    popcount_u64(x.as_u64())
+}
+
+/// Counts the number of zero bits above the highest set bit of a `u32`.
+///
+/// # Arguments
+///
+/// * `x`: [u32] - The value of which to count the bits.
+///
+/// # Returns
+///
+/// * [u32] - The number of leading zero bits, or `32` if `x` is zero.
+///
+/// # Examples
+///
+/// ```sway
+/// use math::bits::leading_zeros_u32;
+///
+/// fn foo() {
+///     assert(leading_zeros_u32(1u32) == 31);
+///     assert(leading_zeros_u32(0u32) == 32);
+/// }
+/// ```
+pub fn leading_zeros_u32(x: u32) -> u32 {
+    // The upper 32 bits of the widened value are always zero
+    leading_zeros_u64(x.as_u64()) - 32
+}
+
+/// Counts the number of zero bits below the lowest set bit of a `u32`.
+///
+/// # Arguments
+///
+/// * `x`: [u32] - The value of which to count the bits.
+///
+/// # Returns
+///
+/// * [u32] - The number of trailing zero bits, or `32` if `x` is zero.
+///
+/// # Examples
+///
+/// ```sway
+/// use math::bits::trailing_zeros_u32;
+///
+/// fn foo() {
+///     assert(trailing_zeros_u32(8u32) == 3);
+///     assert(trailing_zeros_u32(0u32) == 32);
+/// }
+/// ```
+pub fn trailing_zeros_u32(x: u32) -> u32 {
+    if x == 0 {
+        return 32;
+    }
+    trailing_zeros_u64(x.as_u64())
+}
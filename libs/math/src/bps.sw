@@ -0,0 +1,89 @@
+library;
+
+use ::errors::MathError;
+use std::u128::U128;
+
+/// The number of basis points in a whole, as one basis point is 0.01%.
+pub const BPS_DIVISOR: u64 = 10_000;
+/// The largest number of basis points which may be applied, which is 100%.
+pub const MAX_BPS: u64 = 10_000;
+
+/// Computes the portion of `amount` given by a number of basis points, rounded down.
+///
+/// # Additional Information
+///
+/// The product of `amount` and `bps` is computed in 128 bits, so `amount` may be any `u64`.
+///
+/// # Arguments
+///
+/// * `amount`: [u64] - The amount of which to take a portion.
+/// * `bps`: [u64] - The portion in basis points.
+///
+/// # Returns
+///
+/// * [u64] - `amount * bps / 10_000`.
+///
+/// # Reverts
+///
+/// * When `bps` is greater than `MAX_BPS`.
+///
+/// # Examples
+///
+/// ```sway
+/// use math::bps::apply_bps;
+///
+/// fn foo() {
+///     // 0.5% of 10_000
+///     assert(apply_bps(10_000, 50) == 50);
+///     assert(apply_bps(u64::max(), 10_000) == u64::max());
+/// }
+/// ```
+pub fn apply_bps(amount: u64, bps: u64) -> u64 {
+    require(bps <= MAX_BPS, MathError::ExceedsMaxBps);
+
+    let portion = U128::from((0, amount)) * U128::from((0, bps)) / U128::from((0, BPS_DIVISOR));
+    // As `bps` is at most `BPS_DIVISOR` the portion is never above `amount`
+    portion.lower
+}
+
+/// Computes the portion of a `U128` amount given by a number of basis points, rounded down.
+///
+/// # Additional Information
+///
+/// `amount` is split into a multiple of `BPS_DIVISOR` and a remainder, so no part of the computation exceeds 128 bits.
+///
+/// # Arguments
+///
+/// * `amount`: [U128] - The amount of which to take a portion.
+/// * `bps`: [u64] - The portion in basis points.
+///
+/// # Returns
+///
+/// * [U128] - `amount * bps / 10_000`.
+///
+/// # Reverts
+///
+/// * When `bps` is greater than `MAX_BPS`.
+///
+/// # Examples
+///
+/// ```sway
+/// use math::bps::apply_bps_u128;
+/// use std::u128::U128;
+///
+/// fn foo() {
+///     let res = apply_bps_u128(U128::max(), 5_000);
+///     assert(res == U128::max() >> 1);
+/// }
+/// ```
+pub fn apply_bps_u128(amount: U128, bps: u64) -> U128 {
+    require(bps <= MAX_BPS, MathError::ExceedsMaxBps);
+
+    let divisor = U128::from((0, BPS_DIVISOR));
+    let bps = U128::from((0, bps));
+    let quotient = amount / divisor;
+    let remainder = amount - quotient * divisor;
+
+    // amount * bps / divisor == quotient * bps + remainder * bps / divisor
+    quotient * bps + remainder * bps / divisor
+}
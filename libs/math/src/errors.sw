@@ -0,0 +1,13 @@
+library;
+
+/// Error log for when a math operation cannot be completed.
+pub enum MathError {
+    /// Emitted when the result of an operation does not fit in the type.
+    Overflow: (),
+    /// Emitted when the lower bound of a range is greater than its upper bound.
+    InvalidRange: (),
+    /// Emitted when the result of an operation is mathematically undefined.
+    Undefined: (),
+    /// Emitted when a number of basis points is greater than `MAX_BPS`.
+    ExceedsMaxBps: (),
+}
@@ -0,0 +1,567 @@
+library;
+
+pub mod bits;
+pub mod bps;
+pub mod errors;
+
+use bits::leading_zeros_u64;
+use errors::MathError;
+use std::u128::U128;
+
+/// Returns the number of bits needed to represent `n`.
+fn bit_length(n: u64) -> u64 {
+    (64 - leading_zeros_u64(n)).as_u64()
+}
+
+/// Multiplies two `u64` values, returning `None` on overflow.
+fn checked_mul_u64(a: u64, b: u64) -> Option<u64> {
+    let product = U128::from((0, a)) * U128::from((0, b));
+    if product.upper != 0 {
+        return Option::None;
+    }
+    Option::Some(product.lower)
+}
+
+/// Computes the integer square root of a `u64`, rounded down.
+///
+/// # Additional Information
+///
+/// The result `r` always satisfies `r * r <= n < (r + 1) * (r + 1)`.
+/// Uses Newton-Raphson iterations starting from the smallest power of two which is not below the root.
+///
+/// # Arguments
+///
+/// * `n`: [u64] - The number of which to compute the square root.
+///
+/// # Returns
+///
+/// * [u64] - The largest integer whose square is not greater than `n`.
+///
+/// # Examples
+///
+/// ```sway
+/// use math::isqrt_u64;
+///
+/// fn foo() {
+///     assert(isqrt_u64(16) == 4);
+///     assert(isqrt_u64(17) == 4);
+/// }
+/// ```
+pub fn isqrt_u64(n: u64) -> u64 {
+    if n < 2 {
+        return n;
+    }
+
+    // 2 ^ ceil(bits / 2) is never below the root, so the iterations decrease
+    let mut root = 1 << ((bit_length(n) + 1) / 2);
+    let mut next = (root + n / root) >> 1;
+    while next < root {
+        root = next;
+        next = (root + n / root) >> 1;
+    }
+    root
+}
+
+/// Computes the integer square root of a `U128`, rounded down.
+///
+/// # Additional Information
+///
+/// The result `r` always satisfies `r * r <= n < (r + 1) * (r + 1)`.
+/// Uses Newton-Raphson iterations starting from the smallest power of two which is not below the root.
+///
+/// # Arguments
+///
+/// * `n`: [U128] - The number of which to compute the square root.
+///
+/// # Returns
+///
+/// * [u64] - The largest integer whose square is not greater than `n`.
+///
+/// # Examples
+///
+/// ```sway
+/// use math::isqrt_u128;
+/// use std::u128::U128;
+///
+/// fn foo() {
+///     assert(isqrt_u128(U128::from((1, 0))) == 4294967296);
+///     assert(isqrt_u128(U128::max()) == u64::max());
+/// }
+/// ```
+pub fn isqrt_u128(n: U128) -> u64 {
+    if n.upper == 0 {
+        return isqrt_u64(n.lower);
+    }
+
+    // 2 ^ ceil(bits / 2) is never below the root, so the iterations decrease
+    let bits = 64 + bit_length(n.upper);
+    let mut root = U128::from((0, 1)) << ((bits + 1) / 2);
+    let mut next = (root + n / root) >> 1;
+    while next < root {
+        root = next;
+        next = (root + n / root) >> 1;
+    }
+    root.lower
+}
+
+/// Raises a `u64` to the power of `exponent`, returning `None` on overflow.
+///
+/// # Additional Information
+///
+/// Uses binary exponentiation, which takes at most `2 * log2(exponent)` multiplications.
+/// `0` to the power of `0` is defined as `1`.
+///
+/// # Arguments
+///
+/// * `base`: [u64] - The number to raise.
+/// * `exponent`: [u32] - The power to raise `base` to.
+///
+/// # Returns
+///
+/// * [Option<u64>] - `base` raised to the power of `exponent`, or `None` if the result does not fit in a `u64`.
+///
+/// # Examples
+///
+/// ```sway
+/// use math::checked_pow_u64;
+///
+/// fn foo() {
+///     assert(checked_pow_u64(3, 4).unwrap() == 81);
+///     assert(checked_pow_u64(2, 64).is_none());
+/// }
+/// ```
+pub fn checked_pow_u64(base: u64, exponent: u32) -> Option<u64> {
+    let mut result = 1;
+    let mut base = base;
+    let mut exponent = exponent;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            match checked_mul_u64(result, base) {
+                Option::Some(product) => result = product,
+                Option::None => return Option::None,
+            }
+        }
+        exponent = exponent >> 1;
+        // Only square when it is needed, as squaring after the last bit may overflow
+        if exponent > 0 {
+            match checked_mul_u64(base, base) {
+                Option::Some(square) => base = square,
+                Option::None => return Option::None,
+            }
+        }
+    }
+    Option::Some(result)
+}
+
+/// Raises a `u64` to the power of `exponent`.
+///
+/// # Additional Information
+///
+/// Uses binary exponentiation, which takes at most `2 * log2(exponent)` multiplications.
+/// `0` to the power of `0` is defined as `1`.
+///
+/// # Arguments
+///
+/// * `base`: [u64] - The number to raise.
+/// * `exponent`: [u32] - The power to raise `base` to.
+///
+/// # Returns
+///
+/// * [u64] - `base` raised to the power of `exponent`.
+///
+/// # Reverts
+///
+/// * When the result does not fit in a `u64`.
+///
+/// # Examples
+///
+/// ```sway
+/// use math::pow_u64;
+///
+/// fn foo() {
+///     assert(pow_u64(3, 4) == 81);
+///     assert(pow_u64(0, 0) == 1);
+/// }
+/// ```
+pub fn pow_u64(base: u64, exponent: u32) -> u64 {
+    let result = checked_pow_u64(base, exponent);
+    require(result.is_some(), MathError::Overflow);
+    result.unwrap()
+}
+
+/// Computes the greatest common divisor of two `u64` values.
+///
+/// # Additional Information
+///
+/// Uses the binary GCD algorithm (Stein's algorithm), which only needs shifts and subtractions.
+/// `gcd(0, x)` and `gcd(x, 0)` are `x`, so `gcd(0, 0)` is `0`.
+///
+/// # Arguments
+///
+/// * `a`: [u64] - The first number.
+/// * `b`: [u64] - The second number.
+///
+/// # Returns
+///
+/// * [u64] - The largest number which divides both `a` and `b`.
+///
+/// # Examples
+///
+/// ```sway
+/// use math::gcd;
+///
+/// fn foo() {
+///     assert(gcd(12, 18) == 6);
+///     assert(gcd(0, 7) == 7);
+/// }
+/// ```
+pub fn gcd(a: u64, b: u64) -> u64 {
+    if a == 0 {
+        return b;
+    }
+    if b == 0 {
+        return a;
+    }
+
+    // Remove the common factors of two, which are restored at the end
+    let mut a = a;
+    let mut b = b;
+    let mut shift = 0;
+    while (a | b) & 1 == 0 {
+        a = a >> 1;
+        b = b >> 1;
+        shift += 1;
+    }
+
+    while a & 1 == 0 {
+        a = a >> 1;
+    }
+
+    // `a` is always odd from here on
+    while b != 0 {
+        while b & 1 == 0 {
+            b = b >> 1;
+        }
+        if a > b {
+            let temp = a;
+            a = b;
+            b = temp;
+        }
+        b = b - a;
+    }
+
+    a << shift
+}
+
+/// Computes the least common multiple of two `u64` values.
+///
+/// # Additional Information
+///
+/// `lcm(0, x)` and `lcm(x, 0)` are `0`.
+///
+/// # Arguments
+///
+/// * `a`: [u64] - The first number.
+/// * `b`: [u64] - The second number.
+///
+/// # Returns
+///
+/// * [u64] - The smallest number which is a multiple of both `a` and `b`.
+///
+/// # Reverts
+///
+/// * When the result does not fit in a `u64`.
+///
+/// # Examples
+///
+/// ```sway
+/// use math::lcm;
+///
+/// fn foo() {
+///     assert(lcm(4, 6) == 12);
+///     assert(lcm(0, 7) == 0);
+/// }
+/// ```
+pub fn lcm(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+
+    // Dividing before multiplying keeps the intermediate value within the result
+    let result = checked_mul_u64(a / gcd(a, b), b);
+    require(result.is_some(), MathError::Overflow);
+    result.unwrap()
+}
+
+/// Restricts a number to a range.
+pub trait Clamp {
+    /// Restricts `self` to the range from `min` to `max`, inclusive.
+    ///
+    /// # Arguments
+    ///
+    /// * `min`: [Self] - The lower bound of the range.
+    /// * `max`: [Self] - The upper bound of the range.
+    ///
+    /// # Returns
+    ///
+    /// * [Self] - `min` if `self` is less than `min`, `max` if `self` is greater than `max`, otherwise `self`.
+    ///
+    /// # Reverts
+    ///
+    /// * When `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use math::Clamp;
+    /// use std::u128::U128;
+    ///
+    /// fn foo() {
+    ///     assert(15u64.clamp(0, 10) == 10);
+    ///     assert(5u64.clamp(0, 10) == 5);
+    ///
+    ///     let res = U128::max().clamp(U128::from((0, 0)), U128::from((1, 0)));
+    ///     assert(res == U128::from((1, 0)));
+    /// }
+    /// ```
+    fn clamp(self, min: Self, max: Self) -> Self;
+}
+
+impl Clamp for u64 {
+    fn clamp(self, min: Self, max: Self) -> Self {
+        require(!(min > max), MathError::InvalidRange);
+
+        if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
+        }
+    }
+}
+
+impl Clamp for U128 {
+    fn clamp(self, min: Self, max: Self) -> Self {
+        require(!(min > max), MathError::InvalidRange);
+
+        if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
+        }
+    }
+}
+
+/// Computes the binary logarithm of a `u64`, rounded down.
+///
+/// # Additional Information
+///
+/// The result is the position of the highest set bit, which is found from the leading zeros of `n`.
+///
+/// # Arguments
+///
+/// * `n`: [u64] - The number of which to compute the logarithm.
+///
+/// # Returns
+///
+/// * [u32] - The largest integer `k` such that `2 ^ k <= n`.
+///
+/// # Reverts
+///
+/// * When `n` is zero.
+///
+/// # Examples
+///
+/// ```sway
+/// use math::log2_u64;
+///
+/// fn foo() {
+///     assert(log2_u64(1) == 0);
+///     assert(log2_u64(1023) == 9);
+///     assert(log2_u64(1024) == 10);
+/// }
+/// ```
+pub fn log2_u64(n: u64) -> u32 {
+    require(n != 0, MathError::Undefined);
+    63 - leading_zeros_u64(n)
+}
+
+/// Computes the binary logarithm of a `U128`, rounded down.
+///
+/// # Additional Information
+///
+/// The result is the position of the highest set bit, which is found from the leading zeros of `n`.
+///
+/// # Arguments
+///
+/// * `n`: [U128] - The number of which to compute the logarithm.
+///
+/// # Returns
+///
+/// * [u32] - The largest integer `k` such that `2 ^ k <= n`.
+///
+/// # Reverts
+///
+/// * When `n` is zero.
+///
+/// # Examples
+///
+/// ```sway
+/// use math::log2_u128;
+/// use std::u128::U128;
+///
+/// fn foo() {
+///     assert(log2_u128(U128::from((1, 0))) == 64);
+///     assert(log2_u128(U128::max()) == 127);
+/// }
+/// ```
+pub fn log2_u128(n: U128) -> u32 {
+    if n.upper != 0 {
+        return 64 + log2_u64(n.upper);
+    }
+    log2_u64(n.lower)
+}
+
+/// Divides two `u64` values, rounding the quotient up.
+///
+/// # Additional Information
+///
+/// The quotient is incremented when there is a remainder, so unlike `(a + b - 1) / b` this does not overflow when `a` is close to `u64::max()`.
+///
+/// # Arguments
+///
+/// * `a`: [u64] - The dividend.
+/// * `b`: [u64] - The divisor.
+///
+/// # Returns
+///
+/// * [u64] - The smallest integer which is not less than `a / b`.
+///
+/// # Reverts
+///
+/// * When `b` is zero.
+///
+/// # Examples
+///
+/// ```sway
+/// use math::div_ceil_u64;
+///
+/// fn foo() {
+///     assert(div_ceil_u64(10, 3) == 4);
+///     assert(div_ceil_u64(9, 3) == 3);
+/// }
+/// ```
+pub fn div_ceil_u64(a: u64, b: u64) -> u64 {
+    require(b != 0, MathError::Undefined);
+    a / b + if a % b != 0 { 1 } else { 0 }
+}
+
+/// Divides two `u64` values, rounding the quotient down.
+///
+/// # Additional Information
+///
+/// This is the same as `a / b` and exists to make the direction of rounding explicit next to `div_ceil_u64`.
+///
+/// # Arguments
+///
+/// * `a`: [u64] - The dividend.
+/// * `b`: [u64] - The divisor.
+///
+/// # Returns
+///
+/// * [u64] - The largest integer which is not greater than `a / b`.
+///
+/// # Reverts
+///
+/// * When `b` is zero.
+///
+/// # Examples
+///
+/// ```sway
+/// use math::div_floor_u64;
+///
+/// fn foo() {
+///     assert(div_floor_u64(10, 3) == 3);
+///     assert(div_floor_u64(9, 3) == 3);
+/// }
+/// ```
+pub fn div_floor_u64(a: u64, b: u64) -> u64 {
+    require(b != 0, MathError::Undefined);
+    a / b
+}
+
+/// Divides two `U128` values, rounding the quotient up.
+///
+/// # Additional Information
+///
+/// The quotient is incremented when it does not divide `a` exactly, so this does not overflow when `a` is close to `U128::max()`.
+///
+/// # Arguments
+///
+/// * `a`: [U128] - The dividend.
+/// * `b`: [U128] - The divisor.
+///
+/// # Returns
+///
+/// * [U128] - The smallest integer which is not less than `a / b`.
+///
+/// # Reverts
+///
+/// * When `b` is zero.
+///
+/// # Examples
+///
+/// ```sway
+/// use math::div_ceil_u128;
+/// use std::u128::U128;
+///
+/// fn foo() {
+///     assert(div_ceil_u128(U128::from((0, 10)), U128::from((0, 3))) == U128::from((0, 4)));
+///     assert(div_ceil_u128(U128::from((1, 0)), U128::from((0, 2))) == U128::from((0, 1 << 63)));
+/// }
+/// ```
+pub fn div_ceil_u128(a: U128, b: U128) -> U128 {
+    let quotient = div_floor_u128(a, b);
+    if quotient * b == a {
+        quotient
+    } else {
+        quotient + U128::from((0, 1))
+    }
+}
+
+/// Divides two `U128` values, rounding the quotient down.
+///
+/// # Additional Information
+///
+/// This is the same as `a / b` and exists to make the direction of rounding explicit next to `div_ceil_u128`.
+///
+/// # Arguments
+///
+/// * `a`: [U128] - The dividend.
+/// * `b`: [U128] - The divisor.
+///
+/// # Returns
+///
+/// * [U128] - The largest integer which is not greater than `a / b`.
+///
+/// # Reverts
+///
+/// * When `b` is zero.
+///
+/// # Examples
+///
+/// ```sway
+/// use math::div_floor_u128;
+/// use std::u128::U128;
+///
+/// fn foo() {
+///     assert(div_floor_u128(U128::from((0, 10)), U128::from((0, 3))) == U128::from((0, 3)));
+/// }
+/// ```
+pub fn div_floor_u128(a: U128, b: U128) -> U128 {
+    require(b != U128::from((0, 0)), MathError::Undefined);
+    a / b
+}
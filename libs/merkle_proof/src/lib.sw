@@ -1,3 +1,4 @@
 library;
 
 pub mod binary_merkle_proof;
+pub mod sparse_merkle;
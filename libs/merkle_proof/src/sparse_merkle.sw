@@ -0,0 +1,232 @@
+library;
+
+use std::{bytes::Bytes, constants::ZERO_B256, hash::{Hash, sha256}};
+
+pub enum SparseMerkleError {
+    InvalidPath: (),
+    InvalidProofLength: (),
+}
+
+/// Concatenated to leaf hash input as described by
+/// "H(leaf) = SHA-256(0x00 || key || value)"
+pub const LEAF = 0u8;
+/// Concatenated to node hash input as described by
+/// "H(node) = SHA-256(0x01 || H(left) || H(right))"
+pub const NODE = 1u8;
+/// The number of levels between a leaf and the root, one for each bit of a key.
+pub const DEPTH = 256;
+
+/// Returns the computed leaf hash of "H(leaf) = SHA-256(0x00 || key || value)".
+///
+/// # Arguments
+///
+/// * `key`: [b256] - The key of the leaf.
+/// * `value`: [b256] - The value of the leaf.
+///
+/// # Returns
+///
+/// * [b256] - The computed hash.
+fn leaf_digest(key: b256, value: b256) -> b256 {
+    let mut bytes = Bytes::with_capacity(65);
+    let new_ptr_key = bytes.buf.ptr().add_uint_offset(1);
+    let new_ptr_value = bytes.buf.ptr().add_uint_offset(33);
+
+    bytes.buf.ptr().write_byte(LEAF);
+    __addr_of(key).copy_bytes_to(new_ptr_key, 32);
+    __addr_of(value).copy_bytes_to(new_ptr_value, 32);
+    bytes.len = 65;
+
+    sha256(bytes)
+}
+
+/// Returns the computed node hash of "H(node) = SHA-256(0x01 || H(left) || H(right))".
+///
+/// # Additional Information
+///
+/// A subtree without any leaves has a hash of `ZERO_B256` at every height, so a node of two empty children is empty too.
+///
+/// # Arguments
+///
+/// * `left`: [b256] - The hash of the left node.
+/// * `right`: [b256] - The hash of the right node.
+///
+/// # Returns
+///
+/// * [b256] - The hash of the node data.
+fn node_digest(left: b256, right: b256) -> b256 {
+    if left == ZERO_B256 && right == ZERO_B256 {
+        return ZERO_B256;
+    }
+
+    let mut bytes = Bytes::with_capacity(65);
+    let new_ptr_left = bytes.buf.ptr().add_uint_offset(1);
+    let new_ptr_right = bytes.buf.ptr().add_uint_offset(33);
+
+    bytes.buf.ptr().write_byte(NODE);
+    __addr_of(left).copy_bytes_to(new_ptr_left, 32);
+    __addr_of(right).copy_bytes_to(new_ptr_right, 32);
+    bytes.len = 65;
+
+    sha256(bytes)
+}
+
+/// Hashes a leaf up the path of its key to compute the root of the tree.
+///
+/// # Arguments
+///
+/// * `key`: [b256] - The key which determines the path from the root to the leaf.
+/// * `leaf`: [b256] - The hash of the leaf, or `ZERO_B256` if it is empty.
+/// * `proof`: [Vec<(b256, bool)>] - The siblings from the leaf to the root and whether each is on the left.
+///
+/// # Returns
+///
+/// * [Result<b256, SparseMerkleError>] - The calculated root, or `InvalidProofLength` when the proof does not have
+/// one sibling for every level of the tree and `InvalidPath` when the side of a sibling does not match the bit of
+/// the key at its level.
+fn process_path(key: b256, leaf: b256, proof: Vec<(b256, bool)>) -> Result<b256, SparseMerkleError> {
+    if proof.len() != DEPTH {
+        return Result::Err(SparseMerkleError::InvalidProofLength);
+    }
+
+    let one = 0x0000000000000000000000000000000000000000000000000000000000000001;
+    let mut digest = leaf;
+    let mut height = 0;
+
+    // The path follows the bits of the key from the most significant at the root
+    // to the least significant at the leaf, where a set bit goes right.
+    while height < DEPTH {
+        let (sibling, sibling_is_left) = proof.get(height).unwrap();
+        let goes_right = ((key >> height) & one) != ZERO_B256;
+        if sibling_is_left != goes_right {
+            return Result::Err(SparseMerkleError::InvalidPath);
+        }
+
+        if sibling_is_left {
+            digest = node_digest(sibling, digest);
+        } else {
+            digest = node_digest(digest, sibling);
+        }
+
+        height = height + 1;
+    }
+
+    Result::Ok(digest)
+}
+
+/// This function will compute and return a Sparse Merkle root given a leaf and corresponding proof.
+///
+/// # Additional Information
+///
+/// The tree has a depth of 256 and every key has its own leaf, at the end of the path given by the bits of the key.
+///
+/// # Arguments
+///
+/// * `leaf_key`: [b256] - The key of the leaf to prove.
+/// * `leaf_value`: [b256] - The value of the leaf to prove.
+/// * `proof`: [Vec<(b256, bool)>] - The siblings from the leaf to the root and whether each is on the left.
+///
+/// # Returns
+///
+/// * [b256] - The calculated root.
+///
+/// # Reverts
+///
+/// * When the proof does not have 256 siblings.
+/// * When the side of a sibling does not match the bit of the key at its level.
+///
+/// # Examples
+///
+/// ```sway
+/// use merkle_proof::sparse_merkle::root;
+/// use std::constants::ZERO_B256;
+///
+/// fn foo() {
+///     let mut proof = Vec::new();
+///     let mut i = 0;
+///     while i < 256 {
+///         proof.push((ZERO_B256, false));
+///         i = i + 1;
+///     }
+///     let root = root(ZERO_B256, ZERO_B256, proof);
+/// }
+/// ```
+pub fn root(leaf_key: b256, leaf_value: b256, proof: Vec<(b256, bool)>) -> b256 {
+    let result = process_path(leaf_key, leaf_digest(leaf_key, leaf_value), proof);
+    match result {
+        Result::Ok(_) => {},
+        Result::Err(error) => require(false, error),
+    }
+
+    result.unwrap()
+}
+
+/// This function will take a leaf and proof and return whether the leaf is part of the Sparse Merkle Tree with the given root.
+///
+/// # Arguments
+///
+/// * `root`: [b256] - The pre-computed Sparse Merkle root that will be used to verify the leaf and proof.
+/// * `key`: [b256] - The key of the leaf to verify.
+/// * `value`: [b256] - The value of the leaf to verify.
+/// * `proof`: [Vec<(b256, bool)>] - The siblings from the leaf to the root and whether each is on the left.
+///
+/// # Returns
+///
+/// * [bool] - `true` if the computed root matches the provided root, otherwise `false`. A proof which does not have
+/// 256 siblings or does not follow the path of `key` is never valid.
+///
+/// # Examples
+///
+/// ```sway
+/// use merkle_proof::sparse_merkle::{root, verify_membership};
+///
+/// fn foo(key: b256, value: b256, proof: Vec<(b256, bool)>) {
+///     let root = root(key, value, proof);
+///     assert(verify_membership(root, key, value, proof));
+/// }
+/// ```
+pub fn verify_membership(
+    root: b256,
+    key: b256,
+    value: b256,
+    proof: Vec<(b256, bool)>,
+) -> bool {
+    match process_path(key, leaf_digest(key, value), proof) {
+        Result::Ok(computed) => computed == root,
+        Result::Err(_) => false,
+    }
+}
+
+/// This function will take a key and proof and return whether the key has no leaf in the Sparse Merkle Tree with the given root.
+///
+/// # Additional Information
+///
+/// The proof is the same as a membership proof, but for an empty leaf with a hash of `ZERO_B256`.
+///
+/// # Arguments
+///
+/// * `root`: [b256] - The pre-computed Sparse Merkle root that will be used to verify the proof.
+/// * `key`: [b256] - The key which is not in the tree.
+/// * `proof`: [Vec<(b256, bool)>] - The siblings from the empty leaf to the root and whether each is on the left.
+///
+/// # Returns
+///
+/// * [bool] - `true` if the computed root of the empty leaf matches the provided root, otherwise `false`. A proof which
+/// does not have 256 siblings or does not follow the path of `key` is never valid.
+///
+/// # Examples
+///
+/// ```sway
+/// use merkle_proof::sparse_merkle::verify_non_membership;
+/// use std::constants::ZERO_B256;
+///
+/// fn foo(key: b256, proof: Vec<(b256, bool)>) {
+///     // Every key is absent from the empty tree
+///     assert(verify_non_membership(ZERO_B256, key, proof));
+/// }
+/// ```
+pub fn verify_non_membership(root: b256, key: b256, proof: Vec<(b256, bool)>) -> bool {
+    match process_path(key, ZERO_B256, proof) {
+        Result::Ok(computed) => computed == root,
+        Result::Err(_) => false,
+    }
+}
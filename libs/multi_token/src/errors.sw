@@ -0,0 +1,9 @@
+library;
+
+/// Error log for when a balance of the ledger cannot be changed.
+pub enum LedgerError {
+    /// Emitted when an owner does not hold enough of an asset to transfer or burn.
+    InsufficientBalance: (),
+    /// Emitted when a balance would not fit in a `u64`.
+    Overflow: (),
+}
@@ -0,0 +1,190 @@
+library;
+
+pub mod errors;
+
+use errors::LedgerError;
+
+/// The balances of many assets for many owners, held in storage.
+///
+/// # Additional Information
+///
+/// The balances are kept in a `StorageMap<(Identity, AssetId), u64>` at the storage key of the ledger, so the
+/// balance of every owner of every asset has its own slot. Balances which have never been set are zero.
+pub struct Ledger {}
+
+impl StorageKey<Ledger> {
+    /// Returns the balance of an owner for an asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `owner`: [Identity] - The owner of which to query the balance.
+    /// * `asset`: [AssetId] - The asset of which to query the balance.
+    ///
+    /// # Returns
+    ///
+    /// * [u64] - The amount of `asset` held by `owner`.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use multi_token::Ledger;
+    ///
+    /// storage {
+    ///     ledger: Ledger = Ledger {},
+    /// }
+    ///
+    /// fn foo(owner: Identity, asset: AssetId) {
+    ///     assert(storage.ledger.balance_of(owner, asset) == 0);
+    /// }
+    /// ```
+    #[storage(read)]
+    pub fn balance_of(self, owner: Identity, asset: AssetId) -> u64 {
+        self.balances().get((owner, asset)).try_read().unwrap_or(0)
+    }
+
+    /// Moves an amount of an asset from one owner to another.
+    ///
+    /// # Additional Information
+    ///
+    /// The ledger does not check who calls it, so the contract must ensure that the sender may move the balance of `from`.
+    ///
+    /// # Arguments
+    ///
+    /// * `from`: [Identity] - The owner to take the asset from.
+    /// * `to`: [Identity] - The owner to give the asset to.
+    /// * `asset`: [AssetId] - The asset to transfer.
+    /// * `amount`: [u64] - The amount of `asset` to transfer.
+    ///
+    /// # Reverts
+    ///
+    /// * When `from` holds less than `amount` of `asset`.
+    /// * When the balance of `to` would not fit in a `u64`.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `2`
+    /// * Writes: `2`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use multi_token::Ledger;
+    ///
+    /// storage {
+    ///     ledger: Ledger = Ledger {},
+    /// }
+    ///
+    /// fn foo(from: Identity, to: Identity, asset: AssetId) {
+    ///     storage.ledger.mint_to(from, asset, 10);
+    ///     storage.ledger.transfer(from, to, asset, 4);
+    ///     assert(storage.ledger.balance_of(from, asset) == 6);
+    ///     assert(storage.ledger.balance_of(to, asset) == 4);
+    /// }
+    /// ```
+    #[storage(read, write)]
+    pub fn transfer(self, from: Identity, to: Identity, asset: AssetId, amount: u64) {
+        let from_balance = self.balance_of(from, asset);
+        require(from_balance >= amount, LedgerError::InsufficientBalance);
+
+        // A transfer to the same owner does not change any balance
+        if from == to {
+            return;
+        }
+
+        let to_balance = self.balance_of(to, asset);
+        require(to_balance <= u64::max() - amount, LedgerError::Overflow);
+
+        self.balances().insert((from, asset), from_balance - amount);
+        self.balances().insert((to, asset), to_balance + amount);
+    }
+
+    /// Creates an amount of an asset for an owner.
+    ///
+    /// # Arguments
+    ///
+    /// * `to`: [Identity] - The owner to give the new asset to.
+    /// * `asset`: [AssetId] - The asset to mint.
+    /// * `amount`: [u64] - The amount of `asset` to mint.
+    ///
+    /// # Reverts
+    ///
+    /// * When the balance of `to` would not fit in a `u64`.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    /// * Writes: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use multi_token::Ledger;
+    ///
+    /// storage {
+    ///     ledger: Ledger = Ledger {},
+    /// }
+    ///
+    /// fn foo(to: Identity, asset: AssetId) {
+    ///     storage.ledger.mint_to(to, asset, 10);
+    ///     assert(storage.ledger.balance_of(to, asset) == 10);
+    /// }
+    /// ```
+    #[storage(read, write)]
+    pub fn mint_to(self, to: Identity, asset: AssetId, amount: u64) {
+        let balance = self.balance_of(to, asset);
+        require(balance <= u64::max() - amount, LedgerError::Overflow);
+
+        self.balances().insert((to, asset), balance + amount);
+    }
+
+    /// Destroys an amount of an asset held by an owner.
+    ///
+    /// # Arguments
+    ///
+    /// * `from`: [Identity] - The owner to take the asset from.
+    /// * `asset`: [AssetId] - The asset to burn.
+    /// * `amount`: [u64] - The amount of `asset` to burn.
+    ///
+    /// # Reverts
+    ///
+    /// * When `from` holds less than `amount` of `asset`.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    /// * Writes: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use multi_token::Ledger;
+    ///
+    /// storage {
+    ///     ledger: Ledger = Ledger {},
+    /// }
+    ///
+    /// fn foo(owner: Identity, asset: AssetId) {
+    ///     storage.ledger.mint_to(owner, asset, 10);
+    ///     storage.ledger.burn_from(owner, asset, 10);
+    ///     assert(storage.ledger.balance_of(owner, asset) == 0);
+    /// }
+    /// ```
+    #[storage(read, write)]
+    pub fn burn_from(self, from: Identity, asset: AssetId, amount: u64) {
+        let balance = self.balance_of(from, asset);
+        require(balance >= amount, LedgerError::InsufficientBalance);
+
+        self.balances().insert((from, asset), balance - amount);
+    }
+}
+
+impl StorageKey<Ledger> {
+    /// Returns the `StorageMap` which holds the balance of every owner of every asset.
+    fn balances(self) -> StorageKey<StorageMap<(Identity, AssetId), u64>> {
+        StorageKey::new(self.slot, self.offset, self.field_id)
+    }
+}
@@ -0,0 +1,11 @@
+library;
+
+/// Error log for when a token cannot be minted or transferred.
+pub enum NFTError {
+    /// Emitted when a token which already has an owner is minted.
+    AlreadyMinted: (),
+    /// Emitted when a token is transferred from an identity which does not own it.
+    NotTokenOwner: (),
+    /// Emitted when a token is transferred by a sender other than its owner.
+    SenderNotOwner: (),
+}
@@ -0,0 +1,19 @@
+library;
+
+/// Logged when a token is minted.
+pub struct Mint {
+    /// The identity which received the token.
+    to: Identity,
+    /// The id of the token which has been minted.
+    token_id: u64,
+}
+
+/// Logged when a token is transferred.
+pub struct Transfer {
+    /// The previous owner of the token.
+    from: Identity,
+    /// The new owner of the token.
+    to: Identity,
+    /// The id of the token which has been transferred.
+    token_id: u64,
+}
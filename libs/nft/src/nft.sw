@@ -0,0 +1,304 @@
+library;
+
+pub mod errors;
+pub mod events;
+pub mod nft_metadata;
+
+use errors::NFTError;
+use events::{Mint, Transfer};
+use std::auth::msg_sender;
+
+/// Returns the owner of a token.
+///
+/// # Arguments
+///
+/// * `owners_key`: [StorageKey<StorageMap<u64, Identity>>] - The location in storage which the `StorageMap` that stores the owners of tokens is stored.
+/// * `token_id`: [u64] - The token of which to query the owner.
+///
+/// # Returns
+///
+/// * [Option<Identity>] - The owner of `token_id`, or `None` if the token has not been minted.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use nft::_owner_of;
+///
+/// storage {
+///     owners: StorageMap<u64, Identity> = StorageMap {},
+/// }
+///
+/// fn foo() {
+///     let owner = _owner_of(storage.owners, 1);
+///     assert(owner.is_none());
+/// }
+/// ```
+#[storage(read)]
+pub fn _owner_of(
+    owners_key: StorageKey<StorageMap<u64, Identity>>,
+    token_id: u64,
+) -> Option<Identity> {
+    owners_key.get(token_id).try_read()
+}
+
+/// Returns the number of tokens owned by an identity.
+///
+/// # Arguments
+///
+/// * `balances_key`: [StorageKey<StorageMap<Identity, u64>>] - The location in storage which the `StorageMap` that stores the number of tokens of each owner is stored.
+/// * `owner`: [Identity] - The identity of which to query the balance.
+///
+/// # Returns
+///
+/// * [u64] - The number of tokens owned by `owner`.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use nft::_balance_of;
+///
+/// storage {
+///     balances: StorageMap<Identity, u64> = StorageMap {},
+/// }
+///
+/// fn foo(owner: Identity) {
+///     let balance = _balance_of(storage.balances, owner);
+///     assert(balance == 0);
+/// }
+/// ```
+#[storage(read)]
+pub fn _balance_of(balances_key: StorageKey<StorageMap<Identity, u64>>, owner: Identity) -> u64 {
+    balances_key.get(owner).try_read().unwrap_or(0)
+}
+
+/// Mints a new token to an identity.
+///
+/// # Additional Information
+///
+/// Anyone may mint through this function. The contract should restrict who may call it.
+///
+/// # Arguments
+///
+/// * `owners_key`: [StorageKey<StorageMap<u64, Identity>>] - The location in storage which the `StorageMap` that stores the owners of tokens is stored.
+/// * `balances_key`: [StorageKey<StorageMap<Identity, u64>>] - The location in storage which the `StorageMap` that stores the number of tokens of each owner is stored.
+/// * `to`: [Identity] - The identity which receives the token.
+/// * `token_id`: [u64] - The id of the token to mint.
+///
+/// # Reverts
+///
+/// * When `token_id` has already been minted.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `2`
+/// * Writes: `2`
+///
+/// # Examples
+///
+/// ```sway
+/// use nft::{_mint, _owner_of};
+///
+/// storage {
+///     owners: StorageMap<u64, Identity> = StorageMap {},
+///     balances: StorageMap<Identity, u64> = StorageMap {},
+/// }
+///
+/// fn foo(to: Identity) {
+///     _mint(storage.owners, storage.balances, to, 1);
+///     assert(_owner_of(storage.owners, 1).unwrap() == to);
+/// }
+/// ```
+#[storage(read, write)]
+pub fn _mint(
+    owners_key: StorageKey<StorageMap<u64, Identity>>,
+    balances_key: StorageKey<StorageMap<Identity, u64>>,
+    to: Identity,
+    token_id: u64,
+) {
+    require(_owner_of(owners_key, token_id).is_none(), NFTError::AlreadyMinted);
+
+    owners_key.insert(token_id, to);
+    balances_key.insert(to, _balance_of(balances_key, to) + 1);
+
+    log(Mint { to, token_id });
+}
+
+/// Mints `count` consecutive tokens starting from `start_id` to an identity.
+///
+/// # Additional Information
+///
+/// A `Mint` event is logged for every token. Anyone may mint through this function. The contract should restrict who may call it.
+///
+/// # Arguments
+///
+/// * `owners_key`: [StorageKey<StorageMap<u64, Identity>>] - The location in storage which the `StorageMap` that stores the owners of tokens is stored.
+/// * `balances_key`: [StorageKey<StorageMap<Identity, u64>>] - The location in storage which the `StorageMap` that stores the number of tokens of each owner is stored.
+/// * `to`: [Identity] - The identity which receives the tokens.
+/// * `start_id`: [u64] - The id of the first token to mint.
+/// * `count`: [u64] - The number of tokens to mint.
+///
+/// # Reverts
+///
+/// * When any of the tokens has already been minted.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `2 * count`
+/// * Writes: `2 * count`
+///
+/// # Examples
+///
+/// ```sway
+/// use nft::{_balance_of, _batch_mint};
+///
+/// storage {
+///     owners: StorageMap<u64, Identity> = StorageMap {},
+///     balances: StorageMap<Identity, u64> = StorageMap {},
+/// }
+///
+/// fn foo(to: Identity) {
+///     _batch_mint(storage.owners, storage.balances, to, 1, 10);
+///     assert(_balance_of(storage.balances, to) == 10);
+/// }
+/// ```
+#[storage(read, write)]
+pub fn _batch_mint(
+    owners_key: StorageKey<StorageMap<u64, Identity>>,
+    balances_key: StorageKey<StorageMap<Identity, u64>>,
+    to: Identity,
+    start_id: u64,
+    count: u64,
+) {
+    let mut i = 0;
+    while i < count {
+        _mint(owners_key, balances_key, to, start_id + i);
+        i += 1;
+    }
+}
+
+/// Transfers a token to a new owner.
+///
+/// # Arguments
+///
+/// * `owners_key`: [StorageKey<StorageMap<u64, Identity>>] - The location in storage which the `StorageMap` that stores the owners of tokens is stored.
+/// * `balances_key`: [StorageKey<StorageMap<Identity, u64>>] - The location in storage which the `StorageMap` that stores the number of tokens of each owner is stored.
+/// * `from`: [Identity] - The current owner of the token.
+/// * `to`: [Identity] - The identity which receives the token.
+/// * `token_id`: [u64] - The id of the token to transfer.
+///
+/// # Reverts
+///
+/// * When the sender is not `from`.
+/// * When `from` does not own `token_id`.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `3`
+/// * Writes: `3`
+///
+/// # Examples
+///
+/// ```sway
+/// use nft::{_owner_of, _transfer};
+///
+/// storage {
+///     owners: StorageMap<u64, Identity> = StorageMap {},
+///     balances: StorageMap<Identity, u64> = StorageMap {},
+/// }
+///
+/// fn foo(from: Identity, to: Identity) {
+///     _transfer(storage.owners, storage.balances, from, to, 1);
+///     assert(_owner_of(storage.owners, 1).unwrap() == to);
+/// }
+/// ```
+#[storage(read, write)]
+pub fn _transfer(
+    owners_key: StorageKey<StorageMap<u64, Identity>>,
+    balances_key: StorageKey<StorageMap<Identity, u64>>,
+    from: Identity,
+    to: Identity,
+    token_id: u64,
+) {
+    require(msg_sender().unwrap() == from, NFTError::SenderNotOwner);
+    let owner = _owner_of(owners_key, token_id);
+    require(
+        owner.is_some() && owner.unwrap() == from,
+        NFTError::NotTokenOwner,
+    );
+
+    owners_key.insert(token_id, to);
+    balances_key.insert(from, _balance_of(balances_key, from) - 1);
+    balances_key.insert(to, _balance_of(balances_key, to) + 1);
+
+    log(Transfer {
+        from,
+        to,
+        token_id,
+    });
+}
+
+/// Transfers several tokens to a new owner at once.
+///
+/// # Additional Information
+///
+/// A `Transfer` event is logged for every token. If any of the transfers fails the whole call reverts,
+/// so either all or none of the tokens change owner.
+///
+/// # Arguments
+///
+/// * `owners_key`: [StorageKey<StorageMap<u64, Identity>>] - The location in storage which the `StorageMap` that stores the owners of tokens is stored.
+/// * `balances_key`: [StorageKey<StorageMap<Identity, u64>>] - The location in storage which the `StorageMap` that stores the number of tokens of each owner is stored.
+/// * `from`: [Identity] - The current owner of the tokens.
+/// * `to`: [Identity] - The identity which receives the tokens.
+/// * `token_ids`: [Vec<u64>] - The ids of the tokens to transfer.
+///
+/// # Reverts
+///
+/// * When the sender is not `from`.
+/// * When `from` does not own any of the tokens.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `3 * token_ids.len()`
+/// * Writes: `3 * token_ids.len()`
+///
+/// # Examples
+///
+/// ```sway
+/// use nft::{_balance_of, _batch_transfer};
+///
+/// storage {
+///     owners: StorageMap<u64, Identity> = StorageMap {},
+///     balances: StorageMap<Identity, u64> = StorageMap {},
+/// }
+///
+/// fn foo(from: Identity, to: Identity) {
+///     let mut token_ids = Vec::new();
+///     token_ids.push(1);
+///     token_ids.push(2);
+///     _batch_transfer(storage.owners, storage.balances, from, to, token_ids);
+///     assert(_balance_of(storage.balances, to) == 2);
+/// }
+/// ```
+#[storage(read, write)]
+pub fn _batch_transfer(
+    owners_key: StorageKey<StorageMap<u64, Identity>>,
+    balances_key: StorageKey<StorageMap<Identity, u64>>,
+    from: Identity,
+    to: Identity,
+    token_ids: Vec<u64>,
+) {
+    let mut i = 0;
+    while i < token_ids.len() {
+        _transfer(owners_key, balances_key, from, to, token_ids.get(i).unwrap());
+        i += 1;
+    }
+}
@@ -0,0 +1,339 @@
+library;
+
+use bytes::parse::format_u64;
+use ownership::*;
+use src_5::Ownership;
+use std::{bytes::Bytes as RawBytes, storage::storage_string::*, string::String};
+
+abi NFTMetadata {
+    /// Returns the name of the collection, such as "Fuel Punks".
+    #[storage(read)]
+    fn name() -> String;
+    /// Returns the symbol of the collection, such as "PUNK".
+    #[storage(read)]
+    fn symbol() -> String;
+    /// Returns the URI of the off-chain metadata of a token.
+    #[storage(read)]
+    fn token_uri(token_id: u64) -> String;
+    /// Returns the URI of the off-chain metadata of the collection.
+    #[storage(read)]
+    fn contract_uri() -> String;
+}
+
+/// Returns the name of the collection.
+///
+/// # Arguments
+///
+/// * `name_key`: [StorageKey<StorageString>] - The location in storage which the name is stored.
+///
+/// # Returns
+///
+/// * [String] - The name of the collection, or an empty string if it has not been set.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use nft::nft_metadata::_name;
+/// use std::string::String;
+///
+/// storage {
+///     name: StorageString = StorageString {},
+/// }
+///
+/// fn foo() {
+///     let name = _name(storage.name);
+///     assert(name.is_empty());
+/// }
+/// ```
+#[storage(read)]
+pub fn _name(name_key: StorageKey<StorageString>) -> String {
+    name_key.read_slice().unwrap_or(String::new())
+}
+
+/// Returns the symbol of the collection.
+///
+/// # Arguments
+///
+/// * `symbol_key`: [StorageKey<StorageString>] - The location in storage which the symbol is stored.
+///
+/// # Returns
+///
+/// * [String] - The symbol of the collection, or an empty string if it has not been set.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use nft::nft_metadata::_symbol;
+///
+/// storage {
+///     symbol: StorageString = StorageString {},
+/// }
+///
+/// fn foo() {
+///     let symbol = _symbol(storage.symbol);
+///     assert(symbol.is_empty());
+/// }
+/// ```
+#[storage(read)]
+pub fn _symbol(symbol_key: StorageKey<StorageString>) -> String {
+    symbol_key.read_slice().unwrap_or(String::new())
+}
+
+/// Returns the URI of the off-chain metadata of the collection.
+///
+/// # Arguments
+///
+/// * `contract_uri_key`: [StorageKey<StorageString>] - The location in storage which the contract URI is stored.
+///
+/// # Returns
+///
+/// * [String] - The URI of the collection, or an empty string if it has not been set.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use nft::nft_metadata::_contract_uri;
+///
+/// storage {
+///     contract_uri: StorageString = StorageString {},
+/// }
+///
+/// fn foo() {
+///     let uri = _contract_uri(storage.contract_uri);
+///     assert(uri.is_empty());
+/// }
+/// ```
+#[storage(read)]
+pub fn _contract_uri(contract_uri_key: StorageKey<StorageString>) -> String {
+    contract_uri_key.read_slice().unwrap_or(String::new())
+}
+
+/// Returns the URI of the off-chain metadata of a token.
+///
+/// # Additional Information
+///
+/// The URI is the base URI followed by the decimal digits of `token_id`, so a base URI of
+/// `"ipfs://collection/"` results in `"ipfs://collection/42"` for the token `42`.
+///
+/// # Arguments
+///
+/// * `base_uri_key`: [StorageKey<StorageString>] - The location in storage which the base URI is stored.
+/// * `token_id`: [u64] - The token of which to query the URI.
+///
+/// # Returns
+///
+/// * [String] - The URI of `token_id`.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use nft::nft_metadata::_token_uri;
+///
+/// storage {
+///     base_uri: StorageString = StorageString {},
+/// }
+///
+/// fn foo() {
+///     let uri = _token_uri(storage.base_uri, 42);
+///     assert(uri.as_bytes().len() == 2);
+/// }
+/// ```
+#[storage(read)]
+pub fn _token_uri(base_uri_key: StorageKey<StorageString>, token_id: u64) -> String {
+    let mut uri = base_uri_key.read_slice().unwrap_or(String::new()).as_bytes();
+    let mut digits: RawBytes = format_u64(token_id).into();
+    uri.append(digits);
+    String::from_ascii(uri)
+}
+
+/// Sets the name of the collection.
+///
+/// # Arguments
+///
+/// * `name_key`: [StorageKey<StorageString>] - The location in storage which the name is stored.
+/// * `owner_key`: [StorageKey<Ownership>] - The location in storage which the `Ownership` of the admin is stored.
+/// * `name`: [String] - The new name of the collection.
+///
+/// # Reverts
+///
+/// * When the sender is not the owner.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+/// * Writes: `2`
+///
+/// # Examples
+///
+/// ```sway
+/// use nft::nft_metadata::{_name, _set_name};
+/// use ownership::Ownership;
+/// use std::string::String;
+///
+/// storage {
+///     owner: Ownership = Ownership::initialized(Identity::Address(Address::from(0x0000000000000000000000000000000000000000000000000000000000000000))),
+///     name: StorageString = StorageString {},
+/// }
+///
+/// fn foo(name: String) {
+///     _set_name(storage.name, storage.owner, name);
+///     assert(_name(storage.name) == name);
+/// }
+/// ```
+#[storage(read, write)]
+pub fn _set_name(
+    name_key: StorageKey<StorageString>,
+    owner_key: StorageKey<Ownership>,
+    name: String,
+) {
+    owner_key.only_owner();
+    name_key.write_slice(name);
+}
+
+/// Sets the symbol of the collection.
+///
+/// # Arguments
+///
+/// * `symbol_key`: [StorageKey<StorageString>] - The location in storage which the symbol is stored.
+/// * `owner_key`: [StorageKey<Ownership>] - The location in storage which the `Ownership` of the admin is stored.
+/// * `symbol`: [String] - The new symbol of the collection.
+///
+/// # Reverts
+///
+/// * When the sender is not the owner.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+/// * Writes: `2`
+///
+/// # Examples
+///
+/// ```sway
+/// use nft::nft_metadata::{_set_symbol, _symbol};
+/// use ownership::Ownership;
+/// use std::string::String;
+///
+/// storage {
+///     owner: Ownership = Ownership::initialized(Identity::Address(Address::from(0x0000000000000000000000000000000000000000000000000000000000000000))),
+///     symbol: StorageString = StorageString {},
+/// }
+///
+/// fn foo(symbol: String) {
+///     _set_symbol(storage.symbol, storage.owner, symbol);
+///     assert(_symbol(storage.symbol) == symbol);
+/// }
+/// ```
+#[storage(read, write)]
+pub fn _set_symbol(
+    symbol_key: StorageKey<StorageString>,
+    owner_key: StorageKey<Ownership>,
+    symbol: String,
+) {
+    owner_key.only_owner();
+    symbol_key.write_slice(symbol);
+}
+
+/// Sets the URI of the off-chain metadata of the collection.
+///
+/// # Arguments
+///
+/// * `contract_uri_key`: [StorageKey<StorageString>] - The location in storage which the contract URI is stored.
+/// * `owner_key`: [StorageKey<Ownership>] - The location in storage which the `Ownership` of the admin is stored.
+/// * `uri`: [String] - The new URI of the collection.
+///
+/// # Reverts
+///
+/// * When the sender is not the owner.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+/// * Writes: `2`
+///
+/// # Examples
+///
+/// ```sway
+/// use nft::nft_metadata::{_contract_uri, _set_contract_uri};
+/// use ownership::Ownership;
+/// use std::string::String;
+///
+/// storage {
+///     owner: Ownership = Ownership::initialized(Identity::Address(Address::from(0x0000000000000000000000000000000000000000000000000000000000000000))),
+///     contract_uri: StorageString = StorageString {},
+/// }
+///
+/// fn foo(uri: String) {
+///     _set_contract_uri(storage.contract_uri, storage.owner, uri);
+///     assert(_contract_uri(storage.contract_uri) == uri);
+/// }
+/// ```
+#[storage(read, write)]
+pub fn _set_contract_uri(
+    contract_uri_key: StorageKey<StorageString>,
+    owner_key: StorageKey<Ownership>,
+    uri: String,
+) {
+    owner_key.only_owner();
+    contract_uri_key.write_slice(uri);
+}
+
+/// Sets the base URI which the URI of every token is derived from.
+///
+/// # Arguments
+///
+/// * `base_uri_key`: [StorageKey<StorageString>] - The location in storage which the base URI is stored.
+/// * `owner_key`: [StorageKey<Ownership>] - The location in storage which the `Ownership` of the admin is stored.
+/// * `base`: [String] - The new base URI.
+///
+/// # Reverts
+///
+/// * When the sender is not the owner.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+/// * Writes: `2`
+///
+/// # Examples
+///
+/// ```sway
+/// use nft::nft_metadata::{_set_base_uri, _token_uri};
+/// use ownership::Ownership;
+/// use std::string::String;
+///
+/// storage {
+///     owner: Ownership = Ownership::initialized(Identity::Address(Address::from(0x0000000000000000000000000000000000000000000000000000000000000000))),
+///     base_uri: StorageString = StorageString {},
+/// }
+///
+/// fn foo(base: String) {
+///     _set_base_uri(storage.base_uri, storage.owner, base);
+///     let uri = _token_uri(storage.base_uri, 1);
+/// }
+/// ```
+#[storage(read, write)]
+pub fn _set_base_uri(
+    base_uri_key: StorageKey<StorageString>,
+    owner_key: StorageKey<Ownership>,
+    base: String,
+) {
+    owner_key.only_owner();
+    base_uri_key.write_slice(base);
+}
@@ -0,0 +1,7 @@
+library;
+
+/// Error log for when an oracle price cannot be used.
+pub enum OracleError {
+    /// Emitted when a scaled price does not fit in a `u64`.
+    Overflow: (),
+}
@@ -0,0 +1,106 @@
+library;
+
+pub mod errors;
+
+use errors::OracleError;
+use math::checked_pow_u64;
+use std::{block::height, u128::U128};
+
+/// A price reported by an oracle.
+pub struct OraclePrice {
+    /// The price scaled by `10 ^ decimals`.
+    price: u64,
+    /// The number of decimals of `price`.
+    decimals: u8,
+    /// The block height at which the price was reported.
+    timestamp: u64,
+}
+
+/// The interface which oracle contracts implement to report prices.
+abi PriceOracle {
+    /// Returns the latest price reported by the oracle.
+    #[storage(read)]
+    fn latest_price() -> OraclePrice;
+}
+
+/// Checks whether a price is recent and non-zero.
+///
+/// # Arguments
+///
+/// * `p`: [OraclePrice] - The price to validate.
+/// * `max_staleness`: [u64] - The largest number of blocks which may have been produced since the price was reported.
+///
+/// # Returns
+///
+/// * [bool] - `true` if the price is positive and at most `max_staleness` blocks old, otherwise `false`.
+///
+/// # Examples
+///
+/// ```sway
+/// use oracle::{OraclePrice, PriceOracle, validate_price};
+///
+/// fn foo(oracle: ContractId) {
+///     let price = abi(PriceOracle, oracle.value).latest_price();
+///     require(validate_price(price, 100), "Invalid price");
+/// }
+/// ```
+pub fn validate_price(p: OraclePrice, max_staleness: u64) -> bool {
+    let current_height = height().as_u64();
+    // A price reported in the future cannot be trusted either
+    if p.price == 0 || p.timestamp > current_height {
+        return false;
+    }
+
+    current_height - p.timestamp <= max_staleness
+}
+
+/// Scales a price to a number of decimals.
+///
+/// # Additional Information
+///
+/// Scaling down rounds towards zero.
+///
+/// # Arguments
+///
+/// * `p`: [OraclePrice] - The price to scale.
+/// * `target_decimals`: [u8] - The number of decimals of the result.
+///
+/// # Returns
+///
+/// * [u64] - The price scaled by `10 ^ target_decimals`.
+///
+/// # Reverts
+///
+/// * When the scaled price does not fit in a `u64`.
+///
+/// # Examples
+///
+/// ```sway
+/// use oracle::{OraclePrice, to_u64_with_decimals};
+///
+/// fn foo() {
+///     let p = OraclePrice {
+///         price: 150,
+///         decimals: 2,
+///         timestamp: 0,
+///     };
+///     assert(to_u64_with_decimals(p, 4) == 15000);
+///     assert(to_u64_with_decimals(p, 0) == 1);
+/// }
+/// ```
+pub fn to_u64_with_decimals(p: OraclePrice, target_decimals: u8) -> u64 {
+    if target_decimals >= p.decimals {
+        let factor = checked_pow_u64(10, (target_decimals - p.decimals).as_u32());
+        require(factor.is_some(), OracleError::Overflow);
+
+        let scaled = U128::from((0, p.price)) * U128::from((0, factor.unwrap()));
+        require(scaled.upper == 0, OracleError::Overflow);
+        scaled.lower
+    } else {
+        match checked_pow_u64(10, (p.decimals - target_decimals).as_u32()) {
+            Option::Some(divisor) => p.price / divisor,
+            // The divisor is above any u64 so nothing remains of the price
+            Option::None => 0,
+        }
+    }
+}
@@ -6,4 +6,6 @@ pub enum AccessError {
     CannotReinitialized: (),
     /// Emitted when the caller is not the owner of the contract.
     NotOwner: (),
+    /// Emitted when the caller is not the pending owner of the contract.
+    NotPendingOwner: (),
 }
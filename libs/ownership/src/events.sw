@@ -19,3 +19,11 @@ pub struct OwnershipTransferred {
     /// The user which has given up their ownership.
     previous_owner: Identity,
 }
+
+/// Logged when a two-step ownership transfer is started.
+pub struct OwnershipTransferStarted {
+    /// The user which may accept the ownership.
+    pending_owner: Identity,
+    /// The user which is currently the owner.
+    previous_owner: Identity,
+}
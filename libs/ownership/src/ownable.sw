@@ -4,7 +4,7 @@ pub mod errors;
 pub mod events;
 
 use errors::AccessError;
-use events::{OwnershipRenounced, OwnershipSet, OwnershipTransferred};
+use events::{OwnershipRenounced, OwnershipSet, OwnershipTransferStarted, OwnershipTransferred};
 use std::{auth::msg_sender, hash::sha256, storage::storage_api::{read, write}};
 use src_5::{Ownership, State};
 
@@ -150,6 +150,10 @@ impl StorageKey<Ownership> {
 impl StorageKey<Ownership> {
     /// Revokes ownership of the current owner and disallows any new owners.
     ///
+    /// # Additional Information
+    ///
+    /// Any pending two-step ownership transfer is cancelled.
+    ///
     /// # Reverts
     ///
     /// * When the sender is not the owner.
@@ -157,7 +161,7 @@ impl StorageKey<Ownership> {
     /// # Number of Storage Accesses
     ///
     /// * Reads: `1`
-    /// * Writes: `1`
+    /// * Writes: `2`
     ///
     /// # Examples
     ///
@@ -179,6 +183,7 @@ impl StorageKey<Ownership> {
         self.only_owner();
 
         self.write(Ownership::revoked());
+        self.clear_pending_owner();
 
         log(OwnershipRenounced {
             previous_owner: msg_sender().unwrap(),
@@ -226,6 +231,10 @@ impl StorageKey<Ownership> {
 
     /// Transfers ownership to the passed identity.
     ///
+    /// # Additional Information
+    ///
+    /// Any pending two-step ownership transfer is cancelled.
+    ///
     /// # Arguments
     ///
     /// * `new_owner`: [Identity] - The `Identity` that will be the next owner.
@@ -237,7 +246,7 @@ impl StorageKey<Ownership> {
     /// # Number of Storage Acesses
     ///
     /// * Reads: `1`
-    /// * Write: `1`
+    /// * Write: `2`
     ///
     /// # Examples
     ///
@@ -258,6 +267,7 @@ impl StorageKey<Ownership> {
     pub fn transfer_ownership(self, new_owner: Identity) {
         self.only_owner();
         self.write(Ownership::initialized(new_owner));
+        self.clear_pending_owner();
 
         log(OwnershipTransferred {
             new_owner,
@@ -265,3 +275,148 @@ impl StorageKey<Ownership> {
         });
     }
 }
+
+impl StorageKey<Ownership> {
+    /// Returns the pending owner of a two-step ownership transfer.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<Identity>] - The `Identity` which may accept the ownership, or `None` if no transfer is pending.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use ownable::Ownership;
+    ///
+    /// storage {
+    ///     owner: Ownership = Ownership::initalized(Identity::Address(Address::from(ZERO_B256))),
+    /// }
+    ///
+    /// fn foo() {
+    ///     assert(storage.owner.pending_owner().is_none());
+    /// }
+    /// ```
+    #[storage(read)]
+    pub fn pending_owner(self) -> Option<Identity> {
+        match read::<Option<Identity>>(self.pending_owner_slot(), 0) {
+            Option::Some(pending_owner) => pending_owner,
+            Option::None => Option::None,
+        }
+    }
+
+    /// Starts a two-step transfer of the ownership to the passed identity.
+    ///
+    /// # Additional Information
+    ///
+    /// The ownership is only transferred once `new_owner` calls `accept_ownership()`. Until then
+    /// the current owner keeps the ownership and may start a transfer to another identity, which
+    /// replaces the pending one.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_owner`: [Identity] - The `Identity` that may accept the ownership.
+    ///
+    /// # Reverts
+    ///
+    /// * When the sender is not the owner.
+    ///
+    /// # Number of Storage Acesses
+    ///
+    /// * Reads: `1`
+    /// * Write: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use ownable::Ownership;
+    ///
+    /// storage {
+    ///     owner: Ownership = Ownership::initalized(Identity::Address(Address::from(ZERO_B256))),
+    /// }
+    ///
+    /// fn foo(new_owner: Identity) {
+    ///     storage.owner.start_ownership_transfer(new_owner);
+    ///     assert(storage.owner.pending_owner().unwrap() == new_owner);
+    ///     assert(storage.owner.owner() == State::Initialized(Identity::Address(Address::from(ZERO_B256))));
+    /// }
+    /// ```
+    #[storage(read, write)]
+    pub fn start_ownership_transfer(self, new_owner: Identity) {
+        self.only_owner();
+
+        write(self.pending_owner_slot(), 0, Option::Some(new_owner));
+
+        log(OwnershipTransferStarted {
+            pending_owner: new_owner,
+            previous_owner: msg_sender().unwrap(),
+        });
+    }
+
+    /// Completes a two-step ownership transfer, making the sender the owner.
+    ///
+    /// # Reverts
+    ///
+    /// * When the sender is not the pending owner.
+    ///
+    /// # Number of Storage Acesses
+    ///
+    /// * Reads: `2`
+    /// * Write: `2`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use ownable::Ownership;
+    ///
+    /// storage {
+    ///     owner: Ownership = Ownership::initalized(Identity::Address(Address::from(ZERO_B256))),
+    /// }
+    ///
+    /// fn foo() {
+    ///     // Called by the identity passed to `start_ownership_transfer()`
+    ///     storage.owner.accept_ownership();
+    ///     assert(storage.owner.owner() == State::Initialized(msg_sender().unwrap()));
+    ///     assert(storage.owner.pending_owner().is_none());
+    /// }
+    /// ```
+    #[storage(read, write)]
+    pub fn accept_ownership(self) {
+        let sender = msg_sender().unwrap();
+        let is_pending_owner = match self.pending_owner() {
+            Option::Some(pending_owner) => pending_owner == sender,
+            Option::None => false,
+        };
+        let previous_owner = match self.owner() {
+            State::Initialized(owner) => Option::Some(owner),
+            _ => Option::None,
+        };
+        // Renouncing clears the pending owner, so an uninitialized owner is never transferred from
+        require(is_pending_owner && previous_owner.is_some(), AccessError::NotPendingOwner);
+
+        self.write(Ownership::initialized(sender));
+        self.clear_pending_owner();
+
+        log(OwnershipTransferred {
+            new_owner: sender,
+            previous_owner: previous_owner.unwrap(),
+        });
+    }
+}
+
+impl StorageKey<Ownership> {
+    /// The storage slot of the pending owner, derived from the slot of the `Ownership`.
+    fn pending_owner_slot(self) -> b256 {
+        sha256((self.slot, "pending_owner"))
+    }
+
+    /// Removes the pending owner of a two-step ownership transfer.
+    #[storage(write)]
+    fn clear_pending_owner(self) {
+        let none: Option<Identity> = Option::None;
+        write(self.pending_owner_slot(), 0, none);
+    }
+}
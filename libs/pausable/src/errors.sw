@@ -0,0 +1,9 @@
+library;
+
+/// Error log for when a function is called in the wrong pause state.
+pub enum PauseError {
+    /// Emitted when the contract is paused.
+    Paused: (),
+    /// Emitted when the contract is not paused.
+    NotPaused: (),
+}
@@ -0,0 +1,13 @@
+library;
+
+/// Logged when the contract is paused.
+pub struct Paused {
+    /// The user which paused the contract.
+    caller: Identity,
+}
+
+/// Logged when the contract is unpaused.
+pub struct Unpaused {
+    /// The user which unpaused the contract.
+    caller: Identity,
+}
@@ -0,0 +1,208 @@
+library;
+
+pub mod errors;
+pub mod events;
+
+use errors::PauseError;
+use events::{Paused, Unpaused};
+use ownership::*;
+use src_5::Ownership;
+use std::auth::msg_sender;
+
+/// The pause state of a contract.
+pub enum PauseState {
+    /// Guarded functions revert.
+    Paused: (),
+    /// Guarded functions may be called.
+    Unpaused: (),
+}
+
+impl core::ops::Eq for PauseState {
+    fn eq(self, other: Self) -> bool {
+        match (self, other) {
+            (PauseState::Paused, PauseState::Paused) => true,
+            (PauseState::Unpaused, PauseState::Unpaused) => true,
+            _ => false,
+        }
+    }
+}
+
+impl StorageKey<PauseState> {
+    /// Returns whether the contract is paused.
+    ///
+    /// # Returns
+    ///
+    /// * [bool] - `true` if the contract is paused, otherwise `false`.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use pausable::PauseState;
+    ///
+    /// storage {
+    ///     pause_state: PauseState = PauseState::Unpaused,
+    /// }
+    ///
+    /// fn foo() {
+    ///     assert(!storage.pause_state.is_paused());
+    /// }
+    /// ```
+    #[storage(read)]
+    pub fn is_paused(self) -> bool {
+        self.read() == PauseState::Paused
+    }
+}
+
+impl StorageKey<PauseState> {
+    /// Ensures that the contract is not paused.
+    ///
+    /// # Reverts
+    ///
+    /// * When the contract is paused.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use pausable::PauseState;
+    ///
+    /// storage {
+    ///     pause_state: PauseState = PauseState::Unpaused,
+    /// }
+    ///
+    /// fn foo() {
+    ///     storage.pause_state.require_not_paused();
+    ///     // Do stuff here
+    /// }
+    /// ```
+    #[storage(read)]
+    pub fn require_not_paused(self) {
+        require(!self.is_paused(), PauseError::Paused);
+    }
+
+    /// Ensures that the contract is paused.
+    ///
+    /// # Reverts
+    ///
+    /// * When the contract is not paused.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use pausable::PauseState;
+    ///
+    /// storage {
+    ///     pause_state: PauseState = PauseState::Paused,
+    /// }
+    ///
+    /// fn foo() {
+    ///     storage.pause_state.require_paused();
+    ///     // Do emergency stuff here
+    /// }
+    /// ```
+    #[storage(read)]
+    pub fn require_paused(self) {
+        require(self.is_paused(), PauseError::NotPaused);
+    }
+}
+
+impl StorageKey<PauseState> {
+    /// Pauses the contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `owner_key`: [StorageKey<Ownership>] - The location in storage which the `Ownership` of the contract is stored.
+    ///
+    /// # Reverts
+    ///
+    /// * When the sender is not the owner.
+    /// * When the contract is already paused.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `2`
+    /// * Writes: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use src_5::Ownership;
+    /// use pausable::PauseState;
+    ///
+    /// storage {
+    ///     owner: Ownership = Ownership::initialized(Identity::Address(Address::from(ZERO_B256))),
+    ///     pause_state: PauseState = PauseState::Unpaused,
+    /// }
+    ///
+    /// fn foo() {
+    ///     storage.pause_state.pause(storage.owner);
+    ///     assert(storage.pause_state.is_paused());
+    /// }
+    /// ```
+    #[storage(read, write)]
+    pub fn pause(self, owner_key: StorageKey<Ownership>) {
+        owner_key.only_owner();
+        self.require_not_paused();
+
+        self.write(PauseState::Paused);
+
+        log(Paused {
+            caller: msg_sender().unwrap(),
+        });
+    }
+
+    /// Unpauses the contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `owner_key`: [StorageKey<Ownership>] - The location in storage which the `Ownership` of the contract is stored.
+    ///
+    /// # Reverts
+    ///
+    /// * When the sender is not the owner.
+    /// * When the contract is not paused.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `2`
+    /// * Writes: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use src_5::Ownership;
+    /// use pausable::PauseState;
+    ///
+    /// storage {
+    ///     owner: Ownership = Ownership::initialized(Identity::Address(Address::from(ZERO_B256))),
+    ///     pause_state: PauseState = PauseState::Paused,
+    /// }
+    ///
+    /// fn foo() {
+    ///     storage.pause_state.unpause(storage.owner);
+    ///     assert(!storage.pause_state.is_paused());
+    /// }
+    /// ```
+    #[storage(read, write)]
+    pub fn unpause(self, owner_key: StorageKey<Ownership>) {
+        owner_key.only_owner();
+        self.require_paused();
+
+        self.write(PauseState::Unpaused);
+
+        log(Unpaused {
+            caller: msg_sender().unwrap(),
+        });
+    }
+}
@@ -0,0 +1,249 @@
+library;
+
+use collection_backend::Backend;
+
+/// A First In First Out queue on any `Backend`.
+///
+/// # Additional Information
+///
+/// Elements of type `V` are pushed to the end of `items` and the index of the oldest element which has not been
+/// dequeued is the only element of `head`, which is empty until the first element is dequeued. Dequeueing only
+/// increments this index, so elements are never moved once they are written. As both are backends, a queue on
+/// `StorageBackend`s persists between calls, while a queue on `MemBackend`s only lives for the duration of a call.
+pub struct BackendQueue<B, H, V>
+where
+    B: Backend<V>,
+    H: Backend<u64>,
+{
+    /// The backend which holds the elements in the order they were enqueued.
+    items: B,
+    /// The backend which holds the index in `items` of the oldest element which has not been dequeued.
+    head: H,
+}
+
+impl<B, H, V> BackendQueue<B, H, V>
+where
+    B: Backend<V>,
+    H: Backend<u64>,
+{
+    /// Creates a queue on a pair of backends.
+    ///
+    /// # Additional Information
+    ///
+    /// The elements of `items` from the index held by `head` onwards become the elements of the queue, which
+    /// holds for any pair of backends that are empty or were only modified by a `BackendQueue`. A queue in
+    /// storage is recreated on the same `StorageBackend`s in every call.
+    ///
+    /// # Arguments
+    ///
+    /// * `items`: [B] - The backend which holds the elements of the queue.
+    /// * `head`: [H] - The backend which holds the index of the oldest element of the queue.
+    ///
+    /// # Returns
+    ///
+    /// * [BackendQueue<B, H, V>] - The newly created `BackendQueue` struct.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use collection_backend::{MemBackend, StorageBackend};
+    /// use queue::backend_queue::BackendQueue;
+    ///
+    /// storage {
+    ///     items: StorageBackend<u64> = StorageBackend {},
+    ///     head: StorageBackend<u64> = StorageBackend {},
+    /// }
+    ///
+    /// #[storage(read)]
+    /// fn foo() {
+    ///     let stored_queue = BackendQueue::new(storage.items, storage.head);
+    ///     let queue = BackendQueue::new(MemBackend::<u64>::new(), MemBackend::<u64>::new());
+    ///     assert(queue.len() == stored_queue.len());
+    /// }
+    /// ```
+    pub fn new(items: B, head: H) -> Self {
+        Self { items, head }
+    }
+
+    /// Adds an element to the end of the queue.
+    ///
+    /// # Arguments
+    ///
+    /// * `item`: [V] - The value to enqueue.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1` on a `StorageBackend`
+    /// * Writes: `2` on a `StorageBackend`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use collection_backend::MemBackend;
+    /// use queue::backend_queue::BackendQueue;
+    ///
+    /// #[storage(read, write)]
+    /// fn foo() {
+    ///     let mut queue = BackendQueue::new(MemBackend::<u64>::new(), MemBackend::<u64>::new());
+    ///     queue.enqueue(5);
+    ///     assert(queue.peek().unwrap() == 5);
+    /// }
+    /// ```
+    #[storage(read, write)]
+    pub fn enqueue(ref mut self, item: V) {
+        self.items.push(item);
+    }
+
+    /// Removes the oldest element of the queue and returns it.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<V>] - The first element to be enqueued or `None` if the queue is empty.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: At most `8` on `StorageBackend`s
+    /// * Writes: At most `2` on `StorageBackend`s
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use collection_backend::MemBackend;
+    /// use queue::backend_queue::BackendQueue;
+    ///
+    /// #[storage(read, write)]
+    /// fn foo() {
+    ///     let mut queue = BackendQueue::new(MemBackend::<u64>::new(), MemBackend::<u64>::new());
+    ///     queue.enqueue(5);
+    ///     assert(queue.dequeue().unwrap() == 5);
+    ///     assert(queue.is_empty());
+    /// }
+    /// ```
+    #[storage(read, write)]
+    pub fn dequeue(ref mut self) -> Option<V> {
+        let head = self.head();
+        if head == self.items.len() {
+            return Option::None;
+        }
+
+        self.set_head(head + 1);
+        Option::Some(self.items.get(head))
+    }
+
+    /// Returns the oldest element of the queue without removing it.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<V>] - The first element to be enqueued or `None` if the queue is empty.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: At most `6` on `StorageBackend`s
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use collection_backend::MemBackend;
+    /// use queue::backend_queue::BackendQueue;
+    ///
+    /// #[storage(read, write)]
+    /// fn foo() {
+    ///     let mut queue = BackendQueue::new(MemBackend::<u64>::new(), MemBackend::<u64>::new());
+    ///     queue.enqueue(5);
+    ///     assert(queue.peek().unwrap() == 5);
+    ///     assert(queue.len() == 1);
+    /// }
+    /// ```
+    #[storage(read)]
+    pub fn peek(self) -> Option<V> {
+        let head = self.head();
+        if head == self.items.len() {
+            return Option::None;
+        }
+
+        Option::Some(self.items.get(head))
+    }
+
+    /// Returns the number of elements in the queue.
+    ///
+    /// # Returns
+    ///
+    /// * [u64] - The number of elements which have been enqueued and not yet dequeued.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: At most `4` on `StorageBackend`s
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use collection_backend::MemBackend;
+    /// use queue::backend_queue::BackendQueue;
+    ///
+    /// #[storage(read, write)]
+    /// fn foo() {
+    ///     let mut queue = BackendQueue::new(MemBackend::<u64>::new(), MemBackend::<u64>::new());
+    ///     queue.enqueue(5);
+    ///     assert(queue.len() == 1);
+    /// }
+    /// ```
+    #[storage(read)]
+    pub fn len(self) -> u64 {
+        self.items.len() - self.head()
+    }
+
+    /// Returns whether the queue contains no elements.
+    ///
+    /// # Returns
+    ///
+    /// * [bool] - `true` if the queue is empty, otherwise `false`.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: At most `4` on `StorageBackend`s
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use collection_backend::MemBackend;
+    /// use queue::backend_queue::BackendQueue;
+    ///
+    /// #[storage(read, write)]
+    /// fn foo() {
+    ///     let mut queue = BackendQueue::new(MemBackend::<u64>::new(), MemBackend::<u64>::new());
+    ///     assert(queue.is_empty());
+    ///     queue.enqueue(5);
+    ///     assert(!queue.is_empty());
+    /// }
+    /// ```
+    #[storage(read)]
+    pub fn is_empty(self) -> bool {
+        self.head() == self.items.len()
+    }
+}
+
+impl<B, H, V> BackendQueue<B, H, V>
+where
+    B: Backend<V>,
+    H: Backend<u64>,
+{
+    /// Returns the index in `items` of the oldest element which has not been dequeued.
+    #[storage(read)]
+    fn head(self) -> u64 {
+        if self.head.len() == 0 {
+            return 0;
+        }
+
+        self.head.get(0)
+    }
+
+    /// Overwrites the index in `items` of the oldest element which has not been dequeued.
+    #[storage(read, write)]
+    fn set_head(ref mut self, index: u64) {
+        if self.head.len() == 0 {
+            self.head.push(index);
+        } else {
+            self.head.set(0, index);
+        }
+    }
+}
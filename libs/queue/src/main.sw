@@ -1,4 +1,7 @@
 library;
+
+pub mod backend_queue;
+
 /// The `Queue` type corresponds to the same called data structure.
 ///
 /// # Additional Information
@@ -5,6 +5,10 @@ library;
 //! to prevent reentrancy: `assert(!is_reentrant());`
 use std::call_frames::*;
 use std::registers::frame_ptr;
+use std::storage::storage_api::{read, write};
+
+/// The storage slot of the reentrancy lock, which is the SHA-256 hash of `"reentrancy.lock"`.
+const LOCK_SLOT: b256 = 0xc3e401f0f2ba660c5a31ccc244c6c70388a51080a2ba072d2d185f27a14a4095;
 
 /// Error log for when reentrancy has been detected
 pub enum ReentrancyError {
@@ -78,3 +82,96 @@ pub fn is_reentrant() -> bool {
     // The current contract ID wasn't found in any contract calls prior to here.
     false
 }
+
+/// Sets the reentrancy lock of the contract in which this is called.
+///
+/// # Additional Information
+///
+/// Guards a function against every reentrant call, including cross-contract reentrancy, when it calls
+/// `lock()` on entry and `unlock()` before returning. The lock is kept in a storage slot of its own, so it
+/// does not collide with the other storage of the contract. A revert rolls back the lock along with every
+/// other write of the transaction, so the lock cannot be left set by a failed call.
+///
+/// # Reverts
+///
+/// * When the lock is already set.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+/// * Writes: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use reentrancy::{lock, unlock};
+///
+/// #[storage(read, write)]
+/// fn foo() {
+///     lock();
+///     // Do critical stuff here
+///     unlock();
+/// }
+/// ```
+#[storage(read, write)]
+pub fn lock() {
+    require_not_entered();
+    write(LOCK_SLOT, 0, true);
+}
+
+/// Clears the reentrancy lock of the contract in which this is called.
+///
+/// # Number of Storage Accesses
+///
+/// * Writes: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use reentrancy::{lock, unlock};
+///
+/// #[storage(read, write)]
+/// fn foo() {
+///     lock();
+///     // Do critical stuff here
+///     unlock();
+/// }
+/// ```
+#[storage(write)]
+pub fn unlock() {
+    write(LOCK_SLOT, 0, false);
+}
+
+/// Reverts if the reentrancy lock of the contract in which this is called is set.
+///
+/// # Additional Information
+///
+/// Guards functions which do not take the lock themselves, such as those which read state that a locked
+/// function has not finished updating.
+///
+/// # Reverts
+///
+/// * When the lock is set.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use reentrancy::require_not_entered;
+///
+/// #[storage(read)]
+/// fn foo() {
+///     require_not_entered();
+///     // Read state here
+/// }
+/// ```
+#[storage(read)]
+pub fn require_not_entered() {
+    require(
+        !read::<bool>(LOCK_SLOT, 0).unwrap_or(false),
+        ReentrancyError::NonReentrant,
+    );
+}
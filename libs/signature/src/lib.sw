@@ -0,0 +1,72 @@
+library;
+
+pub mod structured_hash;
+
+use std::{b512::B512, ecr::ec_recover_address};
+
+/// Recovers the address which signed a message.
+///
+/// # Additional Information
+///
+/// The signature is in the 64 byte compact form which Fuel uses, where the recovery id is stored in the
+/// highest bit of `s`. Recovery fails for malformed signatures, which results in `None` rather than a revert.
+/// A well formed signature over a different message recovers a different address, so the recovered address
+/// must still be compared against the expected signer, as done by `verify_signature`.
+///
+/// # Arguments
+///
+/// * `message_hash`: [b256] - The hash of the message which was signed.
+/// * `sig`: [B512] - The compact signature of `message_hash`.
+///
+/// # Returns
+///
+/// * [Option<Address>] - The address of the signer, or `None` if `sig` is not a valid signature.
+///
+/// # Examples
+///
+/// ```sway
+/// use signature::recover_address;
+/// use std::b512::B512;
+///
+/// fn foo(message_hash: b256, sig: B512) {
+///     match recover_address(message_hash, sig) {
+///         Option::Some(signer) => log(signer),
+///         Option::None => revert(0),
+///     }
+/// }
+/// ```
+pub fn recover_address(message_hash: b256, sig: B512) -> Option<Address> {
+    match ec_recover_address(sig, message_hash) {
+        Result::Ok(address) => Option::Some(address),
+        Result::Err(_) => Option::None,
+    }
+}
+
+/// Returns whether a message was signed by an address.
+///
+/// # Arguments
+///
+/// * `message_hash`: [b256] - The hash of the message which was signed.
+/// * `sig`: [B512] - The compact signature of `message_hash`.
+/// * `expected`: [Address] - The address which is expected to have signed the message.
+///
+/// # Returns
+///
+/// * [bool] - `true` if `sig` is a valid signature of `message_hash` by `expected`, otherwise `false`.
+///
+/// # Examples
+///
+/// ```sway
+/// use signature::verify_signature;
+/// use std::b512::B512;
+///
+/// fn foo(message_hash: b256, sig: B512, signer: Address) {
+///     require(verify_signature(message_hash, sig, signer), "InvalidSignature");
+/// }
+/// ```
+pub fn verify_signature(message_hash: b256, sig: B512, expected: Address) -> bool {
+    match recover_address(message_hash, sig) {
+        Option::Some(address) => address == expected,
+        Option::None => false,
+    }
+}
@@ -0,0 +1,93 @@
+library;
+
+use std::{bytes::Bytes, hash::{Hash, keccak256}};
+
+/// The type hash of the domain, `keccak256("EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)")`.
+pub const EIP712_DOMAIN_TYPE_HASH: b256 = 0x8b73c3c69bb8fe3d512ecc4cf759cc79239f7b179b0ffacaa9a75d522b39400f;
+
+/// Returns the EIP-712 domain separator which binds a signature to a single contract.
+///
+/// # Additional Information
+///
+/// The separator is `keccak256(EIP712_DOMAIN_TYPE_HASH ++ name ++ version ++ chain_id ++ contract_addr)`, where
+/// `chain_id` is encoded as a 32 byte big endian word. As in EIP-712, `name` and `version` are the `keccak256`
+/// hashes of the strings they represent.
+///
+/// The address is encoded as the full 32 bytes of a Fuel `Address`. An EVM address is 20 bytes, so the separator
+/// only matches one computed by EVM tooling when the first 12 bytes of `contract_addr` are zero.
+///
+/// # Arguments
+///
+/// * `name`: [b256] - The hash of the user readable name of the signing domain.
+/// * `version`: [b256] - The hash of the current major version of the signing domain.
+/// * `chain_id`: [u64] - The id of the chain which the contract is deployed to.
+/// * `contract_addr`: [Address] - The address of the contract which verifies the signature.
+///
+/// # Returns
+///
+/// * [b256] - The domain separator.
+///
+/// # Examples
+///
+/// ```sway
+/// use signature::structured_hash::domain_separator;
+/// use std::hash::keccak256;
+///
+/// fn foo(contract_addr: Address) {
+///     let separator = domain_separator(keccak256("Fuel Permit"), keccak256("1"), 0, contract_addr);
+/// }
+/// ```
+pub fn domain_separator(
+    name: b256,
+    version: b256,
+    chain_id: u64,
+    contract_addr: Address,
+) -> b256 {
+    keccak256((
+        EIP712_DOMAIN_TYPE_HASH,
+        name,
+        version,
+        (0u64, 0u64, 0u64, chain_id),
+        contract_addr.value,
+    ))
+}
+
+/// Returns the digest of a structured message which is to be signed, "keccak256(0x19 || 0x01 || domain_sep || message_hash)".
+///
+/// # Additional Information
+///
+/// The digest is the value passed to `ec_recover` when verifying an EIP-712 signature.
+///
+/// # Arguments
+///
+/// * `domain_sep`: [b256] - The domain separator, as returned by `domain_separator`.
+/// * `message_hash`: [b256] - The EIP-712 `hashStruct` of the message.
+///
+/// # Returns
+///
+/// * [b256] - The digest of the message.
+///
+/// # Examples
+///
+/// ```sway
+/// use signature::structured_hash::{domain_separator, hash_typed_data};
+/// use std::hash::keccak256;
+///
+/// fn foo(contract_addr: Address, message_hash: b256) {
+///     let separator = domain_separator(keccak256("Fuel Permit"), keccak256("1"), 0, contract_addr);
+///     let digest = hash_typed_data(separator, message_hash);
+/// }
+/// ```
+pub fn hash_typed_data(domain_sep: b256, message_hash: b256) -> b256 {
+    let mut bytes = Bytes::with_capacity(66);
+    let new_ptr_domain = bytes.buf.ptr().add_uint_offset(2);
+    let new_ptr_message = bytes.buf.ptr().add_uint_offset(34);
+
+    bytes.buf.ptr().write_byte(0x19u8);
+    bytes.buf.ptr().add_uint_offset(1).write_byte(0x01u8);
+    __addr_of(domain_sep).copy_bytes_to(new_ptr_domain, 32);
+    __addr_of(message_hash).copy_bytes_to(new_ptr_message, 32);
+    bytes.len = 66;
+
+    keccak256(bytes)
+}
@@ -9,3 +9,24 @@ pub trait TwosComplement {
     /// * [Self] - The value as two's complement.
     fn twos_complement(self) -> Self;
 }
+
+/// The result of comparing two values.
+pub enum Ordering {
+    /// The first value is less than the second.
+    Less: (),
+    /// The first value is equal to the second.
+    Equal: (),
+    /// The first value is greater than the second.
+    Greater: (),
+}
+
+impl core::ops::Eq for Ordering {
+    fn eq(self, other: Self) -> bool {
+        match (self, other) {
+            (Ordering::Less, Ordering::Less) => true,
+            (Ordering::Equal, Ordering::Equal) => true,
+            (Ordering::Greater, Ordering::Greater) => true,
+            _ => false,
+        }
+    }
+}
@@ -4,4 +4,8 @@ library;
 pub enum Error {
     /// Emitted when division by zero has occured.
     ZeroDivisor: (),
+    /// Emitted when the result of an operation cannot be represented by the type.
+    Overflow: (),
+    /// Emitted when the lower bound of a range is greater than its upper bound.
+    InvalidRange: (),
 }
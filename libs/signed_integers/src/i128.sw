@@ -31,12 +31,12 @@ impl I128 {
     ///
     /// fn foo() {
     ///     let zero = I128::indent();
-    ///     assert(zero == U128 { upper: 1, lower: 0 } );
+    ///     assert(zero == U128 { upper: 9223372036854775808, lower: 0 } );
     /// }
     /// ```
     pub fn indent() -> U128 {
         U128 {
-            upper: 1,
+            upper: 9223372036854775808,
             lower: 0,
         }
     }
@@ -45,7 +45,7 @@ impl I128 {
 impl From<U128> for I128 {
     /// Helper function to get a signed number from with an underlying
     fn from(value: U128) -> Self {
-        // as the minimal value of I128 is -I128::indent() (1 << 63) we should add I128::indent() (1 << 63) 
+        // as the minimal value of I128 is -I128::indent() (1 << 127) we should add I128::indent() (1 << 127) 
         let underlying: U128 = value + Self::indent();
         Self { underlying }
     }
@@ -181,9 +181,9 @@ impl I128 {
     /// use std::U128::*;
     ///
     /// fn foo() {
-    ///     let underlying = U128::from(1, 0);
+    ///     let underlying = U128::from((0, 1));
     ///     let i128 = I128::neg_from(underlying);
-    ///     assert(i128.underlying == U128::from(0, 0));
+    ///     assert(i128.underlying == U128::from((9223372036854775807, u64::max())));
     /// }
     /// ```
     pub fn neg_from(value: U128) -> Self {
@@ -196,7 +196,7 @@ impl I128 {
     ///
     /// # Additional Information
     ///
-    /// The zero value of I128 is U128 { upper: 1, lower: 0 }.
+    /// The zero value of I128 is U128 { upper: 9223372036854775808, lower: 0 }.
     ///
     /// # Returns
     ///
@@ -210,7 +210,7 @@ impl I128 {
     ///
     /// fn foo() {
     ///     let i128 = I128::new();
-    ///     assert(i128.underlying == U128 { upper: 1, lower: 0 });
+    ///     assert(i128.underlying == U128 { upper: 9223372036854775808, lower: 0 });
     /// }
     /// ```
     pub fn new() -> Self {
@@ -223,21 +223,9 @@ impl I128 {
 impl core::ops::Add for I128 {
     /// Add a I128 to a I128. Panics on overflow.
     fn add(self, other: Self) -> Self {
-        // subtract 1 << 63 to avoid double move
-        let mut res = Self::new();
-        if (self.underlying > Self::indent() || self.underlying == Self::indent()) {
-            res = Self::from_uint(self.underlying - Self::indent() + other.underlying) // subtract 1 << 31 to avoid double move
-        } else if self.underlying < Self::indent()
-            && other.underlying < Self::indent()
-        {
-            res = Self::from_uint(self.underlying + other.underlying - Self::indent());
-        } else if self.underlying < Self::indent()
-            && (other.underlying > Self::indent()
-            || other.underlying == Self::indent())
-        {
-            res = Self::from_uint(other.underlying - Self::indent() + self.underlying);
-        }
-        res
+        let res = self.checked_add(other);
+        require(res.is_some(), Error::Overflow);
+        res.unwrap()
     }
 }
 
@@ -272,66 +260,18 @@ impl core::ops::Divide for I128 {
 impl core::ops::Multiply for I128 {
     /// Multiply a I128 with a I128. Panics of overflow.
     fn multiply(self, other: Self) -> Self {
-        let mut res = Self::new();
-        if (self.underlying > Self::indent()
-            || self.underlying == Self::indent())
-            && (other.underlying > Self::indent()
-            || other.underlying == Self::indent())
-        {
-            res = Self::from_uint((self.underlying - Self::indent()) * (other.underlying - Self::indent()) + Self::indent());
-        } else if self.underlying < Self::indent()
-            && other.underlying < Self::indent()
-        {
-            res = Self::from_uint((Self::indent() - self.underlying) * (Self::indent() - other.underlying) + Self::indent());
-        } else if (self.underlying > Self::indent()
-            || self.underlying == Self::indent())
-            && other.underlying < Self::indent()
-        {
-            res = Self::from_uint(Self::indent() - (self.underlying - Self::indent()) * (Self::indent() - other.underlying));
-        } else if self.underlying < Self::indent()
-            && (other.underlying > Self::indent()
-            || other.underlying == Self::indent())
-        {
-            res = Self::from_uint(Self::indent() - (other.underlying - Self::indent()) * (Self::indent() - self.underlying));
-        }
-        res
+        let res = self.checked_mul(other);
+        require(res.is_some(), Error::Overflow);
+        res.unwrap()
     }
 }
 
 impl core::ops::Subtract for I128 {
-    /// Subtract a I128 from a I128. Panics of overflow.
+    /// Subtract a I128 from a I128. Panics on overflow.
     fn subtract(self, other: Self) -> Self {
-        let mut res = Self::new();
-        if (self.underlying > Self::indent()
-            || self.underlying == Self::indent())
-            && (other.underlying > Self::indent()
-            || other.underlying == Self::indent())
-        {
-            if self.underlying > other.underlying {
-                res = Self::from_uint(self.underlying - other.underlying + Self::indent());
-            } else {
-                res = Self::from_uint(self.underlying - (other.underlying - Self::indent()));
-            }
-        } else if (self.underlying > Self::indent()
-            || self.underlying == Self::indent())
-            && other.underlying < Self::indent()
-        {
-            res = Self::from_uint(self.underlying - Self::indent() + other.underlying);
-        } else if self.underlying < Self::indent()
-            && (other.underlying > Self::indent()
-            || other.underlying == Self::indent())
-        {
-            res = Self::from_uint(self.underlying - (other.underlying - Self::indent()));
-        } else if self.underlying < Self::indent()
-            && other.underlying < Self::indent()
-        {
-            if self.underlying < other.underlying {
-                res = Self::from_uint(other.underlying - self.underlying + Self::indent());
-            } else {
-                res = Self::from_uint(self.underlying + other.underlying - Self::indent());
-            }
-        }
-        res
+        let res = self.checked_sub(other);
+        require(res.is_some(), Error::Overflow);
+        res.unwrap()
     }
 }
 
@@ -350,3 +290,216 @@ impl TwosComplement for I128 {
         res
     }
 }
+
+impl core::ops::Shift for I128 {
+    /// Logical left shift of the two's complement bits of a I128. Shifting by `I128::bits()` or more results in zero.
+    fn lsh(self, other: u64) -> Self {
+        if other >= Self::bits() {
+            return Self::new();
+        }
+
+        // The biased and two's complement representations only differ in the sign bit
+        let twos_complement_bits = if self.underlying < Self::indent() {
+            self.underlying + Self::indent()
+        } else {
+            self.underlying - Self::indent()
+        };
+        let shifted = twos_complement_bits << other;
+        if shifted < Self::indent() {
+            Self::from_uint(shifted + Self::indent())
+        } else {
+            Self::from_uint(shifted - Self::indent())
+        }
+    }
+
+    /// Arithmetic right shift of a I128, preserving the sign. Shifting by `I128::bits()` or more results in zero or negative one.
+    fn rsh(self, other: u64) -> Self {
+        let u_one = U128::from((0, 1));
+        if self.underlying < Self::indent() {
+            // For negative values `x >> n == -(((-x - 1) >> n) + 1)`
+            let mut shifted = Self::indent() - self.underlying - u_one;
+            if other >= Self::bits() {
+                shifted = U128::from((0, 0));
+            } else {
+                shifted = shifted >> other;
+            }
+            Self::from_uint(Self::indent() - shifted - u_one)
+        } else {
+            if other >= Self::bits() {
+                return Self::new();
+            }
+            Self::from_uint(((self.underlying - Self::indent()) >> other) + Self::indent())
+        }
+    }
+}
+
+impl I128 {
+    /// Checked addition. Computes `self + other`, returning `None` if overflow occurred.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I128] - The value to add to `self`.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<I128>] - The sum of the two values or `None` if the sum is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i128::I128;
+    /// use std::u128::U128;
+    ///
+    /// fn foo() {
+    ///     let res = I128::from(U128::from((0, 1))).checked_add(I128::from(U128::from((0, 2))));
+    ///     assert(res.unwrap() == I128::from(U128::from((0, 3))));
+    ///     assert(I128::max().checked_add(I128::from(U128::from((0, 1)))).is_none());
+    /// }
+    /// ```
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        if other.underlying < Self::indent() {
+            let magnitude = Self::indent() - other.underlying;
+            if magnitude > self.underlying - Self::min().underlying {
+                return Option::None;
+            }
+            Option::Some(Self::from_uint(self.underlying - magnitude))
+        } else {
+            let magnitude = other.underlying - Self::indent();
+            if magnitude > Self::max().underlying - self.underlying {
+                return Option::None;
+            }
+            Option::Some(Self::from_uint(self.underlying + magnitude))
+        }
+    }
+
+    /// Checked subtraction. Computes `self - other`, returning `None` if overflow occurred.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I128] - The value to subtract from `self`.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<I128>] - The difference of the two values or `None` if the difference is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i128::I128;
+    /// use std::u128::U128;
+    ///
+    /// fn foo() {
+    ///     let res = I128::from(U128::from((0, 5))).checked_sub(I128::neg_from(U128::from((0, 3))));
+    ///     assert(res.unwrap() == I128::from(U128::from((0, 8))));
+    ///     assert(I128::min().checked_sub(I128::from(U128::from((0, 1)))).is_none());
+    /// }
+    /// ```
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        if other.underlying < Self::indent() {
+            let magnitude = Self::indent() - other.underlying;
+            if magnitude > Self::max().underlying - self.underlying {
+                return Option::None;
+            }
+            Option::Some(Self::from_uint(self.underlying + magnitude))
+        } else {
+            let magnitude = other.underlying - Self::indent();
+            if magnitude > self.underlying - Self::min().underlying {
+                return Option::None;
+            }
+            Option::Some(Self::from_uint(self.underlying - magnitude))
+        }
+    }
+
+    /// Checked multiplication. Computes `self * other`, returning `None` if overflow occurred.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I128] - The value to multiply `self` by.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<I128>] - The product of the two values or `None` if the product is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i128::I128;
+    /// use std::u128::U128;
+    ///
+    /// fn foo() {
+    ///     let res = I128::from(U128::from((0, 2))).checked_mul(I128::neg_from(U128::from((0, 3))));
+    ///     assert(res.unwrap() == I128::neg_from(U128::from((0, 6))));
+    ///     assert(I128::max().checked_mul(I128::from(U128::from((0, 2)))).is_none());
+    /// }
+    /// ```
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        let self_negative = self.underlying < Self::indent();
+        let other_negative = other.underlying < Self::indent();
+        let self_magnitude = if self_negative {
+            Self::indent() - self.underlying
+        } else {
+            self.underlying - Self::indent()
+        };
+        let other_magnitude = if other_negative {
+            Self::indent() - other.underlying
+        } else {
+            other.underlying - Self::indent()
+        };
+
+        // A negative product may be one larger in magnitude than a positive one
+        let limit = if self_negative == other_negative {
+            Self::max().underlying - Self::indent()
+        } else {
+            Self::indent()
+        };
+        // There is no wider intermediate type, so the product is bounded by division before it is computed
+        if other_magnitude != U128::from((0, 0))
+            && self_magnitude > limit / other_magnitude
+        {
+            return Option::None;
+        }
+
+        let product = self_magnitude * other_magnitude;
+        if self_negative == other_negative {
+            Option::Some(Self::from_uint(Self::indent() + product))
+        } else {
+            Option::Some(Self::from_uint(Self::indent() - product))
+        }
+    }
+
+    /// Checked division. Computes `self / other`, returning `None` if `other` is zero or the division overflows.
+    ///
+    /// # Additional Information
+    ///
+    /// The only overflowing division is `I128::min()` divided by negative one.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I128] - The value to divide `self` by.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<I128>] - The quotient of the two values or `None` if the division is not possible.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i128::I128;
+    /// use std::u128::U128;
+    ///
+    /// fn foo() {
+    ///     let res = I128::from(U128::from((0, 6))).checked_div(I128::neg_from(U128::from((0, 3))));
+    ///     assert(res.unwrap() == I128::neg_from(U128::from((0, 2))));
+    ///     assert(I128::from(U128::from((0, 6))).checked_div(I128::new()).is_none());
+    /// }
+    /// ```
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        if other == Self::new()
+            || (self == Self::min() && other == Self::neg_from(U128::from((0, 1))))
+        {
+            return Option::None;
+        }
+
+        Option::Some(self / other)
+    }
+}
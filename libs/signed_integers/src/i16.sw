@@ -1,7 +1,8 @@
 library;
 
+use ::i8::I8;
 use ::errors::Error;
-use ::common::TwosComplement;
+use ::common::{Ordering, TwosComplement};
 
 /// The 16-bit signed integer type.
 ///
@@ -210,29 +211,54 @@ impl I16 {
     }
 }
 
+impl I16 {
+    /// Converts a `u64` to a `I16`, returning `None` if the value is out of range.
+    ///
+    /// # Arguments
+    ///
+    /// * `value`: [u64] - The unsigned value to convert.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<I16>] - The value as a `I16` or `None` if `value` is greater than `I16::max()`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i16::I16;
+    ///
+    /// fn foo() {
+    ///     assert(I16::try_from_u64(32767).unwrap() == I16::max());
+    ///     assert(I16::try_from_u64(32768).is_none());
+    /// }
+    /// ```
+    pub fn try_from_u64(value: u64) -> Option<Self> {
+        if value >= Self::indent().as_u64() {
+            return Option::None;
+        }
+
+        Option::Some(Self::from_uint(asm(ptr: value + Self::indent().as_u64()) { ptr: u16 }))
+    }
+}
+
 impl core::ops::Add for I16 {
     /// Add a I16 to a I16. Panics on overflow.
     fn add(self, other: Self) -> Self {
-        let mut res = Self::new();
-        if self.underlying >= Self::indent() {
-            res = Self::from_uint(self.underlying - Self::indent() + other.underlying) // subtract 1 << 15 to avoid double move
-        } else if self.underlying < Self::indent()
-            && other.underlying < Self::indent()
-        {
-            res = Self::from_uint(self.underlying + other.underlying - Self::indent());
-        } else if self.underlying < Self::indent()
-            && other.underlying >= Self::indent()
-        {
-            res = Self::from_uint(other.underlying - Self::indent() + self.underlying);
-        }
-        res
+        let res = self.checked_add(other);
+        require(res.is_some(), Error::Overflow);
+        res.unwrap()
     }
 }
 
 impl core::ops::Divide for I16 {
-    /// Divide a I16 by a I16. Panics if divisor is zero.
+    /// Divide a I16 by a I16. Panics if divisor is zero or on overflow.
     fn divide(self, divisor: Self) -> Self {
         require(divisor != Self::new(), Error::ZeroDivisor);
+        // `min() / -1` is `max() + 1`, which cannot be represented
+        require(
+            !(self == Self::min() && divisor == Self::neg_from(1u16)),
+            Error::Overflow,
+        );
         let mut res = Self::new();
         if self.underlying >= Self::indent()
             && divisor.underlying > Self::indent()
@@ -258,63 +284,49 @@ impl core::ops::Divide for I16 {
 impl core::ops::Multiply for I16 {
     /// Multiply a I16 with a I16. Panics of overflow.
     fn multiply(self, other: Self) -> Self {
-        let mut res = Self::new();
-        if self.underlying >= Self::indent()
-            && other.underlying >= Self::indent()
-        {
-            res = Self::from_uint((self.underlying - Self::indent()) * (other.underlying - Self::indent()) + Self::indent());
-        } else if self.underlying < Self::indent()
-            && other.underlying < Self::indent()
-        {
-            res = Self::from_uint((Self::indent() - self.underlying) * (Self::indent() - other.underlying) + Self::indent());
-        } else if self.underlying >= Self::indent()
-            && other.underlying < Self::indent()
-        {
-            res = Self::from_uint(Self::indent() - (self.underlying - Self::indent()) * (Self::indent() - other.underlying));
-        } else if self.underlying < Self::indent()
-            && other.underlying >= Self::indent()
-        {
-            res = Self::from_uint(Self::indent() - (other.underlying - Self::indent()) * (Self::indent() - self.underlying));
-        }
-        res
+        let res = self.checked_mul(other);
+        require(res.is_some(), Error::Overflow);
+        res.unwrap()
     }
 }
 
 impl core::ops::Subtract for I16 {
-    /// Subtract a I16 from a I16. Panics of overflow.
+    /// Subtract a I16 from a I16. Panics on overflow.
     fn subtract(self, other: Self) -> Self {
-        let mut res = Self::new();
-        if self.underlying >= Self::indent()
-            && other.underlying >= Self::indent()
-        {
-            if self.underlying > other.underlying {
-                res = Self::from_uint(self.underlying - other.underlying + Self::indent());
-            } else {
-                res = Self::from_uint(self.underlying - (other.underlying - Self::indent()));
-            }
-        } else if self.underlying >= Self::indent()
-            && other.underlying < Self::indent()
-        {
-            res = Self::from_uint(self.underlying - Self::indent() + other.underlying);
-        } else if self.underlying < Self::indent()
-            && other.underlying >= Self::indent()
-        {
-            res = Self::from_uint(self.underlying - (other.underlying - Self::indent()));
-        } else if self.underlying < Self::indent()
-            && other.underlying < Self::indent()
-        {
-            if self.underlying < other.underlying {
-                res = Self::from_uint(other.underlying - self.underlying + Self::indent());
-            } else {
-                res = Self::from_uint(self.underlying + other.underlying - Self::indent());
-            }
-        }
-        res
+        let res = self.checked_sub(other);
+        require(res.is_some(), Error::Overflow);
+        res.unwrap()
     }
 }
 
 impl TwosComplement for I16 {
+    /// Returns the two's complement of a value.
+    ///
+    /// # Additional Information
+    ///
+    /// Negative values are complemented into their magnitude, while zero and positive values are returned unchanged.
+    ///
+    /// # Returns
+    ///
+    /// * [I16] - The value as two's complement.
+    ///
+    /// # Reverts
+    ///
+    /// * When `self` is `I16::min()`, as its complement cannot be represented.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::{common::TwosComplement, i16::I16};
+    ///
+    /// fn foo() {
+    ///     let res = I16::neg_from(5u16).twos_complement();
+    ///     assert(res == I16::from(5u16));
+    /// }
+    /// ```
     fn twos_complement(self) -> Self {
+        require(self != Self::min(), Error::Overflow);
+
         if self.underlying >= Self::indent() {
             return self;
         }
@@ -322,3 +334,627 @@ impl TwosComplement for I16 {
         res
     }
 }
+
+impl I16 {
+    /// Converts the underlying value into its two's complement bit pattern.
+    fn as_twos_complement_bits(self) -> u64 {
+        // The biased and two's complement representations only differ in the sign bit
+        (self.underlying ^ Self::indent()).as_u64()
+    }
+
+    /// Creates an `I16` from a two's complement bit pattern which fits in `Self::bits()` bits.
+    fn from_twos_complement_bits(bits: u64) -> Self {
+        Self::from_uint(asm(ptr: bits) { ptr: u16 } ^ Self::indent())
+    }
+
+    /// Wrapping (modular) addition. Computes `self + other`, wrapping around at the boundary of the type.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I16] - The value to add to `self`.
+    ///
+    /// # Returns
+    ///
+    /// * [I16] - The sum of the two values, wrapped around on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i16::I16;
+    ///
+    /// fn foo() {
+    ///     let res = I16::max().wrapping_add(I16::from(1u16));
+    ///     assert(res == I16::min());
+    /// }
+    /// ```
+    pub fn wrapping_add(self, other: Self) -> Self {
+        let modulus = 1 << Self::bits();
+        let res = (self.as_twos_complement_bits() + other.as_twos_complement_bits()) % modulus;
+        Self::from_twos_complement_bits(res)
+    }
+
+    /// Wrapping (modular) subtraction. Computes `self - other`, wrapping around at the boundary of the type.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I16] - The value to subtract from `self`.
+    ///
+    /// # Returns
+    ///
+    /// * [I16] - The difference of the two values, wrapped around on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i16::I16;
+    ///
+    /// fn foo() {
+    ///     let res = I16::min().wrapping_sub(I16::from(1u16));
+    ///     assert(res == I16::max());
+    /// }
+    /// ```
+    pub fn wrapping_sub(self, other: Self) -> Self {
+        let modulus = 1 << Self::bits();
+        let res = (self.as_twos_complement_bits() + modulus - other.as_twos_complement_bits()) % modulus;
+        Self::from_twos_complement_bits(res)
+    }
+
+    /// Wrapping (modular) multiplication. Computes `self * other`, wrapping around at the boundary of the type.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I16] - The value to multiply `self` by.
+    ///
+    /// # Returns
+    ///
+    /// * [I16] - The product of the two values, wrapped around on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i16::I16;
+    ///
+    /// fn foo() {
+    ///     let res = I16::max().wrapping_mul(I16::from(2u16));
+    ///     assert(res == I16::neg_from(2u16));
+    /// }
+    /// ```
+    pub fn wrapping_mul(self, other: Self) -> Self {
+        let modulus = 1 << Self::bits();
+        let res = (self.as_twos_complement_bits() * other.as_twos_complement_bits()) % modulus;
+        Self::from_twos_complement_bits(res)
+    }
+}
+
+impl I16 {
+    /// Saturating addition. Computes `self + other`, saturating at the numeric bounds instead of overflowing.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I16] - The value to add to `self`.
+    ///
+    /// # Returns
+    ///
+    /// * [I16] - The sum of the two values, or `I16::max()` / `I16::min()` if the sum is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i16::I16;
+    ///
+    /// fn foo() {
+    ///     let res = I16::max().saturating_add(I16::from(1u16));
+    ///     assert(res == I16::max());
+    /// }
+    /// ```
+    pub fn saturating_add(self, other: Self) -> Self {
+        match self.checked_add(other) {
+            Option::Some(res) => res,
+            Option::None => {
+                if other.underlying >= Self::indent() {
+                    Self::max()
+                } else {
+                    Self::min()
+                }
+            },
+        }
+    }
+
+    /// Saturating subtraction. Computes `self - other`, saturating at the numeric bounds instead of overflowing.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I16] - The value to subtract from `self`.
+    ///
+    /// # Returns
+    ///
+    /// * [I16] - The difference of the two values, or `I16::max()` / `I16::min()` if the difference is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i16::I16;
+    ///
+    /// fn foo() {
+    ///     let res = I16::min().saturating_sub(I16::from(1u16));
+    ///     assert(res == I16::min());
+    /// }
+    /// ```
+    pub fn saturating_sub(self, other: Self) -> Self {
+        match self.checked_sub(other) {
+            Option::Some(res) => res,
+            Option::None => {
+                if other.underlying >= Self::indent() {
+                    Self::min()
+                } else {
+                    Self::max()
+                }
+            },
+        }
+    }
+}
+
+impl I16 {
+    /// Checked addition. Computes `self + other`, returning `None` if overflow occurred.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I16] - The value to add to `self`.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<I16>] - The sum of the two values or `None` if the sum is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i16::I16;
+    ///
+    /// fn foo() {
+    ///     let res = I16::from(1u16).checked_add(I16::from(2u16));
+    ///     assert(res.unwrap() == I16::from(3u16));
+    ///     assert(I16::max().checked_add(I16::from(1u16)).is_none());
+    /// }
+    /// ```
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        if other.underlying >= Self::indent() {
+            let magnitude = other.underlying - Self::indent();
+            if magnitude > Self::max().underlying - self.underlying {
+                return Option::None;
+            }
+            Option::Some(Self::from_uint(self.underlying + magnitude))
+        } else {
+            let magnitude = Self::indent() - other.underlying;
+            if magnitude > self.underlying - Self::min().underlying {
+                return Option::None;
+            }
+            Option::Some(Self::from_uint(self.underlying - magnitude))
+        }
+    }
+
+    /// Checked subtraction. Computes `self - other`, returning `None` if overflow occurred.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I16] - The value to subtract from `self`.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<I16>] - The difference of the two values or `None` if the difference is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i16::I16;
+    ///
+    /// fn foo() {
+    ///     let res = I16::from(1u16).checked_sub(I16::from(2u16));
+    ///     assert(res.unwrap() == I16::neg_from(1u16));
+    ///     assert(I16::min().checked_sub(I16::from(1u16)).is_none());
+    /// }
+    /// ```
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        if other.underlying >= Self::indent() {
+            let magnitude = other.underlying - Self::indent();
+            if magnitude > self.underlying - Self::min().underlying {
+                return Option::None;
+            }
+            Option::Some(Self::from_uint(self.underlying - magnitude))
+        } else {
+            let magnitude = Self::indent() - other.underlying;
+            if magnitude > Self::max().underlying - self.underlying {
+                return Option::None;
+            }
+            Option::Some(Self::from_uint(self.underlying + magnitude))
+        }
+    }
+
+    /// Checked multiplication. Computes `self * other`, returning `None` if overflow occurred.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I16] - The value to multiply `self` by.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<I16>] - The product of the two values or `None` if the product is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i16::I16;
+    ///
+    /// fn foo() {
+    ///     let res = I16::from(2u16).checked_mul(I16::neg_from(3u16));
+    ///     assert(res.unwrap() == I16::neg_from(6u16));
+    ///     assert(I16::max().checked_mul(I16::from(2u16)).is_none());
+    /// }
+    /// ```
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        let self_magnitude = if self.underlying >= Self::indent() {
+            (self.underlying - Self::indent()).as_u64()
+        } else {
+            (Self::indent() - self.underlying).as_u64()
+        };
+        let other_magnitude = if other.underlying >= Self::indent() {
+            (other.underlying - Self::indent()).as_u64()
+        } else {
+            (Self::indent() - other.underlying).as_u64()
+        };
+        // The product of two magnitudes of at most 2 ^ 15 always fits in a u64
+        let product = self_magnitude * other_magnitude;
+        let indent = Self::indent().as_u64();
+
+        if (self.underlying >= Self::indent()) == (other.underlying >= Self::indent()) {
+            if product > Self::max().underlying.as_u64() - indent {
+                return Option::None;
+            }
+            Option::Some(Self::from_uint(asm(ptr: indent + product) { ptr: u16 }))
+        } else {
+            if product > indent {
+                return Option::None;
+            }
+            Option::Some(Self::from_uint(asm(ptr: indent - product) { ptr: u16 }))
+        }
+    }
+
+    /// Checked division. Computes `self / other`, returning `None` if `other` is zero or the division overflows.
+    ///
+    /// # Additional Information
+    ///
+    /// The only overflowing division is `I16::min()` divided by negative one.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I16] - The value to divide `self` by.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<I16>] - The quotient of the two values or `None` if the division is not possible.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i16::I16;
+    ///
+    /// fn foo() {
+    ///     let res = I16::from(6u16).checked_div(I16::neg_from(3u16));
+    ///     assert(res.unwrap() == I16::neg_from(2u16));
+    ///     assert(I16::from(6u16).checked_div(I16::new()).is_none());
+    /// }
+    /// ```
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        if other == Self::new()
+            || (self == Self::min() && other == Self::neg_from(1u16))
+        {
+            return Option::None;
+        }
+
+        Option::Some(self / other)
+    }
+}
+
+impl core::ops::Shift for I16 {
+    /// Logical left shift of the two's complement bits of a I16. Shifting by `I16::bits()` or more results in zero.
+    fn lsh(self, other: u64) -> Self {
+        if other >= Self::bits() {
+            return Self::new();
+        }
+
+        let modulus = 1 << Self::bits();
+        Self::from_twos_complement_bits((self.as_twos_complement_bits() << other) % modulus)
+    }
+
+    /// Arithmetic right shift of a I16, preserving the sign. Shifting by `I16::bits()` or more results in zero or negative one.
+    fn rsh(self, other: u64) -> Self {
+        if self.underlying >= Self::indent() {
+            if other >= Self::bits() {
+                return Self::new();
+            }
+            Self::from_uint(((self.underlying - Self::indent()) >> other) + Self::indent())
+        } else {
+            // For negative values `x >> n == -(((-x - 1) >> n) + 1)`
+            let mut shifted = Self::indent() - self.underlying - 1u16;
+            if other >= Self::bits() {
+                shifted = 0u16;
+            } else {
+                shifted = shifted >> other;
+            }
+            Self::from_uint(Self::indent() - shifted - 1u16)
+        }
+    }
+}
+
+impl I16 {
+    /// Converts an `I8` into an `I16`. This conversion can never fail.
+    ///
+    /// # Arguments
+    ///
+    /// * `value`: [I8] - The value to widen.
+    ///
+    /// # Returns
+    ///
+    /// * [I16] - The same value represented as an `I16`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::{i8::I8, i16::I16};
+    ///
+    /// fn foo() {
+    ///     let res = I16::from_i8(I8::min());
+    ///     assert(res == I16::neg_from(128u16));
+    /// }
+    /// ```
+    pub fn from_i8(value: I8) -> Self {
+        Self::from_uint(asm(ptr: value.underlying.as_u64() + Self::indent().as_u64() - I8::indent().as_u64()) { ptr: u16 })
+    }
+
+    /// Converts an `I16` into an `I8` if the value is within the bounds of `I8`.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<I8>] - The same value represented as an `I8`, or `None` if it does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::{i8::I8, i16::I16};
+    ///
+    /// fn foo() {
+    ///     let res = I16::neg_from(1u16).try_into_i8();
+    ///     assert(res.unwrap() == I8::neg_from(1u8));
+    ///
+    ///     let res = I16::max().try_into_i8();
+    ///     assert(res.is_none());
+    /// }
+    /// ```
+    pub fn try_into_i8(self) -> Option<I8> {
+        let offset = Self::indent().as_u64() - I8::indent().as_u64();
+        let underlying = self.underlying.as_u64();
+        if underlying < offset || underlying - offset > I8::max().underlying.as_u64() {
+            return Option::None;
+        }
+
+        let underlying = underlying - offset;
+        Option::Some(I8::from_uint(asm(ptr: underlying) { ptr: u8 }))
+    }
+}
+
+impl I16 {
+    /// Computes the absolute value of `self`.
+    ///
+    /// # Returns
+    ///
+    /// * [I16] - The absolute value of `self`.
+    ///
+    /// # Reverts
+    ///
+    /// * When `self` is `I16::min()`, as its absolute value cannot be represented.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i16::I16;
+    ///
+    /// fn foo() {
+    ///     let res = I16::neg_from(5u16).abs();
+    ///     assert(res == I16::from(5u16));
+    /// }
+    /// ```
+    pub fn abs(self) -> Self {
+        require(self != Self::min(), Error::Overflow);
+
+        if self.underlying >= Self::indent() {
+            self
+        } else {
+            Self::from_uint(Self::indent() - self.underlying + Self::indent())
+        }
+    }
+
+    /// Wrapping (modular) negation. Computes `-self`, wrapping around at the boundary of the type.
+    ///
+    /// # Additional Information
+    ///
+    /// The only case where wrapping occurs is `I16::min()`, which is returned unchanged.
+    ///
+    /// # Returns
+    ///
+    /// * [I16] - The negation of `self`, wrapped around on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i16::I16;
+    ///
+    /// fn foo() {
+    ///     let res = I16::from(5u16).wrapping_neg();
+    ///     assert(res == I16::neg_from(5u16));
+    ///
+    ///     let res = I16::min().wrapping_neg();
+    ///     assert(res == I16::min());
+    /// }
+    /// ```
+    pub fn wrapping_neg(self) -> Self {
+        Self::new().wrapping_sub(self)
+    }
+
+    /// Negation. Computes `-self`.
+    ///
+    /// # Returns
+    ///
+    /// * [I16] - The negation of `self`.
+    ///
+    /// # Reverts
+    ///
+    /// * When `self` is `I16::min()`, as `-I16::min()` is one greater than `I16::max()`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i16::I16;
+    ///
+    /// fn foo() {
+    ///     let res = I16::from(5u16).neg();
+    ///     assert(res == I16::neg_from(5u16));
+    ///
+    ///     let res = I16::neg_from(5u16).neg();
+    ///     assert(res == I16::from(5u16));
+    /// }
+    /// ```
+    pub fn neg(self) -> Self {
+        require(self != Self::min(), Error::Overflow);
+        Self::new().wrapping_sub(self)
+    }
+}
+
+impl I16 {
+    /// Returns the lesser of two values.
+    ///
+    /// # Additional Information
+    ///
+    /// Named `minimum` as `min()` already returns the smallest value of the type. Returns `self` when both are equal.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I16] - The value to compare `self` with.
+    ///
+    /// # Returns
+    ///
+    /// * [I16] - `other` if it is less than `self`, otherwise `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i16::I16;
+    ///
+    /// fn foo() {
+    ///     let res = I16::neg_from(3u16).minimum(I16::from(5u16));
+    ///     assert(res == I16::neg_from(3u16));
+    /// }
+    /// ```
+    pub fn minimum(self, other: Self) -> Self {
+        if other < self {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the greater of two values.
+    ///
+    /// # Additional Information
+    ///
+    /// Named `maximum` as `max()` already returns the largest value of the type. Returns `self` when both are equal.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I16] - The value to compare `self` with.
+    ///
+    /// # Returns
+    ///
+    /// * [I16] - `other` if it is greater than `self`, otherwise `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i16::I16;
+    ///
+    /// fn foo() {
+    ///     let res = I16::neg_from(3u16).maximum(I16::from(5u16));
+    ///     assert(res == I16::from(5u16));
+    /// }
+    /// ```
+    pub fn maximum(self, other: Self) -> Self {
+        if other > self {
+            other
+        } else {
+            self
+        }
+    }
+}
+
+impl I16 {
+    /// Restricts `self` to the range from `min` to `max`, inclusive.
+    ///
+    /// # Arguments
+    ///
+    /// * `min`: [I16] - The lower bound of the range.
+    /// * `max`: [I16] - The upper bound of the range.
+    ///
+    /// # Returns
+    ///
+    /// * [I16] - `min` if `self` is less than `min`, `max` if `self` is greater than `max`, otherwise `self`.
+    ///
+    /// # Reverts
+    ///
+    /// * When `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i16::I16;
+    ///
+    /// fn foo() {
+    ///     let res = I16::neg_from(10u16).clamp(I16::neg_from(5u16), I16::from(5u16));
+    ///     assert(res == I16::neg_from(5u16));
+    /// }
+    /// ```
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        require(!(min > max), Error::InvalidRange);
+
+        self.maximum(min).minimum(max)
+    }
+}
+
+impl I16 {
+    /// Compares two values, returning whether `self` is less than, equal to or greater than `other`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I16] - The value to compare `self` with.
+    ///
+    /// # Returns
+    ///
+    /// * [Ordering] - `Less`, `Equal` or `Greater` depending on how `self` relates to `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::{common::Ordering, i16::I16};
+    ///
+    /// fn foo() {
+    ///     assert(I16::neg_from(2u16).compare(I16::from(1u16)) == Ordering::Less);
+    ///     assert(I16::from(1u16).compare(I16::from(1u16)) == Ordering::Equal);
+    /// }
+    /// ```
+    pub fn compare(self, other: Self) -> Ordering {
+        if self.underlying < other.underlying {
+            Ordering::Less
+        } else if self.underlying > other.underlying {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+}
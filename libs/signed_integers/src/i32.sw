@@ -1,6 +1,8 @@
 library;
 
-use ::common::TwosComplement;
+use ::i8::I8;
+use ::i16::I16;
+use ::common::{Ordering, TwosComplement};
 use ::errors::Error;
 
 /// The 32-bit signed integer type.
@@ -209,87 +211,72 @@ impl I32 {
     }
 }
 
+impl I32 {
+    /// Converts a `u64` to a `I32`, returning `None` if the value is out of range.
+    ///
+    /// # Arguments
+    ///
+    /// * `value`: [u64] - The unsigned value to convert.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<I32>] - The value as a `I32` or `None` if `value` is greater than `I32::max()`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i32::I32;
+    ///
+    /// fn foo() {
+    ///     assert(I32::try_from_u64(2147483647).unwrap() == I32::max());
+    ///     assert(I32::try_from_u64(2147483648).is_none());
+    /// }
+    /// ```
+    pub fn try_from_u64(value: u64) -> Option<Self> {
+        if value >= Self::indent().as_u64() {
+            return Option::None;
+        }
+
+        Option::Some(Self::from_uint(asm(ptr: value + Self::indent().as_u64()) { ptr: u32 }))
+    }
+}
+
 impl core::ops::Add for I32 {
     /// Add a I32 to a I32. Panics on overflow.
     fn add(self, other: Self) -> Self {
-        let mut res = Self::new();
-        if self.underlying >= Self::indent() {
-            res = Self::from_uint(self.underlying - Self::indent() + other.underlying) // subtract 1 << 31 to avoid double move
-        } else if self.underlying < Self::indent()
-            && other.underlying < Self::indent()
-        {
-            res = Self::from_uint(self.underlying + other.underlying - Self::indent());
-        } else if self.underlying < Self::indent()
-            && other.underlying >= Self::indent()
-        {
-            res = Self::from_uint(other.underlying - Self::indent() + self.underlying);
-        }
-        res
+        let res = self.checked_add(other);
+        require(res.is_some(), Error::Overflow);
+        res.unwrap()
     }
 }
 
 impl core::ops::Subtract for I32 {
-    /// Subtract a I32 from a I32. Panics of overflow.
+    /// Subtract a I32 from a I32. Panics on overflow.
     fn subtract(self, other: Self) -> Self {
-        let mut res = Self::new();
-        if self.underlying >= Self::indent()
-            && other.underlying >= Self::indent()
-        {
-            if self.underlying > other.underlying {
-                res = Self::from_uint(self.underlying - other.underlying + Self::indent());
-            } else {
-                res = Self::from_uint(self.underlying - (other.underlying - Self::indent()));
-            }
-        } else if self.underlying >= Self::indent()
-            && other.underlying < Self::indent()
-        {
-            res = Self::from_uint(self.underlying - Self::indent() + other.underlying);
-        } else if self.underlying < Self::indent()
-            && other.underlying >= Self::indent()
-        {
-            res = Self::from_uint(self.underlying - (other.underlying - Self::indent()));
-        } else if self.underlying < Self::indent()
-            && other.underlying < Self::indent()
-        {
-            if self.underlying < other.underlying {
-                res = Self::from_uint(other.underlying - self.underlying + Self::indent());
-            } else {
-                res = Self::from_uint(self.underlying + other.underlying - Self::indent());
-            }
-        }
-        res
+        let res = self.checked_sub(other);
+        require(res.is_some(), Error::Overflow);
+        res.unwrap()
     }
 }
 
 impl core::ops::Multiply for I32 {
     /// Multiply a I32 with a I32. Panics of overflow.
     fn multiply(self, other: Self) -> Self {
-        let mut res = Self::new();
-        if self.underlying >= Self::indent()
-            && other.underlying >= Self::indent()
-        {
-            res = Self::from_uint((self.underlying - Self::indent()) * (other.underlying - Self::indent()) + Self::indent());
-        } else if self.underlying < Self::indent()
-            && other.underlying < Self::indent()
-        {
-            res = Self::from_uint((Self::indent() - self.underlying) * (Self::indent() - other.underlying) + Self::indent());
-        } else if self.underlying >= Self::indent()
-            && other.underlying < Self::indent()
-        {
-            res = Self::from_uint(Self::indent() - (self.underlying - Self::indent()) * (Self::indent() - other.underlying));
-        } else if self.underlying < Self::indent()
-            && other.underlying >= Self::indent()
-        {
-            res = Self::from_uint(Self::indent() - (other.underlying - Self::indent()) * (Self::indent() - self.underlying));
-        }
-        res
+        let res = self.checked_mul(other);
+        require(res.is_some(), Error::Overflow);
+        res.unwrap()
     }
 }
 
 impl core::ops::Divide for I32 {
-    /// Divide a I32 by a I32. Panics if divisor is zero.
+    /// Divide a I32 by a I32. Panics if divisor is zero or on overflow.
     fn divide(self, divisor: Self) -> Self {
         require(divisor != Self::new(), Error::ZeroDivisor);
+        // `min() / -1` is `max() + 1`, which cannot be represented
+        require(
+            !(self == Self::min() && divisor == Self::neg_from(1u32)),
+            Error::Overflow,
+        );
         let mut res = Self::new();
         if self.underlying >= Self::indent()
             && divisor.underlying > Self::indent()
@@ -313,7 +300,33 @@ impl core::ops::Divide for I32 {
 }
 
 impl TwosComplement for I32 {
+    /// Returns the two's complement of a value.
+    ///
+    /// # Additional Information
+    ///
+    /// Negative values are complemented into their magnitude, while zero and positive values are returned unchanged.
+    ///
+    /// # Returns
+    ///
+    /// * [I32] - The value as two's complement.
+    ///
+    /// # Reverts
+    ///
+    /// * When `self` is `I32::min()`, as its complement cannot be represented.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::{common::TwosComplement, i32::I32};
+    ///
+    /// fn foo() {
+    ///     let res = I32::neg_from(5u32).twos_complement();
+    ///     assert(res == I32::from(5u32));
+    /// }
+    /// ```
     fn twos_complement(self) -> Self {
+        require(self != Self::min(), Error::Overflow);
+
         if self.underlying >= Self::indent() {
             return self;
         }
@@ -321,3 +334,791 @@ impl TwosComplement for I32 {
         res
     }
 }
+
+impl I32 {
+    /// Converts the underlying value into its two's complement bit pattern.
+    fn as_twos_complement_bits(self) -> u64 {
+        // The biased and two's complement representations only differ in the sign bit
+        (self.underlying ^ Self::indent()).as_u64()
+    }
+
+    /// Creates an `I32` from a two's complement bit pattern which fits in `Self::bits()` bits.
+    fn from_twos_complement_bits(bits: u64) -> Self {
+        Self::from_uint(asm(ptr: bits) { ptr: u32 } ^ Self::indent())
+    }
+
+    /// Wrapping (modular) addition. Computes `self + other`, wrapping around at the boundary of the type.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I32] - The value to add to `self`.
+    ///
+    /// # Returns
+    ///
+    /// * [I32] - The sum of the two values, wrapped around on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i32::I32;
+    ///
+    /// fn foo() {
+    ///     let res = I32::max().wrapping_add(I32::from(1u32));
+    ///     assert(res == I32::min());
+    /// }
+    /// ```
+    pub fn wrapping_add(self, other: Self) -> Self {
+        let modulus = 1 << Self::bits();
+        let res = (self.as_twos_complement_bits() + other.as_twos_complement_bits()) % modulus;
+        Self::from_twos_complement_bits(res)
+    }
+
+    /// Wrapping (modular) subtraction. Computes `self - other`, wrapping around at the boundary of the type.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I32] - The value to subtract from `self`.
+    ///
+    /// # Returns
+    ///
+    /// * [I32] - The difference of the two values, wrapped around on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i32::I32;
+    ///
+    /// fn foo() {
+    ///     let res = I32::min().wrapping_sub(I32::from(1u32));
+    ///     assert(res == I32::max());
+    /// }
+    /// ```
+    pub fn wrapping_sub(self, other: Self) -> Self {
+        let modulus = 1 << Self::bits();
+        let res = (self.as_twos_complement_bits() + modulus - other.as_twos_complement_bits()) % modulus;
+        Self::from_twos_complement_bits(res)
+    }
+
+    /// Wrapping (modular) multiplication. Computes `self * other`, wrapping around at the boundary of the type.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I32] - The value to multiply `self` by.
+    ///
+    /// # Returns
+    ///
+    /// * [I32] - The product of the two values, wrapped around on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i32::I32;
+    ///
+    /// fn foo() {
+    ///     let res = I32::max().wrapping_mul(I32::from(2u32));
+    ///     assert(res == I32::neg_from(2u32));
+    /// }
+    /// ```
+    pub fn wrapping_mul(self, other: Self) -> Self {
+        let modulus = 1 << Self::bits();
+        let res = (self.as_twos_complement_bits() * other.as_twos_complement_bits()) % modulus;
+        Self::from_twos_complement_bits(res)
+    }
+}
+
+impl I32 {
+    /// Saturating addition. Computes `self + other`, saturating at the numeric bounds instead of overflowing.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I32] - The value to add to `self`.
+    ///
+    /// # Returns
+    ///
+    /// * [I32] - The sum of the two values, or `I32::max()` / `I32::min()` if the sum is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i32::I32;
+    ///
+    /// fn foo() {
+    ///     let res = I32::max().saturating_add(I32::from(1u32));
+    ///     assert(res == I32::max());
+    /// }
+    /// ```
+    pub fn saturating_add(self, other: Self) -> Self {
+        match self.checked_add(other) {
+            Option::Some(res) => res,
+            Option::None => {
+                if other.underlying >= Self::indent() {
+                    Self::max()
+                } else {
+                    Self::min()
+                }
+            },
+        }
+    }
+
+    /// Saturating subtraction. Computes `self - other`, saturating at the numeric bounds instead of overflowing.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I32] - The value to subtract from `self`.
+    ///
+    /// # Returns
+    ///
+    /// * [I32] - The difference of the two values, or `I32::max()` / `I32::min()` if the difference is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i32::I32;
+    ///
+    /// fn foo() {
+    ///     let res = I32::min().saturating_sub(I32::from(1u32));
+    ///     assert(res == I32::min());
+    /// }
+    /// ```
+    pub fn saturating_sub(self, other: Self) -> Self {
+        match self.checked_sub(other) {
+            Option::Some(res) => res,
+            Option::None => {
+                if other.underlying >= Self::indent() {
+                    Self::min()
+                } else {
+                    Self::max()
+                }
+            },
+        }
+    }
+}
+
+impl I32 {
+    /// Checked addition. Computes `self + other`, returning `None` if overflow occurred.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I32] - The value to add to `self`.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<I32>] - The sum of the two values or `None` if the sum is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i32::I32;
+    ///
+    /// fn foo() {
+    ///     let res = I32::from(1u32).checked_add(I32::from(2u32));
+    ///     assert(res.unwrap() == I32::from(3u32));
+    ///     assert(I32::max().checked_add(I32::from(1u32)).is_none());
+    /// }
+    /// ```
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        if other.underlying >= Self::indent() {
+            let magnitude = other.underlying - Self::indent();
+            if magnitude > Self::max().underlying - self.underlying {
+                return Option::None;
+            }
+            Option::Some(Self::from_uint(self.underlying + magnitude))
+        } else {
+            let magnitude = Self::indent() - other.underlying;
+            if magnitude > self.underlying - Self::min().underlying {
+                return Option::None;
+            }
+            Option::Some(Self::from_uint(self.underlying - magnitude))
+        }
+    }
+
+    /// Checked subtraction. Computes `self - other`, returning `None` if overflow occurred.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I32] - The value to subtract from `self`.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<I32>] - The difference of the two values or `None` if the difference is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i32::I32;
+    ///
+    /// fn foo() {
+    ///     let res = I32::from(1u32).checked_sub(I32::from(2u32));
+    ///     assert(res.unwrap() == I32::neg_from(1u32));
+    ///     assert(I32::min().checked_sub(I32::from(1u32)).is_none());
+    /// }
+    /// ```
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        if other.underlying >= Self::indent() {
+            let magnitude = other.underlying - Self::indent();
+            if magnitude > self.underlying - Self::min().underlying {
+                return Option::None;
+            }
+            Option::Some(Self::from_uint(self.underlying - magnitude))
+        } else {
+            let magnitude = Self::indent() - other.underlying;
+            if magnitude > Self::max().underlying - self.underlying {
+                return Option::None;
+            }
+            Option::Some(Self::from_uint(self.underlying + magnitude))
+        }
+    }
+
+    /// Checked multiplication. Computes `self * other`, returning `None` if overflow occurred.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I32] - The value to multiply `self` by.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<I32>] - The product of the two values or `None` if the product is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i32::I32;
+    ///
+    /// fn foo() {
+    ///     let res = I32::from(2u32).checked_mul(I32::neg_from(3u32));
+    ///     assert(res.unwrap() == I32::neg_from(6u32));
+    ///     assert(I32::max().checked_mul(I32::from(2u32)).is_none());
+    /// }
+    /// ```
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        let self_magnitude = if self.underlying >= Self::indent() {
+            (self.underlying - Self::indent()).as_u64()
+        } else {
+            (Self::indent() - self.underlying).as_u64()
+        };
+        let other_magnitude = if other.underlying >= Self::indent() {
+            (other.underlying - Self::indent()).as_u64()
+        } else {
+            (Self::indent() - other.underlying).as_u64()
+        };
+        // The product of two magnitudes of at most 2 ^ 31 always fits in a u64
+        let product = self_magnitude * other_magnitude;
+        let indent = Self::indent().as_u64();
+
+        if (self.underlying >= Self::indent()) == (other.underlying >= Self::indent()) {
+            if product > Self::max().underlying.as_u64() - indent {
+                return Option::None;
+            }
+            Option::Some(Self::from_uint(asm(ptr: indent + product) { ptr: u32 }))
+        } else {
+            if product > indent {
+                return Option::None;
+            }
+            Option::Some(Self::from_uint(asm(ptr: indent - product) { ptr: u32 }))
+        }
+    }
+
+    /// Checked division. Computes `self / other`, returning `None` if `other` is zero or the division overflows.
+    ///
+    /// # Additional Information
+    ///
+    /// The only overflowing division is `I32::min()` divided by negative one.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I32] - The value to divide `self` by.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<I32>] - The quotient of the two values or `None` if the division is not possible.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i32::I32;
+    ///
+    /// fn foo() {
+    ///     let res = I32::from(6u32).checked_div(I32::neg_from(3u32));
+    ///     assert(res.unwrap() == I32::neg_from(2u32));
+    ///     assert(I32::from(6u32).checked_div(I32::new()).is_none());
+    /// }
+    /// ```
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        if other == Self::new()
+            || (self == Self::min() && other == Self::neg_from(1u32))
+        {
+            return Option::None;
+        }
+
+        Option::Some(self / other)
+    }
+}
+
+impl core::ops::Shift for I32 {
+    /// Logical left shift of the two's complement bits of a I32. Shifting by `I32::bits()` or more results in zero.
+    fn lsh(self, other: u64) -> Self {
+        if other >= Self::bits() {
+            return Self::new();
+        }
+
+        let modulus = 1 << Self::bits();
+        Self::from_twos_complement_bits((self.as_twos_complement_bits() << other) % modulus)
+    }
+
+    /// Arithmetic right shift of a I32, preserving the sign. Shifting by `I32::bits()` or more results in zero or negative one.
+    fn rsh(self, other: u64) -> Self {
+        if self.underlying >= Self::indent() {
+            if other >= Self::bits() {
+                return Self::new();
+            }
+            Self::from_uint(((self.underlying - Self::indent()) >> other) + Self::indent())
+        } else {
+            // For negative values `x >> n == -(((-x - 1) >> n) + 1)`
+            let mut shifted = Self::indent() - self.underlying - 1u32;
+            if other >= Self::bits() {
+                shifted = 0u32;
+            } else {
+                shifted = shifted >> other;
+            }
+            Self::from_uint(Self::indent() - shifted - 1u32)
+        }
+    }
+}
+
+impl I32 {
+    /// Converts an `I8` into an `I32`. This conversion can never fail.
+    ///
+    /// # Arguments
+    ///
+    /// * `value`: [I8] - The value to widen.
+    ///
+    /// # Returns
+    ///
+    /// * [I32] - The same value represented as an `I32`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::{i8::I8, i32::I32};
+    ///
+    /// fn foo() {
+    ///     let res = I32::from_i8(I8::min());
+    ///     assert(res == I32::neg_from(128u32));
+    /// }
+    /// ```
+    pub fn from_i8(value: I8) -> Self {
+        Self::from_uint(asm(ptr: value.underlying.as_u64() + Self::indent().as_u64() - I8::indent().as_u64()) { ptr: u32 })
+    }
+
+    /// Converts an `I32` into an `I8` if the value is within the bounds of `I8`.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<I8>] - The same value represented as an `I8`, or `None` if it does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::{i8::I8, i32::I32};
+    ///
+    /// fn foo() {
+    ///     let res = I32::neg_from(1u32).try_into_i8();
+    ///     assert(res.unwrap() == I8::neg_from(1u8));
+    ///
+    ///     let res = I32::max().try_into_i8();
+    ///     assert(res.is_none());
+    /// }
+    /// ```
+    pub fn try_into_i8(self) -> Option<I8> {
+        let offset = Self::indent().as_u64() - I8::indent().as_u64();
+        let underlying = self.underlying.as_u64();
+        if underlying < offset || underlying - offset > I8::max().underlying.as_u64() {
+            return Option::None;
+        }
+
+        let underlying = underlying - offset;
+        Option::Some(I8::from_uint(asm(ptr: underlying) { ptr: u8 }))
+    }
+
+    /// Converts an `I16` into an `I32`. This conversion can never fail.
+    ///
+    /// # Arguments
+    ///
+    /// * `value`: [I16] - The value to widen.
+    ///
+    /// # Returns
+    ///
+    /// * [I32] - The same value represented as an `I32`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::{i16::I16, i32::I32};
+    ///
+    /// fn foo() {
+    ///     let res = I32::from_i16(I16::min());
+    ///     assert(res == I32::neg_from(32768u32));
+    /// }
+    /// ```
+    pub fn from_i16(value: I16) -> Self {
+        Self::from_uint(asm(ptr: value.underlying.as_u64() + Self::indent().as_u64() - I16::indent().as_u64()) { ptr: u32 })
+    }
+
+    /// Converts an `I32` into an `I16` if the value is within the bounds of `I16`.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<I16>] - The same value represented as an `I16`, or `None` if it does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::{i16::I16, i32::I32};
+    ///
+    /// fn foo() {
+    ///     let res = I32::neg_from(1u32).try_into_i16();
+    ///     assert(res.unwrap() == I16::neg_from(1u16));
+    ///
+    ///     let res = I32::max().try_into_i16();
+    ///     assert(res.is_none());
+    /// }
+    /// ```
+    pub fn try_into_i16(self) -> Option<I16> {
+        let offset = Self::indent().as_u64() - I16::indent().as_u64();
+        let underlying = self.underlying.as_u64();
+        if underlying < offset || underlying - offset > I16::max().underlying.as_u64() {
+            return Option::None;
+        }
+
+        let underlying = underlying - offset;
+        Option::Some(I16::from_uint(asm(ptr: underlying) { ptr: u16 }))
+    }
+}
+
+impl I32 {
+    /// Computes the absolute value of `self`.
+    ///
+    /// # Returns
+    ///
+    /// * [I32] - The absolute value of `self`.
+    ///
+    /// # Reverts
+    ///
+    /// * When `self` is `I32::min()`, as its absolute value cannot be represented.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i32::I32;
+    ///
+    /// fn foo() {
+    ///     let res = I32::neg_from(5u32).abs();
+    ///     assert(res == I32::from(5u32));
+    /// }
+    /// ```
+    pub fn abs(self) -> Self {
+        require(self != Self::min(), Error::Overflow);
+
+        if self.underlying >= Self::indent() {
+            self
+        } else {
+            Self::from_uint(Self::indent() - self.underlying + Self::indent())
+        }
+    }
+
+    /// Wrapping (modular) negation. Computes `-self`, wrapping around at the boundary of the type.
+    ///
+    /// # Additional Information
+    ///
+    /// The only case where wrapping occurs is `I32::min()`, which is returned unchanged.
+    ///
+    /// # Returns
+    ///
+    /// * [I32] - The negation of `self`, wrapped around on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i32::I32;
+    ///
+    /// fn foo() {
+    ///     let res = I32::from(5u32).wrapping_neg();
+    ///     assert(res == I32::neg_from(5u32));
+    ///
+    ///     let res = I32::min().wrapping_neg();
+    ///     assert(res == I32::min());
+    /// }
+    /// ```
+    pub fn wrapping_neg(self) -> Self {
+        Self::new().wrapping_sub(self)
+    }
+
+    /// Negation. Computes `-self`.
+    ///
+    /// # Returns
+    ///
+    /// * [I32] - The negation of `self`.
+    ///
+    /// # Reverts
+    ///
+    /// * When `self` is `I32::min()`, as `-I32::min()` is one greater than `I32::max()`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i32::I32;
+    ///
+    /// fn foo() {
+    ///     let res = I32::from(5u32).neg();
+    ///     assert(res == I32::neg_from(5u32));
+    ///
+    ///     let res = I32::neg_from(5u32).neg();
+    ///     assert(res == I32::from(5u32));
+    /// }
+    /// ```
+    pub fn neg(self) -> Self {
+        require(self != Self::min(), Error::Overflow);
+        Self::new().wrapping_sub(self)
+    }
+}
+
+impl I32 {
+    /// Raises `self` to the power of `exponent`.
+    ///
+    /// # Additional Information
+    ///
+    /// Uses binary exponentiation, which takes at most `2 * log2(exponent)` multiplications.
+    /// Any value to the power of `0` is `1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `exponent`: [u32] - The power to raise `self` to.
+    ///
+    /// # Returns
+    ///
+    /// * [I32] - `self` raised to the power of `exponent`.
+    ///
+    /// # Reverts
+    ///
+    /// * When the result cannot be represented by an `I32`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i32::I32;
+    ///
+    /// fn foo() {
+    ///     let res = I32::neg_from(3u32).pow(3);
+    ///     assert(res == I32::neg_from(27u32));
+    /// }
+    /// ```
+    pub fn pow(self, exponent: u32) -> Self {
+        let mut result = Self::from(1u32);
+        let mut base = self;
+        let mut exponent = exponent;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                let product = result.checked_mul(base);
+                require(product.is_some(), Error::Overflow);
+                result = product.unwrap();
+            }
+            exponent = exponent >> 1;
+            // Only square when it is needed, as squaring after the last bit may overflow
+            if exponent > 0 {
+                let square = base.checked_mul(base);
+                require(square.is_some(), Error::Overflow);
+                base = square.unwrap();
+            }
+        }
+        result
+    }
+}
+
+impl I32 {
+    /// Computes the least non-negative remainder of `self` divided by `other`.
+    ///
+    /// # Additional Information
+    ///
+    /// Unlike a truncated remainder, the result is never negative and `0 <= result < |other|`.
+    /// This matches Rust's `rem_euclid`, so `-7` modulo `3` is `2`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I32] - The divisor.
+    ///
+    /// # Returns
+    ///
+    /// * [I32] - The non-negative remainder.
+    ///
+    /// # Reverts
+    ///
+    /// * When `other` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i32::I32;
+    ///
+    /// fn foo() {
+    ///     let res = I32::neg_from(7u32).rem_euclid(I32::from(3u32));
+    ///     assert(res == I32::from(2u32));
+    ///
+    ///     let res = I32::from(7u32).rem_euclid(I32::neg_from(3u32));
+    ///     assert(res == I32::from(1u32));
+    /// }
+    /// ```
+    pub fn rem_euclid(self, other: Self) -> Self {
+        require(other != Self::new(), Error::ZeroDivisor);
+
+        let self_magnitude = if self.underlying >= Self::indent() {
+            (self.underlying - Self::indent()).as_u64()
+        } else {
+            (Self::indent() - self.underlying).as_u64()
+        };
+        let other_magnitude = if other.underlying >= Self::indent() {
+            (other.underlying - Self::indent()).as_u64()
+        } else {
+            (Self::indent() - other.underlying).as_u64()
+        };
+        let indent = Self::indent().as_u64();
+
+        let mut remainder = self_magnitude % other_magnitude;
+        // A negative dividend leaves a remainder below zero which is moved up by one divisor
+        if self.underlying < Self::indent() && remainder != 0 {
+            remainder = other_magnitude - remainder;
+        }
+        // The remainder is below the magnitude of the divisor so it always fits
+        Self::from_uint(asm(ptr: indent + remainder) { ptr: u32 })
+    }
+}
+
+impl I32 {
+    /// Returns the lesser of two values.
+    ///
+    /// # Additional Information
+    ///
+    /// Named `minimum` as `min()` already returns the smallest value of the type. Returns `self` when both are equal.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I32] - The value to compare `self` with.
+    ///
+    /// # Returns
+    ///
+    /// * [I32] - `other` if it is less than `self`, otherwise `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i32::I32;
+    ///
+    /// fn foo() {
+    ///     let res = I32::neg_from(3u32).minimum(I32::from(5u32));
+    ///     assert(res == I32::neg_from(3u32));
+    /// }
+    /// ```
+    pub fn minimum(self, other: Self) -> Self {
+        if other < self {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the greater of two values.
+    ///
+    /// # Additional Information
+    ///
+    /// Named `maximum` as `max()` already returns the largest value of the type. Returns `self` when both are equal.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I32] - The value to compare `self` with.
+    ///
+    /// # Returns
+    ///
+    /// * [I32] - `other` if it is greater than `self`, otherwise `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i32::I32;
+    ///
+    /// fn foo() {
+    ///     let res = I32::neg_from(3u32).maximum(I32::from(5u32));
+    ///     assert(res == I32::from(5u32));
+    /// }
+    /// ```
+    pub fn maximum(self, other: Self) -> Self {
+        if other > self {
+            other
+        } else {
+            self
+        }
+    }
+}
+
+impl I32 {
+    /// Restricts `self` to the range from `min` to `max`, inclusive.
+    ///
+    /// # Arguments
+    ///
+    /// * `min`: [I32] - The lower bound of the range.
+    /// * `max`: [I32] - The upper bound of the range.
+    ///
+    /// # Returns
+    ///
+    /// * [I32] - `min` if `self` is less than `min`, `max` if `self` is greater than `max`, otherwise `self`.
+    ///
+    /// # Reverts
+    ///
+    /// * When `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i32::I32;
+    ///
+    /// fn foo() {
+    ///     let res = I32::neg_from(10u32).clamp(I32::neg_from(5u32), I32::from(5u32));
+    ///     assert(res == I32::neg_from(5u32));
+    /// }
+    /// ```
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        require(!(min > max), Error::InvalidRange);
+
+        self.maximum(min).minimum(max)
+    }
+}
+
+impl I32 {
+    /// Compares two values, returning whether `self` is less than, equal to or greater than `other`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I32] - The value to compare `self` with.
+    ///
+    /// # Returns
+    ///
+    /// * [Ordering] - `Less`, `Equal` or `Greater` depending on how `self` relates to `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::{common::Ordering, i32::I32};
+    ///
+    /// fn foo() {
+    ///     assert(I32::neg_from(2u32).compare(I32::from(1u32)) == Ordering::Less);
+    ///     assert(I32::from(1u32).compare(I32::from(1u32)) == Ordering::Equal);
+    /// }
+    /// ```
+    pub fn compare(self, other: Self) -> Ordering {
+        if self.underlying < other.underlying {
+            Ordering::Less
+        } else if self.underlying > other.underlying {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+}
@@ -1,6 +1,10 @@
 library;
 
-use ::common::TwosComplement;
+use ::i8::I8;
+use ::i16::I16;
+use ::i32::I32;
+use std::u128::U128;
+use ::common::{Ordering, TwosComplement};
 use ::errors::Error;
 
 /// The 64-bit signed integer type.
@@ -209,88 +213,72 @@ impl I64 {
     }
 }
 
+impl I64 {
+    /// Converts a `u64` to a `I64`, returning `None` if the value is out of range.
+    ///
+    /// # Arguments
+    ///
+    /// * `value`: [u64] - The unsigned value to convert.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<I64>] - The value as a `I64` or `None` if `value` is greater than `I64::max()`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i64::I64;
+    ///
+    /// fn foo() {
+    ///     assert(I64::try_from_u64(9223372036854775807).unwrap() == I64::max());
+    ///     assert(I64::try_from_u64(9223372036854775808).is_none());
+    /// }
+    /// ```
+    pub fn try_from_u64(value: u64) -> Option<Self> {
+        if value >= Self::indent() {
+            return Option::None;
+        }
+
+        Option::Some(Self::from_uint(value + Self::indent()))
+    }
+}
+
 impl core::ops::Add for I64 {
     /// Add a I64 to a I64. Panics on overflow.
     fn add(self, other: Self) -> Self {
-        // subtract 1 << 63 to avoid double move
-        let mut res = Self::new();
-        if self.underlying >= Self::indent() {
-            res = Self::from_uint(self.underlying - Self::indent() + other.underlying) // subtract 1 << 31 to avoid double move
-        } else if self.underlying < Self::indent()
-            && other.underlying < Self::indent()
-        {
-            res = Self::from_uint(self.underlying + other.underlying - Self::indent());
-        } else if self.underlying < Self::indent()
-            && other.underlying >= Self::indent()
-        {
-            res = Self::from_uint(other.underlying - Self::indent() + self.underlying);
-        }
-        res
+        let res = self.checked_add(other);
+        require(res.is_some(), Error::Overflow);
+        res.unwrap()
     }
 }
 
 impl core::ops::Subtract for I64 {
-    /// Subtract a I64 from a I64. Panics of overflow.
+    /// Subtract a I64 from a I64. Panics on overflow.
     fn subtract(self, other: Self) -> Self {
-        let mut res = Self::new();
-        if self.underlying >= Self::indent()
-            && other.underlying >= Self::indent()
-        {
-            if self.underlying > other.underlying {
-                res = Self::from_uint(self.underlying - other.underlying + Self::indent());
-            } else {
-                res = Self::from_uint(self.underlying - (other.underlying - Self::indent()));
-            }
-        } else if self.underlying >= Self::indent()
-            && other.underlying < Self::indent()
-        {
-            res = Self::from_uint(self.underlying - Self::indent() + other.underlying);
-        } else if self.underlying < Self::indent()
-            && other.underlying >= Self::indent()
-        {
-            res = Self::from_uint(self.underlying - (other.underlying - Self::indent()));
-        } else if self.underlying < Self::indent()
-            && other.underlying < Self::indent()
-        {
-            if self.underlying < other.underlying {
-                res = Self::from_uint(other.underlying - self.underlying + Self::indent());
-            } else {
-                res = Self::from_uint(self.underlying + other.underlying - Self::indent());
-            }
-        }
-        res
+        let res = self.checked_sub(other);
+        require(res.is_some(), Error::Overflow);
+        res.unwrap()
     }
 }
 
 impl core::ops::Multiply for I64 {
     /// Multiply a I64 with a I64. Panics of overflow.
     fn multiply(self, other: Self) -> Self {
-        let mut res = Self::new();
-        if self.underlying >= Self::indent()
-            && other.underlying >= Self::indent()
-        {
-            res = Self::from_uint((self.underlying - Self::indent()) * (other.underlying - Self::indent()) + Self::indent());
-        } else if self.underlying < Self::indent()
-            && other.underlying < Self::indent()
-        {
-            res = Self::from_uint((Self::indent() - self.underlying) * (Self::indent() - other.underlying) + Self::indent());
-        } else if self.underlying >= Self::indent()
-            && other.underlying < Self::indent()
-        {
-            res = Self::from_uint(Self::indent() - (self.underlying - Self::indent()) * (Self::indent() - other.underlying));
-        } else if self.underlying < Self::indent()
-            && other.underlying >= Self::indent()
-        {
-            res = Self::from_uint(Self::indent() - (other.underlying - Self::indent()) * (Self::indent() - self.underlying));
-        }
-        res
+        let res = self.checked_mul(other);
+        require(res.is_some(), Error::Overflow);
+        res.unwrap()
     }
 }
 
 impl core::ops::Divide for I64 {
-    /// Divide a I64 by a I64. Panics if divisor is zero.
+    /// Divide a I64 by a I64. Panics if divisor is zero or on overflow.
     fn divide(self, divisor: Self) -> Self {
         require(divisor != Self::new(), Error::ZeroDivisor);
+        // `min() / -1` is `max() + 1`, which cannot be represented
+        require(
+            !(self == Self::min() && divisor == Self::neg_from(1u64)),
+            Error::Overflow,
+        );
         let mut res = Self::new();
         if self.underlying >= Self::indent()
             && divisor.underlying > Self::indent()
@@ -314,7 +302,33 @@ impl core::ops::Divide for I64 {
 }
 
 impl TwosComplement for I64 {
+    /// Returns the two's complement of a value.
+    ///
+    /// # Additional Information
+    ///
+    /// Negative values are complemented into their magnitude, while zero and positive values are returned unchanged.
+    ///
+    /// # Returns
+    ///
+    /// * [I64] - The value as two's complement.
+    ///
+    /// # Reverts
+    ///
+    /// * When `self` is `I64::min()`, as its complement cannot be represented.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::{common::TwosComplement, i64::I64};
+    ///
+    /// fn foo() {
+    ///     let res = I64::neg_from(5u64).twos_complement();
+    ///     assert(res == I64::from(5u64));
+    /// }
+    /// ```
     fn twos_complement(self) -> Self {
+        require(self != Self::min(), Error::Overflow);
+
         if self.underlying >= Self::indent() {
             return self;
         }
@@ -322,3 +336,995 @@ impl TwosComplement for I64 {
         res
     }
 }
+
+impl I64 {
+    /// Converts the underlying value into its two's complement bit pattern.
+    fn as_twos_complement_bits(self) -> u64 {
+        // The biased and two's complement representations only differ in the sign bit
+        self.underlying ^ Self::indent()
+    }
+
+    /// Creates an `I64` from a two's complement bit pattern.
+    fn from_twos_complement_bits(bits: u64) -> Self {
+        Self::from_uint(bits ^ Self::indent())
+    }
+
+    /// Wrapping (modular) addition. Computes `self + other`, wrapping around at the boundary of the type.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I64] - The value to add to `self`.
+    ///
+    /// # Returns
+    ///
+    /// * [I64] - The sum of the two values, wrapped around on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i64::I64;
+    ///
+    /// fn foo() {
+    ///     let res = I64::max().wrapping_add(I64::from(1u64));
+    ///     assert(res == I64::min());
+    /// }
+    /// ```
+    pub fn wrapping_add(self, other: Self) -> Self {
+        // Only the lower 64 bits of the sum are kept
+        let res = U128::from((0, self.as_twos_complement_bits())) + U128::from((0, other.as_twos_complement_bits()));
+        Self::from_twos_complement_bits(res.lower)
+    }
+
+    /// Wrapping (modular) subtraction. Computes `self - other`, wrapping around at the boundary of the type.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I64] - The value to subtract from `self`.
+    ///
+    /// # Returns
+    ///
+    /// * [I64] - The difference of the two values, wrapped around on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i64::I64;
+    ///
+    /// fn foo() {
+    ///     let res = I64::min().wrapping_sub(I64::from(1u64));
+    ///     assert(res == I64::max());
+    /// }
+    /// ```
+    pub fn wrapping_sub(self, other: Self) -> Self {
+        // Borrow 2 ^ 64 so that the subtraction can never underflow
+        let res = U128::from((1, self.as_twos_complement_bits())) - U128::from((0, other.as_twos_complement_bits()));
+        Self::from_twos_complement_bits(res.lower)
+    }
+
+    /// Wrapping (modular) multiplication. Computes `self * other`, wrapping around at the boundary of the type.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I64] - The value to multiply `self` by.
+    ///
+    /// # Returns
+    ///
+    /// * [I64] - The product of the two values, wrapped around on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i64::I64;
+    ///
+    /// fn foo() {
+    ///     let res = I64::max().wrapping_mul(I64::from(2u64));
+    ///     assert(res == I64::neg_from(2u64));
+    /// }
+    /// ```
+    pub fn wrapping_mul(self, other: Self) -> Self {
+        let res = U128::from((0, self.as_twos_complement_bits())) * U128::from((0, other.as_twos_complement_bits()));
+        Self::from_twos_complement_bits(res.lower)
+    }
+}
+
+impl I64 {
+    /// Saturating addition. Computes `self + other`, saturating at the numeric bounds instead of overflowing.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I64] - The value to add to `self`.
+    ///
+    /// # Returns
+    ///
+    /// * [I64] - The sum of the two values, or `I64::max()` / `I64::min()` if the sum is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i64::I64;
+    ///
+    /// fn foo() {
+    ///     let res = I64::max().saturating_add(I64::from(1u64));
+    ///     assert(res == I64::max());
+    /// }
+    /// ```
+    pub fn saturating_add(self, other: Self) -> Self {
+        match self.checked_add(other) {
+            Option::Some(res) => res,
+            Option::None => {
+                if other.underlying >= Self::indent() {
+                    Self::max()
+                } else {
+                    Self::min()
+                }
+            },
+        }
+    }
+
+    /// Saturating subtraction. Computes `self - other`, saturating at the numeric bounds instead of overflowing.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I64] - The value to subtract from `self`.
+    ///
+    /// # Returns
+    ///
+    /// * [I64] - The difference of the two values, or `I64::max()` / `I64::min()` if the difference is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i64::I64;
+    ///
+    /// fn foo() {
+    ///     let res = I64::min().saturating_sub(I64::from(1u64));
+    ///     assert(res == I64::min());
+    /// }
+    /// ```
+    pub fn saturating_sub(self, other: Self) -> Self {
+        match self.checked_sub(other) {
+            Option::Some(res) => res,
+            Option::None => {
+                if other.underlying >= Self::indent() {
+                    Self::min()
+                } else {
+                    Self::max()
+                }
+            },
+        }
+    }
+}
+
+impl I64 {
+    /// Checked addition. Computes `self + other`, returning `None` if overflow occurred.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I64] - The value to add to `self`.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<I64>] - The sum of the two values or `None` if the sum is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i64::I64;
+    ///
+    /// fn foo() {
+    ///     let res = I64::from(1u64).checked_add(I64::from(2u64));
+    ///     assert(res.unwrap() == I64::from(3u64));
+    ///     assert(I64::max().checked_add(I64::from(1u64)).is_none());
+    /// }
+    /// ```
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        if other.underlying >= Self::indent() {
+            let magnitude = other.underlying - Self::indent();
+            if magnitude > Self::max().underlying - self.underlying {
+                return Option::None;
+            }
+            Option::Some(Self::from_uint(self.underlying + magnitude))
+        } else {
+            let magnitude = Self::indent() - other.underlying;
+            if magnitude > self.underlying - Self::min().underlying {
+                return Option::None;
+            }
+            Option::Some(Self::from_uint(self.underlying - magnitude))
+        }
+    }
+
+    /// Checked subtraction. Computes `self - other`, returning `None` if overflow occurred.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I64] - The value to subtract from `self`.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<I64>] - The difference of the two values or `None` if the difference is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i64::I64;
+    ///
+    /// fn foo() {
+    ///     let res = I64::from(1u64).checked_sub(I64::from(2u64));
+    ///     assert(res.unwrap() == I64::neg_from(1u64));
+    ///     assert(I64::min().checked_sub(I64::from(1u64)).is_none());
+    /// }
+    /// ```
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        if other.underlying >= Self::indent() {
+            let magnitude = other.underlying - Self::indent();
+            if magnitude > self.underlying - Self::min().underlying {
+                return Option::None;
+            }
+            Option::Some(Self::from_uint(self.underlying - magnitude))
+        } else {
+            let magnitude = Self::indent() - other.underlying;
+            if magnitude > Self::max().underlying - self.underlying {
+                return Option::None;
+            }
+            Option::Some(Self::from_uint(self.underlying + magnitude))
+        }
+    }
+
+    /// Checked multiplication. Computes `self * other`, returning `None` if overflow occurred.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I64] - The value to multiply `self` by.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<I64>] - The product of the two values or `None` if the product is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i64::I64;
+    ///
+    /// fn foo() {
+    ///     let res = I64::from(2u64).checked_mul(I64::neg_from(3u64));
+    ///     assert(res.unwrap() == I64::neg_from(6u64));
+    ///     assert(I64::max().checked_mul(I64::from(2u64)).is_none());
+    /// }
+    /// ```
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        let self_magnitude = if self.underlying >= Self::indent() {
+            self.underlying - Self::indent()
+        } else {
+            Self::indent() - self.underlying
+        };
+        let other_magnitude = if other.underlying >= Self::indent() {
+            other.underlying - Self::indent()
+        } else {
+            Self::indent() - other.underlying
+        };
+        // Use a U128 intermediate as the product of two magnitudes may not fit in a u64
+        let product = U128::from((0, self_magnitude)) * U128::from((0, other_magnitude));
+        if product.upper != 0 {
+            return Option::None;
+        }
+
+        if (self.underlying >= Self::indent()) == (other.underlying >= Self::indent()) {
+            if product.lower > Self::max().underlying - Self::indent() {
+                return Option::None;
+            }
+            Option::Some(Self::from_uint(Self::indent() + product.lower))
+        } else {
+            if product.lower > Self::indent() {
+                return Option::None;
+            }
+            Option::Some(Self::from_uint(Self::indent() - product.lower))
+        }
+    }
+
+    /// Checked division. Computes `self / other`, returning `None` if `other` is zero or the division overflows.
+    ///
+    /// # Additional Information
+    ///
+    /// The only overflowing division is `I64::min()` divided by negative one.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I64] - The value to divide `self` by.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<I64>] - The quotient of the two values or `None` if the division is not possible.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i64::I64;
+    ///
+    /// fn foo() {
+    ///     let res = I64::from(6u64).checked_div(I64::neg_from(3u64));
+    ///     assert(res.unwrap() == I64::neg_from(2u64));
+    ///     assert(I64::from(6u64).checked_div(I64::new()).is_none());
+    /// }
+    /// ```
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        if other == Self::new()
+            || (self == Self::min() && other == Self::neg_from(1u64))
+        {
+            return Option::None;
+        }
+
+        Option::Some(self / other)
+    }
+}
+
+impl I64 {
+    /// Widening multiplication. Computes the full 128-bit product of `self * other`, which never overflows.
+    ///
+    /// # Additional Information
+    ///
+    /// The product is returned as the two halves of a 128-bit two's complement number, high half first. The high half
+    /// is signed, so it is negative exactly when the product is negative, and the low half holds the remaining bits
+    /// without a sign. Note that Rust's `i64::widening_mul` returns the same halves in the opposite order.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I64] - The value to multiply `self` by.
+    ///
+    /// # Returns
+    ///
+    /// * [(I64, u64)] - The high and low halves of the product.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i64::I64;
+    ///
+    /// fn foo() {
+    ///     let (high, low) = I64::max().wide_mul(I64::from(2u64));
+    ///     assert(high == I64::new());
+    ///     assert(low == 18446744073709551614);
+    ///
+    ///     let (high, low) = I64::neg_from(1u64).wide_mul(I64::from(1u64));
+    ///     assert(high == I64::neg_from(1u64));
+    ///     assert(low == u64::max());
+    /// }
+    /// ```
+    pub fn wide_mul(self, other: Self) -> (Self, u64) {
+        let self_magnitude = if self.underlying >= Self::indent() {
+            self.underlying - Self::indent()
+        } else {
+            Self::indent() - self.underlying
+        };
+        let other_magnitude = if other.underlying >= Self::indent() {
+            other.underlying - Self::indent()
+        } else {
+            Self::indent() - other.underlying
+        };
+        // The product of two magnitudes of at most 2 ^ 63 is at most 2 ^ 126, so it always fits in a U128
+        let product = U128::from((0, self_magnitude)) * U128::from((0, other_magnitude));
+
+        let negative = (self.underlying >= Self::indent()) != (other.underlying >= Self::indent());
+        let (upper, lower) = if !negative || (product.upper == 0 && product.lower == 0) {
+            (product.upper, product.lower)
+        } else if product.lower == 0 {
+            (u64::max() - product.upper + 1, 0)
+        } else {
+            (u64::max() - product.upper, u64::max() - product.lower + 1)
+        };
+
+        // Flipping the sign bit of a two's complement word gives the underlying value
+        (Self::from_uint(upper ^ Self::indent()), lower)
+    }
+}
+
+impl core::ops::Shift for I64 {
+    /// Logical left shift of the two's complement bits of a I64. Shifting by `I64::bits()` or more results in zero.
+    fn lsh(self, other: u64) -> Self {
+        if other >= Self::bits() {
+            return Self::new();
+        }
+
+        Self::from_twos_complement_bits(self.as_twos_complement_bits() << other)
+    }
+
+    /// Arithmetic right shift of a I64, preserving the sign. Shifting by `I64::bits()` or more results in zero or negative one.
+    fn rsh(self, other: u64) -> Self {
+        if self.underlying >= Self::indent() {
+            if other >= Self::bits() {
+                return Self::new();
+            }
+            Self::from_uint(((self.underlying - Self::indent()) >> other) + Self::indent())
+        } else {
+            // For negative values `x >> n == -(((-x - 1) >> n) + 1)`
+            let mut shifted = Self::indent() - self.underlying - 1u64;
+            if other >= Self::bits() {
+                shifted = 0u64;
+            } else {
+                shifted = shifted >> other;
+            }
+            Self::from_uint(Self::indent() - shifted - 1u64)
+        }
+    }
+}
+
+impl I64 {
+    /// Converts an `I8` into an `I64`. This conversion can never fail.
+    ///
+    /// # Arguments
+    ///
+    /// * `value`: [I8] - The value to widen.
+    ///
+    /// # Returns
+    ///
+    /// * [I64] - The same value represented as an `I64`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::{i8::I8, i64::I64};
+    ///
+    /// fn foo() {
+    ///     let res = I64::from_i8(I8::min());
+    ///     assert(res == I64::neg_from(128u64));
+    /// }
+    /// ```
+    pub fn from_i8(value: I8) -> Self {
+        Self::from_uint(value.underlying.as_u64() + Self::indent() - I8::indent().as_u64())
+    }
+
+    /// Converts an `I64` into an `I8` if the value is within the bounds of `I8`.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<I8>] - The same value represented as an `I8`, or `None` if it does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::{i8::I8, i64::I64};
+    ///
+    /// fn foo() {
+    ///     let res = I64::neg_from(1u64).try_into_i8();
+    ///     assert(res.unwrap() == I8::neg_from(1u8));
+    ///
+    ///     let res = I64::max().try_into_i8();
+    ///     assert(res.is_none());
+    /// }
+    /// ```
+    pub fn try_into_i8(self) -> Option<I8> {
+        let offset = Self::indent() - I8::indent().as_u64();
+        let underlying = self.underlying;
+        if underlying < offset || underlying - offset > I8::max().underlying.as_u64() {
+            return Option::None;
+        }
+
+        let underlying = underlying - offset;
+        Option::Some(I8::from_uint(asm(ptr: underlying) { ptr: u8 }))
+    }
+
+    /// Converts an `I16` into an `I64`. This conversion can never fail.
+    ///
+    /// # Arguments
+    ///
+    /// * `value`: [I16] - The value to widen.
+    ///
+    /// # Returns
+    ///
+    /// * [I64] - The same value represented as an `I64`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::{i16::I16, i64::I64};
+    ///
+    /// fn foo() {
+    ///     let res = I64::from_i16(I16::min());
+    ///     assert(res == I64::neg_from(32768u64));
+    /// }
+    /// ```
+    pub fn from_i16(value: I16) -> Self {
+        Self::from_uint(value.underlying.as_u64() + Self::indent() - I16::indent().as_u64())
+    }
+
+    /// Converts an `I64` into an `I16` if the value is within the bounds of `I16`.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<I16>] - The same value represented as an `I16`, or `None` if it does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::{i16::I16, i64::I64};
+    ///
+    /// fn foo() {
+    ///     let res = I64::neg_from(1u64).try_into_i16();
+    ///     assert(res.unwrap() == I16::neg_from(1u16));
+    ///
+    ///     let res = I64::max().try_into_i16();
+    ///     assert(res.is_none());
+    /// }
+    /// ```
+    pub fn try_into_i16(self) -> Option<I16> {
+        let offset = Self::indent() - I16::indent().as_u64();
+        let underlying = self.underlying;
+        if underlying < offset || underlying - offset > I16::max().underlying.as_u64() {
+            return Option::None;
+        }
+
+        let underlying = underlying - offset;
+        Option::Some(I16::from_uint(asm(ptr: underlying) { ptr: u16 }))
+    }
+
+    /// Converts an `I32` into an `I64`. This conversion can never fail.
+    ///
+    /// # Arguments
+    ///
+    /// * `value`: [I32] - The value to widen.
+    ///
+    /// # Returns
+    ///
+    /// * [I64] - The same value represented as an `I64`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::{i32::I32, i64::I64};
+    ///
+    /// fn foo() {
+    ///     let res = I64::from_i32(I32::min());
+    ///     assert(res == I64::neg_from(2147483648u64));
+    /// }
+    /// ```
+    pub fn from_i32(value: I32) -> Self {
+        Self::from_uint(value.underlying.as_u64() + Self::indent() - I32::indent().as_u64())
+    }
+
+    /// Converts an `I64` into an `I32` if the value is within the bounds of `I32`.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<I32>] - The same value represented as an `I32`, or `None` if it does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::{i32::I32, i64::I64};
+    ///
+    /// fn foo() {
+    ///     let res = I64::neg_from(1u64).try_into_i32();
+    ///     assert(res.unwrap() == I32::neg_from(1u32));
+    ///
+    ///     let res = I64::max().try_into_i32();
+    ///     assert(res.is_none());
+    /// }
+    /// ```
+    pub fn try_into_i32(self) -> Option<I32> {
+        let offset = Self::indent() - I32::indent().as_u64();
+        let underlying = self.underlying;
+        if underlying < offset || underlying - offset > I32::max().underlying.as_u64() {
+            return Option::None;
+        }
+
+        let underlying = underlying - offset;
+        Option::Some(I32::from_uint(asm(ptr: underlying) { ptr: u32 }))
+    }
+}
+
+impl I8 {
+    /// Converts a `I64` to a `I8`, returning `None` if the value is out of range.
+    ///
+    /// # Additional Information
+    ///
+    /// This is the same as `I64::try_into_i8` and allows the conversion to be written from the side of the narrower type.
+    ///
+    /// # Arguments
+    ///
+    /// * `value`: [I64] - The value to convert.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<I8>] - The value as a `I8` or `None` if `value` is outside of the range of `I8`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::{i8::I8, i64::I64};
+    ///
+    /// fn foo() {
+    ///     assert(I8::try_from_i64(I64::neg_from(128u64)).unwrap() == I8::min());
+    ///     assert(I8::try_from_i64(I64::neg_from(200u64)).is_none());
+    /// }
+    /// ```
+    pub fn try_from_i64(value: I64) -> Option<Self> {
+        value.try_into_i8()
+    }
+}
+
+impl I16 {
+    /// Converts a `I64` to a `I16`, returning `None` if the value is out of range.
+    ///
+    /// # Additional Information
+    ///
+    /// This is the same as `I64::try_into_i16` and allows the conversion to be written from the side of the narrower type.
+    ///
+    /// # Arguments
+    ///
+    /// * `value`: [I64] - The value to convert.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<I16>] - The value as a `I16` or `None` if `value` is outside of the range of `I16`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::{i16::I16, i64::I64};
+    ///
+    /// fn foo() {
+    ///     assert(I16::try_from_i64(I64::neg_from(32768u64)).unwrap() == I16::min());
+    ///     assert(I16::try_from_i64(I64::neg_from(40000u64)).is_none());
+    /// }
+    /// ```
+    pub fn try_from_i64(value: I64) -> Option<Self> {
+        value.try_into_i16()
+    }
+}
+
+impl I32 {
+    /// Converts a `I64` to a `I32`, returning `None` if the value is out of range.
+    ///
+    /// # Additional Information
+    ///
+    /// This is the same as `I64::try_into_i32` and allows the conversion to be written from the side of the narrower type.
+    ///
+    /// # Arguments
+    ///
+    /// * `value`: [I64] - The value to convert.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<I32>] - The value as a `I32` or `None` if `value` is outside of the range of `I32`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::{i32::I32, i64::I64};
+    ///
+    /// fn foo() {
+    ///     assert(I32::try_from_i64(I64::neg_from(2147483648u64)).unwrap() == I32::min());
+    ///     assert(I32::try_from_i64(I64::neg_from(3000000000u64)).is_none());
+    /// }
+    /// ```
+    pub fn try_from_i64(value: I64) -> Option<Self> {
+        value.try_into_i32()
+    }
+}
+
+impl I64 {
+    /// Computes the absolute value of `self`.
+    ///
+    /// # Returns
+    ///
+    /// * [I64] - The absolute value of `self`.
+    ///
+    /// # Reverts
+    ///
+    /// * When `self` is `I64::min()`, as its absolute value cannot be represented.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i64::I64;
+    ///
+    /// fn foo() {
+    ///     let res = I64::neg_from(5u64).abs();
+    ///     assert(res == I64::from(5u64));
+    /// }
+    /// ```
+    pub fn abs(self) -> Self {
+        require(self != Self::min(), Error::Overflow);
+
+        if self.underlying >= Self::indent() {
+            self
+        } else {
+            Self::from_uint(Self::indent() - self.underlying + Self::indent())
+        }
+    }
+
+    /// Wrapping (modular) negation. Computes `-self`, wrapping around at the boundary of the type.
+    ///
+    /// # Additional Information
+    ///
+    /// The only case where wrapping occurs is `I64::min()`, which is returned unchanged.
+    ///
+    /// # Returns
+    ///
+    /// * [I64] - The negation of `self`, wrapped around on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i64::I64;
+    ///
+    /// fn foo() {
+    ///     let res = I64::from(5u64).wrapping_neg();
+    ///     assert(res == I64::neg_from(5u64));
+    ///
+    ///     let res = I64::min().wrapping_neg();
+    ///     assert(res == I64::min());
+    /// }
+    /// ```
+    pub fn wrapping_neg(self) -> Self {
+        Self::new().wrapping_sub(self)
+    }
+
+    /// Negation. Computes `-self`.
+    ///
+    /// # Returns
+    ///
+    /// * [I64] - The negation of `self`.
+    ///
+    /// # Reverts
+    ///
+    /// * When `self` is `I64::min()`, as `-I64::min()` is one greater than `I64::max()`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i64::I64;
+    ///
+    /// fn foo() {
+    ///     let res = I64::from(5u64).neg();
+    ///     assert(res == I64::neg_from(5u64));
+    ///
+    ///     let res = I64::neg_from(5u64).neg();
+    ///     assert(res == I64::from(5u64));
+    /// }
+    /// ```
+    pub fn neg(self) -> Self {
+        require(self != Self::min(), Error::Overflow);
+        Self::new().wrapping_sub(self)
+    }
+}
+
+impl I64 {
+    /// Raises `self` to the power of `exponent`.
+    ///
+    /// # Additional Information
+    ///
+    /// Uses binary exponentiation, which takes at most `2 * log2(exponent)` multiplications.
+    /// Any value to the power of `0` is `1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `exponent`: [u32] - The power to raise `self` to.
+    ///
+    /// # Returns
+    ///
+    /// * [I64] - `self` raised to the power of `exponent`.
+    ///
+    /// # Reverts
+    ///
+    /// * When the result cannot be represented by an `I64`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i64::I64;
+    ///
+    /// fn foo() {
+    ///     let res = I64::neg_from(3u64).pow(3);
+    ///     assert(res == I64::neg_from(27u64));
+    /// }
+    /// ```
+    pub fn pow(self, exponent: u32) -> Self {
+        let mut result = Self::from(1u64);
+        let mut base = self;
+        let mut exponent = exponent;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                let product = result.checked_mul(base);
+                require(product.is_some(), Error::Overflow);
+                result = product.unwrap();
+            }
+            exponent = exponent >> 1;
+            // Only square when it is needed, as squaring after the last bit may overflow
+            if exponent > 0 {
+                let square = base.checked_mul(base);
+                require(square.is_some(), Error::Overflow);
+                base = square.unwrap();
+            }
+        }
+        result
+    }
+}
+
+impl I64 {
+    /// Computes the least non-negative remainder of `self` divided by `other`.
+    ///
+    /// # Additional Information
+    ///
+    /// Unlike a truncated remainder, the result is never negative and `0 <= result < |other|`.
+    /// This matches Rust's `rem_euclid`, so `-7` modulo `3` is `2`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I64] - The divisor.
+    ///
+    /// # Returns
+    ///
+    /// * [I64] - The non-negative remainder.
+    ///
+    /// # Reverts
+    ///
+    /// * When `other` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i64::I64;
+    ///
+    /// fn foo() {
+    ///     let res = I64::neg_from(7u64).rem_euclid(I64::from(3u64));
+    ///     assert(res == I64::from(2u64));
+    ///
+    ///     let res = I64::from(7u64).rem_euclid(I64::neg_from(3u64));
+    ///     assert(res == I64::from(1u64));
+    /// }
+    /// ```
+    pub fn rem_euclid(self, other: Self) -> Self {
+        require(other != Self::new(), Error::ZeroDivisor);
+
+        let self_magnitude = if self.underlying >= Self::indent() {
+            self.underlying - Self::indent()
+        } else {
+            Self::indent() - self.underlying
+        };
+        let other_magnitude = if other.underlying >= Self::indent() {
+            other.underlying - Self::indent()
+        } else {
+            Self::indent() - other.underlying
+        };
+        let indent = Self::indent();
+
+        let mut remainder = self_magnitude % other_magnitude;
+        // A negative dividend leaves a remainder below zero which is moved up by one divisor
+        if self.underlying < Self::indent() && remainder != 0 {
+            remainder = other_magnitude - remainder;
+        }
+        // The remainder is below the magnitude of the divisor so it always fits
+        Self::from_uint(indent + remainder)
+    }
+}
+
+impl I64 {
+    /// Returns the lesser of two values.
+    ///
+    /// # Additional Information
+    ///
+    /// Named `minimum` as `min()` already returns the smallest value of the type. Returns `self` when both are equal.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I64] - The value to compare `self` with.
+    ///
+    /// # Returns
+    ///
+    /// * [I64] - `other` if it is less than `self`, otherwise `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i64::I64;
+    ///
+    /// fn foo() {
+    ///     let res = I64::neg_from(3u64).minimum(I64::from(5u64));
+    ///     assert(res == I64::neg_from(3u64));
+    /// }
+    /// ```
+    pub fn minimum(self, other: Self) -> Self {
+        if other < self {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the greater of two values.
+    ///
+    /// # Additional Information
+    ///
+    /// Named `maximum` as `max()` already returns the largest value of the type. Returns `self` when both are equal.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I64] - The value to compare `self` with.
+    ///
+    /// # Returns
+    ///
+    /// * [I64] - `other` if it is greater than `self`, otherwise `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i64::I64;
+    ///
+    /// fn foo() {
+    ///     let res = I64::neg_from(3u64).maximum(I64::from(5u64));
+    ///     assert(res == I64::from(5u64));
+    /// }
+    /// ```
+    pub fn maximum(self, other: Self) -> Self {
+        if other > self {
+            other
+        } else {
+            self
+        }
+    }
+}
+
+impl I64 {
+    /// Restricts `self` to the range from `min` to `max`, inclusive.
+    ///
+    /// # Arguments
+    ///
+    /// * `min`: [I64] - The lower bound of the range.
+    /// * `max`: [I64] - The upper bound of the range.
+    ///
+    /// # Returns
+    ///
+    /// * [I64] - `min` if `self` is less than `min`, `max` if `self` is greater than `max`, otherwise `self`.
+    ///
+    /// # Reverts
+    ///
+    /// * When `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i64::I64;
+    ///
+    /// fn foo() {
+    ///     let res = I64::neg_from(10u64).clamp(I64::neg_from(5u64), I64::from(5u64));
+    ///     assert(res == I64::neg_from(5u64));
+    /// }
+    /// ```
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        require(!(min > max), Error::InvalidRange);
+
+        self.maximum(min).minimum(max)
+    }
+}
+
+impl I64 {
+    /// Compares two values, returning whether `self` is less than, equal to or greater than `other`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I64] - The value to compare `self` with.
+    ///
+    /// # Returns
+    ///
+    /// * [Ordering] - `Less`, `Equal` or `Greater` depending on how `self` relates to `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::{common::Ordering, i64::I64};
+    ///
+    /// fn foo() {
+    ///     assert(I64::neg_from(2u64).compare(I64::from(1u64)) == Ordering::Less);
+    ///     assert(I64::from(1u64).compare(I64::from(1u64)) == Ordering::Equal);
+    /// }
+    /// ```
+    pub fn compare(self, other: Self) -> Ordering {
+        if self.underlying < other.underlying {
+            Ordering::Less
+        } else if self.underlying > other.underlying {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+}
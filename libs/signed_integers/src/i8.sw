@@ -1,7 +1,7 @@
 library;
 
 use ::errors::Error;
-use ::common::TwosComplement;
+use ::common::{Ordering, TwosComplement};
 
 /// The 8-bit signed integer type.
 ///
@@ -209,29 +209,54 @@ impl I8 {
     }
 }
 
+impl I8 {
+    /// Converts a `u64` to a `I8`, returning `None` if the value is out of range.
+    ///
+    /// # Arguments
+    ///
+    /// * `value`: [u64] - The unsigned value to convert.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<I8>] - The value as a `I8` or `None` if `value` is greater than `I8::max()`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i8::I8;
+    ///
+    /// fn foo() {
+    ///     assert(I8::try_from_u64(127).unwrap() == I8::max());
+    ///     assert(I8::try_from_u64(128).is_none());
+    /// }
+    /// ```
+    pub fn try_from_u64(value: u64) -> Option<Self> {
+        if value >= Self::indent().as_u64() {
+            return Option::None;
+        }
+
+        Option::Some(Self::from_uint(asm(ptr: value + Self::indent().as_u64()) { ptr: u8 }))
+    }
+}
+
 impl core::ops::Add for I8 {
     /// Add a I8 to a I8. Panics on overflow.
     fn add(self, other: Self) -> Self {
-        let mut res = Self::new();
-        if self.underlying >= Self::indent() {
-            res = Self::from_uint(self.underlying - Self::indent() + other.underlying) // subtract 1 << 7 to avoid double move
-        } else if self.underlying < Self::indent()
-            && other.underlying < Self::indent()
-        {
-            res = Self::from_uint(self.underlying + other.underlying - Self::indent());
-        } else if self.underlying < Self::indent()
-            && other.underlying >= Self::indent()
-        {
-            res = Self::from_uint(other.underlying - Self::indent() + self.underlying);
-        }
-        res
+        let res = self.checked_add(other);
+        require(res.is_some(), Error::Overflow);
+        res.unwrap()
     }
 }
 
 impl core::ops::Divide for I8 {
-    /// Divide a I8 by a I8. Panics if divisor is zero.
+    /// Divide a I8 by a I8. Panics if divisor is zero or on overflow.
     fn divide(self, divisor: Self) -> Self {
         require(divisor != Self::new(), Error::ZeroDivisor);
+        // `min() / -1` is `max() + 1`, which cannot be represented
+        require(
+            !(self == Self::min() && divisor == Self::neg_from(1u8)),
+            Error::Overflow,
+        );
         let mut res = Self::new();
         if self.underlying >= Self::indent()
             && divisor.underlying > Self::indent()
@@ -257,63 +282,49 @@ impl core::ops::Divide for I8 {
 impl core::ops::Multiply for I8 {
     /// Multiply a I8 with a I8. Panics of overflow.
     fn multiply(self, other: Self) -> Self {
-        let mut res = Self::new();
-        if self.underlying >= Self::indent()
-            && other.underlying >= Self::indent()
-        {
-            res = Self::from_uint((self.underlying - Self::indent()) * (other.underlying - Self::indent()) + Self::indent());
-        } else if self.underlying < Self::indent()
-            && other.underlying < Self::indent()
-        {
-            res = Self::from_uint((Self::indent() - self.underlying) * (Self::indent() - other.underlying) + Self::indent());
-        } else if self.underlying >= Self::indent()
-            && other.underlying < Self::indent()
-        {
-            res = Self::from_uint(Self::indent() - (self.underlying - Self::indent()) * (Self::indent() - other.underlying));
-        } else if self.underlying < Self::indent()
-            && other.underlying >= Self::indent()
-        {
-            res = Self::from_uint(Self::indent() - (other.underlying - Self::indent()) * (Self::indent() - self.underlying));
-        }
-        res
+        let res = self.checked_mul(other);
+        require(res.is_some(), Error::Overflow);
+        res.unwrap()
     }
 }
 
 impl core::ops::Subtract for I8 {
-    /// Subtract a I8 from a I8. Panics of overflow.
+    /// Subtract a I8 from a I8. Panics on overflow.
     fn subtract(self, other: Self) -> Self {
-        let mut res = Self::new();
-        if self.underlying >= Self::indent()
-            && other.underlying >= Self::indent()
-        {
-            if self.underlying > other.underlying {
-                res = Self::from_uint(self.underlying - other.underlying + Self::indent());
-            } else {
-                res = Self::from_uint(self.underlying - (other.underlying - Self::indent()));
-            }
-        } else if self.underlying >= Self::indent()
-            && other.underlying < Self::indent()
-        {
-            res = Self::from_uint(self.underlying - Self::indent() + other.underlying);
-        } else if self.underlying < Self::indent()
-            && other.underlying >= Self::indent()
-        {
-            res = Self::from_uint(self.underlying - (other.underlying - Self::indent()));
-        } else if self.underlying < Self::indent()
-            && other.underlying < Self::indent()
-        {
-            if self.underlying < other.underlying {
-                res = Self::from_uint(other.underlying - self.underlying + Self::indent());
-            } else {
-                res = Self::from_uint(self.underlying + other.underlying - Self::indent());
-            }
-        }
-        res
+        let res = self.checked_sub(other);
+        require(res.is_some(), Error::Overflow);
+        res.unwrap()
     }
 }
 
 impl TwosComplement for I8 {
+    /// Returns the two's complement of a value.
+    ///
+    /// # Additional Information
+    ///
+    /// Negative values are complemented into their magnitude, while zero and positive values are returned unchanged.
+    ///
+    /// # Returns
+    ///
+    /// * [I8] - The value as two's complement.
+    ///
+    /// # Reverts
+    ///
+    /// * When `self` is `I8::min()`, as its complement cannot be represented.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::{common::TwosComplement, i8::I8};
+    ///
+    /// fn foo() {
+    ///     let res = I8::neg_from(5u8).twos_complement();
+    ///     assert(res == I8::from(5u8));
+    /// }
+    /// ```
     fn twos_complement(self) -> Self {
+        require(self != Self::min(), Error::Overflow);
+
         if self.underlying >= Self::indent() {
             return self;
         }
@@ -321,3 +332,629 @@ impl TwosComplement for I8 {
         res
     }
 }
+
+impl I8 {
+    /// Converts the underlying value into its two's complement bit pattern.
+    fn as_twos_complement_bits(self) -> u64 {
+        // The biased and two's complement representations only differ in the sign bit
+        (self.underlying ^ Self::indent()).as_u64()
+    }
+
+    /// Creates an `I8` from a two's complement bit pattern which fits in `Self::bits()` bits.
+    fn from_twos_complement_bits(bits: u64) -> Self {
+        Self::from_uint(asm(ptr: bits) { ptr: u8 } ^ Self::indent())
+    }
+
+    /// Wrapping (modular) addition. Computes `self + other`, wrapping around at the boundary of the type.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I8] - The value to add to `self`.
+    ///
+    /// # Returns
+    ///
+    /// * [I8] - The sum of the two values, wrapped around on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i8::I8;
+    ///
+    /// fn foo() {
+    ///     let res = I8::max().wrapping_add(I8::from(1u8));
+    ///     assert(res == I8::min());
+    /// }
+    /// ```
+    pub fn wrapping_add(self, other: Self) -> Self {
+        let modulus = 1 << Self::bits();
+        let res = (self.as_twos_complement_bits() + other.as_twos_complement_bits()) % modulus;
+        Self::from_twos_complement_bits(res)
+    }
+
+    /// Wrapping (modular) subtraction. Computes `self - other`, wrapping around at the boundary of the type.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I8] - The value to subtract from `self`.
+    ///
+    /// # Returns
+    ///
+    /// * [I8] - The difference of the two values, wrapped around on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i8::I8;
+    ///
+    /// fn foo() {
+    ///     let res = I8::min().wrapping_sub(I8::from(1u8));
+    ///     assert(res == I8::max());
+    /// }
+    /// ```
+    pub fn wrapping_sub(self, other: Self) -> Self {
+        let modulus = 1 << Self::bits();
+        let res = (self.as_twos_complement_bits() + modulus - other.as_twos_complement_bits()) % modulus;
+        Self::from_twos_complement_bits(res)
+    }
+
+    /// Wrapping (modular) multiplication. Computes `self * other`, wrapping around at the boundary of the type.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I8] - The value to multiply `self` by.
+    ///
+    /// # Returns
+    ///
+    /// * [I8] - The product of the two values, wrapped around on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i8::I8;
+    ///
+    /// fn foo() {
+    ///     let res = I8::from(16u8).wrapping_mul(I8::from(16u8));
+    ///     assert(res == I8::new());
+    /// }
+    /// ```
+    pub fn wrapping_mul(self, other: Self) -> Self {
+        let modulus = 1 << Self::bits();
+        let res = (self.as_twos_complement_bits() * other.as_twos_complement_bits()) % modulus;
+        Self::from_twos_complement_bits(res)
+    }
+}
+
+impl I8 {
+    /// Saturating addition. Computes `self + other`, saturating at the numeric bounds instead of overflowing.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I8] - The value to add to `self`.
+    ///
+    /// # Returns
+    ///
+    /// * [I8] - The sum of the two values, or `I8::max()` / `I8::min()` if the sum is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i8::I8;
+    ///
+    /// fn foo() {
+    ///     let res = I8::max().saturating_add(I8::from(1u8));
+    ///     assert(res == I8::max());
+    /// }
+    /// ```
+    pub fn saturating_add(self, other: Self) -> Self {
+        match self.checked_add(other) {
+            Option::Some(res) => res,
+            Option::None => {
+                if other.underlying >= Self::indent() {
+                    Self::max()
+                } else {
+                    Self::min()
+                }
+            },
+        }
+    }
+
+    /// Saturating subtraction. Computes `self - other`, saturating at the numeric bounds instead of overflowing.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I8] - The value to subtract from `self`.
+    ///
+    /// # Returns
+    ///
+    /// * [I8] - The difference of the two values, or `I8::max()` / `I8::min()` if the difference is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i8::I8;
+    ///
+    /// fn foo() {
+    ///     let res = I8::min().saturating_sub(I8::from(1u8));
+    ///     assert(res == I8::min());
+    /// }
+    /// ```
+    pub fn saturating_sub(self, other: Self) -> Self {
+        match self.checked_sub(other) {
+            Option::Some(res) => res,
+            Option::None => {
+                if other.underlying >= Self::indent() {
+                    Self::min()
+                } else {
+                    Self::max()
+                }
+            },
+        }
+    }
+}
+
+impl I8 {
+    /// Checked addition. Computes `self + other`, returning `None` if overflow occurred.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I8] - The value to add to `self`.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<I8>] - The sum of the two values or `None` if the sum is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i8::I8;
+    ///
+    /// fn foo() {
+    ///     let res = I8::from(1u8).checked_add(I8::from(2u8));
+    ///     assert(res.unwrap() == I8::from(3u8));
+    ///     assert(I8::max().checked_add(I8::from(1u8)).is_none());
+    /// }
+    /// ```
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        if other.underlying >= Self::indent() {
+            let magnitude = other.underlying - Self::indent();
+            if magnitude > Self::max().underlying - self.underlying {
+                return Option::None;
+            }
+            Option::Some(Self::from_uint(self.underlying + magnitude))
+        } else {
+            let magnitude = Self::indent() - other.underlying;
+            if magnitude > self.underlying - Self::min().underlying {
+                return Option::None;
+            }
+            Option::Some(Self::from_uint(self.underlying - magnitude))
+        }
+    }
+
+    /// Checked subtraction. Computes `self - other`, returning `None` if overflow occurred.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I8] - The value to subtract from `self`.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<I8>] - The difference of the two values or `None` if the difference is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i8::I8;
+    ///
+    /// fn foo() {
+    ///     let res = I8::from(1u8).checked_sub(I8::from(2u8));
+    ///     assert(res.unwrap() == I8::neg_from(1u8));
+    ///     assert(I8::min().checked_sub(I8::from(1u8)).is_none());
+    /// }
+    /// ```
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        if other.underlying >= Self::indent() {
+            let magnitude = other.underlying - Self::indent();
+            if magnitude > self.underlying - Self::min().underlying {
+                return Option::None;
+            }
+            Option::Some(Self::from_uint(self.underlying - magnitude))
+        } else {
+            let magnitude = Self::indent() - other.underlying;
+            if magnitude > Self::max().underlying - self.underlying {
+                return Option::None;
+            }
+            Option::Some(Self::from_uint(self.underlying + magnitude))
+        }
+    }
+
+    /// Checked multiplication. Computes `self * other`, returning `None` if overflow occurred.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I8] - The value to multiply `self` by.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<I8>] - The product of the two values or `None` if the product is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i8::I8;
+    ///
+    /// fn foo() {
+    ///     let res = I8::from(2u8).checked_mul(I8::neg_from(3u8));
+    ///     assert(res.unwrap() == I8::neg_from(6u8));
+    ///     assert(I8::max().checked_mul(I8::from(2u8)).is_none());
+    /// }
+    /// ```
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        let self_magnitude = if self.underlying >= Self::indent() {
+            (self.underlying - Self::indent()).as_u64()
+        } else {
+            (Self::indent() - self.underlying).as_u64()
+        };
+        let other_magnitude = if other.underlying >= Self::indent() {
+            (other.underlying - Self::indent()).as_u64()
+        } else {
+            (Self::indent() - other.underlying).as_u64()
+        };
+        // The product of two magnitudes of at most 2 ^ 7 always fits in a u64
+        let product = self_magnitude * other_magnitude;
+        let indent = Self::indent().as_u64();
+
+        if (self.underlying >= Self::indent()) == (other.underlying >= Self::indent()) {
+            if product > Self::max().underlying.as_u64() - indent {
+                return Option::None;
+            }
+            Option::Some(Self::from_uint(asm(ptr: indent + product) { ptr: u8 }))
+        } else {
+            if product > indent {
+                return Option::None;
+            }
+            Option::Some(Self::from_uint(asm(ptr: indent - product) { ptr: u8 }))
+        }
+    }
+
+    /// Checked division. Computes `self / other`, returning `None` if `other` is zero or the division overflows.
+    ///
+    /// # Additional Information
+    ///
+    /// The only overflowing division is `I8::min()` divided by negative one.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I8] - The value to divide `self` by.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<I8>] - The quotient of the two values or `None` if the division is not possible.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i8::I8;
+    ///
+    /// fn foo() {
+    ///     let res = I8::from(6u8).checked_div(I8::neg_from(3u8));
+    ///     assert(res.unwrap() == I8::neg_from(2u8));
+    ///     assert(I8::from(6u8).checked_div(I8::new()).is_none());
+    /// }
+    /// ```
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        if other == Self::new()
+            || (self == Self::min() && other == Self::neg_from(1u8))
+        {
+            return Option::None;
+        }
+
+        Option::Some(self / other)
+    }
+}
+
+impl core::ops::Shift for I8 {
+    /// Logical left shift of the two's complement bits of a I8. Shifting by `I8::bits()` or more results in zero.
+    fn lsh(self, other: u64) -> Self {
+        if other >= Self::bits() {
+            return Self::new();
+        }
+
+        let modulus = 1 << Self::bits();
+        Self::from_twos_complement_bits((self.as_twos_complement_bits() << other) % modulus)
+    }
+
+    /// Arithmetic right shift of a I8, preserving the sign. Shifting by `I8::bits()` or more results in zero or negative one.
+    fn rsh(self, other: u64) -> Self {
+        if self.underlying >= Self::indent() {
+            if other >= Self::bits() {
+                return Self::new();
+            }
+            Self::from_uint(((self.underlying - Self::indent()) >> other) + Self::indent())
+        } else {
+            // For negative values `x >> n == -(((-x - 1) >> n) + 1)`
+            let mut shifted = Self::indent() - self.underlying - 1u8;
+            if other >= Self::bits() {
+                shifted = 0u8;
+            } else {
+                shifted = shifted >> other;
+            }
+            Self::from_uint(Self::indent() - shifted - 1u8)
+        }
+    }
+}
+
+impl I8 {
+    /// Computes the absolute value of `self`.
+    ///
+    /// # Returns
+    ///
+    /// * [I8] - The absolute value of `self`.
+    ///
+    /// # Reverts
+    ///
+    /// * When `self` is `I8::min()`, as its absolute value cannot be represented.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i8::I8;
+    ///
+    /// fn foo() {
+    ///     let res = I8::neg_from(5u8).abs();
+    ///     assert(res == I8::from(5u8));
+    /// }
+    /// ```
+    pub fn abs(self) -> Self {
+        require(self != Self::min(), Error::Overflow);
+
+        if self.underlying >= Self::indent() {
+            self
+        } else {
+            Self::from_uint(Self::indent() - self.underlying + Self::indent())
+        }
+    }
+
+    /// Wrapping (modular) negation. Computes `-self`, wrapping around at the boundary of the type.
+    ///
+    /// # Additional Information
+    ///
+    /// The only case where wrapping occurs is `I8::min()`, which is returned unchanged.
+    ///
+    /// # Returns
+    ///
+    /// * [I8] - The negation of `self`, wrapped around on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i8::I8;
+    ///
+    /// fn foo() {
+    ///     let res = I8::from(5u8).wrapping_neg();
+    ///     assert(res == I8::neg_from(5u8));
+    ///
+    ///     let res = I8::min().wrapping_neg();
+    ///     assert(res == I8::min());
+    /// }
+    /// ```
+    pub fn wrapping_neg(self) -> Self {
+        Self::new().wrapping_sub(self)
+    }
+
+    /// Negation. Computes `-self`.
+    ///
+    /// # Returns
+    ///
+    /// * [I8] - The negation of `self`.
+    ///
+    /// # Reverts
+    ///
+    /// * When `self` is `I8::min()`, as `-I8::min()` is one greater than `I8::max()`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i8::I8;
+    ///
+    /// fn foo() {
+    ///     let res = I8::from(5u8).neg();
+    ///     assert(res == I8::neg_from(5u8));
+    ///
+    ///     let res = I8::neg_from(5u8).neg();
+    ///     assert(res == I8::from(5u8));
+    /// }
+    /// ```
+    pub fn neg(self) -> Self {
+        require(self != Self::min(), Error::Overflow);
+        Self::new().wrapping_sub(self)
+    }
+}
+
+impl I8 {
+    /// Computes the least non-negative remainder of `self` divided by `other`.
+    ///
+    /// # Additional Information
+    ///
+    /// Unlike a truncated remainder, the result is never negative and `0 <= result < |other|`.
+    /// This matches Rust's `rem_euclid`, so `-7` modulo `3` is `2`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I8] - The divisor.
+    ///
+    /// # Returns
+    ///
+    /// * [I8] - The non-negative remainder.
+    ///
+    /// # Reverts
+    ///
+    /// * When `other` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i8::I8;
+    ///
+    /// fn foo() {
+    ///     let res = I8::neg_from(7u8).rem_euclid(I8::from(3u8));
+    ///     assert(res == I8::from(2u8));
+    ///
+    ///     let res = I8::from(7u8).rem_euclid(I8::neg_from(3u8));
+    ///     assert(res == I8::from(1u8));
+    /// }
+    /// ```
+    pub fn rem_euclid(self, other: Self) -> Self {
+        require(other != Self::new(), Error::ZeroDivisor);
+
+        let self_magnitude = if self.underlying >= Self::indent() {
+            (self.underlying - Self::indent()).as_u64()
+        } else {
+            (Self::indent() - self.underlying).as_u64()
+        };
+        let other_magnitude = if other.underlying >= Self::indent() {
+            (other.underlying - Self::indent()).as_u64()
+        } else {
+            (Self::indent() - other.underlying).as_u64()
+        };
+        let indent = Self::indent().as_u64();
+
+        let mut remainder = self_magnitude % other_magnitude;
+        // A negative dividend leaves a remainder below zero which is moved up by one divisor
+        if self.underlying < Self::indent() && remainder != 0 {
+            remainder = other_magnitude - remainder;
+        }
+        // The remainder is below the magnitude of the divisor so it always fits
+        Self::from_uint(asm(ptr: indent + remainder) { ptr: u8 })
+    }
+}
+
+impl I8 {
+    /// Returns the lesser of two values.
+    ///
+    /// # Additional Information
+    ///
+    /// Named `minimum` as `min()` already returns the smallest value of the type. Returns `self` when both are equal.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I8] - The value to compare `self` with.
+    ///
+    /// # Returns
+    ///
+    /// * [I8] - `other` if it is less than `self`, otherwise `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i8::I8;
+    ///
+    /// fn foo() {
+    ///     let res = I8::neg_from(3u8).minimum(I8::from(5u8));
+    ///     assert(res == I8::neg_from(3u8));
+    /// }
+    /// ```
+    pub fn minimum(self, other: Self) -> Self {
+        if other < self {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the greater of two values.
+    ///
+    /// # Additional Information
+    ///
+    /// Named `maximum` as `max()` already returns the largest value of the type. Returns `self` when both are equal.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I8] - The value to compare `self` with.
+    ///
+    /// # Returns
+    ///
+    /// * [I8] - `other` if it is greater than `self`, otherwise `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i8::I8;
+    ///
+    /// fn foo() {
+    ///     let res = I8::neg_from(3u8).maximum(I8::from(5u8));
+    ///     assert(res == I8::from(5u8));
+    /// }
+    /// ```
+    pub fn maximum(self, other: Self) -> Self {
+        if other > self {
+            other
+        } else {
+            self
+        }
+    }
+}
+
+impl I8 {
+    /// Restricts `self` to the range from `min` to `max`, inclusive.
+    ///
+    /// # Arguments
+    ///
+    /// * `min`: [I8] - The lower bound of the range.
+    /// * `max`: [I8] - The upper bound of the range.
+    ///
+    /// # Returns
+    ///
+    /// * [I8] - `min` if `self` is less than `min`, `max` if `self` is greater than `max`, otherwise `self`.
+    ///
+    /// # Reverts
+    ///
+    /// * When `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::i8::I8;
+    ///
+    /// fn foo() {
+    ///     let res = I8::neg_from(10u8).clamp(I8::neg_from(5u8), I8::from(5u8));
+    ///     assert(res == I8::neg_from(5u8));
+    /// }
+    /// ```
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        require(!(min > max), Error::InvalidRange);
+
+        self.maximum(min).minimum(max)
+    }
+}
+
+impl I8 {
+    /// Compares two values, returning whether `self` is less than, equal to or greater than `other`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [I8] - The value to compare `self` with.
+    ///
+    /// # Returns
+    ///
+    /// * [Ordering] - `Less`, `Equal` or `Greater` depending on how `self` relates to `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use signed_integers::{common::Ordering, i8::I8};
+    ///
+    /// fn foo() {
+    ///     assert(I8::neg_from(2u8).compare(I8::from(1u8)) == Ordering::Less);
+    ///     assert(I8::from(1u8).compare(I8::from(1u8)) == Ordering::Equal);
+    /// }
+    /// ```
+    pub fn compare(self, other: Self) -> Ordering {
+        if self.underlying < other.underlying {
+            Ordering::Less
+        } else if self.underlying > other.underlying {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+}
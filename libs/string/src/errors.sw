@@ -0,0 +1,7 @@
+library;
+
+/// Error log for when a `String` cannot be created.
+pub enum StringError {
+    /// Emitted when a byte is not a valid ASCII character.
+    InvalidAscii: (),
+}
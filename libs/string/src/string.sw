@@ -0,0 +1,184 @@
+library;
+
+pub mod errors;
+
+use bytes::Bytes;
+use errors::StringError;
+
+/// A dynamically sized ASCII string.
+pub struct String {
+    /// The ASCII characters of the string.
+    bytes: Bytes,
+}
+
+impl String {
+    /// Creates a new empty `String`.
+    ///
+    /// # Returns
+    ///
+    /// * [String] - The newly created string.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use string::String;
+    ///
+    /// fn foo() {
+    ///     let string = String::new();
+    ///     assert(string.is_empty());
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            bytes: Bytes::new(),
+        }
+    }
+
+    /// Creates a `String` from a buffer of ASCII characters.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes`: [Bytes] - The ASCII characters of the string.
+    ///
+    /// # Returns
+    ///
+    /// * [String] - The string holding the characters.
+    ///
+    /// # Reverts
+    ///
+    /// * When a byte is greater than `127`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use bytes::Bytes;
+    /// use string::String;
+    ///
+    /// fn foo() {
+    ///     let mut bytes = Bytes::new();
+    ///     bytes.push(70u8);
+    ///
+    ///     let string = String::from_ascii(bytes);
+    ///     assert(string.len() == 1);
+    /// }
+    /// ```
+    pub fn from_ascii(bytes: Bytes) -> Self {
+        let mut index = 0;
+        while index < bytes.len() {
+            require(bytes.get(index).unwrap() < 128u8, StringError::InvalidAscii);
+            index += 1;
+        }
+
+        Self { bytes }
+    }
+
+    /// Returns the number of characters in the string.
+    ///
+    /// # Returns
+    ///
+    /// * [u64] - The number of characters.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use string::String;
+    ///
+    /// fn foo() {
+    ///     let string = String::new();
+    ///     assert(string.len() == 0);
+    /// }
+    /// ```
+    pub fn len(self) -> u64 {
+        self.bytes.len()
+    }
+
+    /// Checks whether the string contains no characters.
+    ///
+    /// # Returns
+    ///
+    /// * [bool] - `true` if the string is empty, otherwise `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use string::String;
+    ///
+    /// fn foo() {
+    ///     let string = String::new();
+    ///     assert(string.is_empty());
+    /// }
+    /// ```
+    pub fn is_empty(self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Creates a new `String` holding the characters of the string followed by those of `other`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [String] - The string to place at the end.
+    ///
+    /// # Returns
+    ///
+    /// * [String] - The concatenated string. Neither input is changed.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use bytes::Bytes;
+    /// use string::String;
+    ///
+    /// fn foo(first: Bytes, second: Bytes) {
+    ///     let first = String::from_ascii(first);
+    ///     let second = String::from_ascii(second);
+    ///
+    ///     let string = first.concat(second);
+    ///     assert(string.len() == first.len() + second.len());
+    /// }
+    /// ```
+    pub fn concat(self, other: Self) -> Self {
+        let mut bytes = Bytes::new();
+        bytes.append(self.bytes);
+        bytes.append(other.bytes);
+
+        Self { bytes }
+    }
+
+    /// Returns the ASCII characters of the string.
+    ///
+    /// # Returns
+    ///
+    /// * [Bytes] - The characters of the string.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use string::String;
+    ///
+    /// fn foo() {
+    ///     let string = String::new();
+    ///     assert(string.to_bytes().len() == 0);
+    /// }
+    /// ```
+    pub fn to_bytes(self) -> Bytes {
+        self.bytes
+    }
+}
+
+impl core::ops::Eq for String {
+    /// Compares the characters of two strings, so strings built in different ways are equal when their contents are.
+    fn eq(self, other: Self) -> bool {
+        if self.len() != other.len() {
+            return false;
+        }
+
+        let mut index = 0;
+        while index < self.len() {
+            if self.bytes.get(index).unwrap() != other.bytes.get(index).unwrap() {
+                return false;
+            }
+            index += 1;
+        }
+        true
+    }
+}
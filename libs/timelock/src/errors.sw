@@ -0,0 +1,11 @@
+library;
+
+/// Error log for when a queued call cannot be managed.
+pub enum TimelockError {
+    /// Emitted when a call is queued while it is already pending.
+    AlreadyQueued: (),
+    /// Emitted when a call is executed or cancelled while it is not pending.
+    NotQueued: (),
+    /// Emitted when a call is executed before its delay has passed.
+    NotReady: (),
+}
@@ -0,0 +1,21 @@
+library;
+
+/// Logged when a pending call is cancelled.
+pub struct CallCancelled {
+    /// The hash of the call which has been cancelled.
+    call_hash: b256,
+}
+
+/// Logged when a pending call is executed.
+pub struct CallExecuted {
+    /// The hash of the call which has been executed.
+    call_hash: b256,
+}
+
+/// Logged when a call is queued.
+pub struct CallQueued {
+    /// The hash of the call which has been queued.
+    call_hash: b256,
+    /// The earliest block height at which the call may be executed.
+    scheduled_height: u64,
+}
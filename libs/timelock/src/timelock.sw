@@ -0,0 +1,201 @@
+library;
+
+pub mod errors;
+pub mod events;
+
+use errors::TimelockError;
+use events::{CallCancelled, CallExecuted, CallQueued};
+use ownership::*;
+use src_5::Ownership;
+use std::block::height;
+
+/// The calls held in storage which have been queued and may be executed once their delay has passed.
+///
+/// # Additional Information
+///
+/// The earliest block height at which each pending call may be executed is kept in a `StorageMap<b256, u64>`
+/// at the storage key of the timelock, indexed by the hash of the call.
+pub struct Timelock {}
+
+impl StorageKey<Timelock> {
+    /// Returns the earliest block height at which a pending call may be executed.
+    ///
+    /// # Arguments
+    ///
+    /// * `call_hash`: [b256] - The hash of the call.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<u64>] - The block height at which the call may be executed, or `None` if the call is not pending.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use timelock::Timelock;
+    ///
+    /// storage {
+    ///     timelock: Timelock = Timelock {},
+    /// }
+    ///
+    /// fn foo(call_hash: b256) {
+    ///     assert(storage.timelock.scheduled_height(call_hash).is_none());
+    /// }
+    /// ```
+    #[storage(read)]
+    pub fn scheduled_height(self, call_hash: b256) -> Option<u64> {
+        self.pending_calls().get(call_hash).try_read()
+    }
+
+    /// Schedules a call which may be executed once `delay` blocks have been produced.
+    ///
+    /// # Arguments
+    ///
+    /// * `owner_key`: [StorageKey<Ownership>] - The location in storage which the `Ownership` of the admin is stored.
+    /// * `call_hash`: [b256] - The hash of the call to schedule.
+    /// * `delay`: [u64] - The number of blocks which must be produced before the call may be executed.
+    ///
+    /// # Reverts
+    ///
+    /// * When the sender is not the owner.
+    /// * When the call is already pending.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `2`
+    /// * Writes: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use src_5::Ownership;
+    /// use timelock::Timelock;
+    ///
+    /// storage {
+    ///     owner: Ownership = Ownership::initialized(Identity::Address(Address::from(0x0000000000000000000000000000000000000000000000000000000000000000))),
+    ///     timelock: Timelock = Timelock {},
+    /// }
+    ///
+    /// fn foo(call_hash: b256) {
+    ///     storage.timelock.queue(storage.owner, call_hash, 100);
+    ///     assert(storage.timelock.scheduled_height(call_hash).is_some());
+    /// }
+    /// ```
+    #[storage(read, write)]
+    pub fn queue(self, owner_key: StorageKey<Ownership>, call_hash: b256, delay: u64) {
+        owner_key.only_owner();
+        require(
+            self.scheduled_height(call_hash).is_none(),
+            TimelockError::AlreadyQueued,
+        );
+
+        let scheduled = height().as_u64() + delay;
+        self.pending_calls().insert(call_hash, scheduled);
+
+        log(CallQueued {
+            call_hash,
+            scheduled_height: scheduled,
+        });
+    }
+
+    /// Removes a pending call once its delay has passed, allowing it to be performed.
+    ///
+    /// # Additional Information
+    ///
+    /// Anyone may execute a call once it is ready. The contract should perform the call after this function returns.
+    ///
+    /// # Arguments
+    ///
+    /// * `call_hash`: [b256] - The hash of the call to execute.
+    ///
+    /// # Reverts
+    ///
+    /// * When the call is not pending.
+    /// * When the current block height is below the scheduled block height of the call.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    /// * Writes: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use timelock::Timelock;
+    ///
+    /// storage {
+    ///     timelock: Timelock = Timelock {},
+    /// }
+    ///
+    /// fn foo(call_hash: b256) {
+    ///     storage.timelock.execute(call_hash);
+    ///     // Perform the call here
+    /// }
+    /// ```
+    #[storage(read, write)]
+    pub fn execute(self, call_hash: b256) {
+        let scheduled = self.scheduled_height(call_hash);
+        require(scheduled.is_some(), TimelockError::NotQueued);
+        require(height().as_u64() >= scheduled.unwrap(), TimelockError::NotReady);
+
+        let _ = self.pending_calls().remove(call_hash);
+
+        log(CallExecuted { call_hash });
+    }
+
+    /// Removes a pending call so that it can no longer be executed.
+    ///
+    /// # Arguments
+    ///
+    /// * `owner_key`: [StorageKey<Ownership>] - The location in storage which the `Ownership` of the admin is stored.
+    /// * `call_hash`: [b256] - The hash of the call to cancel.
+    ///
+    /// # Reverts
+    ///
+    /// * When the sender is not the owner.
+    /// * When the call is not pending.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `2`
+    /// * Writes: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use src_5::Ownership;
+    /// use timelock::Timelock;
+    ///
+    /// storage {
+    ///     owner: Ownership = Ownership::initialized(Identity::Address(Address::from(0x0000000000000000000000000000000000000000000000000000000000000000))),
+    ///     timelock: Timelock = Timelock {},
+    /// }
+    ///
+    /// fn foo(call_hash: b256) {
+    ///     storage.timelock.cancel(storage.owner, call_hash);
+    ///     assert(storage.timelock.scheduled_height(call_hash).is_none());
+    /// }
+    /// ```
+    #[storage(read, write)]
+    pub fn cancel(self, owner_key: StorageKey<Ownership>, call_hash: b256) {
+        owner_key.only_owner();
+        require(
+            self.scheduled_height(call_hash).is_some(),
+            TimelockError::NotQueued,
+        );
+
+        let _ = self.pending_calls().remove(call_hash);
+
+        log(CallCancelled { call_hash });
+    }
+}
+
+impl StorageKey<Timelock> {
+    /// Returns the `StorageMap` which holds the scheduled block height of every pending call.
+    fn pending_calls(self) -> StorageKey<StorageMap<b256, u64>> {
+        StorageKey::new(self.slot, self.offset, self.field_id)
+    }
+}
@@ -0,0 +1,9 @@
+library;
+
+/// Error log for when a timestamp operation cannot be completed.
+pub enum TimestampError {
+    /// Emitted when a timestamp is before the Unix epoch and has no Unix time.
+    BeforeUnixEpoch: (),
+    /// Emitted when the earlier timestamp of a duration is after the later one.
+    EarlierIsLater: (),
+}
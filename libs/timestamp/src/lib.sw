@@ -0,0 +1,190 @@
+library;
+
+pub mod errors;
+
+use errors::TimestampError;
+use std::block::timestamp;
+
+/// The TAI64 label of the Unix epoch.
+///
+/// # Additional Information
+///
+/// A TAI64 label is `2 ^ 62` plus the number of seconds of International Atomic Time since 1970. TAI is
+/// ahead of UTC by the leap seconds which have been inserted since, which are currently `37` seconds, and
+/// this is the offset which Fuel uses to convert between its block timestamps and Unix time.
+pub const TAI64_UNIX_OFFSET: u64 = 4611686018427387941;
+
+/// A point in time in the TAI64 format used by the timestamps of Fuel blocks.
+///
+/// # Additional Information
+///
+/// Represented by an underlying `u64` TAI64 label, which counts seconds.
+pub struct Tai64 {
+    /// The underlying TAI64 label.
+    value: u64,
+}
+
+impl From<u64> for Tai64 {
+    /// Creates a `Tai64` from a TAI64 label. Note that `Tai64::from(0)` is long before the Unix epoch.
+    fn from(value: u64) -> Self {
+        Self { value }
+    }
+
+    fn into(self) -> u64 {
+        self.value
+    }
+}
+
+impl core::ops::Eq for Tai64 {
+    fn eq(self, other: Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl core::ops::Ord for Tai64 {
+    fn gt(self, other: Self) -> bool {
+        self.value > other.value
+    }
+
+    fn lt(self, other: Self) -> bool {
+        self.value < other.value
+    }
+}
+
+impl Tai64 {
+    /// Converts a Unix timestamp into a `Tai64`.
+    ///
+    /// # Arguments
+    ///
+    /// * `unix_secs`: [u64] - The number of seconds since the Unix epoch.
+    ///
+    /// # Returns
+    ///
+    /// * [Tai64] - The same point in time as a TAI64 label.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use timestamp::{Tai64, TAI64_UNIX_OFFSET};
+    ///
+    /// fn foo() {
+    ///     let time = Tai64::from_unix(1_700_000_000);
+    ///     assert(time.value == TAI64_UNIX_OFFSET + 1_700_000_000);
+    /// }
+    /// ```
+    pub fn from_unix(unix_secs: u64) -> Self {
+        Self {
+            value: TAI64_UNIX_OFFSET + unix_secs,
+        }
+    }
+
+    /// Converts a `Tai64` into a Unix timestamp.
+    ///
+    /// # Returns
+    ///
+    /// * [u64] - The number of seconds since the Unix epoch.
+    ///
+    /// # Reverts
+    ///
+    /// * When the timestamp is before the Unix epoch.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use timestamp::Tai64;
+    ///
+    /// fn foo() {
+    ///     let time = Tai64::from_unix(1_700_000_000);
+    ///     assert(time.to_unix() == 1_700_000_000);
+    /// }
+    /// ```
+    pub fn to_unix(self) -> u64 {
+        require(
+            !(self.value < TAI64_UNIX_OFFSET),
+            TimestampError::BeforeUnixEpoch,
+        );
+        self.value - TAI64_UNIX_OFFSET
+    }
+
+    /// Returns the timestamp a number of seconds later.
+    ///
+    /// # Arguments
+    ///
+    /// * `secs`: [u64] - The number of seconds to add.
+    ///
+    /// # Returns
+    ///
+    /// * [Tai64] - The timestamp `secs` seconds after `self`.
+    ///
+    /// # Reverts
+    ///
+    /// * When the result does not fit in a `u64`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use timestamp::Tai64;
+    ///
+    /// fn foo() {
+    ///     let start = Tai64::from_unix(1_700_000_000);
+    ///     let end = start.add_seconds(86_400);
+    ///     assert(end.to_unix() == 1_700_086_400);
+    /// }
+    /// ```
+    pub fn add_seconds(self, secs: u64) -> Self {
+        Self {
+            value: self.value + secs,
+        }
+    }
+
+    /// Returns the number of seconds which have passed since an earlier timestamp.
+    ///
+    /// # Arguments
+    ///
+    /// * `earlier`: [Tai64] - The timestamp to measure from.
+    ///
+    /// # Returns
+    ///
+    /// * [u64] - The number of seconds from `earlier` to `self`.
+    ///
+    /// # Reverts
+    ///
+    /// * When `earlier` is after `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use timestamp::Tai64;
+    ///
+    /// fn foo() {
+    ///     let start = Tai64::from_unix(1_700_000_000);
+    ///     let end = start.add_seconds(60);
+    ///     assert(end.seconds_since(start) == 60);
+    /// }
+    /// ```
+    pub fn seconds_since(self, earlier: Self) -> u64 {
+        require(!(self < earlier), TimestampError::EarlierIsLater);
+        self.value - earlier.value
+    }
+}
+
+/// Returns the timestamp of the current block.
+///
+/// # Returns
+///
+/// * [Tai64] - The time at which the current block was produced.
+///
+/// # Examples
+///
+/// ```sway
+/// use timestamp::{now, Tai64};
+///
+/// fn foo(deadline: Tai64) {
+///     require(now() < deadline, "Expired");
+/// }
+/// ```
+pub fn now() -> Tai64 {
+    Tai64 {
+        value: timestamp(),
+    }
+}
@@ -0,0 +1,9 @@
+library;
+
+/// Error log for when a time-weighted average price cannot be read.
+pub enum TwapError {
+    /// Emitted when the window is zero blocks or starts before the first block.
+    InvalidWindow: (),
+    /// Emitted when the accumulator was not updated at the block at which the window starts.
+    SnapshotNotFound: (),
+}
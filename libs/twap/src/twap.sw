@@ -0,0 +1,289 @@
+library;
+
+pub mod errors;
+
+use errors::TwapError;
+use std::{block::height, hash::sha256, u128::U128};
+
+/// The accumulated price of an asset held in storage, from which time-weighted average prices are read.
+///
+/// # Additional Information
+///
+/// Every block adds the price which was last set to the cumulative price, so the difference between the
+/// cumulative prices of two blocks divided by the number of blocks between them is the average price over
+/// those blocks. Unlike a spot price, this average cannot be moved by trades within a single block.
+///
+/// All fields are kept together at the storage key of the `TwapAccumulator`. The cumulative price at every
+/// block at which the accumulator is updated is kept as a snapshot in a `StorageMap<u64, U128>` at a slot
+/// derived from the slot of the accumulator.
+pub struct TwapAccumulator {
+    /// The sum of the price of every block up to the last update.
+    cumulative_price: U128,
+    /// The price which was set by the last update.
+    last_price: u64,
+    /// The block height of the last update.
+    last_update_block: u64,
+}
+
+impl TwapAccumulator {
+    /// Returns a `TwapAccumulator` which has not been updated.
+    ///
+    /// # Returns
+    ///
+    /// * [TwapAccumulator] - The `TwapAccumulator` with a cumulative price and last price of zero.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use twap::TwapAccumulator;
+    ///
+    /// storage {
+    ///     twap: TwapAccumulator = TwapAccumulator::new(),
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            cumulative_price: U128::new(),
+            last_price: 0,
+            last_update_block: 0,
+        }
+    }
+}
+
+impl StorageKey<TwapAccumulator> {
+    /// Returns the cumulative price as of the last update.
+    ///
+    /// # Returns
+    ///
+    /// * [U128] - The sum of the price of every block up to the last update.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::u128::U128;
+    /// use twap::TwapAccumulator;
+    ///
+    /// storage {
+    ///     twap: TwapAccumulator = TwapAccumulator::new(),
+    /// }
+    ///
+    /// fn foo() {
+    ///     assert(storage.twap.cumulative_price() == U128::new());
+    /// }
+    /// ```
+    #[storage(read)]
+    pub fn cumulative_price(self) -> U128 {
+        self.read().cumulative_price
+    }
+
+    /// Returns the price which was set by the last update.
+    ///
+    /// # Returns
+    ///
+    /// * [u64] - The last price, or `0` before the first update.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use twap::TwapAccumulator;
+    ///
+    /// storage {
+    ///     twap: TwapAccumulator = TwapAccumulator::new(),
+    /// }
+    ///
+    /// fn foo() {
+    ///     storage.twap.update(100);
+    ///     assert(storage.twap.last_price() == 100);
+    /// }
+    /// ```
+    #[storage(read)]
+    pub fn last_price(self) -> u64 {
+        self.read().last_price
+    }
+
+    /// Returns the block height of the last update.
+    ///
+    /// # Returns
+    ///
+    /// * [u64] - The block height of the last update, or `0` before the first update.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::block::height;
+    /// use twap::TwapAccumulator;
+    ///
+    /// storage {
+    ///     twap: TwapAccumulator = TwapAccumulator::new(),
+    /// }
+    ///
+    /// fn foo() {
+    ///     storage.twap.update(100);
+    ///     assert(storage.twap.last_update_block() == height().as_u64());
+    /// }
+    /// ```
+    #[storage(read)]
+    pub fn last_update_block(self) -> u64 {
+        self.read().last_update_block
+    }
+
+    /// Returns the cumulative price which was recorded at a block.
+    ///
+    /// # Arguments
+    ///
+    /// * `block`: [u64] - The block height of which to query the snapshot.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<U128>] - The cumulative price at `block` or `None` if the accumulator was not updated at `block`.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::block::height;
+    /// use twap::TwapAccumulator;
+    ///
+    /// storage {
+    ///     twap: TwapAccumulator = TwapAccumulator::new(),
+    /// }
+    ///
+    /// fn foo() {
+    ///     storage.twap.update(100);
+    ///     assert(storage.twap.snapshot(height().as_u64()).unwrap() == storage.twap.cumulative_price());
+    /// }
+    /// ```
+    #[storage(read)]
+    pub fn snapshot(self, block: u64) -> Option<U128> {
+        self.snapshots().get(block).try_read()
+    }
+
+    /// Sets the price of the current block and records the cumulative price.
+    ///
+    /// # Additional Information
+    ///
+    /// The last price is added to the cumulative price once for every block since the last update, and `current_price`
+    /// then holds from the current block onwards. A second update within the same block only replaces the price.
+    /// The accumulator does not check who calls it, so the contract should only update it with a trusted price,
+    /// such as the spot price of a pool before the first trade of the block.
+    ///
+    /// # Arguments
+    ///
+    /// * `current_price`: [u64] - The price of the current block.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    /// * Writes: `2`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use twap::TwapAccumulator;
+    ///
+    /// storage {
+    ///     twap: TwapAccumulator = TwapAccumulator::new(),
+    /// }
+    ///
+    /// fn foo(spot_price: u64) {
+    ///     storage.twap.update(spot_price);
+    ///     assert(storage.twap.last_price() == spot_price);
+    /// }
+    /// ```
+    #[storage(read, write)]
+    pub fn update(self, current_price: u64) {
+        let current_block = height().as_u64();
+        let mut accumulator = self.read();
+
+        accumulator.cumulative_price = accumulated(accumulator, current_block);
+        accumulator.last_price = current_price;
+        accumulator.last_update_block = current_block;
+
+        self.snapshots().insert(current_block, accumulator.cumulative_price);
+        self.write(accumulator);
+    }
+
+    /// Returns the time-weighted average price over the most recent blocks.
+    ///
+    /// # Additional Information
+    ///
+    /// The window starts `window_blocks` before the current block and ends before the current block, so the
+    /// accumulator must have been updated at the block at which the window starts. Blocks after the last update
+    /// count at the last price. The average is rounded down.
+    ///
+    /// # Arguments
+    ///
+    /// * `window_blocks`: [u64] - The number of blocks to average the price over.
+    ///
+    /// # Returns
+    ///
+    /// * [u64] - The average price of the `window_blocks` blocks before the current block.
+    ///
+    /// # Reverts
+    ///
+    /// * When `window_blocks` is zero or greater than the current block height.
+    /// * When there is no snapshot at the block at which the window starts.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `2`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use twap::TwapAccumulator;
+    ///
+    /// storage {
+    ///     twap: TwapAccumulator = TwapAccumulator::new(),
+    /// }
+    ///
+    /// fn foo() {
+    ///     // Averages the price of the last 10 blocks
+    ///     let twap = storage.twap.read_twap(10);
+    /// }
+    /// ```
+    #[storage(read)]
+    pub fn read_twap(self, window_blocks: u64) -> u64 {
+        let current_block = height().as_u64();
+        require(
+            window_blocks != 0 && window_blocks <= current_block,
+            TwapError::InvalidWindow,
+        );
+
+        let start = self.snapshot(current_block - window_blocks);
+        require(start.is_some(), TwapError::SnapshotNotFound);
+
+        let end = accumulated(self.read(), current_block);
+        // The average of `u64` prices always fits in a `u64`
+        ((end - start.unwrap()) / U128::from((0, window_blocks))).lower
+    }
+}
+
+impl StorageKey<TwapAccumulator> {
+    /// Returns the `StorageMap` which holds the cumulative price at every block at which the accumulator was updated.
+    fn snapshots(self) -> StorageKey<StorageMap<u64, U128>> {
+        let slot = sha256((self.slot, "snapshots"));
+        StorageKey::new(slot, 0, slot)
+    }
+}
+
+/// Returns the cumulative price of an accumulator with its last price carried forward to a block.
+fn accumulated(accumulator: TwapAccumulator, block: u64) -> U128 {
+    let elapsed = block - accumulator.last_update_block;
+    accumulator.cumulative_price + U128::from((0, accumulator.last_price)) * U128::from((0, elapsed))
+}
@@ -0,0 +1,7 @@
+library;
+
+/// Error log for when a vector is accessed incorrectly.
+pub enum VecUtilsError {
+    /// Emitted when an index does not lie within the vector.
+    IndexOutOfBounds: (),
+}
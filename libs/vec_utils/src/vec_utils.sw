@@ -0,0 +1,166 @@
+library;
+
+pub mod errors;
+
+use errors::VecUtilsError;
+
+/// Returns whether a vector contains an element equal to `target`.
+///
+/// # Arguments
+///
+/// * `v`: [Vec<T>] - The vector to search.
+/// * `target`: [T] - The element to search for.
+///
+/// # Returns
+///
+/// * [bool] - `true` if an element of `v` is equal to `target`, otherwise `false`.
+///
+/// # Examples
+///
+/// ```sway
+/// use vec_utils::contains;
+///
+/// fn foo() {
+///     let mut v = Vec::new();
+///     v.push(1);
+///     v.push(2);
+///     assert(contains(v, 2));
+///     assert(!contains(v, 3));
+/// }
+/// ```
+pub fn contains<T>(v: Vec<T>, target: T) -> bool
+where
+    T: Eq,
+{
+    index_of(v, target).is_some()
+}
+
+/// Returns the index of the first element of a vector equal to `target`.
+///
+/// # Arguments
+///
+/// * `v`: [Vec<T>] - The vector to search.
+/// * `target`: [T] - The element to search for.
+///
+/// # Returns
+///
+/// * [Option<u64>] - The index of the first matching element, or `None` if there is none.
+///
+/// # Examples
+///
+/// ```sway
+/// use vec_utils::index_of;
+///
+/// fn foo() {
+///     let mut v = Vec::new();
+///     v.push(1);
+///     v.push(2);
+///     assert(index_of(v, 2).unwrap() == 1);
+///     assert(index_of(v, 3).is_none());
+/// }
+/// ```
+pub fn index_of<T>(v: Vec<T>, target: T) -> Option<u64>
+where
+    T: Eq,
+{
+    let mut i = 0;
+    while i < v.len() {
+        if v.get(i).unwrap() == target {
+            return Option::Some(i);
+        }
+        i += 1;
+    }
+    Option::None
+}
+
+/// Removes the element at `index` by replacing it with the last element of the vector.
+///
+/// # Additional Information
+///
+/// This does not preserve the order of the elements but only moves a single element, unlike
+/// `Vec::remove` which shifts every element after `index`.
+///
+/// # Arguments
+///
+/// * `v`: [Vec<T>] - The vector to remove the element from.
+/// * `index`: [u64] - The index of the element to remove.
+///
+/// # Returns
+///
+/// * [Vec<T>] - The vector without the removed element.
+///
+/// # Reverts
+///
+/// * When `index` is not less than the length of `v`.
+///
+/// # Examples
+///
+/// ```sway
+/// use vec_utils::remove_unordered;
+///
+/// fn foo() {
+///     let mut v = Vec::new();
+///     v.push(1);
+///     v.push(2);
+///     v.push(3);
+///     let v = remove_unordered(v, 0);
+///     assert(v.len() == 2);
+///     assert(v.get(0).unwrap() == 3);
+///     assert(v.get(1).unwrap() == 2);
+/// }
+/// ```
+pub fn remove_unordered<T>(v: Vec<T>, index: u64) -> Vec<T> {
+    require(index < v.len(), VecUtilsError::IndexOutOfBounds);
+
+    let mut v = v;
+    let last = v.pop().unwrap();
+    if index < v.len() {
+        v.set(index, last);
+    }
+    v
+}
+
+/// Removes consecutive repeated elements from a vector.
+///
+/// # Additional Information
+///
+/// Only adjacent duplicates are removed, so a sorted vector results in every element being unique.
+///
+/// # Arguments
+///
+/// * `v`: [Vec<T>] - The vector to remove the duplicates from.
+///
+/// # Returns
+///
+/// * [Vec<T>] - A new vector where no element is equal to the one before it.
+///
+/// # Examples
+///
+/// ```sway
+/// use vec_utils::dedup;
+///
+/// fn foo() {
+///     let mut v = Vec::new();
+///     v.push(1);
+///     v.push(1);
+///     v.push(2);
+///     v.push(1);
+///     let v = dedup(v);
+///     assert(v.len() == 3);
+/// }
+/// ```
+pub fn dedup<T>(v: Vec<T>) -> Vec<T>
+where
+    T: Eq,
+{
+    let mut result = Vec::with_capacity(v.len());
+    let mut i = 0;
+    while i < v.len() {
+        let element = v.get(i).unwrap();
+        if i == 0 || result.get(result.len() - 1).unwrap() != element {
+            result.push(element);
+        }
+        i += 1;
+    }
+    result
+}
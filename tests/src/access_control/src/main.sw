@@ -0,0 +1,61 @@
+contract;
+
+use access_control::*;
+
+storage {
+    access: AccessControl = AccessControl {},
+}
+
+abi AccessControlTest {
+    #[storage(read, write)]
+    fn grant_role(role: b256, account: Identity);
+    #[storage(read)]
+    fn has_role(role: b256, account: Identity) -> bool;
+    #[storage(read, write)]
+    fn initialize(admin: Identity);
+    #[storage(read)]
+    fn require_role(role: b256);
+    #[storage(read, write)]
+    fn revoke_role(role: b256, account: Identity);
+    #[storage(read)]
+    fn role_admin(role: b256) -> b256;
+    #[storage(read, write)]
+    fn set_role_admin(role: b256, admin_role: b256);
+}
+
+impl AccessControlTest for Contract {
+    #[storage(read, write)]
+    fn grant_role(role: b256, account: Identity) {
+        storage.access.grant_role(role, account);
+    }
+
+    #[storage(read)]
+    fn has_role(role: b256, account: Identity) -> bool {
+        storage.access.has_role(role, account)
+    }
+
+    #[storage(read, write)]
+    fn initialize(admin: Identity) {
+        storage.access.initialize(admin);
+    }
+
+    #[storage(read)]
+    fn require_role(role: b256) {
+        storage.access.require_role(role);
+    }
+
+    #[storage(read, write)]
+    fn revoke_role(role: b256, account: Identity) {
+        storage.access.revoke_role(role, account);
+    }
+
+    #[storage(read)]
+    fn role_admin(role: b256) -> b256 {
+        storage.access.role_admin(role)
+    }
+
+    #[storage(read, write)]
+    fn set_role_admin(role: b256, admin_role: b256) {
+        storage.access.set_role_admin(role, admin_role);
+    }
+}
@@ -0,0 +1,67 @@
+use crate::access_control::tests::utils::{
+    abi_calls::{grant_role, has_role, set_role_admin},
+    test_helpers::setup,
+    DEFAULT_ADMIN_ROLE, MINTER_ADMIN_ROLE, MINTER_ROLE,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn admin_grants_role() {
+        let (admin, user1, _user2) = setup().await;
+
+        assert!(!has_role(&admin.contract, MINTER_ROLE, user1.identity()).await);
+
+        grant_role(&admin.contract, MINTER_ROLE, user1.identity()).await;
+
+        assert!(has_role(&admin.contract, MINTER_ROLE, user1.identity()).await);
+    }
+
+    #[tokio::test]
+    async fn admin_grants_admin_role() {
+        let (admin, user1, user2) = setup().await;
+
+        grant_role(&admin.contract, DEFAULT_ADMIN_ROLE, user1.identity()).await;
+        grant_role(&user1.contract, MINTER_ROLE, user2.identity()).await;
+
+        assert!(has_role(&admin.contract, DEFAULT_ADMIN_ROLE, user1.identity()).await);
+        assert!(has_role(&admin.contract, MINTER_ROLE, user2.identity()).await);
+    }
+
+    #[tokio::test]
+    async fn custom_admin_grants_role() {
+        let (admin, user1, user2) = setup().await;
+
+        set_role_admin(&admin.contract, MINTER_ROLE, MINTER_ADMIN_ROLE).await;
+        grant_role(&admin.contract, MINTER_ADMIN_ROLE, user1.identity()).await;
+
+        grant_role(&user1.contract, MINTER_ROLE, user2.identity()).await;
+
+        assert!(has_role(&admin.contract, MINTER_ROLE, user2.identity()).await);
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "MissingRole")]
+    async fn when_not_admin() {
+        let (_admin, user1, user2) = setup().await;
+
+        grant_role(&user1.contract, MINTER_ROLE, user2.identity()).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "MissingRole")]
+    async fn when_role_has_other_admin_role() {
+        let (admin, user1, _user2) = setup().await;
+
+        set_role_admin(&admin.contract, MINTER_ROLE, MINTER_ADMIN_ROLE).await;
+
+        grant_role(&admin.contract, MINTER_ROLE, user1.identity()).await;
+    }
+}
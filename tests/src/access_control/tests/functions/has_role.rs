@@ -0,0 +1,30 @@
+use crate::access_control::tests::utils::{
+    abi_calls::{grant_role, has_role},
+    test_helpers::setup,
+    DEFAULT_ADMIN_ROLE, MINTER_ROLE,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_initial_roles() {
+        let (admin, user1, _user2) = setup().await;
+
+        assert!(has_role(&admin.contract, DEFAULT_ADMIN_ROLE, admin.identity()).await);
+        assert!(!has_role(&admin.contract, DEFAULT_ADMIN_ROLE, user1.identity()).await);
+        assert!(!has_role(&admin.contract, MINTER_ROLE, admin.identity()).await);
+    }
+
+    #[tokio::test]
+    async fn roles_are_independent() {
+        let (admin, user1, user2) = setup().await;
+
+        grant_role(&admin.contract, MINTER_ROLE, user1.identity()).await;
+
+        assert!(has_role(&admin.contract, MINTER_ROLE, user1.identity()).await);
+        assert!(!has_role(&admin.contract, MINTER_ROLE, user2.identity()).await);
+        assert!(!has_role(&admin.contract, DEFAULT_ADMIN_ROLE, user1.identity()).await);
+    }
+}
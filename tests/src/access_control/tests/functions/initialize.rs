@@ -0,0 +1,30 @@
+use crate::access_control::tests::utils::{
+    abi_calls::{has_role, initialize},
+    test_helpers::setup,
+    DEFAULT_ADMIN_ROLE,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn grants_default_admin_role() {
+        let (admin, _user1, _user2) = setup().await;
+
+        assert!(has_role(&admin.contract, DEFAULT_ADMIN_ROLE, admin.identity()).await);
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "AlreadyInitialized")]
+    async fn when_already_initialized() {
+        let (_admin, user1, _user2) = setup().await;
+
+        initialize(&user1.contract, user1.identity()).await;
+    }
+}
@@ -0,0 +1,6 @@
+mod grant_role;
+mod has_role;
+mod initialize;
+mod require_role;
+mod revoke_role;
+mod role_admin;
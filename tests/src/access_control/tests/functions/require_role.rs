@@ -0,0 +1,51 @@
+use crate::access_control::tests::utils::{
+    abi_calls::{grant_role, require_role, revoke_role},
+    test_helpers::setup,
+    MINTER_ROLE,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn user_with_role_may_call() {
+        let (admin, user1, _user2) = setup().await;
+
+        grant_role(&admin.contract, MINTER_ROLE, user1.identity()).await;
+
+        require_role(&user1.contract, MINTER_ROLE).await;
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "MissingRole")]
+    async fn when_missing_role() {
+        let (_admin, user1, _user2) = setup().await;
+
+        require_role(&user1.contract, MINTER_ROLE).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "MissingRole")]
+    async fn when_role_revoked() {
+        let (admin, user1, _user2) = setup().await;
+
+        grant_role(&admin.contract, MINTER_ROLE, user1.identity()).await;
+        revoke_role(&admin.contract, MINTER_ROLE, user1.identity()).await;
+
+        require_role(&user1.contract, MINTER_ROLE).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "MissingRole")]
+    async fn when_only_admin() {
+        let (admin, _user1, _user2) = setup().await;
+
+        require_role(&admin.contract, MINTER_ROLE).await;
+    }
+}
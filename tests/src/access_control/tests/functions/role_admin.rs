@@ -0,0 +1,49 @@
+use crate::access_control::tests::utils::{
+    abi_calls::{role_admin, set_role_admin},
+    test_helpers::setup,
+    DEFAULT_ADMIN_ROLE, MINTER_ADMIN_ROLE, MINTER_ROLE,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn defaults_to_default_admin_role() {
+        let (admin, _user1, _user2) = setup().await;
+
+        assert_eq!(
+            role_admin(&admin.contract, MINTER_ROLE).await,
+            DEFAULT_ADMIN_ROLE
+        );
+        assert_eq!(
+            role_admin(&admin.contract, DEFAULT_ADMIN_ROLE).await,
+            DEFAULT_ADMIN_ROLE
+        );
+    }
+
+    #[tokio::test]
+    async fn sets_role_admin() {
+        let (admin, _user1, _user2) = setup().await;
+
+        set_role_admin(&admin.contract, MINTER_ROLE, MINTER_ADMIN_ROLE).await;
+
+        assert_eq!(
+            role_admin(&admin.contract, MINTER_ROLE).await,
+            MINTER_ADMIN_ROLE
+        );
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "MissingRole")]
+    async fn when_not_admin() {
+        let (_admin, user1, _user2) = setup().await;
+
+        set_role_admin(&user1.contract, MINTER_ROLE, MINTER_ADMIN_ROLE).await;
+    }
+}
@@ -0,0 +1,153 @@
+use crate::common::{deploy_contract, get_wallets};
+use fuels::{
+    prelude::{abigen, LoadConfiguration, StorageConfiguration, WalletUnlocked},
+    programs::call_response::FuelCallResponse,
+    types::{Bits256, Identity},
+};
+
+// Load abi from json
+abigen!(Contract(
+    name = "AccessControlLib",
+    abi = "src/access_control/out/debug/access_control_test-abi.json"
+));
+
+pub const DEFAULT_ADMIN_ROLE: Bits256 = Bits256([0u8; 32]);
+pub const MINTER_ROLE: Bits256 = Bits256([1u8; 32]);
+pub const MINTER_ADMIN_ROLE: Bits256 = Bits256([2u8; 32]);
+
+pub struct Metadata {
+    pub contract: AccessControlLib<WalletUnlocked>,
+    pub wallet: WalletUnlocked,
+}
+
+impl Metadata {
+    pub fn identity(&self) -> Identity {
+        Identity::Address(self.wallet.address().into())
+    }
+}
+
+pub mod abi_calls {
+
+    use super::*;
+
+    pub async fn grant_role(
+        contract: &AccessControlLib<WalletUnlocked>,
+        role: Bits256,
+        account: Identity,
+    ) -> FuelCallResponse<()> {
+        contract
+            .methods()
+            .grant_role(role, account)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn has_role(
+        contract: &AccessControlLib<WalletUnlocked>,
+        role: Bits256,
+        account: Identity,
+    ) -> bool {
+        contract
+            .methods()
+            .has_role(role, account)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn initialize(
+        contract: &AccessControlLib<WalletUnlocked>,
+        admin: Identity,
+    ) -> FuelCallResponse<()> {
+        contract.methods().initialize(admin).call().await.unwrap()
+    }
+
+    pub async fn require_role(
+        contract: &AccessControlLib<WalletUnlocked>,
+        role: Bits256,
+    ) -> FuelCallResponse<()> {
+        contract.methods().require_role(role).call().await.unwrap()
+    }
+
+    pub async fn revoke_role(
+        contract: &AccessControlLib<WalletUnlocked>,
+        role: Bits256,
+        account: Identity,
+    ) -> FuelCallResponse<()> {
+        contract
+            .methods()
+            .revoke_role(role, account)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn role_admin(contract: &AccessControlLib<WalletUnlocked>, role: Bits256) -> Bits256 {
+        contract
+            .methods()
+            .role_admin(role)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn set_role_admin(
+        contract: &AccessControlLib<WalletUnlocked>,
+        role: Bits256,
+        admin_role: Bits256,
+    ) -> FuelCallResponse<()> {
+        contract
+            .methods()
+            .set_role_admin(role, admin_role)
+            .call()
+            .await
+            .unwrap()
+    }
+}
+
+pub mod test_helpers {
+
+    use super::abi_calls::initialize;
+    use super::*;
+
+    pub async fn setup() -> (Metadata, Metadata, Metadata) {
+        let mut wallets = get_wallets(4).await;
+
+        // Get the wallets from that provider
+        let wallet1 = wallets.pop().unwrap();
+        let wallet2 = wallets.pop().unwrap();
+        let wallet3 = wallets.pop().unwrap();
+
+        let storage_configuration = StorageConfiguration::load_from(
+            "src/access_control/out/debug/access_control_test-storage_slots.json",
+        );
+        let id = deploy_contract(
+            &wallet1,
+            "src/access_control/out/debug/access_control_test.bin",
+            LoadConfiguration::default().set_storage_configuration(storage_configuration.unwrap()),
+        )
+        .await;
+
+        let admin = Metadata {
+            contract: AccessControlLib::new(id.clone(), wallet1.clone()),
+            wallet: wallet1.clone(),
+        };
+
+        let user1 = Metadata {
+            contract: AccessControlLib::new(id.clone(), wallet2.clone()),
+            wallet: wallet2.clone(),
+        };
+
+        let user2 = Metadata {
+            contract: AccessControlLib::new(id.clone(), wallet3.clone()),
+            wallet: wallet3.clone(),
+        };
+
+        initialize(&admin.contract, admin.identity()).await;
+
+        (admin, user1, user2)
+    }
+}
@@ -0,0 +1,146 @@
+use crate::common::script_instance;
+use fuels::prelude::{abigen, WalletUnlocked};
+
+abigen!(
+    Script(
+        name = "TestGetAmountIn",
+        abi = "src/amm/get_amount_in_test/out/debug/get_amount_in_test-abi.json"
+    ),
+    Script(
+        name = "TestGetAmountOut",
+        abi = "src/amm/get_amount_out_test/out/debug/get_amount_out_test-abi.json"
+    ),
+);
+
+const PATH_TO_BIN: &str = "src/amm/get_amount_in_test/out/debug/get_amount_in_test.bin";
+const PATH_TO_AMOUNT_OUT_BIN: &str =
+    "src/amm/get_amount_out_test/out/debug/get_amount_out_test.bin";
+
+async fn setup() -> TestGetAmountIn<WalletUnlocked> {
+    script_instance!(TestGetAmountIn, PATH_TO_BIN)
+}
+
+mod success {
+
+    use super::*;
+
+    // The expected amounts are those of the reference implementation
+    // `reserve_in * amount_out * 10_000 // ((reserve_out - amount_out) * (10_000 - fee_bps)) + 1`
+    // computed with Python's arbitrary precision integers.
+
+    #[tokio::test]
+    async fn matches_reference_implementation() {
+        let instance = setup().await;
+
+        let result = instance.main(19_743, 1_000_000, 2_000_000, 30).call().await;
+        assert_eq!(result.unwrap().value, 10_000);
+    }
+
+    #[tokio::test]
+    async fn does_not_overflow_with_max_values() {
+        let instance = setup().await;
+
+        let result = instance
+            .main(9_209_516_195_036_766_630, u64::MAX, u64::MAX, 30)
+            .call()
+            .await;
+        assert_eq!(result.unwrap().value, u64::MAX);
+    }
+
+    #[tokio::test]
+    async fn is_inverse_of_get_amount_out() {
+        let amount_in_instance = setup().await;
+        let amount_out_instance = script_instance!(TestGetAmountOut, PATH_TO_AMOUNT_OUT_BIN);
+        let (reserve_in, reserve_out, fee_bps) = (1_000_000, 2_000_000, 30);
+
+        for amount_in in [1_000, 10_000, 123_456, 500_000] {
+            let amount_out = amount_out_instance
+                .main(amount_in, reserve_in, reserve_out, fee_bps)
+                .call()
+                .await
+                .unwrap()
+                .value;
+            let required_in = amount_in_instance
+                .main(amount_out, reserve_in, reserve_out, fee_bps)
+                .call()
+                .await
+                .unwrap()
+                .value;
+            // Rounding never lets the round trip ask for more than was given
+            assert!(required_in <= amount_in);
+
+            let received_out = amount_out_instance
+                .main(required_in, reserve_in, reserve_out, fee_bps)
+                .call()
+                .await
+                .unwrap()
+                .value;
+            assert_eq!(received_out, amount_out);
+        }
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "InsufficientOutputAmount")]
+    async fn when_amount_out_is_zero() {
+        let instance = setup().await;
+
+        instance
+            .main(0, 1_000_000, 2_000_000, 30)
+            .call()
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InsufficientLiquidity")]
+    async fn when_reserve_in_is_zero() {
+        let instance = setup().await;
+
+        instance
+            .main(10_000, 0, 2_000_000, 30)
+            .call()
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InsufficientLiquidity")]
+    async fn when_amount_out_is_reserve_out() {
+        let instance = setup().await;
+
+        instance
+            .main(2_000_000, 1_000_000, 2_000_000, 30)
+            .call()
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidFee")]
+    async fn when_fee_is_whole() {
+        let instance = setup().await;
+
+        instance
+            .main(10_000, 1_000_000, 2_000_000, 10_000)
+            .call()
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Overflow")]
+    async fn when_amount_in_overflows() {
+        let instance = setup().await;
+
+        instance
+            .main(u64::MAX - 1, u64::MAX, u64::MAX, 30)
+            .call()
+            .await
+            .unwrap();
+    }
+}
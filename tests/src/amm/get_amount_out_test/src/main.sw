@@ -0,0 +1,7 @@
+script;
+
+use amm::get_amount_out;
+
+fn main(amount_in: u64, reserve_in: u64, reserve_out: u64, fee_bps: u64) -> u64 {
+    get_amount_out(amount_in, reserve_in, reserve_out, fee_bps)
+}
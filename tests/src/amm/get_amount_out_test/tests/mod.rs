@@ -0,0 +1,105 @@
+use crate::common::script_instance;
+use fuels::prelude::{abigen, WalletUnlocked};
+
+abigen!(Script(
+    name = "TestGetAmountOut",
+    abi = "src/amm/get_amount_out_test/out/debug/get_amount_out_test-abi.json"
+),);
+
+const PATH_TO_BIN: &str = "src/amm/get_amount_out_test/out/debug/get_amount_out_test.bin";
+
+async fn setup() -> TestGetAmountOut<WalletUnlocked> {
+    script_instance!(TestGetAmountOut, PATH_TO_BIN)
+}
+
+mod success {
+
+    use super::*;
+
+    // The expected amounts are those of the reference implementation
+    // `a = amount_in * (10_000 - fee_bps); a * reserve_out // (reserve_in * 10_000 + a)`
+    // computed with Python's arbitrary precision integers.
+
+    #[tokio::test]
+    async fn matches_reference_implementation() {
+        let instance = setup().await;
+
+        let result = instance.main(10_000, 1_000_000, 2_000_000, 30).call().await;
+        assert_eq!(result.unwrap().value, 19_743);
+
+        let result = instance
+            .main(1_000_000_000, 5_000_000_000_000, 2_500_000_000_000, 30)
+            .call()
+            .await;
+        assert_eq!(result.unwrap().value, 498_400_618);
+    }
+
+    #[tokio::test]
+    async fn does_not_overflow_with_max_values() {
+        let instance = setup().await;
+
+        let result = instance.main(u64::MAX, u64::MAX, u64::MAX, 30).call().await;
+        assert_eq!(result.unwrap().value, 9_209_516_195_036_766_630);
+    }
+
+    #[tokio::test]
+    async fn swaps_without_fee() {
+        let instance = setup().await;
+
+        let result = instance.main(1_000, 1_000, 1_000, 0).call().await;
+        assert_eq!(result.unwrap().value, 500);
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "InsufficientInputAmount")]
+    async fn when_amount_in_is_zero() {
+        let instance = setup().await;
+
+        instance
+            .main(0, 1_000_000, 2_000_000, 30)
+            .call()
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InsufficientLiquidity")]
+    async fn when_reserve_in_is_zero() {
+        let instance = setup().await;
+
+        instance
+            .main(10_000, 0, 2_000_000, 30)
+            .call()
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InsufficientLiquidity")]
+    async fn when_reserve_out_is_zero() {
+        let instance = setup().await;
+
+        instance
+            .main(10_000, 1_000_000, 0, 30)
+            .call()
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidFee")]
+    async fn when_fee_is_whole() {
+        let instance = setup().await;
+
+        instance
+            .main(10_000, 1_000_000, 2_000_000, 10_000)
+            .call()
+            .await
+            .unwrap();
+    }
+}
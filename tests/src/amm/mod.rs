@@ -0,0 +1,3 @@
+mod get_amount_in_test;
+mod get_amount_out_test;
+mod quote_test;
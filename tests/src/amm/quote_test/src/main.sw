@@ -0,0 +1,7 @@
+script;
+
+use amm::quote;
+
+fn main(amount_a: u64, reserve_a: u64, reserve_b: u64) -> u64 {
+    quote(amount_a, reserve_a, reserve_b)
+}
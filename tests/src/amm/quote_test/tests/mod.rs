@@ -0,0 +1,74 @@
+use crate::common::script_instance;
+use fuels::prelude::{abigen, WalletUnlocked};
+
+abigen!(Script(
+    name = "TestQuote",
+    abi = "src/amm/quote_test/out/debug/quote_test-abi.json"
+),);
+
+const PATH_TO_BIN: &str = "src/amm/quote_test/out/debug/quote_test.bin";
+
+async fn setup() -> TestQuote<WalletUnlocked> {
+    script_instance!(TestQuote, PATH_TO_BIN)
+}
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn quotes_at_reserve_ratio() {
+        let instance = setup().await;
+
+        let result = instance.main(1_000, 1_000_000, 2_000_000).call().await;
+        assert_eq!(result.unwrap().value, 2_000);
+
+        let result = instance.main(1_000, 3_000, 1_000).call().await;
+        assert_eq!(result.unwrap().value, 333);
+    }
+
+    #[tokio::test]
+    async fn does_not_overflow_with_max_values() {
+        let instance = setup().await;
+
+        let result = instance.main(u64::MAX, u64::MAX, u64::MAX).call().await;
+        assert_eq!(result.unwrap().value, u64::MAX);
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "InsufficientInputAmount")]
+    async fn when_amount_is_zero() {
+        let instance = setup().await;
+
+        instance.main(0, 1_000_000, 2_000_000).call().await.unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InsufficientLiquidity")]
+    async fn when_reserve_a_is_zero() {
+        let instance = setup().await;
+
+        instance.main(1_000, 0, 2_000_000).call().await.unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InsufficientLiquidity")]
+    async fn when_reserve_b_is_zero() {
+        let instance = setup().await;
+
+        instance.main(1_000, 1_000_000, 0).call().await.unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Overflow")]
+    async fn when_result_overflows() {
+        let instance = setup().await;
+
+        instance.main(u64::MAX, 1, 2).call().await.unwrap();
+    }
+}
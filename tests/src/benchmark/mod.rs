@@ -0,0 +1,30 @@
+use fuels::programs::call_response::FuelCallResponse;
+
+/// Access to the gas consumed by a call.
+pub(crate) trait GasUsed {
+    /// Returns the gas used by the script of the transaction, taken from its `ScriptResult` receipt.
+    fn gas_used(&self) -> u64;
+}
+
+impl<T> GasUsed for FuelCallResponse<T> {
+    fn gas_used(&self) -> u64 {
+        self.gas_used
+    }
+}
+
+/// Asserts that a call used no more than `budget` gas.
+///
+/// The gas used is always printed so it can be compared across changes by running the benchmarks
+/// with `cargo test benchmarks -- --nocapture`.
+///
+/// A budget is the gas printed this way with a margin of around 20% which leaves room for small changes in
+/// code generation, so it catches a function becoming dramatically more expensive rather than tracking every
+/// unit of gas.
+pub(crate) fn assert_within_budget(name: &str, response: &impl GasUsed, budget: u64) {
+    let gas_used = response.gas_used();
+    println!("{name}: {gas_used} gas used of a {budget} gas budget");
+    assert!(
+        gas_used <= budget,
+        "{name} used {gas_used} gas which exceeds its budget of {budget}"
+    );
+}
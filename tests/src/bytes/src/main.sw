@@ -0,0 +1,121 @@
+contract;
+
+use bytes::{*, parse::{format_u64, parse_u64}};
+
+storage {
+    bytes: StorageBytes = StorageBytes {},
+}
+
+abi BytesTest {
+    fn append(first: Vec<u8>, second: Vec<u8>) -> b256;
+    fn format_u64(n: u64) -> Vec<u8>;
+    fn get(data: Vec<u8>, index: u64) -> Option<u8>;
+    fn len(data: Vec<u8>) -> u64;
+    fn parse_u64(data: Vec<u8>) -> Option<u64>;
+    fn round_trip(n: u64) -> Option<u64>;
+    fn sha256(data: Vec<u8>) -> b256;
+    fn slice(data: Vec<u8>, start: u64, end: u64) -> b256;
+    fn to_b256(data: Vec<u8>) -> b256;
+    #[storage(write)]
+    fn clear_stored();
+    #[storage(read)]
+    fn get_stored(index: u64) -> Option<u8>;
+    #[storage(read)]
+    fn len_stored() -> u64;
+    #[storage(read)]
+    fn sha256_stored() -> b256;
+    #[storage(read)]
+    fn slice_stored(start: u64, end: u64) -> b256;
+    #[storage(read, write)]
+    fn store(data: Vec<u8>);
+}
+
+impl BytesTest for Contract {
+    fn append(first: Vec<u8>, second: Vec<u8>) -> b256 {
+        let mut bytes = into_bytes(first);
+        bytes.append(into_bytes(second));
+        bytes.sha256()
+    }
+
+    fn format_u64(n: u64) -> Vec<u8> {
+        into_vec(format_u64(n))
+    }
+
+    fn get(data: Vec<u8>, index: u64) -> Option<u8> {
+        into_bytes(data).get(index)
+    }
+
+    fn len(data: Vec<u8>) -> u64 {
+        into_bytes(data).len()
+    }
+
+    fn parse_u64(data: Vec<u8>) -> Option<u64> {
+        parse_u64(into_bytes(data))
+    }
+
+    fn round_trip(n: u64) -> Option<u64> {
+        parse_u64(format_u64(n))
+    }
+
+    fn sha256(data: Vec<u8>) -> b256 {
+        into_bytes(data).sha256()
+    }
+
+    fn slice(data: Vec<u8>, start: u64, end: u64) -> b256 {
+        into_bytes(data).slice(start, end).sha256()
+    }
+
+    fn to_b256(data: Vec<u8>) -> b256 {
+        into_bytes(data).to_b256()
+    }
+
+    #[storage(write)]
+    fn clear_stored() {
+        storage.bytes.clear();
+    }
+
+    #[storage(read)]
+    fn get_stored(index: u64) -> Option<u8> {
+        storage.bytes.get(index)
+    }
+
+    #[storage(read)]
+    fn len_stored() -> u64 {
+        storage.bytes.len()
+    }
+
+    #[storage(read)]
+    fn sha256_stored() -> b256 {
+        storage.bytes.sha256()
+    }
+
+    #[storage(read)]
+    fn slice_stored(start: u64, end: u64) -> b256 {
+        storage.bytes.slice(start, end).sha256()
+    }
+
+    #[storage(read, write)]
+    fn store(data: Vec<u8>) {
+        storage.bytes.append(into_bytes(data));
+    }
+}
+
+fn into_bytes(data: Vec<u8>) -> Bytes {
+    let mut bytes = Bytes::new();
+    let mut index = 0;
+    while index < data.len() {
+        bytes.push(data.get(index).unwrap());
+        index += 1;
+    }
+    bytes
+}
+
+fn into_vec(bytes: Bytes) -> Vec<u8> {
+    let mut data = Vec::new();
+    let mut index = 0;
+    while index < bytes.len() {
+        data.push(bytes.get(index).unwrap());
+        index += 1;
+    }
+    data
+}
@@ -0,0 +1,37 @@
+use crate::bytes::tests::utils::{
+    abi_calls::append,
+    test_helpers::{hash, setup},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn appends_two_buffers() {
+        let instance = setup().await;
+
+        let first = vec![1u8, 2, 3];
+        let second = vec![4u8, 5];
+
+        assert_eq!(
+            append(&instance, first, second).await,
+            hash(&[1u8, 2, 3, 4, 5])
+        );
+    }
+
+    #[tokio::test]
+    async fn appends_empty_buffers() {
+        let instance = setup().await;
+
+        assert_eq!(
+            append(&instance, vec![1u8, 2], vec![]).await,
+            hash(&[1u8, 2])
+        );
+        assert_eq!(
+            append(&instance, vec![], vec![1u8, 2]).await,
+            hash(&[1u8, 2])
+        );
+        assert_eq!(append(&instance, vec![], vec![]).await, hash(&[]));
+    }
+}
@@ -0,0 +1,54 @@
+use crate::bytes::tests::utils::{
+    abi_calls::{format_u64, round_trip},
+    test_helpers::setup,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn formats_zero() {
+        let instance = setup().await;
+
+        assert_eq!(format_u64(&instance, 0).await, b"0".to_vec());
+    }
+
+    #[tokio::test]
+    async fn formats_max() {
+        let instance = setup().await;
+
+        assert_eq!(
+            format_u64(&instance, u64::MAX).await,
+            b"18446744073709551615".to_vec()
+        );
+    }
+
+    #[tokio::test]
+    async fn formats_like_rust() {
+        let instance = setup().await;
+
+        for n in [
+            1u64,
+            9,
+            10,
+            42,
+            100,
+            999,
+            1_000_000,
+            u32::MAX as u64,
+            u64::MAX - 1,
+        ] {
+            assert_eq!(format_u64(&instance, n).await, n.to_string().into_bytes());
+        }
+    }
+
+    #[tokio::test]
+    async fn round_trips_through_parse() {
+        let instance = setup().await;
+
+        for n in [0u64, 1, 9, 10, 99, 100, 12345, 10u64.pow(19), u64::MAX] {
+            assert_eq!(round_trip(&instance, n).await, Some(n));
+        }
+    }
+}
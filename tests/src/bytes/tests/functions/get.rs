@@ -0,0 +1,25 @@
+use crate::bytes::tests::utils::{abi_calls::get, test_helpers::setup};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn gets_bytes() {
+        let instance = setup().await;
+
+        let data = vec![10u8, 20, 30];
+
+        assert_eq!(get(&instance, data.clone(), 0).await, Some(10));
+        assert_eq!(get(&instance, data.clone(), 1).await, Some(20));
+        assert_eq!(get(&instance, data, 2).await, Some(30));
+    }
+
+    #[tokio::test]
+    async fn returns_none_when_out_of_bounds() {
+        let instance = setup().await;
+
+        assert_eq!(get(&instance, vec![10u8, 20, 30], 3).await, None);
+        assert_eq!(get(&instance, vec![], 0).await, None);
+    }
+}
@@ -0,0 +1,15 @@
+use crate::bytes::tests::utils::{abi_calls::len, test_helpers::setup};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn counts_bytes() {
+        let instance = setup().await;
+
+        assert_eq!(len(&instance, vec![]).await, 0);
+        assert_eq!(len(&instance, vec![7u8]).await, 1);
+        assert_eq!(len(&instance, vec![7u8; 100]).await, 100);
+    }
+}
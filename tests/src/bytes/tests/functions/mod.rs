@@ -0,0 +1,9 @@
+mod append;
+mod format_u64;
+mod get;
+mod len;
+mod parse_u64;
+mod sha256;
+mod slice;
+mod storage;
+mod to_b256;
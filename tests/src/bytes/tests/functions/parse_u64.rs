@@ -0,0 +1,68 @@
+use crate::bytes::tests::utils::{abi_calls::parse_u64, test_helpers::setup};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn parses_zero() {
+        let instance = setup().await;
+
+        assert_eq!(parse_u64(&instance, b"0".to_vec()).await, Some(0));
+        assert_eq!(parse_u64(&instance, b"000".to_vec()).await, Some(0));
+    }
+
+    #[tokio::test]
+    async fn parses_digits() {
+        let instance = setup().await;
+
+        assert_eq!(parse_u64(&instance, b"42".to_vec()).await, Some(42));
+        assert_eq!(parse_u64(&instance, b"007".to_vec()).await, Some(7));
+        assert_eq!(
+            parse_u64(&instance, b"1234567890".to_vec()).await,
+            Some(1234567890)
+        );
+    }
+
+    #[tokio::test]
+    async fn parses_max() {
+        let instance = setup().await;
+
+        assert_eq!(
+            parse_u64(&instance, u64::MAX.to_string().into_bytes()).await,
+            Some(u64::MAX)
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_none_for_empty_input() {
+        let instance = setup().await;
+
+        assert_eq!(parse_u64(&instance, vec![]).await, None);
+    }
+
+    #[tokio::test]
+    async fn returns_none_for_non_digits() {
+        let instance = setup().await;
+
+        for input in [
+            "abc", "12a", "-1", "+1", " 1", "1 ", "1.5", "1_000", "/", ":",
+        ] {
+            assert_eq!(parse_u64(&instance, input.as_bytes().to_vec()).await, None);
+        }
+    }
+
+    #[tokio::test]
+    async fn returns_none_on_overflow() {
+        let instance = setup().await;
+
+        assert_eq!(
+            parse_u64(&instance, b"18446744073709551616".to_vec()).await,
+            None
+        );
+        assert_eq!(
+            parse_u64(&instance, b"100000000000000000000".to_vec()).await,
+            None
+        );
+    }
+}
@@ -0,0 +1,34 @@
+use crate::bytes::tests::utils::{
+    abi_calls::sha256,
+    test_helpers::{hash, setup},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn hashes_empty_buffer() {
+        let instance = setup().await;
+
+        assert_eq!(sha256(&instance, vec![]).await, hash(&[]));
+    }
+
+    #[tokio::test]
+    async fn hashes_bytes() {
+        let instance = setup().await;
+
+        let data = "Fuel".as_bytes().to_vec();
+
+        assert_eq!(sha256(&instance, data.clone()).await, hash(&data));
+    }
+
+    #[tokio::test]
+    async fn hashes_more_than_one_word() {
+        let instance = setup().await;
+
+        let data: Vec<u8> = (0..=255).collect();
+
+        assert_eq!(sha256(&instance, data.clone()).await, hash(&data));
+    }
+}
@@ -0,0 +1,63 @@
+use crate::bytes::tests::utils::{
+    abi_calls::slice,
+    test_helpers::{hash, setup},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn slices_middle() {
+        let instance = setup().await;
+
+        let data = vec![1u8, 2, 3, 4, 5];
+
+        assert_eq!(slice(&instance, data, 1, 4).await, hash(&[2u8, 3, 4]));
+    }
+
+    #[tokio::test]
+    async fn slices_at_boundaries() {
+        let instance = setup().await;
+
+        let data = vec![1u8, 2, 3, 4, 5];
+
+        assert_eq!(
+            slice(&instance, data.clone(), 0, 5).await,
+            hash(&[1u8, 2, 3, 4, 5])
+        );
+        assert_eq!(slice(&instance, data.clone(), 0, 1).await, hash(&[1u8]));
+        assert_eq!(slice(&instance, data.clone(), 4, 5).await, hash(&[5u8]));
+        assert_eq!(slice(&instance, data.clone(), 0, 0).await, hash(&[]));
+        assert_eq!(slice(&instance, data, 5, 5).await, hash(&[]));
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "IndexOutOfBounds")]
+    async fn when_end_out_of_bounds() {
+        let instance = setup().await;
+
+        slice(&instance, vec![1u8, 2, 3], 0, 4).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "IndexOutOfBounds")]
+    async fn when_start_after_end() {
+        let instance = setup().await;
+
+        slice(&instance, vec![1u8, 2, 3], 2, 1).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "IndexOutOfBounds")]
+    async fn when_empty() {
+        let instance = setup().await;
+
+        slice(&instance, vec![], 0, 1).await;
+    }
+}
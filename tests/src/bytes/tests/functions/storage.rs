@@ -0,0 +1,71 @@
+use crate::bytes::tests::utils::{
+    abi_calls::{clear_stored, get_stored, len_stored, sha256_stored, slice_stored, store},
+    test_helpers::{hash, setup},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn starts_empty() {
+        let instance = setup().await;
+
+        assert_eq!(len_stored(&instance).await, 0);
+        assert_eq!(get_stored(&instance, 0).await, None);
+        assert_eq!(sha256_stored(&instance).await, hash(&[]));
+    }
+
+    #[tokio::test]
+    async fn appends_across_calls() {
+        let instance = setup().await;
+
+        store(&instance, vec![1u8, 2, 3]).await;
+        store(&instance, vec![4u8, 5]).await;
+
+        assert_eq!(len_stored(&instance).await, 5);
+        assert_eq!(get_stored(&instance, 0).await, Some(1));
+        assert_eq!(get_stored(&instance, 4).await, Some(5));
+        assert_eq!(get_stored(&instance, 5).await, None);
+        assert_eq!(sha256_stored(&instance).await, hash(&[1u8, 2, 3, 4, 5]));
+    }
+
+    #[tokio::test]
+    async fn slices_stored_bytes() {
+        let instance = setup().await;
+
+        store(&instance, vec![1u8, 2, 3, 4, 5]).await;
+
+        assert_eq!(slice_stored(&instance, 1, 4).await, hash(&[2u8, 3, 4]));
+        assert_eq!(
+            slice_stored(&instance, 0, 5).await,
+            hash(&[1u8, 2, 3, 4, 5])
+        );
+        assert_eq!(slice_stored(&instance, 5, 5).await, hash(&[]));
+    }
+
+    #[tokio::test]
+    async fn clears_stored_bytes() {
+        let instance = setup().await;
+
+        store(&instance, vec![1u8, 2, 3]).await;
+        clear_stored(&instance).await;
+
+        assert_eq!(len_stored(&instance).await, 0);
+        assert_eq!(get_stored(&instance, 0).await, None);
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "IndexOutOfBounds")]
+    async fn when_slice_out_of_bounds() {
+        let instance = setup().await;
+
+        store(&instance, vec![1u8, 2, 3]).await;
+        slice_stored(&instance, 0, 4).await;
+    }
+}
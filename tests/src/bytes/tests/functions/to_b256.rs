@@ -0,0 +1,43 @@
+use crate::bytes::tests::utils::{abi_calls::to_b256, test_helpers::setup};
+use fuels::types::Bits256;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn pads_short_buffer() {
+        let instance = setup().await;
+
+        let mut expected = [0u8; 32];
+        expected[0] = 1;
+        expected[1] = 2;
+
+        assert_eq!(to_b256(&instance, vec![1u8, 2]).await, Bits256(expected));
+        assert_eq!(to_b256(&instance, vec![]).await, Bits256([0u8; 32]));
+    }
+
+    #[tokio::test]
+    async fn converts_exact_buffer() {
+        let instance = setup().await;
+
+        let data: Vec<u8> = (1..=32).collect();
+
+        assert_eq!(
+            to_b256(&instance, data.clone()).await,
+            Bits256(data.try_into().unwrap())
+        );
+    }
+
+    #[tokio::test]
+    async fn truncates_long_buffer() {
+        let instance = setup().await;
+
+        let data: Vec<u8> = (1..=40).collect();
+
+        assert_eq!(
+            to_b256(&instance, data.clone()).await,
+            Bits256(data[..32].try_into().unwrap())
+        );
+    }
+}
@@ -0,0 +1,158 @@
+use crate::common::{deploy_contract, get_wallet};
+use fuels::{
+    prelude::{abigen, LoadConfiguration, StorageConfiguration, WalletUnlocked},
+    programs::call_response::FuelCallResponse,
+    types::Bits256,
+};
+use sha2::{Digest, Sha256};
+
+abigen!(Contract(
+    name = "BytesLib",
+    abi = "src/bytes/out/debug/bytes_test-abi.json"
+));
+
+pub mod abi_calls {
+
+    use super::*;
+
+    pub async fn append(
+        contract: &BytesLib<WalletUnlocked>,
+        first: Vec<u8>,
+        second: Vec<u8>,
+    ) -> Bits256 {
+        contract
+            .methods()
+            .append(first, second)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn clear_stored(contract: &BytesLib<WalletUnlocked>) -> FuelCallResponse<()> {
+        contract.methods().clear_stored().call().await.unwrap()
+    }
+
+    pub async fn format_u64(contract: &BytesLib<WalletUnlocked>, n: u64) -> Vec<u8> {
+        contract.methods().format_u64(n).call().await.unwrap().value
+    }
+
+    pub async fn get(contract: &BytesLib<WalletUnlocked>, data: Vec<u8>, index: u64) -> Option<u8> {
+        contract
+            .methods()
+            .get(data, index)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn get_stored(contract: &BytesLib<WalletUnlocked>, index: u64) -> Option<u8> {
+        contract
+            .methods()
+            .get_stored(index)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn len(contract: &BytesLib<WalletUnlocked>, data: Vec<u8>) -> u64 {
+        contract.methods().len(data).call().await.unwrap().value
+    }
+
+    pub async fn len_stored(contract: &BytesLib<WalletUnlocked>) -> u64 {
+        contract.methods().len_stored().call().await.unwrap().value
+    }
+
+    pub async fn parse_u64(contract: &BytesLib<WalletUnlocked>, data: Vec<u8>) -> Option<u64> {
+        contract
+            .methods()
+            .parse_u64(data)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn round_trip(contract: &BytesLib<WalletUnlocked>, n: u64) -> Option<u64> {
+        contract.methods().round_trip(n).call().await.unwrap().value
+    }
+
+    pub async fn sha256(contract: &BytesLib<WalletUnlocked>, data: Vec<u8>) -> Bits256 {
+        contract.methods().sha256(data).call().await.unwrap().value
+    }
+
+    pub async fn sha256_stored(contract: &BytesLib<WalletUnlocked>) -> Bits256 {
+        contract
+            .methods()
+            .sha256_stored()
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn slice(
+        contract: &BytesLib<WalletUnlocked>,
+        data: Vec<u8>,
+        start: u64,
+        end: u64,
+    ) -> Bits256 {
+        contract
+            .methods()
+            .slice(data, start, end)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn slice_stored(
+        contract: &BytesLib<WalletUnlocked>,
+        start: u64,
+        end: u64,
+    ) -> Bits256 {
+        contract
+            .methods()
+            .slice_stored(start, end)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn store(contract: &BytesLib<WalletUnlocked>, data: Vec<u8>) -> FuelCallResponse<()> {
+        contract.methods().store(data).call().await.unwrap()
+    }
+
+    pub async fn to_b256(contract: &BytesLib<WalletUnlocked>, data: Vec<u8>) -> Bits256 {
+        contract.methods().to_b256(data).call().await.unwrap().value
+    }
+}
+
+pub mod test_helpers {
+
+    use super::*;
+
+    pub fn hash(data: &[u8]) -> Bits256 {
+        let mut hasher = Sha256::new();
+        hasher.update(data);
+        Bits256(hasher.finalize().into())
+    }
+
+    pub async fn setup() -> BytesLib<WalletUnlocked> {
+        let wallet = get_wallet().await;
+
+        let storage_configuration =
+            StorageConfiguration::load_from("src/bytes/out/debug/bytes_test-storage_slots.json");
+        let id = deploy_contract(
+            &wallet,
+            "src/bytes/out/debug/bytes_test.bin",
+            LoadConfiguration::default().set_storage_configuration(storage_configuration.unwrap()),
+        )
+        .await;
+
+        BytesLib::new(id, wallet)
+    }
+}
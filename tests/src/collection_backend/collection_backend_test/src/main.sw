@@ -0,0 +1,143 @@
+contract;
+
+use collection_backend::{Backend, MemBackend, StorageBackend};
+use heap::backend_heap::BackendHeap;
+use queue::backend_queue::BackendQueue;
+
+storage {
+    backend: StorageBackend<u64> = StorageBackend {},
+    heap: StorageBackend<(u64, b256)> = StorageBackend {},
+    queue_items: StorageBackend<u64> = StorageBackend {},
+    queue_head: StorageBackend<u64> = StorageBackend {},
+}
+
+abi CollectionBackendTest {
+    #[storage(read, write)]
+    fn backend_operations(in_storage: bool, values: Vec<u64>) -> Vec<u64>;
+    #[storage(read, write)]
+    fn heap_order(in_storage: bool, priorities: Vec<u64>) -> Vec<u64>;
+    #[storage(read, write)]
+    fn queue_order(in_storage: bool, items: Vec<u64>) -> Vec<u64>;
+}
+
+impl CollectionBackendTest for Contract {
+    #[storage(read, write)]
+    fn backend_operations(in_storage: bool, values: Vec<u64>) -> Vec<u64> {
+        if in_storage {
+            let mut backend = storage.backend;
+            run_backend(backend, values)
+        } else {
+            let mut backend = MemBackend::new();
+            run_backend(backend, values)
+        }
+    }
+
+    #[storage(read, write)]
+    fn heap_order(in_storage: bool, priorities: Vec<u64>) -> Vec<u64> {
+        if in_storage {
+            let mut heap = BackendHeap::new(storage.heap);
+            run_heap(heap, priorities)
+        } else {
+            let mut heap = BackendHeap::new(MemBackend::new());
+            run_heap(heap, priorities)
+        }
+    }
+
+    #[storage(read, write)]
+    fn queue_order(in_storage: bool, items: Vec<u64>) -> Vec<u64> {
+        if in_storage {
+            let mut queue = BackendQueue::new(storage.queue_items, storage.queue_head);
+            run_queue(queue, items)
+        } else {
+            let mut queue = BackendQueue::new(MemBackend::new(), MemBackend::new());
+            run_queue(queue, items)
+        }
+    }
+}
+
+/// Pushes `values`, doubles every element in place and then pops every element.
+#[storage(read, write)]
+fn run_backend<B>(ref mut backend: B, values: Vec<u64>) -> Vec<u64>
+where
+    B: Backend<u64>,
+{
+    let mut i = 0;
+    while i < values.len() {
+        backend.push(values.get(i).unwrap());
+        i += 1;
+    }
+    assert(backend.len() == values.len());
+
+    let mut i = 0;
+    while i < backend.len() {
+        let value = backend.get(i);
+        backend.set(i, value * 2);
+        i += 1;
+    }
+
+    let mut popped = Vec::new();
+    let mut next = backend.pop();
+    while next.is_some() {
+        popped.push(next.unwrap());
+        next = backend.pop();
+    }
+    assert(backend.len() == 0);
+    popped
+}
+
+/// Pushes every priority and then pops every item, returning the priorities in the order they were popped.
+#[storage(read, write)]
+fn run_heap<B>(ref mut heap: BackendHeap<B>, priorities: Vec<u64>) -> Vec<u64>
+where
+    B: Backend<(u64, b256)>,
+{
+    let mut i = 0;
+    while i < priorities.len() {
+        heap.push(priorities.get(i).unwrap(), b256::min());
+        i += 1;
+    }
+    assert(heap.len() == priorities.len());
+
+    let mut popped = Vec::new();
+    let mut next = heap.pop();
+    while next.is_some() {
+        popped.push(next.unwrap().0);
+        next = heap.pop();
+    }
+    assert(heap.peek().is_none());
+    popped
+}
+
+/// Enqueues `items`, dequeues up to two of them, enqueues `items` again and then dequeues every element.
+#[storage(read, write)]
+fn run_queue<B, H>(ref mut queue: BackendQueue<B, H, u64>, items: Vec<u64>) -> Vec<u64>
+where
+    B: Backend<u64>,
+    H: Backend<u64>,
+{
+    let mut i = 0;
+    while i < items.len() {
+        queue.enqueue(items.get(i).unwrap());
+        i += 1;
+    }
+
+    let mut dequeued = Vec::new();
+    while dequeued.len() < 2 && !queue.is_empty() {
+        dequeued.push(queue.dequeue().unwrap());
+    }
+
+    let mut i = 0;
+    while i < items.len() {
+        queue.enqueue(items.get(i).unwrap());
+        i += 1;
+    }
+    assert(queue.len() == 2 * items.len() - dequeued.len());
+
+    let mut next = queue.dequeue();
+    while next.is_some() {
+        dequeued.push(next.unwrap());
+        next = queue.dequeue();
+    }
+    assert(queue.is_empty());
+    dequeued
+}
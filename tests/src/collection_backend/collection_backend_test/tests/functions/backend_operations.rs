@@ -0,0 +1,29 @@
+use crate::collection_backend::collection_backend_test::tests::utils::{
+    abi_calls::backend_operations, test_helpers::setup,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_nothing_when_empty() {
+        let instance = setup().await;
+
+        assert_eq!(backend_operations(&instance, false, vec![]).await, vec![]);
+        assert_eq!(backend_operations(&instance, true, vec![]).await, vec![]);
+    }
+
+    #[tokio::test]
+    async fn sets_and_pops_in_both_backends() {
+        let instance = setup().await;
+        let values = vec![3, 1, 4, 1, 5, 9, 2, 6];
+        let expected: Vec<u64> = values.iter().rev().map(|value| value * 2).collect();
+
+        let in_memory = backend_operations(&instance, false, values.clone()).await;
+        let in_storage = backend_operations(&instance, true, values).await;
+
+        assert_eq!(in_memory, expected);
+        assert_eq!(in_storage, in_memory);
+    }
+}
@@ -0,0 +1,45 @@
+use crate::collection_backend::collection_backend_test::tests::utils::{
+    abi_calls::heap_order, test_helpers::setup,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_nothing_when_empty() {
+        let instance = setup().await;
+
+        assert_eq!(heap_order(&instance, false, vec![]).await, vec![]);
+        assert_eq!(heap_order(&instance, true, vec![]).await, vec![]);
+    }
+
+    #[tokio::test]
+    async fn pops_lowest_priority_first_in_both_backends() {
+        let instance = setup().await;
+        let priorities = vec![50, 10, 40, 10, 30, 0, 20, u64::MAX, 5];
+        let mut expected = priorities.clone();
+        expected.sort();
+
+        let in_memory = heap_order(&instance, false, priorities.clone()).await;
+        let in_storage = heap_order(&instance, true, priorities).await;
+
+        assert_eq!(in_memory, expected);
+        assert_eq!(in_storage, in_memory);
+    }
+
+    #[tokio::test]
+    async fn pops_sorted_input_in_both_backends() {
+        let instance = setup().await;
+        let ascending: Vec<u64> = (1..=10).collect();
+        let descending: Vec<u64> = (1..=10).rev().collect();
+
+        for priorities in [ascending.clone(), descending] {
+            assert_eq!(
+                heap_order(&instance, false, priorities.clone()).await,
+                ascending
+            );
+            assert_eq!(heap_order(&instance, true, priorities).await, ascending);
+        }
+    }
+}
@@ -0,0 +1,3 @@
+mod backend_operations;
+mod heap_order;
+mod queue_order;
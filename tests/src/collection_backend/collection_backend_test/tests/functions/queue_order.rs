@@ -0,0 +1,38 @@
+use crate::collection_backend::collection_backend_test::tests::utils::{
+    abi_calls::queue_order, test_helpers::setup,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_nothing_when_empty() {
+        let instance = setup().await;
+
+        assert_eq!(queue_order(&instance, false, vec![]).await, vec![]);
+        assert_eq!(queue_order(&instance, true, vec![]).await, vec![]);
+    }
+
+    #[tokio::test]
+    async fn dequeues_in_fifo_order_in_both_backends() {
+        let instance = setup().await;
+        let items = vec![7, 3, 9, 1, 5];
+        // The items are enqueued twice and every element is dequeued in the order it was enqueued
+        let expected: Vec<u64> = items.iter().chain(items.iter()).copied().collect();
+
+        let in_memory = queue_order(&instance, false, items.clone()).await;
+        let in_storage = queue_order(&instance, true, items).await;
+
+        assert_eq!(in_memory, expected);
+        assert_eq!(in_storage, in_memory);
+    }
+
+    #[tokio::test]
+    async fn dequeues_single_item_in_both_backends() {
+        let instance = setup().await;
+
+        assert_eq!(queue_order(&instance, false, vec![42]).await, vec![42, 42]);
+        assert_eq!(queue_order(&instance, true, vec![42]).await, vec![42, 42]);
+    }
+}
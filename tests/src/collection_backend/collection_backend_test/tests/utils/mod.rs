@@ -0,0 +1,76 @@
+use crate::common::{deploy_contract, get_wallet};
+use fuels::prelude::{abigen, LoadConfiguration, StorageConfiguration, WalletUnlocked};
+
+abigen!(Contract(
+    name = "CollectionBackendLib",
+    abi =
+        "src/collection_backend/collection_backend_test/out/debug/collection_backend_test-abi.json"
+));
+
+pub mod abi_calls {
+
+    use super::*;
+
+    pub async fn backend_operations(
+        contract: &CollectionBackendLib<WalletUnlocked>,
+        in_storage: bool,
+        values: Vec<u64>,
+    ) -> Vec<u64> {
+        contract
+            .methods()
+            .backend_operations(in_storage, values)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn heap_order(
+        contract: &CollectionBackendLib<WalletUnlocked>,
+        in_storage: bool,
+        priorities: Vec<u64>,
+    ) -> Vec<u64> {
+        contract
+            .methods()
+            .heap_order(in_storage, priorities)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn queue_order(
+        contract: &CollectionBackendLib<WalletUnlocked>,
+        in_storage: bool,
+        items: Vec<u64>,
+    ) -> Vec<u64> {
+        contract
+            .methods()
+            .queue_order(in_storage, items)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+}
+
+pub mod test_helpers {
+
+    use super::*;
+
+    pub async fn setup() -> CollectionBackendLib<WalletUnlocked> {
+        let wallet = get_wallet().await;
+
+        let storage_configuration = StorageConfiguration::load_from(
+            "src/collection_backend/collection_backend_test/out/debug/collection_backend_test-storage_slots.json",
+        );
+        let id = deploy_contract(
+            &wallet,
+            "src/collection_backend/collection_backend_test/out/debug/collection_backend_test.bin",
+            LoadConfiguration::default().set_storage_configuration(storage_configuration.unwrap()),
+        )
+        .await;
+
+        CollectionBackendLib::new(id, wallet)
+    }
+}
@@ -0,0 +1 @@
+mod collection_backend_test;
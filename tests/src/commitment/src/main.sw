@@ -0,0 +1,50 @@
+contract;
+
+use commitment::{commit, CommitStore, verify_commitment};
+
+storage {
+    commits: CommitStore = CommitStore {},
+}
+
+abi CommitmentTest {
+    fn commit(value: b256, nonce: b256) -> b256;
+    #[storage(read)]
+    fn commitment_of(committer: Identity) -> Option<b256>;
+    #[storage(read)]
+    fn has_revealed(committer: Identity) -> bool;
+    #[storage(read, write)]
+    fn reveal(value: b256, nonce: b256, cutoff: u64);
+    #[storage(read, write)]
+    fn submit(commitment: b256, cutoff: u64);
+    fn verify_commitment(commitment: b256, value: b256, nonce: b256) -> bool;
+}
+
+impl CommitmentTest for Contract {
+    fn commit(value: b256, nonce: b256) -> b256 {
+        commit(value, nonce)
+    }
+
+    #[storage(read)]
+    fn commitment_of(committer: Identity) -> Option<b256> {
+        storage.commits.commitment_of(committer)
+    }
+
+    #[storage(read)]
+    fn has_revealed(committer: Identity) -> bool {
+        storage.commits.has_revealed(committer)
+    }
+
+    #[storage(read, write)]
+    fn reveal(value: b256, nonce: b256, cutoff: u64) {
+        storage.commits.reveal(msg_sender().unwrap(), value, nonce, cutoff);
+    }
+
+    #[storage(read, write)]
+    fn submit(commitment: b256, cutoff: u64) {
+        storage.commits.submit(msg_sender().unwrap(), commitment, cutoff);
+    }
+
+    fn verify_commitment(commitment: b256, value: b256, nonce: b256) -> bool {
+        verify_commitment(commitment, value, nonce)
+    }
+}
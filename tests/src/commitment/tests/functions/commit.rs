@@ -0,0 +1,41 @@
+use crate::commitment::tests::utils::{
+    abi_calls::commit,
+    test_helpers::{commitment, setup},
+    NONCE, VALUE,
+};
+use fuels::types::Bits256;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn hashes_value_and_nonce() {
+        let (alice, _bob, _cutoff) = setup().await;
+
+        assert_eq!(
+            commit(&alice.contract, VALUE, NONCE).await,
+            commitment(VALUE, NONCE)
+        );
+    }
+
+    #[tokio::test]
+    async fn hides_value_with_nonce() {
+        let (alice, _bob, _cutoff) = setup().await;
+
+        assert_ne!(
+            commit(&alice.contract, VALUE, NONCE).await,
+            commit(&alice.contract, VALUE, Bits256([8u8; 32])).await
+        );
+    }
+
+    #[tokio::test]
+    async fn depends_on_order() {
+        let (alice, _bob, _cutoff) = setup().await;
+
+        assert_ne!(
+            commit(&alice.contract, VALUE, NONCE).await,
+            commit(&alice.contract, NONCE, VALUE).await
+        );
+    }
+}
@@ -0,0 +1,36 @@
+use crate::commitment::tests::utils::{
+    abi_calls::{commitment_of, submit},
+    test_helpers::{commitment, setup},
+    NONCE, VALUE,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_none_before_commit() {
+        let (alice, _bob, _cutoff) = setup().await;
+
+        assert_eq!(
+            commitment_of(&alice.contract, alice.identity.clone()).await,
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_commitment_of_committer() {
+        let (alice, bob, cutoff) = setup().await;
+
+        submit(&alice.contract, commitment(VALUE, NONCE), cutoff).await;
+
+        assert_eq!(
+            commitment_of(&alice.contract, alice.identity.clone()).await,
+            Some(commitment(VALUE, NONCE))
+        );
+        assert_eq!(
+            commitment_of(&alice.contract, bob.identity.clone()).await,
+            None
+        );
+    }
+}
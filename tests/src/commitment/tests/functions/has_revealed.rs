@@ -0,0 +1,33 @@
+use crate::commitment::tests::utils::{
+    abi_calls::{has_revealed, reveal, submit},
+    test_helpers::{commitment, produce_blocks, setup},
+    CUTOFF_DELAY, NONCE, VALUE,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_false_before_reveal() {
+        let (alice, _bob, cutoff) = setup().await;
+
+        assert!(!has_revealed(&alice.contract, alice.identity.clone()).await);
+
+        submit(&alice.contract, commitment(VALUE, NONCE), cutoff).await;
+
+        assert!(!has_revealed(&alice.contract, alice.identity.clone()).await);
+    }
+
+    #[tokio::test]
+    async fn returns_true_after_reveal() {
+        let (alice, bob, cutoff) = setup().await;
+
+        submit(&alice.contract, commitment(VALUE, NONCE), cutoff).await;
+        produce_blocks(&alice.wallet, CUTOFF_DELAY).await;
+        reveal(&alice.contract, VALUE, NONCE, cutoff).await;
+
+        assert!(has_revealed(&alice.contract, alice.identity.clone()).await);
+        assert!(!has_revealed(&alice.contract, bob.identity.clone()).await);
+    }
+}
@@ -0,0 +1,6 @@
+mod commit;
+mod commitment_of;
+mod has_revealed;
+mod reveal;
+mod submit;
+mod verify_commitment;
@@ -0,0 +1,129 @@
+use crate::commitment::tests::utils::{
+    abi_calls::{commitment_of, has_revealed, reveal, submit},
+    test_helpers::{commitment, produce_blocks, setup},
+    CUTOFF_DELAY, NONCE, VALUE,
+};
+use fuels::types::Bits256;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn reveals_after_cutoff() {
+        let (alice, _bob, cutoff) = setup().await;
+
+        submit(&alice.contract, commitment(VALUE, NONCE), cutoff).await;
+        produce_blocks(&alice.wallet, CUTOFF_DELAY).await;
+
+        reveal(&alice.contract, VALUE, NONCE, cutoff).await;
+
+        assert!(has_revealed(&alice.contract, alice.identity.clone()).await);
+        assert_eq!(
+            commitment_of(&alice.contract, alice.identity.clone()).await,
+            Some(commitment(VALUE, NONCE))
+        );
+    }
+
+    #[tokio::test]
+    async fn reveals_in_block_of_cutoff() {
+        let (alice, _bob, cutoff) = setup().await;
+
+        submit(&alice.contract, commitment(VALUE, NONCE), cutoff).await;
+        // The commitment is included in the block after the deployment and the reveal in the block of the cutoff
+        produce_blocks(&alice.wallet, CUTOFF_DELAY - 2).await;
+
+        reveal(&alice.contract, VALUE, NONCE, cutoff).await;
+
+        assert!(has_revealed(&alice.contract, alice.identity.clone()).await);
+    }
+
+    #[tokio::test]
+    async fn reveals_for_many_committers() {
+        let (alice, bob, cutoff) = setup().await;
+        let other_value = Bits256([2u8; 32]);
+
+        submit(&alice.contract, commitment(VALUE, NONCE), cutoff).await;
+        submit(&bob.contract, commitment(other_value, NONCE), cutoff).await;
+        produce_blocks(&alice.wallet, CUTOFF_DELAY).await;
+
+        reveal(&bob.contract, other_value, NONCE, cutoff).await;
+
+        assert!(!has_revealed(&alice.contract, alice.identity.clone()).await);
+        assert!(has_revealed(&alice.contract, bob.identity.clone()).await);
+
+        reveal(&alice.contract, VALUE, NONCE, cutoff).await;
+
+        assert!(has_revealed(&alice.contract, alice.identity.clone()).await);
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "RevealPeriodNotStarted")]
+    async fn when_cutoff_is_not_reached() {
+        let (alice, _bob, cutoff) = setup().await;
+
+        submit(&alice.contract, commitment(VALUE, NONCE), cutoff).await;
+
+        reveal(&alice.contract, VALUE, NONCE, cutoff).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotCommitted")]
+    async fn when_not_committed() {
+        let (alice, _bob, cutoff) = setup().await;
+
+        produce_blocks(&alice.wallet, CUTOFF_DELAY).await;
+
+        reveal(&alice.contract, VALUE, NONCE, cutoff).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotCommitted")]
+    async fn when_only_another_committer_committed() {
+        let (alice, bob, cutoff) = setup().await;
+
+        submit(&alice.contract, commitment(VALUE, NONCE), cutoff).await;
+        produce_blocks(&alice.wallet, CUTOFF_DELAY).await;
+
+        reveal(&bob.contract, VALUE, NONCE, cutoff).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidReveal")]
+    async fn when_value_is_wrong() {
+        let (alice, _bob, cutoff) = setup().await;
+
+        submit(&alice.contract, commitment(VALUE, NONCE), cutoff).await;
+        produce_blocks(&alice.wallet, CUTOFF_DELAY).await;
+
+        reveal(&alice.contract, Bits256([2u8; 32]), NONCE, cutoff).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidReveal")]
+    async fn when_nonce_is_wrong() {
+        let (alice, _bob, cutoff) = setup().await;
+
+        submit(&alice.contract, commitment(VALUE, NONCE), cutoff).await;
+        produce_blocks(&alice.wallet, CUTOFF_DELAY).await;
+
+        reveal(&alice.contract, VALUE, Bits256([8u8; 32]), cutoff).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "AlreadyRevealed")]
+    async fn when_already_revealed() {
+        let (alice, _bob, cutoff) = setup().await;
+
+        submit(&alice.contract, commitment(VALUE, NONCE), cutoff).await;
+        produce_blocks(&alice.wallet, CUTOFF_DELAY).await;
+        reveal(&alice.contract, VALUE, NONCE, cutoff).await;
+
+        reveal(&alice.contract, VALUE, NONCE, cutoff).await;
+    }
+}
@@ -0,0 +1,85 @@
+use crate::commitment::tests::utils::{
+    abi_calls::{commitment_of, submit},
+    test_helpers::{commitment, current_height, produce_blocks, setup},
+    CUTOFF_DELAY, NONCE, VALUE,
+};
+use fuels::types::Bits256;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn submits_commitment() {
+        let (alice, _bob, cutoff) = setup().await;
+
+        submit(&alice.contract, commitment(VALUE, NONCE), cutoff).await;
+
+        assert_eq!(
+            commitment_of(&alice.contract, alice.identity.clone()).await,
+            Some(commitment(VALUE, NONCE))
+        );
+    }
+
+    #[tokio::test]
+    async fn submits_commitments_of_many_committers() {
+        let (alice, bob, cutoff) = setup().await;
+        let other_value = Bits256([2u8; 32]);
+
+        submit(&alice.contract, commitment(VALUE, NONCE), cutoff).await;
+        submit(&bob.contract, commitment(other_value, NONCE), cutoff).await;
+
+        assert_eq!(
+            commitment_of(&alice.contract, alice.identity.clone()).await,
+            Some(commitment(VALUE, NONCE))
+        );
+        assert_eq!(
+            commitment_of(&alice.contract, bob.identity.clone()).await,
+            Some(commitment(other_value, NONCE))
+        );
+    }
+
+    #[tokio::test]
+    async fn submits_in_block_before_cutoff() {
+        let (alice, _bob, cutoff) = setup().await;
+
+        // The commitment is included in the block after the produced blocks
+        produce_blocks(&alice.wallet, CUTOFF_DELAY - 2).await;
+        assert_eq!(current_height(&alice.wallet).await + 1, cutoff - 1);
+
+        submit(&alice.contract, commitment(VALUE, NONCE), cutoff).await;
+
+        assert!(commitment_of(&alice.contract, alice.identity.clone())
+            .await
+            .is_some());
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "AlreadyCommitted")]
+    async fn when_already_committed() {
+        let (alice, _bob, cutoff) = setup().await;
+
+        submit(&alice.contract, commitment(VALUE, NONCE), cutoff).await;
+        submit(
+            &alice.contract,
+            commitment(Bits256([2u8; 32]), NONCE),
+            cutoff,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "CommitPeriodOver")]
+    async fn when_cutoff_is_reached() {
+        let (alice, _bob, cutoff) = setup().await;
+
+        produce_blocks(&alice.wallet, CUTOFF_DELAY - 1).await;
+
+        submit(&alice.contract, commitment(VALUE, NONCE), cutoff).await;
+    }
+}
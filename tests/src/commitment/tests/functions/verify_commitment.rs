@@ -0,0 +1,48 @@
+use crate::commitment::tests::utils::{
+    abi_calls::verify_commitment,
+    test_helpers::{commitment, setup},
+    NONCE, VALUE,
+};
+use fuels::types::Bits256;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn verifies_value_and_nonce() {
+        let (alice, _bob, _cutoff) = setup().await;
+
+        assert!(verify_commitment(&alice.contract, commitment(VALUE, NONCE), VALUE, NONCE).await);
+    }
+
+    #[tokio::test]
+    async fn fails_for_wrong_value() {
+        let (alice, _bob, _cutoff) = setup().await;
+
+        assert!(
+            !verify_commitment(
+                &alice.contract,
+                commitment(VALUE, NONCE),
+                Bits256([2u8; 32]),
+                NONCE
+            )
+            .await
+        );
+    }
+
+    #[tokio::test]
+    async fn fails_for_wrong_nonce() {
+        let (alice, _bob, _cutoff) = setup().await;
+
+        assert!(
+            !verify_commitment(
+                &alice.contract,
+                commitment(VALUE, NONCE),
+                VALUE,
+                Bits256([8u8; 32])
+            )
+            .await
+        );
+    }
+}
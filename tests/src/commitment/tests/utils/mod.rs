@@ -0,0 +1,174 @@
+use crate::common::{deploy_contract, get_isolated_wallets};
+use fuels::{
+    prelude::{abigen, LoadConfiguration, StorageConfiguration, WalletUnlocked},
+    types::{Bits256, Identity},
+};
+use sha2::{Digest, Sha256};
+
+abigen!(Contract(
+    name = "CommitmentLib",
+    abi = "src/commitment/out/debug/commitment_test-abi.json"
+));
+
+pub const NONCE: Bits256 = Bits256([7u8; 32]);
+pub const VALUE: Bits256 = Bits256([1u8; 32]);
+/// The number of blocks after the deployment at which commitments are revealed.
+pub const CUTOFF_DELAY: u64 = 10;
+
+pub struct Metadata {
+    pub contract: CommitmentLib<WalletUnlocked>,
+    pub identity: Identity,
+    pub wallet: WalletUnlocked,
+}
+
+pub mod abi_calls {
+
+    use super::*;
+
+    pub async fn commit(
+        contract: &CommitmentLib<WalletUnlocked>,
+        value: Bits256,
+        nonce: Bits256,
+    ) -> Bits256 {
+        contract
+            .methods()
+            .commit(value, nonce)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn commitment_of(
+        contract: &CommitmentLib<WalletUnlocked>,
+        committer: Identity,
+    ) -> Option<Bits256> {
+        contract
+            .methods()
+            .commitment_of(committer)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn has_revealed(
+        contract: &CommitmentLib<WalletUnlocked>,
+        committer: Identity,
+    ) -> bool {
+        contract
+            .methods()
+            .has_revealed(committer)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn reveal(
+        contract: &CommitmentLib<WalletUnlocked>,
+        value: Bits256,
+        nonce: Bits256,
+        cutoff: u64,
+    ) {
+        contract
+            .methods()
+            .reveal(value, nonce, cutoff)
+            .call()
+            .await
+            .unwrap();
+    }
+
+    pub async fn submit(
+        contract: &CommitmentLib<WalletUnlocked>,
+        commitment: Bits256,
+        cutoff: u64,
+    ) {
+        contract
+            .methods()
+            .submit(commitment, cutoff)
+            .call()
+            .await
+            .unwrap();
+    }
+
+    pub async fn verify_commitment(
+        contract: &CommitmentLib<WalletUnlocked>,
+        commitment: Bits256,
+        value: Bits256,
+        nonce: Bits256,
+    ) -> bool {
+        contract
+            .methods()
+            .verify_commitment(commitment, value, nonce)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+}
+
+pub mod test_helpers {
+
+    use super::*;
+
+    /// Computes the commitment off-chain as the SHA-256 hash of the value followed by the nonce.
+    pub fn commitment(value: Bits256, nonce: Bits256) -> Bits256 {
+        let mut hasher = Sha256::new();
+        hasher.update(value.0);
+        hasher.update(nonce.0);
+        Bits256(hasher.finalize().into())
+    }
+
+    pub async fn current_height(wallet: &WalletUnlocked) -> u64 {
+        wallet
+            .provider()
+            .unwrap()
+            .latest_block_height()
+            .await
+            .unwrap() as u64
+    }
+
+    pub async fn produce_blocks(wallet: &WalletUnlocked, blocks: u64) {
+        wallet
+            .provider()
+            .unwrap()
+            .produce_blocks(blocks, None)
+            .await
+            .unwrap();
+    }
+
+    /// Deploys the contract and returns two committers with the cutoff block height of the commitments.
+    pub async fn setup() -> (Metadata, Metadata, u64) {
+        let mut wallets = get_isolated_wallets(2).await;
+
+        let wallet1 = wallets.pop().unwrap();
+        let wallet2 = wallets.pop().unwrap();
+
+        let storage_configuration = StorageConfiguration::load_from(
+            "src/commitment/out/debug/commitment_test-storage_slots.json",
+        );
+        let id = deploy_contract(
+            &wallet1,
+            "src/commitment/out/debug/commitment_test.bin",
+            LoadConfiguration::default().set_storage_configuration(storage_configuration.unwrap()),
+        )
+        .await;
+
+        let alice = Metadata {
+            contract: CommitmentLib::new(id.clone(), wallet1.clone()),
+            identity: Identity::Address(wallet1.address().into()),
+            wallet: wallet1.clone(),
+        };
+
+        let bob = Metadata {
+            contract: CommitmentLib::new(id, wallet2.clone()),
+            identity: Identity::Address(wallet2.address().into()),
+            wallet: wallet2,
+        };
+
+        let cutoff = current_height(&wallet1).await + CUTOFF_DELAY;
+
+        (alice, bob, cutoff)
+    }
+}
@@ -0,0 +1,249 @@
+use fuels::prelude::{
+    launch_custom_provider_and_get_wallets, setup_single_asset_coins, setup_test_provider,
+    Bech32ContractId, Contract, LoadConfiguration, Provider, Salt, TxParameters, WalletUnlocked,
+    WalletsConfig, BASE_ASSET_ID,
+};
+use std::{
+    net::SocketAddr,
+    sync::atomic::{AtomicU64, AtomicUsize, Ordering},
+    thread,
+};
+use tokio::sync::{oneshot, OnceCell};
+
+const COINS_PER_WALLET: u64 = 1;
+const COIN_AMOUNT: u64 = 1000000;
+
+/// The number of funded wallets on the shared node. Each wallet is handed out once so that tests running in
+/// parallel never spend the same coins.
+const SHARED_WALLETS: usize = 4096;
+
+/// A node which is launched once and used by every test which does not need a chain of its own.
+struct SharedNode {
+    address: SocketAddr,
+    wallets: Vec<WalletUnlocked>,
+}
+
+static SHARED_NODE: OnceCell<SharedNode> = OnceCell::const_new();
+static NEXT_WALLET: AtomicUsize = AtomicUsize::new(0);
+static NEXT_SALT: AtomicU64 = AtomicU64::new(0);
+
+/// Returns the shared node, launching it on first use.
+///
+/// Every `#[tokio::test]` has its own runtime and the tasks of the node would stop with the runtime of the test
+/// which launched it, so the node runs on a runtime of its own which lives as long as the test binary.
+async fn shared_node() -> &'static SharedNode {
+    SHARED_NODE
+        .get_or_init(|| async {
+            let (sender, receiver) = oneshot::channel();
+
+            thread::spawn(move || {
+                let runtime = tokio::runtime::Builder::new_multi_thread()
+                    .enable_all()
+                    .build()
+                    .unwrap();
+
+                runtime.block_on(async move {
+                    let wallets: Vec<_> = (0..SHARED_WALLETS)
+                        .map(|_| WalletUnlocked::new_random(None))
+                        .collect();
+                    let coins = wallets
+                        .iter()
+                        .flat_map(|wallet| {
+                            setup_single_asset_coins(
+                                wallet.address(),
+                                BASE_ASSET_ID,
+                                COINS_PER_WALLET,
+                                COIN_AMOUNT,
+                            )
+                        })
+                        .collect();
+
+                    let (_provider, address) = setup_test_provider(coins, vec![], None, None).await;
+                    sender.send(SharedNode { address, wallets }).ok().unwrap();
+
+                    std::future::pending::<()>().await;
+                });
+            });
+
+            receiver.await.unwrap()
+        })
+        .await
+}
+
+/// Returns a funded wallet on the node shared by the tests.
+pub(crate) async fn get_wallet() -> WalletUnlocked {
+    get_wallets(1).await.pop().unwrap()
+}
+
+/// Returns `num_wallets` funded wallets on the node shared by the tests, which no other test uses.
+pub(crate) async fn get_wallets(num_wallets: u64) -> Vec<WalletUnlocked> {
+    let node = shared_node().await;
+
+    let first = NEXT_WALLET.fetch_add(num_wallets as usize, Ordering::Relaxed);
+    let last = first + num_wallets as usize;
+    assert!(
+        last <= SHARED_WALLETS,
+        "the shared node has run out of funded wallets"
+    );
+
+    // A client is tied to the runtime it is created on, so each test connects with its own
+    let provider = Provider::connect(node.address.to_string()).await.unwrap();
+
+    node.wallets[first..last]
+        .iter()
+        .cloned()
+        .map(|mut wallet| {
+            wallet.set_provider(provider.clone());
+            wallet
+        })
+        .collect()
+}
+
+/// Launches a node of its own and returns `num_wallets` funded wallets connected to it.
+///
+/// For tests which produce blocks or compare block heights, as the blocks of other tests would interfere on the
+/// shared node.
+pub(crate) async fn get_isolated_wallets(num_wallets: u64) -> Vec<WalletUnlocked> {
+    launch_custom_provider_and_get_wallets(
+        WalletsConfig::new(Some(num_wallets), Some(COINS_PER_WALLET), Some(COIN_AMOUNT)),
+        None,
+        None,
+    )
+    .await
+}
+
+/// Deploys the contract at `path_to_bin` and returns its id.
+///
+/// Every deployment is given a new salt, so tests which deploy the same contract to the shared node each get an
+/// instance of their own.
+pub(crate) async fn deploy_contract(
+    wallet: &WalletUnlocked,
+    path_to_bin: &str,
+    configuration: LoadConfiguration,
+) -> Bech32ContractId {
+    let mut salt = [0u8; 32];
+    salt[..8].copy_from_slice(&NEXT_SALT.fetch_add(1, Ordering::Relaxed).to_be_bytes());
+
+    Contract::load_from(path_to_bin, configuration.set_salt(Salt::new(salt)))
+        .unwrap()
+        .deploy(wallet, TxParameters::default())
+        .await
+        .unwrap()
+}
+
+/// Creates an instance of a script generated by `abigen!` which is called with a funded wallet.
+///
+/// ```ignore
+/// let instance = script_instance!(TestI8, "src/signed_integers/signed_i8/out/debug/i8_test.bin");
+/// ```
+macro_rules! script_instance {
+    ($script:ident, $path_to_bin:expr) => {
+        $script::new($crate::common::get_wallet().await, $path_to_bin)
+    };
+}
+
+/// Runs the `main` function of a script generated by `abigen!` with a funded wallet and returns its value.
+///
+/// ```ignore
+/// let result = run_script!(TestI8, "src/signed_integers/signed_i8/out/debug/i8_test.bin");
+/// ```
+macro_rules! run_script {
+    ($script:ident, $path_to_bin:expr $(, $arg:expr)* $(,)?) => {
+        $crate::common::script_instance!($script, $path_to_bin)
+            .main($($arg),*)
+            .call()
+            .await
+            .unwrap()
+            .value
+    };
+}
+
+/// Generates property tests which compare a signed integer script generated by `abigen!` with Rust's
+/// checked arithmetic on `$primitive`. `setup()`, the script's `Operation` enum and `$encode`, which converts
+/// a `$primitive` into the script's signed type, must be in scope.
+///
+/// ```ignore
+/// signed_operations_tests!(i8, i8);
+/// ```
+macro_rules! signed_operations_tests {
+    ($primitive:ident, $encode:ident) => {
+        // Every case runs the script, so the number of cases is kept low
+        const CASES: u32 = 64;
+
+        /// Generates arbitrary values, favouring the bounds and zero where the encodings are most likely to disagree.
+        fn values() -> impl ::proptest::strategy::Strategy<Value = $primitive> {
+            use ::proptest::prelude::*;
+
+            prop_oneof![
+                1 => Just($primitive::MIN),
+                1 => Just($primitive::MIN + 1),
+                1 => Just(-1),
+                1 => Just(0),
+                1 => Just(1),
+                1 => Just($primitive::MAX),
+                6 => any::<$primitive>(),
+            ]
+        }
+
+        /// Runs `operation` for generated inputs and compares the result with `expected`, which returns `None`
+        /// for inputs on which the script must revert. Failing inputs are shrunk before they are reported.
+        fn assert_matches_rust(
+            operation: Operation,
+            expected: fn($primitive, $primitive) -> Option<$primitive>,
+        ) {
+            use ::proptest::prelude::*;
+
+            let runtime = ::tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .unwrap();
+            let instance = runtime.block_on(setup());
+
+            proptest!(ProptestConfig::with_cases(CASES), |(lhs in values(), rhs in values())| {
+                let result = runtime.block_on(instance.main(operation.clone(), $encode(lhs), $encode(rhs)).call());
+
+                match expected(lhs, rhs) {
+                    Some(value) => {
+                        prop_assert!(result.is_ok(), "reverted for {} and {}", lhs, rhs);
+                        prop_assert_eq!(result.unwrap().value, $encode(value));
+                    }
+                    None => prop_assert!(result.is_err(), "did not revert for {} and {}", lhs, rhs),
+                }
+            });
+        }
+
+        mod success {
+
+            use super::*;
+
+            #[test]
+            fn adds_like_rust() {
+                assert_matches_rust(Operation::Add, $primitive::checked_add);
+            }
+
+            #[test]
+            fn subtracts_like_rust() {
+                assert_matches_rust(Operation::Subtract, $primitive::checked_sub);
+            }
+
+            #[test]
+            fn multiplies_like_rust() {
+                assert_matches_rust(Operation::Multiply, $primitive::checked_mul);
+            }
+
+            #[test]
+            fn negates_like_rust() {
+                assert_matches_rust(Operation::Negate, |value, _| value.checked_neg());
+            }
+
+            #[test]
+            fn takes_absolute_value_like_rust() {
+                assert_matches_rust(Operation::Abs, |value, _| value.checked_abs());
+            }
+        }
+    };
+}
+
+pub(crate) use run_script;
+pub(crate) use script_instance;
+pub(crate) use signed_operations_tests;
@@ -0,0 +1,110 @@
+contract;
+
+use contract_base::ContractBase;
+
+storage {
+    base: ContractBase = ContractBase::new(),
+}
+
+abi ContractBaseTest {
+    #[storage(read, write)]
+    fn accept_ownership();
+    #[storage(read)]
+    fn is_paused() -> bool;
+    #[storage(read)]
+    fn only_owner();
+    #[storage(read)]
+    fn only_owner_and_not_paused();
+    #[storage(read)]
+    fn only_owner_and_paused();
+    #[storage(read)]
+    fn only_owner_or_paused();
+    #[storage(read)]
+    fn owner() -> Option<Identity>;
+    #[storage(read, write)]
+    fn pause();
+    #[storage(read)]
+    fn pending_owner() -> Option<Identity>;
+    #[storage(read)]
+    fn require_not_paused();
+    #[storage(read)]
+    fn require_paused();
+    #[storage(read, write)]
+    fn set_ownership(new_owner: Identity);
+    #[storage(read, write)]
+    fn start_ownership_transfer(new_owner: Identity);
+    #[storage(read, write)]
+    fn unpause();
+}
+
+impl ContractBaseTest for Contract {
+    #[storage(read, write)]
+    fn accept_ownership() {
+        storage.base.accept_ownership();
+    }
+
+    #[storage(read)]
+    fn is_paused() -> bool {
+        storage.base.is_paused()
+    }
+
+    #[storage(read)]
+    fn only_owner() {
+        storage.base.only_owner();
+    }
+
+    #[storage(read)]
+    fn only_owner_and_not_paused() {
+        storage.base.only_owner_and_not_paused();
+    }
+
+    #[storage(read)]
+    fn only_owner_and_paused() {
+        storage.base.only_owner_and_paused();
+    }
+
+    #[storage(read)]
+    fn only_owner_or_paused() {
+        storage.base.only_owner_or_paused();
+    }
+
+    #[storage(read)]
+    fn owner() -> Option<Identity> {
+        storage.base.owner()
+    }
+
+    #[storage(read, write)]
+    fn pause() {
+        storage.base.pause();
+    }
+
+    #[storage(read)]
+    fn pending_owner() -> Option<Identity> {
+        storage.base.pending_owner()
+    }
+
+    #[storage(read)]
+    fn require_not_paused() {
+        storage.base.require_not_paused();
+    }
+
+    #[storage(read)]
+    fn require_paused() {
+        storage.base.require_paused();
+    }
+
+    #[storage(read, write)]
+    fn set_ownership(new_owner: Identity) {
+        storage.base.set_ownership(new_owner);
+    }
+
+    #[storage(read, write)]
+    fn start_ownership_transfer(new_owner: Identity) {
+        storage.base.start_ownership_transfer(new_owner);
+    }
+
+    #[storage(read, write)]
+    fn unpause() {
+        storage.base.unpause();
+    }
+}
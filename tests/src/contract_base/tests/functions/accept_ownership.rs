@@ -0,0 +1,55 @@
+use crate::contract_base::tests::utils::{
+    abi_calls::{accept_ownership, only_owner, owner, pending_owner, start_ownership_transfer},
+    test_helpers::setup,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn pending_owner_becomes_owner() {
+        let (admin, user) = setup().await;
+
+        start_ownership_transfer(&admin.contract, user.identity.clone()).await;
+        accept_ownership(&user.contract).await;
+
+        assert_eq!(owner(&admin.contract).await, Some(user.identity.clone()));
+        assert_eq!(pending_owner(&admin.contract).await, None);
+        only_owner(&user.contract).await;
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotPendingOwner")]
+    async fn when_no_transfer_is_pending() {
+        let (_owner, user) = setup().await;
+
+        accept_ownership(&user.contract).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotPendingOwner")]
+    async fn when_not_pending_owner() {
+        let (admin, user) = setup().await;
+
+        start_ownership_transfer(&admin.contract, user.identity.clone()).await;
+
+        accept_ownership(&admin.contract).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn previous_owner_loses_access() {
+        let (admin, user) = setup().await;
+
+        start_ownership_transfer(&admin.contract, user.identity.clone()).await;
+        accept_ownership(&user.contract).await;
+
+        only_owner(&admin.contract).await;
+    }
+}
@@ -0,0 +1,11 @@
+mod accept_ownership;
+mod only_owner;
+mod only_owner_and_not_paused;
+mod only_owner_and_paused;
+mod only_owner_or_paused;
+mod pause;
+mod require_not_paused;
+mod require_paused;
+mod set_ownership;
+mod start_ownership_transfer;
+mod unpause;
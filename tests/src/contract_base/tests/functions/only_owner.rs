@@ -0,0 +1,32 @@
+use crate::contract_base::tests::utils::{
+    abi_calls::{only_owner, pause},
+    test_helpers::setup,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn owner_can_call() {
+        let (admin, _user) = setup().await;
+
+        only_owner(&admin.contract).await;
+
+        pause(&admin.contract).await;
+        only_owner(&admin.contract).await;
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_not_owner() {
+        let (_owner, user) = setup().await;
+
+        only_owner(&user.contract).await;
+    }
+}
@@ -0,0 +1,49 @@
+use crate::contract_base::tests::utils::{
+    abi_calls::{only_owner_and_not_paused, pause, unpause},
+    test_helpers::setup,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn owner_can_call_when_not_paused() {
+        let (admin, _user) = setup().await;
+
+        only_owner_and_not_paused(&admin.contract).await;
+    }
+
+    #[tokio::test]
+    async fn owner_can_call_after_unpause() {
+        let (admin, _user) = setup().await;
+
+        pause(&admin.contract).await;
+        unpause(&admin.contract).await;
+
+        only_owner_and_not_paused(&admin.contract).await;
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_not_owner() {
+        let (_owner, user) = setup().await;
+
+        only_owner_and_not_paused(&user.contract).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Paused")]
+    async fn when_paused() {
+        let (admin, _user) = setup().await;
+
+        pause(&admin.contract).await;
+
+        only_owner_and_not_paused(&admin.contract).await;
+    }
+}
@@ -0,0 +1,41 @@
+use crate::contract_base::tests::utils::{
+    abi_calls::{only_owner_and_paused, pause},
+    test_helpers::setup,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn owner_can_call_when_paused() {
+        let (admin, _user) = setup().await;
+
+        pause(&admin.contract).await;
+
+        only_owner_and_paused(&admin.contract).await;
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_not_owner() {
+        let (admin, user) = setup().await;
+
+        pause(&admin.contract).await;
+
+        only_owner_and_paused(&user.contract).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotPaused")]
+    async fn when_not_paused() {
+        let (admin, _user) = setup().await;
+
+        only_owner_and_paused(&admin.contract).await;
+    }
+}
@@ -0,0 +1,32 @@
+use crate::contract_base::tests::utils::{
+    abi_calls::{pause, require_paused},
+    test_helpers::setup,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn anyone_can_call_when_paused() {
+        let (admin, user) = setup().await;
+
+        pause(&admin.contract).await;
+
+        require_paused(&admin.contract).await;
+        require_paused(&user.contract).await;
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotPaused")]
+    async fn when_not_paused() {
+        let (_owner, user) = setup().await;
+
+        require_paused(&user.contract).await;
+    }
+}
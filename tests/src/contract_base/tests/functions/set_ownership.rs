@@ -0,0 +1,29 @@
+use crate::contract_base::tests::utils::{
+    abi_calls::{owner, set_ownership},
+    test_helpers::setup,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn sets_the_initial_owner() {
+        let (admin, _user) = setup().await;
+
+        assert_eq!(owner(&admin.contract).await, Some(admin.identity));
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "CannotReinitialized")]
+    async fn when_already_set() {
+        let (_owner, user) = setup().await;
+
+        set_ownership(&user.contract, user.identity.clone()).await;
+    }
+}
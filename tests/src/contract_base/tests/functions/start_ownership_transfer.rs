@@ -0,0 +1,37 @@
+use crate::contract_base::tests::utils::{
+    abi_calls::{owner, pending_owner, start_ownership_transfer},
+    test_helpers::setup,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn sets_the_pending_owner() {
+        let (admin, user) = setup().await;
+
+        assert_eq!(pending_owner(&admin.contract).await, None);
+
+        start_ownership_transfer(&admin.contract, user.identity.clone()).await;
+
+        assert_eq!(
+            pending_owner(&admin.contract).await,
+            Some(user.identity.clone())
+        );
+        assert_eq!(owner(&admin.contract).await, Some(admin.identity));
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_not_owner() {
+        let (_owner, user) = setup().await;
+
+        start_ownership_transfer(&user.contract, user.identity.clone()).await;
+    }
+}
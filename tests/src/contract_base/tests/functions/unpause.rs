@@ -0,0 +1,42 @@
+use crate::contract_base::tests::utils::{
+    abi_calls::{is_paused, pause, unpause},
+    test_helpers::setup,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn owner_can_unpause() {
+        let (admin, _user) = setup().await;
+
+        pause(&admin.contract).await;
+        unpause(&admin.contract).await;
+
+        assert!(!is_paused(&admin.contract).await);
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_not_owner() {
+        let (admin, user) = setup().await;
+
+        pause(&admin.contract).await;
+
+        unpause(&user.contract).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotPaused")]
+    async fn when_not_paused() {
+        let (admin, _user) = setup().await;
+
+        unpause(&admin.contract).await;
+    }
+}
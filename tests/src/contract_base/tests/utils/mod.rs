@@ -0,0 +1,171 @@
+use crate::common::{deploy_contract, get_wallets};
+use fuels::{
+    prelude::{abigen, LoadConfiguration, StorageConfiguration, WalletUnlocked},
+    programs::call_response::FuelCallResponse,
+    types::Identity,
+};
+
+abigen!(Contract(
+    name = "ContractBaseLib",
+    abi = "src/contract_base/out/debug/contract_base_test-abi.json"
+));
+
+pub struct Metadata {
+    pub contract: ContractBaseLib<WalletUnlocked>,
+    pub identity: Identity,
+    pub wallet: WalletUnlocked,
+}
+
+pub mod abi_calls {
+
+    use super::*;
+
+    pub async fn accept_ownership(
+        contract: &ContractBaseLib<WalletUnlocked>,
+    ) -> FuelCallResponse<()> {
+        contract.methods().accept_ownership().call().await.unwrap()
+    }
+
+    pub async fn is_paused(contract: &ContractBaseLib<WalletUnlocked>) -> bool {
+        contract.methods().is_paused().call().await.unwrap().value
+    }
+
+    pub async fn only_owner(contract: &ContractBaseLib<WalletUnlocked>) -> FuelCallResponse<()> {
+        contract.methods().only_owner().call().await.unwrap()
+    }
+
+    pub async fn only_owner_and_not_paused(
+        contract: &ContractBaseLib<WalletUnlocked>,
+    ) -> FuelCallResponse<()> {
+        contract
+            .methods()
+            .only_owner_and_not_paused()
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn only_owner_and_paused(
+        contract: &ContractBaseLib<WalletUnlocked>,
+    ) -> FuelCallResponse<()> {
+        contract
+            .methods()
+            .only_owner_and_paused()
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn only_owner_or_paused(
+        contract: &ContractBaseLib<WalletUnlocked>,
+    ) -> FuelCallResponse<()> {
+        contract
+            .methods()
+            .only_owner_or_paused()
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn owner(contract: &ContractBaseLib<WalletUnlocked>) -> Option<Identity> {
+        contract.methods().owner().call().await.unwrap().value
+    }
+
+    pub async fn pause(contract: &ContractBaseLib<WalletUnlocked>) -> FuelCallResponse<()> {
+        contract.methods().pause().call().await.unwrap()
+    }
+
+    pub async fn pending_owner(contract: &ContractBaseLib<WalletUnlocked>) -> Option<Identity> {
+        contract
+            .methods()
+            .pending_owner()
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn require_not_paused(
+        contract: &ContractBaseLib<WalletUnlocked>,
+    ) -> FuelCallResponse<()> {
+        contract
+            .methods()
+            .require_not_paused()
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn require_paused(
+        contract: &ContractBaseLib<WalletUnlocked>,
+    ) -> FuelCallResponse<()> {
+        contract.methods().require_paused().call().await.unwrap()
+    }
+
+    pub async fn set_ownership(
+        contract: &ContractBaseLib<WalletUnlocked>,
+        new_owner: Identity,
+    ) -> FuelCallResponse<()> {
+        contract
+            .methods()
+            .set_ownership(new_owner)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn start_ownership_transfer(
+        contract: &ContractBaseLib<WalletUnlocked>,
+        new_owner: Identity,
+    ) -> FuelCallResponse<()> {
+        contract
+            .methods()
+            .start_ownership_transfer(new_owner)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn unpause(contract: &ContractBaseLib<WalletUnlocked>) -> FuelCallResponse<()> {
+        contract.methods().unpause().call().await.unwrap()
+    }
+}
+
+pub mod test_helpers {
+
+    use super::abi_calls::set_ownership;
+    use super::*;
+
+    pub async fn setup() -> (Metadata, Metadata) {
+        let mut wallets = get_wallets(2).await;
+
+        let wallet1 = wallets.pop().unwrap();
+        let wallet2 = wallets.pop().unwrap();
+
+        let storage_configuration = StorageConfiguration::load_from(
+            "src/contract_base/out/debug/contract_base_test-storage_slots.json",
+        );
+        let id = deploy_contract(
+            &wallet1,
+            "src/contract_base/out/debug/contract_base_test.bin",
+            LoadConfiguration::default().set_storage_configuration(storage_configuration.unwrap()),
+        )
+        .await;
+
+        let admin = Metadata {
+            contract: ContractBaseLib::new(id.clone(), wallet1.clone()),
+            identity: Identity::Address(wallet1.address().into()),
+            wallet: wallet1,
+        };
+
+        let user = Metadata {
+            contract: ContractBaseLib::new(id, wallet2.clone()),
+            identity: Identity::Address(wallet2.address().into()),
+            wallet: wallet2,
+        };
+
+        set_ownership(&admin.contract, admin.identity.clone()).await;
+
+        (admin, user)
+    }
+}
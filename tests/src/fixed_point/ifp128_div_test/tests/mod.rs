@@ -1,4 +1,5 @@
-use fuels::prelude::{abigen, launch_provider_and_get_wallet};
+use crate::common::script_instance;
+use fuels::prelude::abigen;
 
 abigen!(Script(
     name = "TestIfp128Div",
@@ -13,9 +14,7 @@ mod success {
     async fn runs_ifp128_div_test_script() {
         let path_to_bin = "src/fixed_point/ifp128_div_test/out/debug/ifp128_div_test.bin";
 
-        let wallet = launch_provider_and_get_wallet().await;
-
-        let instance = TestIfp128Div::new(wallet, path_to_bin);
+        let instance = script_instance!(TestIfp128Div, path_to_bin);
 
         let _result = instance.main().call().await;
     }
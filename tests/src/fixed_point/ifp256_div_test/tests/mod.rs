@@ -1,4 +1,5 @@
-use fuels::prelude::{abigen, launch_provider_and_get_wallet};
+use crate::common::script_instance;
+use fuels::prelude::abigen;
 
 abigen!(Script(
     name = "TestIfp256Div",
@@ -13,9 +14,7 @@ mod success {
     async fn runs_ifp256_div_test_script() {
         let path_to_bin = "src/fixed_point/ifp256_div_test/out/debug/ifp256_div_test.bin";
 
-        let wallet = launch_provider_and_get_wallet().await;
-
-        let instance = TestIfp256Div::new(wallet, path_to_bin);
+        let instance = script_instance!(TestIfp256Div, path_to_bin);
 
         let _result = instance.main().call().await;
     }
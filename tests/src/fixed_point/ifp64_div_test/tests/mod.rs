@@ -1,4 +1,5 @@
-use fuels::prelude::{abigen, launch_provider_and_get_wallet};
+use crate::common::script_instance;
+use fuels::prelude::abigen;
 
 abigen!(Script(
     name = "TestIfp64Div",
@@ -13,9 +14,7 @@ mod success {
     async fn runs_ifp64_div_test_script() {
         let path_to_bin = "src/fixed_point/ifp64_div_test/out/debug/ifp64_div_test.bin";
 
-        let wallet = launch_provider_and_get_wallet().await;
-
-        let instance = TestIfp64Div::new(wallet, path_to_bin);
+        let instance = script_instance!(TestIfp64Div, path_to_bin);
 
         let _result = instance.main().call().await;
     }
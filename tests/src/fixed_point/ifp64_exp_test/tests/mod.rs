@@ -1,4 +1,5 @@
-use fuels::prelude::{abigen, launch_provider_and_get_wallet};
+use crate::common::script_instance;
+use fuels::prelude::abigen;
 
 abigen!(Script(
     name = "TestIfp64Exp",
@@ -12,9 +13,8 @@ mod success {
     #[tokio::test]
     async fn runs_ifp64_exp_test_script() {
         let path_to_bin = "src/fixed_point/ifp64_exp_test/out/debug/ifp64_exp_test.bin";
-        let wallet = launch_provider_and_get_wallet().await;
 
-        let instance = TestIfp64Exp::new(wallet, path_to_bin);
+        let instance = script_instance!(TestIfp64Exp, path_to_bin);
 
         let _result = instance.main().call().await;
     }
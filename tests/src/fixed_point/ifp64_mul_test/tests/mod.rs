@@ -1,4 +1,5 @@
-use fuels::prelude::{abigen, launch_provider_and_get_wallet};
+use crate::common::script_instance;
+use fuels::prelude::abigen;
 
 abigen!(Script(
     name = "TestIfp64Mul",
@@ -13,9 +14,7 @@ mod success {
     async fn runs_ifp64_mul_test_script() {
         let path_to_bin = "src/fixed_point/ifp64_mul_test/out/debug/ifp64_mul_test.bin";
 
-        let wallet = launch_provider_and_get_wallet().await;
-
-        let instance = TestIfp64Mul::new(wallet, path_to_bin);
+        let instance = script_instance!(TestIfp64Mul, path_to_bin);
 
         let _result = instance.main().call().await;
     }
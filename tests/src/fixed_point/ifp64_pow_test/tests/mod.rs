@@ -1,4 +1,5 @@
-use fuels::prelude::{abigen, launch_provider_and_get_wallet};
+use crate::common::script_instance;
+use fuels::prelude::abigen;
 
 abigen!(Script(
     name = "TestIfp64Pow",
@@ -12,9 +13,8 @@ mod success {
     #[tokio::test]
     async fn runs_ifp64_pow_test_script() {
         let path_to_bin = "src/fixed_point/ifp64_pow_test/out/debug/ifp64_pow_test.bin";
-        let wallet = launch_provider_and_get_wallet().await;
 
-        let instance = TestIfp64Pow::new(wallet, path_to_bin);
+        let instance = script_instance!(TestIfp64Pow, path_to_bin);
 
         let _result = instance.main().call().await;
     }
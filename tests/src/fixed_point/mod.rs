@@ -8,6 +8,8 @@ mod ifp64_mul_test;
 mod ifp64_pow_test;
 mod ifp64_test;
 mod ufp128_div_test;
+mod ufp128_mul_test;
+mod ufp128_root_test;
 mod ufp128_test;
 mod ufp32_div_test;
 mod ufp32_exp_test;
@@ -17,7 +19,11 @@ mod ufp32_root_test;
 mod ufp32_test;
 mod ufp64_div_test;
 mod ufp64_exp_test;
+mod ufp64_floor_ceil_test;
+mod ufp64_from_rational_test;
 mod ufp64_mul_test;
 mod ufp64_pow_test;
 mod ufp64_root_test;
+mod ufp64_sqrt_test;
 mod ufp64_test;
+mod ufp64_to_u64_round_test;
@@ -0,0 +1,31 @@
+script;
+
+use fixed_point::ufp128::UFP128;
+
+fn main() -> bool {
+    let one = UFP128::from_uint(1);
+    let two = UFP128::from_uint(2);
+    let mut res = one * two;
+    assert(two == res);
+
+    let ufp_128_10 = UFP128::from_uint(10);
+    res = ufp_128_10 * two;
+    assert(UFP128::from_uint(20) == res);
+
+    // 1.5 * 2.5 = 3.75
+    let one_and_half = UFP128::from((1, 1 << 63));
+    let two_and_half = UFP128::from((2, 1 << 63));
+    res = one_and_half * two_and_half;
+    assert(UFP128::from((3, (1 << 63) + (1 << 62))) == res);
+
+    // The product of the underlying values exceeds 128 bits
+    let large = UFP128::from((1 << 40, 1 << 63));
+    res = large * UFP128::from_uint(1 << 20);
+    assert(UFP128::from_uint((1 << 60) + (1 << 19)) == res);
+
+    let u32_max = UFP128::from_uint(4294967295);
+    res = u32_max * u32_max;
+    assert(UFP128::from_uint(18446744065119617025) == res);
+
+    true
+}
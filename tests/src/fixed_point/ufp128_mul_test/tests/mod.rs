@@ -0,0 +1,19 @@
+use crate::common::run_script;
+use fuels::prelude::abigen;
+
+abigen!(Script(
+    name = "TestUfp128Mul",
+    abi = "src/fixed_point/ufp128_mul_test/out/debug/ufp128_mul_test-abi.json"
+),);
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn runs_ufp128_mul_test_script() {
+        let path_to_bin = "src/fixed_point/ufp128_mul_test/out/debug/ufp128_mul_test.bin";
+
+        assert!(run_script!(TestUfp128Mul, path_to_bin));
+    }
+}
@@ -0,0 +1,38 @@
+script;
+
+use fixed_point::ufp128::UFP128;
+use std::math::Root;
+
+fn main() -> bool {
+    let zero = UFP128::zero();
+    let mut res = zero.sqrt();
+    assert(zero == res);
+
+    let one = UFP128::from_uint(1);
+    res = one.sqrt();
+    assert(one == res);
+
+    let four = UFP128::from_uint(4);
+    res = four.sqrt();
+    assert(UFP128::from_uint(2) == res);
+
+    let ufp_128_169 = UFP128::from_uint(169);
+    res = ufp_128_169.sqrt();
+    assert(UFP128::from_uint(13) == res);
+
+    // sqrt(2.25) = 1.5
+    let value = UFP128::from((2, 1 << 62));
+    res = value.sqrt();
+    assert(UFP128::from((1, 1 << 63)) == res);
+
+    // sqrt(0.25) = 0.5
+    let value = UFP128::from((0, 1 << 62));
+    res = value.sqrt();
+    assert(UFP128::from((0, 1 << 63)) == res);
+
+    let u32_max_squared = UFP128::from_uint(18446744065119617025);
+    res = u32_max_squared.sqrt();
+    assert(UFP128::from_uint(4294967295) == res);
+
+    true
+}
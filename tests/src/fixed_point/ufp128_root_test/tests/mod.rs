@@ -0,0 +1,19 @@
+use crate::common::run_script;
+use fuels::prelude::abigen;
+
+abigen!(Script(
+    name = "TestUfp128Root",
+    abi = "src/fixed_point/ufp128_root_test/out/debug/ufp128_root_test-abi.json"
+),);
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn runs_ufp128_root_test_script() {
+        let path_to_bin = "src/fixed_point/ufp128_root_test/out/debug/ufp128_root_test.bin";
+
+        assert!(run_script!(TestUfp128Root, path_to_bin));
+    }
+}
@@ -24,6 +24,21 @@ fn main() -> bool {
     res = ufp_128_169 - ufp_128_13;
     assert(UFP128::from((156, 0)) == res);
 
+    // from_rational
+    res = UFP128::from_rational(10, 5);
+    assert(two == res);
+
+    res = UFP128::from_rational(3, 4);
+    assert(UFP128::from((0, (1 << 63) + (1 << 62))) == res);
+
+    res = UFP128::from_rational(1, 3);
+    assert(UFP128::from((0, 6148914691236517205)) == res);
+
+    // to_u64
+    assert(UFP128::from_rational(7, 2).to_u64() == 3);
+    assert(UFP128::from((5, 0)).to_u64() == 5);
+    assert(UFP128::from((0, 18446744073709551615)).to_u64() == 0);
+
     // recip
     let mut value = UFP128 {
         value: U128::from((1, 3)),
@@ -44,8 +59,7 @@ fn main() -> bool {
     value = UFP128 {
         value: U128::from((1, 3)),
     };
-    res = value.floor();
-    assert(UFP128::from_uint(1) == res);
+    assert(value.floor() == 1);
 
     // fract
     value = UFP128 {
@@ -64,12 +78,10 @@ fn main() -> bool {
     value = UFP128 {
         value: U128::from((1, 3)),
     };
-    res = value.ceil();
-    assert(UFP128::from_uint(2) == res);
+    assert(value.ceil() == 2);
 
     value = UFP128::from_uint(1);
-    res = value.ceil();
-    assert(UFP128::from_uint(1) == res);
+    assert(value.ceil() == 1);
 
     // round
     value = UFP128 {
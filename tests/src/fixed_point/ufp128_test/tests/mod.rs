@@ -1,4 +1,5 @@
-use fuels::prelude::{abigen, launch_provider_and_get_wallet};
+use crate::common::script_instance;
+use fuels::prelude::abigen;
 
 abigen!(Script(
     name = "TestUfp128",
@@ -13,9 +14,7 @@ mod success {
     async fn runs_ufp128_test_script() {
         let path_to_bin = "src/fixed_point/ufp128_test/out/debug/ufp128_test.bin";
 
-        let wallet = launch_provider_and_get_wallet().await;
-
-        let instance = TestUfp128::new(wallet, path_to_bin);
+        let instance = script_instance!(TestUfp128, path_to_bin);
 
         let _result = instance.main().call().await;
     }
@@ -1,4 +1,5 @@
-use fuels::prelude::{abigen, launch_provider_and_get_wallet};
+use crate::common::script_instance;
+use fuels::prelude::abigen;
 
 abigen!(Script(
     name = "TestUfp32Pow",
@@ -12,9 +13,8 @@ mod success {
     #[tokio::test]
     async fn runs_ufp32_pow_test_script() {
         let path_to_bin = "src/fixed_point/ufp32_pow_test/out/debug/ufp32_pow_test.bin";
-        let wallet = launch_provider_and_get_wallet().await;
 
-        let instance = TestUfp32Pow::new(wallet, path_to_bin);
+        let instance = script_instance!(TestUfp32Pow, path_to_bin);
 
         let _result = instance.main().call().await;
     }
@@ -38,8 +38,10 @@ fn main() -> bool {
 
     // floor
     value = UFP32 { value: 3u32 };
-    res = value.floor();
-    assert(UFP32::from_uint(1) == res);
+    assert(value.floor() == 0u32);
+
+    value = UFP32::from_uint(1) + UFP32 { value: 3u32 };
+    assert(value.floor() == 1u32);
 
     // fract
     value = UFP32 { value: 3u32 };
@@ -52,12 +54,13 @@ fn main() -> bool {
 
     // ceil
     value = UFP32 { value: 3u32 };
-    res = value.ceil();
-    assert(UFP32::from_uint(2) == res);
+    assert(value.ceil() == 1u32);
+
+    value = UFP32::from_uint(1) + UFP32 { value: 3u32 };
+    assert(value.ceil() == 2u32);
 
     value = UFP32::from_uint(1);
-    res = value.ceil();
-    assert(UFP32::from_uint(1) == res);
+    assert(value.ceil() == 1u32);
 
     // round
     value = UFP32 { value: 3u32 };
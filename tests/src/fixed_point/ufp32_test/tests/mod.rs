@@ -1,4 +1,5 @@
-use fuels::prelude::{abigen, launch_provider_and_get_wallet};
+use crate::common::script_instance;
+use fuels::prelude::abigen;
 
 abigen!(Script(
     name = "TestUfp32",
@@ -13,9 +14,7 @@ mod success {
     async fn runs_ufp32_test_script() {
         let path_to_bin = "src/fixed_point/ufp32_test/out/debug/ufp32_test.bin";
 
-        let wallet = launch_provider_and_get_wallet().await;
-
-        let instance = TestUfp32::new(wallet, path_to_bin);
+        let instance = script_instance!(TestUfp32, path_to_bin);
 
         let _result = instance.main().call().await;
     }
@@ -1,4 +1,5 @@
-use fuels::prelude::{abigen, launch_provider_and_get_wallet};
+use crate::common::script_instance;
+use fuels::prelude::abigen;
 
 abigen!(Script(
     name = "TestUfp64Div",
@@ -13,9 +14,7 @@ mod success {
     async fn runs_ufp64_div_test_script() {
         let path_to_bin = "src/fixed_point/ufp64_div_test/out/debug/ufp64_div_test.bin";
 
-        let wallet = launch_provider_and_get_wallet().await;
-
-        let instance = TestUfp64Div::new(wallet, path_to_bin);
+        let instance = script_instance!(TestUfp64Div, path_to_bin);
 
         let _result = instance.main().call().await;
     }
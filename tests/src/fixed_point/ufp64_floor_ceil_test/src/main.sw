@@ -0,0 +1,41 @@
+script;
+
+use fixed_point::ufp64::UFP64;
+
+fn main() -> bool {
+    // Integers are unchanged
+    assert(UFP64::zero().floor() == 0);
+    assert(UFP64::zero().ceil() == 0);
+    assert(UFP64::one().floor() == 1);
+    assert(UFP64::one().ceil() == 1);
+    assert(UFP64::from_uint(7).floor() == 7);
+    assert(UFP64::from_uint(7).ceil() == 7);
+
+    // Fractions are rounded towards the neighbouring integers
+    let three_and_a_half = UFP64::from_rational(7, 2);
+    assert(three_and_a_half.floor() == 3);
+    assert(three_and_a_half.ceil() == 4);
+
+    let third = UFP64::from_rational(1, 3);
+    assert(third.floor() == 0);
+    assert(third.ceil() == 1);
+
+    // The smallest fraction above an integer still rounds up
+    let just_above_one = UFP64 {
+        value: (1 << 32) + 1,
+    };
+    assert(just_above_one.floor() == 1);
+    assert(just_above_one.ceil() == 2);
+
+    // The largest value rounds up to 2 ^ 32
+    assert(UFP64::max().floor() == 4294967295);
+    assert(UFP64::max().ceil() == 4294967296);
+
+    // The constants match their integer values
+    assert(UFP64::zero() == UFP64::from_uint(0));
+    assert(UFP64::one() == UFP64::from_uint(1));
+    assert(UFP64::one() * UFP64::from_rational(1, 3) == UFP64::from_rational(1, 3));
+    assert(UFP64::zero() + UFP64::one() == UFP64::one());
+
+    true
+}
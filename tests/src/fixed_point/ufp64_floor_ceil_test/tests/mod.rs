@@ -0,0 +1,20 @@
+use crate::common::run_script;
+use fuels::prelude::abigen;
+
+abigen!(Script(
+    name = "TestUfp64FloorCeil",
+    abi = "src/fixed_point/ufp64_floor_ceil_test/out/debug/ufp64_floor_ceil_test-abi.json"
+),);
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn runs_ufp64_floor_ceil_test_script() {
+        let path_to_bin =
+            "src/fixed_point/ufp64_floor_ceil_test/out/debug/ufp64_floor_ceil_test.bin";
+
+        assert!(run_script!(TestUfp64FloorCeil, path_to_bin));
+    }
+}
@@ -0,0 +1,7 @@
+script;
+
+use fixed_point::ufp64::UFP64;
+
+fn main(numerator: u64, denominator: u64) -> u64 {
+    UFP64::from_rational(numerator, denominator).value
+}
@@ -0,0 +1,74 @@
+use crate::common::script_instance;
+use fuels::prelude::{abigen, WalletUnlocked};
+
+abigen!(Script(
+    name = "TestUfp64FromRational",
+    abi = "src/fixed_point/ufp64_from_rational_test/out/debug/ufp64_from_rational_test-abi.json"
+),);
+
+const PATH_TO_BIN: &str =
+    "src/fixed_point/ufp64_from_rational_test/out/debug/ufp64_from_rational_test.bin";
+
+async fn setup() -> TestUfp64FromRational<WalletUnlocked> {
+    script_instance!(TestUfp64FromRational, PATH_TO_BIN)
+}
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn creates_integer_values() {
+        let instance = setup().await;
+
+        let result = instance.main(10, 5).call().await.unwrap();
+        assert_eq!(result.value, 2 << 32);
+
+        let result = instance.main(0, 7).call().await.unwrap();
+        assert_eq!(result.value, 0);
+    }
+
+    #[tokio::test]
+    async fn creates_fractional_values() {
+        let instance = setup().await;
+
+        let result = instance.main(1, 2).call().await.unwrap();
+        assert_eq!(result.value, 1 << 31);
+
+        let result = instance.main(3, 4).call().await.unwrap();
+        assert_eq!(result.value, 3 << 30);
+
+        // 1 / 3 is rounded down
+        let result = instance.main(1, 3).call().await.unwrap();
+        assert_eq!(result.value, 1431655765);
+    }
+
+    #[tokio::test]
+    async fn creates_largest_integer_value() {
+        let instance = setup().await;
+
+        let result = instance.main(u32::MAX as u64, 1).call().await.unwrap();
+        assert_eq!(result.value, (u32::MAX as u64) << 32);
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn when_denominator_is_zero() {
+        let instance = setup().await;
+
+        let result = instance.main(1, 0).call().await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn when_result_overflows() {
+        let instance = setup().await;
+
+        let result = instance.main(u64::MAX, 1).call().await;
+        assert!(result.is_err());
+    }
+}
@@ -1,4 +1,5 @@
-use fuels::prelude::{abigen, launch_provider_and_get_wallet};
+use crate::common::script_instance;
+use fuels::prelude::abigen;
 
 abigen!(Script(
     name = "TestUfp64Root",
@@ -13,9 +14,7 @@ mod success {
     async fn runs_ufp64_root_test_script() {
         let path_to_bin = "src/fixed_point/ufp64_root_test/out/debug/ufp64_root_test.bin";
 
-        let wallet = launch_provider_and_get_wallet().await;
-
-        let instance = TestUfp64Root::new(wallet, path_to_bin);
+        let instance = script_instance!(TestUfp64Root, path_to_bin);
 
         let _result = instance.main().call().await;
     }
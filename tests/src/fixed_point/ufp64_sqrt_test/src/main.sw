@@ -0,0 +1,7 @@
+script;
+
+use fixed_point::ufp64::UFP64;
+
+fn main(value: u64) -> u64 {
+    UFP64::from(value).sqrt().value
+}
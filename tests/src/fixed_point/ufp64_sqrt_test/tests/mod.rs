@@ -0,0 +1,97 @@
+use crate::common::script_instance;
+use fuels::prelude::{abigen, WalletUnlocked};
+
+abigen!(Script(
+    name = "TestUfp64Sqrt",
+    abi = "src/fixed_point/ufp64_sqrt_test/out/debug/ufp64_sqrt_test-abi.json"
+),);
+
+const PATH_TO_BIN: &str = "src/fixed_point/ufp64_sqrt_test/out/debug/ufp64_sqrt_test.bin";
+
+// The underlying value of UFP64::from_uint(1)
+const ONE: u64 = 1 << 32;
+
+async fn setup() -> TestUfp64Sqrt<WalletUnlocked> {
+    script_instance!(TestUfp64Sqrt, PATH_TO_BIN)
+}
+
+async fn sqrt(instance: &TestUfp64Sqrt<WalletUnlocked>, value: u64) -> u64 {
+    instance.main(value).call().await.unwrap().value
+}
+
+/// Checks that `root` is the exact square root of `value` rounded down to a multiple of 2 ^ -32.
+fn is_floor_root(value: u64, root: u64) -> bool {
+    let scaled = (value as u128) << 32;
+    let root = root as u128;
+    root * root <= scaled && scaled < (root + 1) * (root + 1)
+}
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_zero_for_zero() {
+        let instance = setup().await;
+
+        assert_eq!(sqrt(&instance, 0).await, 0);
+    }
+
+    #[tokio::test]
+    async fn returns_one_for_one() {
+        let instance = setup().await;
+
+        assert_eq!(sqrt(&instance, ONE).await, ONE);
+    }
+
+    #[tokio::test]
+    async fn computes_perfect_squares() {
+        let instance = setup().await;
+
+        for root in [2u64, 3, 7, 13, 65535] {
+            assert_eq!(sqrt(&instance, root * root * ONE).await, root * ONE);
+        }
+    }
+
+    #[tokio::test]
+    async fn computes_fractional_squares() {
+        let instance = setup().await;
+
+        // sqrt(2.25) == 1.5 and sqrt(0.25) == 0.5
+        assert_eq!(sqrt(&instance, ONE * 9 / 4).await, ONE * 3 / 2);
+        assert_eq!(sqrt(&instance, ONE / 4).await, ONE / 2);
+    }
+
+    #[tokio::test]
+    async fn computes_non_perfect_squares_within_one_ulp() {
+        let instance = setup().await;
+
+        for value in [
+            2 * ONE,
+            3 * ONE,
+            10 * ONE,
+            ONE + 1,
+            ONE - 1,
+            123456789,
+            u64::MAX,
+        ] {
+            let root = sqrt(&instance, value).await;
+            assert!(is_floor_root(value, root));
+        }
+
+        // sqrt(2) * 2 ^ 32 == 6074000999.95...
+        assert_eq!(sqrt(&instance, 2 * ONE).await, 6074000999);
+    }
+
+    #[tokio::test]
+    async fn terminates_for_very_small_inputs() {
+        let instance = setup().await;
+
+        // sqrt(2 ^ -32) == 2 ^ -16
+        assert_eq!(sqrt(&instance, 1).await, 1 << 16);
+        for value in [2, 3, 4, 5, 255, 256, 65535] {
+            let root = sqrt(&instance, value).await;
+            assert!(is_floor_root(value, root));
+        }
+    }
+}
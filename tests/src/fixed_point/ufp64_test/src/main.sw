@@ -44,8 +44,7 @@ fn main() -> bool {
     value = UFP64 {
         value: (1 << 32) + 3,
     };
-    res = value.floor();
-    assert(UFP64::from_uint(1) == res);
+    assert(value.floor() == 1);
 
     // fract
     value = UFP64 {
@@ -62,12 +61,10 @@ fn main() -> bool {
     value = UFP64 {
         value: (1 << 32) + 3,
     };
-    res = value.ceil();
-    assert(UFP64::from_uint(2) == res);
+    assert(value.ceil() == 2);
 
     value = UFP64::from_uint(1);
-    res = value.ceil();
-    assert(UFP64::from_uint(1) == res);
+    assert(value.ceil() == 1);
 
     // round
     value = UFP64 {
@@ -0,0 +1,37 @@
+script;
+
+use fixed_point::ufp64::UFP64;
+
+fn main() -> bool {
+    // 1 / 3 multiplied by 3 is within one unit of the last place of 1
+    let third = UFP64::from_rational(1, 3);
+    let product = third * UFP64::from_uint(3);
+    assert(UFP64::from_uint(1).value - product.value <= 1);
+    assert(product.to_u64_round() == 1);
+
+    // Half-way cases round up
+    assert(UFP64::from_rational(1, 2).to_u64_round() == 1);
+    assert(UFP64::from_rational(5, 2).to_u64_round() == 3);
+
+    // Values below the half-way point round down
+    assert(third.to_u64_round() == 0);
+    assert(UFP64::from_rational(49, 100).to_u64_round() == 0);
+    let below_half = UFP64 {
+        value: (2 << 32) + (1 << 31) - 1,
+    };
+    assert(below_half.to_u64_round() == 2);
+
+    // Values above the half-way point round up
+    assert(UFP64::from_rational(2, 3).to_u64_round() == 1);
+    assert(UFP64::from_rational(51, 100).to_u64_round() == 1);
+
+    // Integers are unchanged
+    assert(UFP64::zero().to_u64_round() == 0);
+    assert(UFP64::from_uint(7).to_u64_round() == 7);
+    assert(UFP64::from_uint(4294967295).to_u64_round() == 4294967295);
+
+    // The largest value rounds up to 2 ^ 32
+    assert(UFP64::max().to_u64_round() == 4294967296);
+
+    true
+}
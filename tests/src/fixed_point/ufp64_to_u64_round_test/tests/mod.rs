@@ -0,0 +1,20 @@
+use crate::common::run_script;
+use fuels::prelude::abigen;
+
+abigen!(Script(
+    name = "TestUfp64ToU64Round",
+    abi = "src/fixed_point/ufp64_to_u64_round_test/out/debug/ufp64_to_u64_round_test-abi.json"
+),);
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn runs_ufp64_to_u64_round_test_script() {
+        let path_to_bin =
+            "src/fixed_point/ufp64_to_u64_round_test/out/debug/ufp64_to_u64_round_test.bin";
+
+        assert!(run_script!(TestUfp64ToU64Round, path_to_bin));
+    }
+}
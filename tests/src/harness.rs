@@ -1,7 +1,29 @@
 // Add test modules here:
 
+mod access_control;
+mod amm;
+mod benchmark;
+mod bytes;
+mod collection_backend;
+mod commitment;
+mod common;
+mod contract_base;
 mod fixed_point;
+mod heap;
+mod matching;
+mod math;
 mod merkle_proof;
+mod multi_token;
+mod nft;
+mod oracle;
 mod ownership;
+mod pausable;
+mod queue;
 mod reentrancy;
+mod signature;
 mod signed_integers;
+mod string;
+mod timelock;
+mod timestamp;
+mod twap;
+mod vec_utils;
@@ -0,0 +1,43 @@
+contract;
+
+use collection_backend::StorageBackend;
+use heap::backend_heap::BackendHeap;
+
+storage {
+    heap: StorageBackend<(u64, b256)> = StorageBackend {},
+}
+
+abi HeapTest {
+    #[storage(read)]
+    fn len() -> u64;
+    #[storage(read)]
+    fn peek() -> Option<(u64, b256)>;
+    #[storage(read, write)]
+    fn pop() -> Option<(u64, b256)>;
+    #[storage(read, write)]
+    fn push(priority: u64, data: b256);
+}
+
+impl HeapTest for Contract {
+    #[storage(read)]
+    fn len() -> u64 {
+        BackendHeap::new(storage.heap).len()
+    }
+
+    #[storage(read)]
+    fn peek() -> Option<(u64, b256)> {
+        BackendHeap::new(storage.heap).peek()
+    }
+
+    #[storage(read, write)]
+    fn pop() -> Option<(u64, b256)> {
+        let mut heap = BackendHeap::new(storage.heap);
+        heap.pop()
+    }
+
+    #[storage(read, write)]
+    fn push(priority: u64, data: b256) {
+        let mut heap = BackendHeap::new(storage.heap);
+        heap.push(priority, data);
+    }
+}
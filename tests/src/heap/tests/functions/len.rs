@@ -0,0 +1,23 @@
+use crate::heap::tests::utils::{
+    abi_calls::{len, pop, push},
+    test_helpers::{data, setup},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn counts_remaining_items() {
+        let instance = setup().await;
+
+        assert_eq!(len(&instance).await, 0);
+
+        push(&instance, 1, data(1)).await;
+        push(&instance, 2, data(2)).await;
+        assert_eq!(len(&instance).await, 2);
+
+        pop(&instance).await;
+        assert_eq!(len(&instance).await, 1);
+    }
+}
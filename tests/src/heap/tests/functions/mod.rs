@@ -0,0 +1,4 @@
+mod len;
+mod peek;
+mod pop;
+mod push;
@@ -0,0 +1,28 @@
+use crate::heap::tests::utils::{
+    abi_calls::{len, peek, push},
+    test_helpers::{data, setup},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_none_when_empty() {
+        let instance = setup().await;
+
+        assert_eq!(peek(&instance).await, None);
+    }
+
+    #[tokio::test]
+    async fn does_not_remove_the_minimum() {
+        let instance = setup().await;
+
+        push(&instance, 2, data(2)).await;
+        push(&instance, 1, data(1)).await;
+
+        assert_eq!(peek(&instance).await, Some((1, data(1))));
+        assert_eq!(peek(&instance).await, Some((1, data(1))));
+        assert_eq!(len(&instance).await, 2);
+    }
+}
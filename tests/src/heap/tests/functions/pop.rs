@@ -0,0 +1,66 @@
+use crate::heap::tests::utils::{
+    abi_calls::{len, pop, push},
+    test_helpers::{data, setup},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_none_when_empty() {
+        let instance = setup().await;
+
+        assert_eq!(pop(&instance).await, None);
+        assert_eq!(len(&instance).await, 0);
+    }
+
+    #[tokio::test]
+    async fn pops_in_ascending_priority_order() {
+        let instance = setup().await;
+
+        let priorities = [42, 7, 19, 3, 88, 1, 56, 23, 11, 64];
+        for priority in priorities {
+            push(&instance, priority, data(priority)).await;
+        }
+
+        let mut sorted = priorities;
+        sorted.sort();
+        for priority in sorted {
+            assert_eq!(pop(&instance).await, Some((priority, data(priority))));
+        }
+
+        assert_eq!(len(&instance).await, 0);
+        assert_eq!(pop(&instance).await, None);
+    }
+
+    #[tokio::test]
+    async fn pops_duplicate_priorities() {
+        let instance = setup().await;
+
+        for priority in [5, 2, 5, 2, 9] {
+            push(&instance, priority, data(priority)).await;
+        }
+
+        for priority in [2, 2, 5, 5, 9] {
+            assert_eq!(pop(&instance).await.unwrap().0, priority);
+        }
+        assert_eq!(pop(&instance).await, None);
+    }
+
+    #[tokio::test]
+    async fn pops_when_interleaved_with_pushes() {
+        let instance = setup().await;
+
+        push(&instance, 10, data(10)).await;
+        push(&instance, 30, data(30)).await;
+        assert_eq!(pop(&instance).await, Some((10, data(10))));
+
+        push(&instance, 20, data(20)).await;
+        push(&instance, 5, data(5)).await;
+        assert_eq!(pop(&instance).await, Some((5, data(5))));
+        assert_eq!(pop(&instance).await, Some((20, data(20))));
+        assert_eq!(pop(&instance).await, Some((30, data(30))));
+        assert_eq!(pop(&instance).await, None);
+    }
+}
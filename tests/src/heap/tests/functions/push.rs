@@ -0,0 +1,24 @@
+use crate::heap::tests::utils::{
+    abi_calls::{len, peek, push},
+    test_helpers::{data, setup},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn sifts_lower_priorities_to_the_top() {
+        let instance = setup().await;
+
+        push(&instance, 30, data(30)).await;
+        assert_eq!(peek(&instance).await, Some((30, data(30))));
+
+        push(&instance, 40, data(40)).await;
+        assert_eq!(peek(&instance).await, Some((30, data(30))));
+
+        push(&instance, 10, data(10)).await;
+        assert_eq!(peek(&instance).await, Some((10, data(10))));
+        assert_eq!(len(&instance).await, 3);
+    }
+}
@@ -0,0 +1,67 @@
+use crate::common::{deploy_contract, get_wallet};
+use fuels::{
+    prelude::{abigen, LoadConfiguration, StorageConfiguration, WalletUnlocked},
+    programs::call_response::FuelCallResponse,
+    types::Bits256,
+};
+
+abigen!(Contract(
+    name = "HeapLib",
+    abi = "src/heap/out/debug/heap_test-abi.json"
+));
+
+pub mod abi_calls {
+
+    use super::*;
+
+    pub async fn len(contract: &HeapLib<WalletUnlocked>) -> u64 {
+        contract.methods().len().call().await.unwrap().value
+    }
+
+    pub async fn peek(contract: &HeapLib<WalletUnlocked>) -> Option<(u64, Bits256)> {
+        contract.methods().peek().call().await.unwrap().value
+    }
+
+    pub async fn pop(contract: &HeapLib<WalletUnlocked>) -> Option<(u64, Bits256)> {
+        contract.methods().pop().call().await.unwrap().value
+    }
+
+    pub async fn push(
+        contract: &HeapLib<WalletUnlocked>,
+        priority: u64,
+        data: Bits256,
+    ) -> FuelCallResponse<()> {
+        contract
+            .methods()
+            .push(priority, data)
+            .call()
+            .await
+            .unwrap()
+    }
+}
+
+pub mod test_helpers {
+
+    use super::*;
+
+    pub fn data(priority: u64) -> Bits256 {
+        let mut bytes = [0u8; 32];
+        bytes[24..].copy_from_slice(&priority.to_be_bytes());
+        Bits256(bytes)
+    }
+
+    pub async fn setup() -> HeapLib<WalletUnlocked> {
+        let wallet = get_wallet().await;
+
+        let storage_configuration =
+            StorageConfiguration::load_from("src/heap/out/debug/heap_test-storage_slots.json");
+        let id = deploy_contract(
+            &wallet,
+            "src/heap/out/debug/heap_test.bin",
+            LoadConfiguration::default().set_storage_configuration(storage_configuration.unwrap()),
+        )
+        .await;
+
+        HeapLib::new(id, wallet)
+    }
+}
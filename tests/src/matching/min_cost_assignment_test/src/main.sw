@@ -0,0 +1,8 @@
+script;
+
+use matching::min_cost_assignment_4x4;
+use signed_integers::i64::I64;
+
+fn main(cost: [[I64; 4]; 4]) -> ([u8; 4], I64) {
+    min_cost_assignment_4x4(cost)
+}
@@ -0,0 +1,115 @@
+use crate::common::script_instance;
+use fuels::prelude::{abigen, WalletUnlocked};
+
+abigen!(Script(
+    name = "TestMinCostAssignment",
+    abi = "src/matching/min_cost_assignment_test/out/debug/min_cost_assignment_test-abi.json"
+),);
+
+const PATH_TO_BIN: &str =
+    "src/matching/min_cost_assignment_test/out/debug/min_cost_assignment_test.bin";
+
+async fn setup() -> TestMinCostAssignment<WalletUnlocked> {
+    script_instance!(TestMinCostAssignment, PATH_TO_BIN)
+}
+
+fn i64(value: i64) -> I64 {
+    // The underlying value is biased by 2 ^ 63, which only flips the sign bit
+    I64 {
+        underlying: (value as u64) ^ (1 << 63),
+    }
+}
+
+fn matrix(cost: [[i64; 4]; 4]) -> [[I64; 4]; 4] {
+    cost.map(|row| row.map(i64))
+}
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn assigns_positive_costs() {
+        let instance = setup().await;
+
+        // The cheapest cost of the first row is not part of the optimal assignment
+        let cost = [[9, 2, 7, 8], [6, 4, 3, 7], [5, 8, 1, 8], [7, 6, 9, 4]];
+
+        let (assignment, total) = instance.main(matrix(cost)).call().await.unwrap().value;
+        assert_eq!(assignment, [1, 0, 2, 3]);
+        assert_eq!(total, i64(13));
+    }
+
+    #[tokio::test]
+    async fn assigns_negative_costs() {
+        let instance = setup().await;
+
+        // Negated profits, of which the most profitable assignment is a rotation
+        let cost = [
+            [10, -30, 20, 5],
+            [-15, 25, -10, 40],
+            [35, -5, 15, -20],
+            [-25, 10, 30, 0],
+        ];
+
+        let (assignment, total) = instance.main(matrix(cost)).call().await.unwrap().value;
+        assert_eq!(assignment, [1, 2, 3, 0]);
+        assert_eq!(total, i64(-85));
+    }
+
+    #[tokio::test]
+    async fn assigns_mixed_costs() {
+        let instance = setup().await;
+
+        let cost = [[-5, 3, -2, 0], [4, -7, 1, 2], [-1, 2, -9, 3], [0, 1, 4, -6]];
+
+        let (assignment, total) = instance.main(matrix(cost)).call().await.unwrap().value;
+        assert_eq!(assignment, [0, 1, 2, 3]);
+        assert_eq!(total, i64(-27));
+    }
+
+    #[tokio::test]
+    async fn assigns_anti_diagonal() {
+        let instance = setup().await;
+
+        // Pairing the largest multiplier with the smallest is the cheapest
+        let cost = [[1, 2, 3, 4], [2, 4, 6, 8], [3, 6, 9, 12], [4, 8, 12, 16]];
+
+        let (assignment, total) = instance.main(matrix(cost)).call().await.unwrap().value;
+        assert_eq!(assignment, [3, 2, 1, 0]);
+        assert_eq!(total, i64(20));
+    }
+
+    #[tokio::test]
+    async fn assigns_equal_costs() {
+        let instance = setup().await;
+
+        let cost = [[-7; 4]; 4];
+
+        let (assignment, total) = instance.main(matrix(cost)).call().await.unwrap().value;
+        let mut columns = assignment;
+        columns.sort();
+        assert_eq!(columns, [0, 1, 2, 3]);
+        assert_eq!(total, i64(-28));
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn when_costs_overflow() {
+        let instance = setup().await;
+
+        let cost = [
+            [i64::MIN, i64::MAX, 0, 0],
+            [i64::MAX, i64::MIN, 0, 0],
+            [0, 0, i64::MIN, i64::MAX],
+            [0, 0, i64::MAX, i64::MIN],
+        ];
+
+        let result = instance.main(matrix(cost)).call().await;
+        assert!(result.is_err());
+    }
+}
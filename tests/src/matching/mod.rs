@@ -0,0 +1 @@
+mod min_cost_assignment_test;
@@ -0,0 +1,7 @@
+script;
+
+use math::bits::{leading_zeros_u32, popcount_u32, trailing_zeros_u32};
+
+fn main(x: u32) -> (u32, u32, u32) {
+    (popcount_u32(x), leading_zeros_u32(x), trailing_zeros_u32(x))
+}
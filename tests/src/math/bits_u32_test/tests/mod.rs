@@ -0,0 +1,60 @@
+use crate::common::script_instance;
+use fuels::prelude::{abigen, WalletUnlocked};
+
+abigen!(Script(
+    name = "TestBitsU32",
+    abi = "src/math/bits_u32_test/out/debug/bits_u32_test-abi.json"
+),);
+
+const PATH_TO_BIN: &str = "src/math/bits_u32_test/out/debug/bits_u32_test.bin";
+
+async fn setup() -> TestBitsU32<WalletUnlocked> {
+    script_instance!(TestBitsU32, PATH_TO_BIN)
+}
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn counts_bits_of_edge_cases() {
+        let instance = setup().await;
+
+        let result = instance.main(0).call().await.unwrap();
+        assert_eq!(result.value, (0, 32, 32));
+
+        let result = instance.main(1).call().await.unwrap();
+        assert_eq!(result.value, (1, 31, 0));
+
+        let result = instance.main(u32::MAX).call().await.unwrap();
+        assert_eq!(result.value, (32, 0, 0));
+
+        let result = instance.main(1 << 31).call().await.unwrap();
+        assert_eq!(result.value, (1, 0, 31));
+    }
+
+    #[tokio::test]
+    async fn counts_bits_like_rust() {
+        let instance = setup().await;
+
+        for x in [
+            2u32,
+            3,
+            0b1011_0000,
+            0x8000_0001,
+            0x0f0f_0f0f,
+            u32::MAX - 1,
+            u32::MAX >> 1,
+            u32::MAX / 3,
+            32_000_001,
+            (1 << 16) - 1,
+            1 << 16,
+        ] {
+            let result = instance.main(x).call().await.unwrap();
+            assert_eq!(
+                result.value,
+                (x.count_ones(), x.leading_zeros(), x.trailing_zeros())
+            );
+        }
+    }
+}
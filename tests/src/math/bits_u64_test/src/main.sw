@@ -0,0 +1,7 @@
+script;
+
+use math::bits::{leading_zeros_u64, popcount_u64, trailing_zeros_u64};
+
+fn main(x: u64) -> (u32, u32, u32) {
+    (popcount_u64(x), leading_zeros_u64(x), trailing_zeros_u64(x))
+}
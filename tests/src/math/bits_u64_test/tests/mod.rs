@@ -0,0 +1,60 @@
+use crate::common::script_instance;
+use fuels::prelude::{abigen, WalletUnlocked};
+
+abigen!(Script(
+    name = "TestBitsU64",
+    abi = "src/math/bits_u64_test/out/debug/bits_u64_test-abi.json"
+),);
+
+const PATH_TO_BIN: &str = "src/math/bits_u64_test/out/debug/bits_u64_test.bin";
+
+async fn setup() -> TestBitsU64<WalletUnlocked> {
+    script_instance!(TestBitsU64, PATH_TO_BIN)
+}
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn counts_bits_of_edge_cases() {
+        let instance = setup().await;
+
+        let result = instance.main(0).call().await.unwrap();
+        assert_eq!(result.value, (0, 64, 64));
+
+        let result = instance.main(1).call().await.unwrap();
+        assert_eq!(result.value, (1, 63, 0));
+
+        let result = instance.main(u64::MAX).call().await.unwrap();
+        assert_eq!(result.value, (64, 0, 0));
+
+        let result = instance.main(1 << 63).call().await.unwrap();
+        assert_eq!(result.value, (1, 0, 63));
+    }
+
+    #[tokio::test]
+    async fn counts_bits_like_rust() {
+        let instance = setup().await;
+
+        for x in [
+            2u64,
+            3,
+            0b1011_0000,
+            0x8000_0001,
+            0x0f0f_0f0f,
+            u64::MAX - 1,
+            u64::MAX >> 1,
+            u64::MAX / 3,
+            64_000_001,
+            (1 << 32) - 1,
+            1 << 32,
+        ] {
+            let result = instance.main(x).call().await.unwrap();
+            assert_eq!(
+                result.value,
+                (x.count_ones(), x.leading_zeros(), x.trailing_zeros())
+            );
+        }
+    }
+}
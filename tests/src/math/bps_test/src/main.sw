@@ -0,0 +1,7 @@
+script;
+
+use math::bps::apply_bps;
+
+fn main(amount: u64, bps: u64) -> u64 {
+    apply_bps(amount, bps)
+}
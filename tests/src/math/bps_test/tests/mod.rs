@@ -0,0 +1,75 @@
+use crate::common::script_instance;
+use fuels::prelude::{abigen, WalletUnlocked};
+
+abigen!(Script(
+    name = "TestBps",
+    abi = "src/math/bps_test/out/debug/bps_test-abi.json"
+),);
+
+const PATH_TO_BIN: &str = "src/math/bps_test/out/debug/bps_test.bin";
+
+async fn setup() -> TestBps<WalletUnlocked> {
+    script_instance!(TestBps, PATH_TO_BIN)
+}
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn applies_zero_bps() {
+        let instance = setup().await;
+
+        for amount in [0, 1, 10_000, u64::MAX] {
+            let result = instance.main(amount, 0).call().await.unwrap();
+            assert_eq!(result.value, 0);
+        }
+    }
+
+    #[tokio::test]
+    async fn applies_max_bps() {
+        let instance = setup().await;
+
+        for amount in [0, 1, 10_000, u64::MAX] {
+            let result = instance.main(amount, 10_000).call().await.unwrap();
+            assert_eq!(result.value, amount);
+        }
+    }
+
+    #[tokio::test]
+    async fn applies_fee() {
+        let instance = setup().await;
+
+        let result = instance.main(10_000, 50).call().await.unwrap();
+        assert_eq!(result.value, 50);
+
+        let result = instance.main(1_000, 50).call().await.unwrap();
+        assert_eq!(result.value, 5);
+
+        // Rounds down
+        let result = instance.main(199, 50).call().await.unwrap();
+        assert_eq!(result.value, 0);
+    }
+
+    #[tokio::test]
+    async fn applies_bps_to_large_amount() {
+        let instance = setup().await;
+
+        let amount = u64::MAX - 1;
+        let result = instance.main(amount, 9_999).call().await.unwrap();
+        assert_eq!(result.value, (amount as u128 * 9_999 / 10_000) as u64);
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "ExceedsMaxBps")]
+    async fn when_bps_exceeds_max() {
+        let instance = setup().await;
+
+        instance.main(10_000, 10_001).call().await.unwrap();
+    }
+}
@@ -0,0 +1,9 @@
+script;
+
+use math::bps::apply_bps_u128;
+use std::u128::U128;
+
+fn main(upper: u64, lower: u64, bps: u64) -> (u64, u64) {
+    let portion = apply_bps_u128(U128::from((upper, lower)), bps);
+    (portion.upper, portion.lower)
+}
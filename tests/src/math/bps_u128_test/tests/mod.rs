@@ -0,0 +1,79 @@
+use crate::common::script_instance;
+use fuels::prelude::{abigen, WalletUnlocked};
+
+abigen!(Script(
+    name = "TestBpsU128",
+    abi = "src/math/bps_u128_test/out/debug/bps_u128_test-abi.json"
+),);
+
+const PATH_TO_BIN: &str = "src/math/bps_u128_test/out/debug/bps_u128_test.bin";
+
+async fn setup() -> TestBpsU128<WalletUnlocked> {
+    script_instance!(TestBpsU128, PATH_TO_BIN)
+}
+
+async fn apply_bps(instance: &TestBpsU128<WalletUnlocked>, amount: u128, bps: u64) -> u128 {
+    let result = instance
+        .main((amount >> 64) as u64, amount as u64, bps)
+        .call()
+        .await
+        .unwrap();
+    let (upper, lower) = result.value;
+    ((upper as u128) << 64) + lower as u128
+}
+
+// Equal to `amount * bps / 10_000` without overflowing the product
+fn expected(amount: u128, bps: u64) -> u128 {
+    let bps = bps as u128;
+    amount / 10_000 * bps + amount % 10_000 * bps / 10_000
+}
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn applies_zero_and_max_bps() {
+        let instance = setup().await;
+
+        for amount in [0, 1, 10_000, u64::MAX as u128 + 1, u128::MAX] {
+            assert_eq!(apply_bps(&instance, amount, 0).await, 0);
+            assert_eq!(apply_bps(&instance, amount, 10_000).await, amount);
+        }
+    }
+
+    #[tokio::test]
+    async fn applies_fee() {
+        let instance = setup().await;
+
+        assert_eq!(apply_bps(&instance, 10_000, 50).await, 50);
+        assert_eq!(apply_bps(&instance, 1_000, 50).await, 5);
+    }
+
+    #[tokio::test]
+    async fn applies_bps_to_large_amount() {
+        let instance = setup().await;
+
+        for amount in [u64::MAX as u128 * 3, u128::MAX / 7, u128::MAX] {
+            for bps in [1, 50, 5_000, 9_999] {
+                assert_eq!(
+                    apply_bps(&instance, amount, bps).await,
+                    expected(amount, bps)
+                );
+            }
+        }
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "ExceedsMaxBps")]
+    async fn when_bps_exceeds_max() {
+        let instance = setup().await;
+
+        apply_bps(&instance, 10_000, 10_001).await;
+    }
+}
@@ -0,0 +1,27 @@
+script;
+
+use math::checked_pow_u64;
+
+fn main() -> bool {
+    assert(checked_pow_u64(0, 0).unwrap() == 1);
+    assert(checked_pow_u64(7, 0).unwrap() == 1);
+    assert(checked_pow_u64(u64::max(), 0).unwrap() == 1);
+    assert(checked_pow_u64(0, 1).unwrap() == 0);
+    assert(checked_pow_u64(0, 63).unwrap() == 0);
+    assert(checked_pow_u64(1, 4294967295).unwrap() == 1);
+    assert(checked_pow_u64(2, 10).unwrap() == 1024);
+    assert(checked_pow_u64(3, 4).unwrap() == 81);
+    assert(checked_pow_u64(10, 19).unwrap() == 10000000000000000000);
+    assert(checked_pow_u64(2, 63).unwrap() == 9223372036854775808);
+    assert(checked_pow_u64(4294967296, 1).unwrap() == 4294967296);
+    assert(checked_pow_u64(4294967295, 2).unwrap() == 18446744065119617025);
+    assert(checked_pow_u64(u64::max(), 1).unwrap() == u64::max());
+
+    assert(checked_pow_u64(2, 64).is_none());
+    assert(checked_pow_u64(10, 20).is_none());
+    assert(checked_pow_u64(4294967296, 2).is_none());
+    assert(checked_pow_u64(u64::max(), 2).is_none());
+    assert(checked_pow_u64(3, 4294967295).is_none());
+
+    true
+}
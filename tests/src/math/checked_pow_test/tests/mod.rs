@@ -0,0 +1,19 @@
+use crate::common::run_script;
+use fuels::prelude::abigen;
+
+abigen!(Script(
+    name = "TestCheckedPow",
+    abi = "src/math/checked_pow_test/out/debug/checked_pow_test-abi.json"
+),);
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn runs_checked_pow_test_script() {
+        let path_to_bin = "src/math/checked_pow_test/out/debug/checked_pow_test.bin";
+
+        assert!(run_script!(TestCheckedPow, path_to_bin));
+    }
+}
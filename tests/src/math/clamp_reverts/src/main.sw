@@ -0,0 +1,14 @@
+script;
+
+use math::Clamp;
+use std::u128::U128;
+
+fn main(bits: u64) -> bool {
+    if bits == 64 {
+        let _ = 5u64.clamp(10, 9);
+    } else {
+        let _ = U128::from((0, 5)).clamp(U128::from((1, 0)), U128::from((0, u64::max())));
+    }
+
+    true
+}
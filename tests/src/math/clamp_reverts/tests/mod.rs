@@ -0,0 +1,34 @@
+use crate::common::script_instance;
+use fuels::prelude::{abigen, WalletUnlocked};
+
+abigen!(Script(
+    name = "TestClampReverts",
+    abi = "src/math/clamp_reverts/out/debug/clamp_reverts_test-abi.json"
+),);
+
+const PATH_TO_BIN: &str = "src/math/clamp_reverts/out/debug/clamp_reverts_test.bin";
+
+async fn setup() -> TestClampReverts<WalletUnlocked> {
+    script_instance!(TestClampReverts, PATH_TO_BIN)
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidRange")]
+    async fn when_u64_min_exceeds_max() {
+        let instance = setup().await;
+
+        instance.main(64).call().await.unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidRange")]
+    async fn when_u128_min_exceeds_max() {
+        let instance = setup().await;
+
+        instance.main(128).call().await.unwrap();
+    }
+}
@@ -0,0 +1,31 @@
+script;
+
+use math::Clamp;
+use std::u128::U128;
+
+fn main() -> bool {
+    // u64
+    assert(0u64.clamp(5, 10) == 5);
+    assert(4u64.clamp(5, 10) == 5);
+    assert(5u64.clamp(5, 10) == 5);
+    assert(7u64.clamp(5, 10) == 7);
+    assert(10u64.clamp(5, 10) == 10);
+    assert(11u64.clamp(5, 10) == 10);
+    assert(u64::max().clamp(5, 10) == 10);
+    assert(3u64.clamp(7, 7) == 7);
+    assert(9u64.clamp(7, 7) == 7);
+    assert(u64::max().clamp(0, u64::max()) == u64::max());
+
+    // U128
+    let min = U128::from((1, 0));
+    let max = U128::from((2, 0));
+    assert(U128::from((0, u64::max())).clamp(min, max) == min);
+    assert(min.clamp(min, max) == min);
+    assert(U128::from((1, 5)).clamp(min, max) == U128::from((1, 5)));
+    assert(max.clamp(min, max) == max);
+    assert(U128::from((2, 1)).clamp(min, max) == max);
+    assert(U128::max().clamp(min, max) == max);
+    assert(U128::max().clamp(max, max) == max);
+
+    true
+}
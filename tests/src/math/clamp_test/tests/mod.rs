@@ -0,0 +1,19 @@
+use crate::common::run_script;
+use fuels::prelude::abigen;
+
+abigen!(Script(
+    name = "TestClamp",
+    abi = "src/math/clamp_test/out/debug/clamp_test-abi.json"
+),);
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn runs_clamp_test_script() {
+        let path_to_bin = "src/math/clamp_test/out/debug/clamp_test.bin";
+
+        assert!(run_script!(TestClamp, path_to_bin));
+    }
+}
@@ -0,0 +1,22 @@
+script;
+
+use math::{div_ceil_u128, div_ceil_u64, div_floor_u128, div_floor_u64};
+use std::u128::U128;
+
+fn main(bits: u64, ceil: bool) -> bool {
+    if bits == 64 {
+        if ceil {
+            let _ = div_ceil_u64(10, 0);
+        } else {
+            let _ = div_floor_u64(10, 0);
+        }
+    } else {
+        if ceil {
+            let _ = div_ceil_u128(U128::from((0, 10)), U128::from((0, 0)));
+        } else {
+            let _ = div_floor_u128(U128::from((0, 10)), U128::from((0, 0)));
+        }
+    }
+
+    true
+}
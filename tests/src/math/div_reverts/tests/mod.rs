@@ -0,0 +1,50 @@
+use crate::common::script_instance;
+use fuels::prelude::{abigen, WalletUnlocked};
+
+abigen!(Script(
+    name = "TestDivReverts",
+    abi = "src/math/div_reverts/out/debug/div_reverts_test-abi.json"
+),);
+
+const PATH_TO_BIN: &str = "src/math/div_reverts/out/debug/div_reverts_test.bin";
+
+async fn setup() -> TestDivReverts<WalletUnlocked> {
+    script_instance!(TestDivReverts, PATH_TO_BIN)
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "Undefined")]
+    async fn when_u64_ceil_divides_by_zero() {
+        let instance = setup().await;
+
+        instance.main(64, true).call().await.unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Undefined")]
+    async fn when_u64_floor_divides_by_zero() {
+        let instance = setup().await;
+
+        instance.main(64, false).call().await.unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Undefined")]
+    async fn when_u128_ceil_divides_by_zero() {
+        let instance = setup().await;
+
+        instance.main(128, true).call().await.unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Undefined")]
+    async fn when_u128_floor_divides_by_zero() {
+        let instance = setup().await;
+
+        instance.main(128, false).call().await.unwrap();
+    }
+}
@@ -0,0 +1,34 @@
+script;
+
+use math::{div_ceil_u128, div_ceil_u64, div_floor_u128, div_floor_u64};
+use std::u128::U128;
+
+fn main() -> bool {
+    // div_ceil_u64
+    assert(div_ceil_u64(10, 3) == 4);
+    assert(div_ceil_u64(9, 3) == 3);
+    assert(div_ceil_u64(0, 3) == 0);
+    assert(div_ceil_u64(1, 3) == 1);
+    assert(div_ceil_u64(u64::max(), 2) == (u64::max() / 2) + 1);
+    assert(div_ceil_u64(u64::max(), 1) == u64::max());
+    assert(div_ceil_u64(u64::max(), u64::max()) == 1);
+
+    // div_floor_u64
+    assert(div_floor_u64(10, 3) == 3);
+    assert(div_floor_u64(9, 3) == 3);
+    assert(div_floor_u64(0, 3) == 0);
+    assert(div_floor_u64(u64::max(), 2) == u64::max() / 2);
+
+    // div_ceil_u128
+    assert(div_ceil_u128(U128::from((0, 10)), U128::from((0, 3))) == U128::from((0, 4)));
+    assert(div_ceil_u128(U128::from((0, 9)), U128::from((0, 3))) == U128::from((0, 3)));
+    assert(div_ceil_u128(U128::from((0, 0)), U128::from((0, 3))) == U128::from((0, 0)));
+    assert(div_ceil_u128(U128::max(), U128::from((0, 2))) == U128::from((u64::max() >> 1, u64::max())) + U128::from((0, 1)));
+    assert(div_ceil_u128(U128::max(), U128::max()) == U128::from((0, 1)));
+
+    // div_floor_u128
+    assert(div_floor_u128(U128::from((0, 10)), U128::from((0, 3))) == U128::from((0, 3)));
+    assert(div_floor_u128(U128::max(), U128::from((0, 2))) == U128::from((u64::max() >> 1, u64::max())));
+
+    true
+}
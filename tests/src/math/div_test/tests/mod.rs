@@ -0,0 +1,19 @@
+use crate::common::run_script;
+use fuels::prelude::abigen;
+
+abigen!(Script(
+    name = "TestDiv",
+    abi = "src/math/div_test/out/debug/div_test-abi.json"
+),);
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn runs_div_test_script() {
+        let path_to_bin = "src/math/div_test/out/debug/div_test.bin";
+
+        assert!(run_script!(TestDiv, path_to_bin));
+    }
+}
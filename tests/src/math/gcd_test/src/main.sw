@@ -0,0 +1,7 @@
+script;
+
+use math::gcd;
+
+fn main(a: u64, b: u64) -> u64 {
+    gcd(a, b)
+}
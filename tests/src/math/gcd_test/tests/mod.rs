@@ -0,0 +1,74 @@
+use crate::common::script_instance;
+use fuels::prelude::{abigen, WalletUnlocked};
+
+abigen!(Script(
+    name = "TestGcd",
+    abi = "src/math/gcd_test/out/debug/gcd_test-abi.json"
+),);
+
+const PATH_TO_BIN: &str = "src/math/gcd_test/out/debug/gcd_test.bin";
+
+async fn setup() -> TestGcd<WalletUnlocked> {
+    script_instance!(TestGcd, PATH_TO_BIN)
+}
+
+fn expected_gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        expected_gcd(b, a % b)
+    }
+}
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn computes_gcd_of_coprime_values() {
+        let instance = setup().await;
+
+        for (a, b) in [
+            (1u64, 1u64),
+            (2, 3),
+            (17, 31),
+            (35, 64),
+            (u64::MAX, u64::MAX - 1),
+        ] {
+            let result = instance.main(a, b).call().await.unwrap();
+            assert_eq!(result.value, 1);
+        }
+    }
+
+    #[tokio::test]
+    async fn computes_gcd_of_non_coprime_values() {
+        let instance = setup().await;
+
+        for (a, b) in [
+            (12u64, 18u64),
+            (18, 12),
+            (48, 180),
+            (1 << 40, 1 << 20),
+            (3 * (1 << 33), 9 * (1 << 7)),
+            (u64::MAX, 5),
+            (u64::MAX, u64::MAX),
+        ] {
+            let result = instance.main(a, b).call().await.unwrap();
+            assert_eq!(result.value, expected_gcd(a, b));
+        }
+    }
+
+    #[tokio::test]
+    async fn computes_gcd_with_zero() {
+        let instance = setup().await;
+
+        let result = instance.main(0, 42).call().await.unwrap();
+        assert_eq!(result.value, 42);
+
+        let result = instance.main(42, 0).call().await.unwrap();
+        assert_eq!(result.value, 42);
+
+        let result = instance.main(0, 0).call().await.unwrap();
+        assert_eq!(result.value, 0);
+    }
+}
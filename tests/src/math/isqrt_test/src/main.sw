@@ -0,0 +1,73 @@
+script;
+
+use math::{isqrt_u128, isqrt_u64};
+use std::u128::U128;
+
+/// Checks that `root * root <= n < (root + 1) * (root + 1)`.
+fn is_floor_root(n: U128, root: u64) -> bool {
+    let root_u128 = U128::from((0, root));
+    if root_u128 * root_u128 > n {
+        return false;
+    }
+
+    // (u64::max() + 1) ^ 2 is above any U128
+    if root == u64::max() {
+        return true;
+    }
+    let next_u128 = root_u128 + U128::from((0, 1));
+    n < next_u128 * next_u128
+}
+
+fn main() -> bool {
+    // isqrt_u64
+    assert(isqrt_u64(0) == 0);
+    assert(isqrt_u64(1) == 1);
+    assert(isqrt_u64(2) == 1);
+    assert(isqrt_u64(3) == 1);
+    assert(isqrt_u64(4) == 2);
+    assert(isqrt_u64(15) == 3);
+    assert(isqrt_u64(16) == 4);
+    assert(isqrt_u64(17) == 4);
+    assert(isqrt_u64(1000000) == 1000);
+    assert(isqrt_u64(999999) == 999);
+    assert(isqrt_u64(4294967296) == 65536);
+    assert(isqrt_u64(18446744065119617025) == 4294967295);
+    assert(isqrt_u64(18446744065119617024) == 4294967294);
+    assert(isqrt_u64(u64::max()) == 4294967295);
+
+    let values = [0, 1, 2, 3, 8, 9, 10, 99, 100, 101, 123456789, 9223372036854775808, u64::max()];
+    let mut i = 0;
+    while i < 13 {
+        assert(is_floor_root(U128::from((0, values[i])), isqrt_u64(values[i])));
+        i += 1;
+    }
+
+    // isqrt_u128
+    assert(isqrt_u128(U128::from((0, 0))) == 0);
+    assert(isqrt_u128(U128::from((0, 16))) == 4);
+    assert(isqrt_u128(U128::from((1, 0))) == 4294967296);
+    assert(isqrt_u128(U128::from((0, u64::max()))) == 4294967295);
+    assert(isqrt_u128(U128::from((4, 0))) == 8589934592);
+
+    // (2 ^ 64 - 1) ^ 2 = 2 ^ 128 - 2 ^ 65 + 1
+    let max_root_squared = U128::from((18446744073709551614, 1));
+    assert(isqrt_u128(max_root_squared) == u64::max());
+    assert(isqrt_u128(max_root_squared - U128::from((0, 1))) == u64::max() - 1);
+    assert(isqrt_u128(U128::max()) == u64::max());
+
+    let values = [
+        U128::from((1, 0)),
+        U128::from((1, 1)),
+        U128::from((12345, 67890)),
+        U128::from((9223372036854775808, 0)),
+        max_root_squared,
+        U128::max(),
+    ];
+    let mut i = 0;
+    while i < 6 {
+        assert(is_floor_root(values[i], isqrt_u128(values[i])));
+        i += 1;
+    }
+
+    true
+}
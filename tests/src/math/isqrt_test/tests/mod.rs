@@ -0,0 +1,44 @@
+use crate::{benchmark::assert_within_budget, common::script_instance};
+use fuels::prelude::{abigen, WalletUnlocked};
+
+abigen!(Script(
+    name = "TestIsqrt",
+    abi = "src/math/isqrt_test/out/debug/isqrt_test-abi.json"
+),);
+
+const PATH_TO_BIN: &str = "src/math/isqrt_test/out/debug/isqrt_test.bin";
+
+async fn setup() -> TestIsqrt<WalletUnlocked> {
+    script_instance!(TestIsqrt, PATH_TO_BIN)
+}
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn runs_isqrt_test_script() {
+        let instance = setup().await;
+
+        let result = instance.main().call().await.unwrap();
+        assert!(result.value);
+    }
+}
+
+mod benchmarks {
+
+    use super::*;
+
+    // The script computes around forty roots of both widths, including the largest inputs.
+    // This bound has not been measured yet, see `assert_within_budget` for how budgets are set.
+    const BUDGET: u64 = 1_000_000;
+
+    #[tokio::test]
+    async fn isqrt_test_script() {
+        let instance = setup().await;
+
+        let response = instance.main().call().await.unwrap();
+        assert!(response.value);
+        assert_within_budget("isqrt_test script", &response, BUDGET);
+    }
+}
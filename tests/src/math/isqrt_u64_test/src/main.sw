@@ -0,0 +1,7 @@
+script;
+
+use math::isqrt_u64;
+
+fn main(n: u64) -> u64 {
+    isqrt_u64(n)
+}
@@ -0,0 +1,60 @@
+use crate::{benchmark::assert_within_budget, common::script_instance};
+use fuels::prelude::{abigen, WalletUnlocked};
+
+abigen!(Script(
+    name = "TestIsqrtU64",
+    abi = "src/math/isqrt_u64_test/out/debug/isqrt_u64_test-abi.json"
+),);
+
+const PATH_TO_BIN: &str = "src/math/isqrt_u64_test/out/debug/isqrt_u64_test.bin";
+
+async fn setup() -> TestIsqrtU64<WalletUnlocked> {
+    script_instance!(TestIsqrtU64, PATH_TO_BIN)
+}
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn computes_floor_root() {
+        let instance = setup().await;
+
+        for (n, root) in [
+            (0, 0),
+            (1, 1),
+            (15, 3),
+            (16, 4),
+            (17, 4),
+            (1000000, 1000),
+            (u64::MAX, 4294967295),
+        ] {
+            assert_eq!(instance.main(n).call().await.unwrap().value, root);
+        }
+    }
+}
+
+mod benchmarks {
+
+    use super::*;
+
+    // The number of Newton-Raphson iterations grows with the size of the input.
+    // This bound has not been measured yet, see `assert_within_budget` for how budgets are set.
+    const BUDGET: u64 = 20_000;
+
+    #[tokio::test]
+    async fn isqrt_u64_of_small_value() {
+        let instance = setup().await;
+
+        let response = instance.main(17).call().await.unwrap();
+        assert_within_budget("isqrt_u64(17)", &response, BUDGET);
+    }
+
+    #[tokio::test]
+    async fn isqrt_u64_of_max() {
+        let instance = setup().await;
+
+        let response = instance.main(u64::MAX).call().await.unwrap();
+        assert_within_budget("isqrt_u64(u64::MAX)", &response, BUDGET);
+    }
+}
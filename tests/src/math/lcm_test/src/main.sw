@@ -0,0 +1,7 @@
+script;
+
+use math::lcm;
+
+fn main(a: u64, b: u64) -> u64 {
+    lcm(a, b)
+}
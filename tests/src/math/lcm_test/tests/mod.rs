@@ -0,0 +1,80 @@
+use crate::common::script_instance;
+use fuels::prelude::{abigen, WalletUnlocked};
+
+abigen!(Script(
+    name = "TestLcm",
+    abi = "src/math/lcm_test/out/debug/lcm_test-abi.json"
+),);
+
+const PATH_TO_BIN: &str = "src/math/lcm_test/out/debug/lcm_test.bin";
+
+async fn setup() -> TestLcm<WalletUnlocked> {
+    script_instance!(TestLcm, PATH_TO_BIN)
+}
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn computes_lcm_of_coprime_values() {
+        let instance = setup().await;
+
+        for (a, b) in [(1u64, 1u64), (2, 3), (17, 31), (35, 64)] {
+            let result = instance.main(a, b).call().await.unwrap();
+            assert_eq!(result.value, a * b);
+        }
+    }
+
+    #[tokio::test]
+    async fn computes_lcm_of_non_coprime_values() {
+        let instance = setup().await;
+
+        for (a, b, expected) in [
+            (4u64, 6u64, 12u64),
+            (6, 4, 12),
+            (48, 180, 720),
+            (1 << 40, 1 << 20, 1 << 40),
+            (u64::MAX, u64::MAX, u64::MAX),
+            (u64::MAX, 5, u64::MAX),
+        ] {
+            let result = instance.main(a, b).call().await.unwrap();
+            assert_eq!(result.value, expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn computes_lcm_with_zero() {
+        let instance = setup().await;
+
+        let result = instance.main(0, 42).call().await.unwrap();
+        assert_eq!(result.value, 0);
+
+        let result = instance.main(42, 0).call().await.unwrap();
+        assert_eq!(result.value, 0);
+
+        let result = instance.main(0, 0).call().await.unwrap();
+        assert_eq!(result.value, 0);
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "Overflow")]
+    async fn when_result_overflows() {
+        let instance = setup().await;
+
+        instance.main(u64::MAX, u64::MAX - 1).call().await.unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Overflow")]
+    async fn when_large_coprime_values_overflow() {
+        let instance = setup().await;
+
+        instance.main(1 << 32, (1 << 32) + 1).call().await.unwrap();
+    }
+}
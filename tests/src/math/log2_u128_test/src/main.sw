@@ -0,0 +1,8 @@
+script;
+
+use math::log2_u128;
+use std::u128::U128;
+
+fn main(upper: u64, lower: u64) -> u32 {
+    log2_u128(U128::from((upper, lower)))
+}
@@ -0,0 +1,68 @@
+use crate::common::script_instance;
+use fuels::prelude::{abigen, WalletUnlocked};
+
+abigen!(Script(
+    name = "TestLog2U128",
+    abi = "src/math/log2_u128_test/out/debug/log2_u128_test-abi.json"
+),);
+
+const PATH_TO_BIN: &str = "src/math/log2_u128_test/out/debug/log2_u128_test.bin";
+
+async fn setup() -> TestLog2U128<WalletUnlocked> {
+    script_instance!(TestLog2U128, PATH_TO_BIN)
+}
+
+fn split(value: u128) -> (u64, u64) {
+    ((value >> 64) as u64, value as u64)
+}
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn computes_log2_around_powers_of_two() {
+        let instance = setup().await;
+
+        for k in [0u32, 1, 2, 31, 32, 62, 63, 64, 65, 100, 126, 127] {
+            let power = 1u128 << k;
+
+            let (upper, lower) = split(power);
+            let result = instance.main(upper, lower).call().await.unwrap();
+            assert_eq!(result.value, k);
+
+            if k > 1 {
+                let (upper, lower) = split(power - 1);
+                let result = instance.main(upper, lower).call().await.unwrap();
+                assert_eq!(result.value, k - 1);
+            }
+
+            if k > 0 {
+                let (upper, lower) = split(power + 1);
+                let result = instance.main(upper, lower).call().await.unwrap();
+                assert_eq!(result.value, k);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn computes_log2_of_max() {
+        let instance = setup().await;
+
+        let result = instance.main(u64::MAX, u64::MAX).call().await.unwrap();
+        assert_eq!(result.value, 127);
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "Undefined")]
+    async fn when_zero() {
+        let instance = setup().await;
+
+        instance.main(0, 0).call().await.unwrap();
+    }
+}
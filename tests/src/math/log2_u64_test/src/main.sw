@@ -0,0 +1,7 @@
+script;
+
+use math::log2_u64;
+
+fn main(n: u64) -> u32 {
+    log2_u64(n)
+}
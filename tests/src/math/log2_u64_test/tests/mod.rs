@@ -0,0 +1,61 @@
+use crate::common::script_instance;
+use fuels::prelude::{abigen, WalletUnlocked};
+
+abigen!(Script(
+    name = "TestLog2U64",
+    abi = "src/math/log2_u64_test/out/debug/log2_u64_test-abi.json"
+),);
+
+const PATH_TO_BIN: &str = "src/math/log2_u64_test/out/debug/log2_u64_test.bin";
+
+async fn setup() -> TestLog2U64<WalletUnlocked> {
+    script_instance!(TestLog2U64, PATH_TO_BIN)
+}
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn computes_log2_around_powers_of_two() {
+        let instance = setup().await;
+
+        for k in 0..64u32 {
+            let power = 1u64 << k;
+
+            let result = instance.main(power).call().await.unwrap();
+            assert_eq!(result.value, k);
+
+            if k > 1 {
+                let result = instance.main(power - 1).call().await.unwrap();
+                assert_eq!(result.value, k - 1);
+            }
+
+            if k > 0 {
+                let result = instance.main(power + 1).call().await.unwrap();
+                assert_eq!(result.value, k);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn computes_log2_of_max() {
+        let instance = setup().await;
+
+        let result = instance.main(u64::MAX).call().await.unwrap();
+        assert_eq!(result.value, 63);
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "Undefined")]
+    async fn when_zero() {
+        let instance = setup().await;
+
+        instance.main(0).call().await.unwrap();
+    }
+}
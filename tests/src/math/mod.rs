@@ -0,0 +1,16 @@
+mod bits_u32_test;
+mod bits_u64_test;
+mod bps_test;
+mod bps_u128_test;
+mod checked_pow_test;
+mod clamp_reverts;
+mod clamp_test;
+mod div_reverts;
+mod div_test;
+mod gcd_test;
+mod isqrt_test;
+mod isqrt_u64_test;
+mod lcm_test;
+mod log2_u128_test;
+mod log2_u64_test;
+mod pow_test;
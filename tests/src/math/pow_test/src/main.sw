@@ -0,0 +1,7 @@
+script;
+
+use math::pow_u64;
+
+fn main(base: u64, exponent: u32) -> u64 {
+    pow_u64(base, exponent)
+}
@@ -0,0 +1,77 @@
+use crate::common::script_instance;
+use fuels::prelude::{abigen, WalletUnlocked};
+
+abigen!(Script(
+    name = "TestPow",
+    abi = "src/math/pow_test/out/debug/pow_test-abi.json"
+),);
+
+const PATH_TO_BIN: &str = "src/math/pow_test/out/debug/pow_test.bin";
+
+async fn setup() -> TestPow<WalletUnlocked> {
+    script_instance!(TestPow, PATH_TO_BIN)
+}
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn raises_small_values() {
+        let instance = setup().await;
+
+        for (base, exponent) in [(2u64, 10u32), (3, 4), (5, 3), (7, 7), (10, 19)] {
+            let result = instance.main(base, exponent).call().await.unwrap();
+            assert_eq!(result.value, base.pow(exponent));
+        }
+    }
+
+    #[tokio::test]
+    async fn handles_zero() {
+        let instance = setup().await;
+
+        let result = instance.main(0, 0).call().await.unwrap();
+        assert_eq!(result.value, 1);
+
+        let result = instance.main(12345, 0).call().await.unwrap();
+        assert_eq!(result.value, 1);
+
+        let result = instance.main(0, 5).call().await.unwrap();
+        assert_eq!(result.value, 0);
+    }
+
+    #[tokio::test]
+    async fn raises_to_largest_values() {
+        let instance = setup().await;
+
+        let result = instance.main(2, 63).call().await.unwrap();
+        assert_eq!(result.value, 1 << 63);
+
+        let result = instance.main(u32::MAX as u64, 2).call().await.unwrap();
+        assert_eq!(result.value, (u32::MAX as u64).pow(2));
+
+        let result = instance.main(u64::MAX, 1).call().await.unwrap();
+        assert_eq!(result.value, u64::MAX);
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "Overflow")]
+    async fn when_result_overflows() {
+        let instance = setup().await;
+
+        instance.main(2, 64).call().await.unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Overflow")]
+    async fn when_large_base_overflows() {
+        let instance = setup().await;
+
+        instance.main(1 << 32, 2).call().await.unwrap();
+    }
+}
@@ -1,12 +1,18 @@
 contract;
 
-use merkle_proof::binary_merkle_proof::{leaf_digest, node_digest, process_proof, verify_proof};
+use merkle_proof::{
+    binary_merkle_proof::{leaf_digest, node_digest, process_proof, verify_proof},
+    sparse_merkle::{root, verify_membership, verify_non_membership},
+};
 
 abi MerkleProofTest {
     fn leaf_digest(data: b256) -> b256;
     fn node_digest(left: b256, right: b256) -> b256;
     fn process_proof(key: u64, merkle_leaf: b256, num_leaves: u64, proof: Vec<b256>) -> b256;
     fn verify_proof(key: u64, merkle_leaf: b256, merkle_root: b256, num_leaves: u64, proof: Vec<b256>) -> bool;
+    fn root(leaf_key: b256, leaf_value: b256, proof: Vec<(b256, bool)>) -> b256;
+    fn verify_membership(root: b256, key: b256, value: b256, proof: Vec<(b256, bool)>) -> bool;
+    fn verify_non_membership(root: b256, key: b256, proof: Vec<(b256, bool)>) -> bool;
 }
 
 impl MerkleProofTest for Contract {
@@ -36,4 +42,21 @@ impl MerkleProofTest for Contract {
     ) -> bool {
         verify_proof(key, merkle_leaf, merkle_root, num_leaves, proof)
     }
+
+    fn root(leaf_key: b256, leaf_value: b256, proof: Vec<(b256, bool)>) -> b256 {
+        root(leaf_key, leaf_value, proof)
+    }
+
+    fn verify_membership(
+        root: b256,
+        key: b256,
+        value: b256,
+        proof: Vec<(b256, bool)>,
+    ) -> bool {
+        verify_membership(root, key, value, proof)
+    }
+
+    fn verify_non_membership(root: b256, key: b256, proof: Vec<(b256, bool)>) -> bool {
+        verify_non_membership(root, key, proof)
+    }
 }
@@ -1,4 +1,7 @@
 mod leaf_digest;
 mod node_digest;
 mod process_proof;
+mod root;
+mod verify_membership;
+mod verify_non_membership;
 mod verify_proof;
@@ -0,0 +1,81 @@
+use crate::merkle_proof::tests::utils::{
+    abi_calls::root,
+    test_helpers::{build_sparse_tree, merkle_proof_instance, sparse_leaves},
+};
+use fuels::types::Bits256;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn computes_root_of_single_leaf() {
+        let instance = merkle_proof_instance().await;
+
+        let leaves = vec![([7u8; 32], [9u8; 32])];
+        let (expected_root, proof) = build_sparse_tree(&leaves, leaves[0].0);
+
+        assert_eq!(
+            root(&instance, Bits256(leaves[0].0), Bits256(leaves[0].1), proof).await,
+            expected_root
+        );
+    }
+
+    #[tokio::test]
+    async fn computes_root_of_many_leaves() {
+        let instance = merkle_proof_instance().await;
+
+        let leaves = sparse_leaves();
+
+        for (key, value) in leaves.iter() {
+            let (expected_root, proof) = build_sparse_tree(&leaves, *key);
+
+            assert_eq!(
+                root(&instance, Bits256(*key), Bits256(*value), proof).await,
+                expected_root
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn computes_different_root_for_different_value() {
+        let instance = merkle_proof_instance().await;
+
+        let leaves = sparse_leaves();
+        let (expected_root, proof) = build_sparse_tree(&leaves, leaves[0].0);
+
+        assert_ne!(
+            root(&instance, Bits256(leaves[0].0), Bits256([0u8; 32]), proof).await,
+            expected_root
+        );
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidProofLength")]
+    async fn when_proof_is_too_short() {
+        let instance = merkle_proof_instance().await;
+
+        let leaves = sparse_leaves();
+        let (_root, mut proof) = build_sparse_tree(&leaves, leaves[0].0);
+        proof.pop();
+
+        root(&instance, Bits256(leaves[0].0), Bits256(leaves[0].1), proof).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidPath")]
+    async fn when_proof_is_for_another_path() {
+        let instance = merkle_proof_instance().await;
+
+        let leaves = sparse_leaves();
+        // The keys 0xAA..AA and 0x01..01 take different sides at the root
+        let (_root, proof) = build_sparse_tree(&leaves, leaves[2].0);
+
+        root(&instance, Bits256(leaves[0].0), Bits256(leaves[0].1), proof).await;
+    }
+}
@@ -0,0 +1,96 @@
+use crate::merkle_proof::tests::utils::{
+    abi_calls::verify_membership,
+    test_helpers::{build_sparse_tree, merkle_proof_instance, sparse_leaves},
+};
+use fuels::types::Bits256;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn verifies_set_leaf() {
+        let instance = merkle_proof_instance().await;
+
+        let leaves = sparse_leaves();
+
+        for (key, value) in leaves.iter() {
+            let (root, proof) = build_sparse_tree(&leaves, *key);
+
+            assert!(
+                verify_membership(&instance, root, Bits256(*key), Bits256(*value), proof).await
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn fails_for_wrong_value() {
+        let instance = merkle_proof_instance().await;
+
+        let leaves = sparse_leaves();
+        let (root, proof) = build_sparse_tree(&leaves, leaves[0].0);
+
+        assert!(
+            !verify_membership(
+                &instance,
+                root,
+                Bits256(leaves[0].0),
+                Bits256(leaves[1].1),
+                proof
+            )
+            .await
+        );
+    }
+
+    #[tokio::test]
+    async fn fails_for_unset_leaf() {
+        let instance = merkle_proof_instance().await;
+
+        let leaves = sparse_leaves();
+        let key = [0x55u8; 32];
+        let (root, proof) = build_sparse_tree(&leaves, key);
+
+        assert!(!verify_membership(&instance, root, Bits256(key), Bits256([1u8; 32]), proof).await);
+    }
+
+    #[tokio::test]
+    async fn fails_for_wrong_root() {
+        let instance = merkle_proof_instance().await;
+
+        let leaves = sparse_leaves();
+        let (root, proof) = build_sparse_tree(&leaves, leaves[0].0);
+        let mut tampered_root = root;
+        tampered_root.0[0] ^= 1;
+
+        assert!(
+            !verify_membership(
+                &instance,
+                tampered_root,
+                Bits256(leaves[0].0),
+                Bits256(leaves[0].1),
+                proof
+            )
+            .await
+        );
+    }
+
+    #[tokio::test]
+    async fn fails_for_proof_that_is_too_long() {
+        let instance = merkle_proof_instance().await;
+
+        let leaves = sparse_leaves();
+        let (root, mut proof) = build_sparse_tree(&leaves, leaves[0].0);
+        proof.push((Bits256([0u8; 32]), false));
+
+        assert!(
+            !verify_membership(
+                &instance,
+                root,
+                Bits256(leaves[0].0),
+                Bits256(leaves[0].1),
+                proof
+            )
+            .await
+        );
+    }
+}
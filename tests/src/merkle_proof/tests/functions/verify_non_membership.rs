@@ -0,0 +1,68 @@
+use crate::merkle_proof::tests::utils::{
+    abi_calls::verify_non_membership,
+    test_helpers::{build_sparse_tree, merkle_proof_instance, sparse_leaves},
+};
+use fuels::types::Bits256;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn verifies_unset_leaf() {
+        let instance = merkle_proof_instance().await;
+
+        let leaves = sparse_leaves();
+        let key = [0x55u8; 32];
+        let (root, proof) = build_sparse_tree(&leaves, key);
+
+        assert!(verify_non_membership(&instance, root, Bits256(key), proof).await);
+    }
+
+    #[tokio::test]
+    async fn verifies_unset_leaf_sharing_path() {
+        let instance = merkle_proof_instance().await;
+
+        let leaves = sparse_leaves();
+        // Only differs from the set keys 0xAA..AA and 0xAA..AB in the last byte
+        let mut key = [0xAAu8; 32];
+        key[31] = 0xAC;
+        let (root, proof) = build_sparse_tree(&leaves, key);
+
+        assert!(verify_non_membership(&instance, root, Bits256(key), proof).await);
+    }
+
+    #[tokio::test]
+    async fn verifies_leaf_of_empty_tree() {
+        let instance = merkle_proof_instance().await;
+
+        let key = [0x55u8; 32];
+        let (root, proof) = build_sparse_tree(&[], key);
+
+        assert_eq!(root, Bits256([0u8; 32]));
+        assert!(verify_non_membership(&instance, root, Bits256(key), proof).await);
+    }
+
+    #[tokio::test]
+    async fn fails_for_set_leaf() {
+        let instance = merkle_proof_instance().await;
+
+        let leaves = sparse_leaves();
+
+        for (key, _value) in leaves.iter() {
+            let (root, proof) = build_sparse_tree(&leaves, *key);
+
+            assert!(!verify_non_membership(&instance, root, Bits256(*key), proof).await);
+        }
+    }
+
+    #[tokio::test]
+    async fn fails_for_proof_of_another_path() {
+        let instance = merkle_proof_instance().await;
+
+        let leaves = sparse_leaves();
+        let (root, proof) = build_sparse_tree(&leaves, [0x55u8; 32]);
+
+        assert!(!verify_non_membership(&instance, root, Bits256([0xAAu8; 32]), proof).await);
+    }
+}
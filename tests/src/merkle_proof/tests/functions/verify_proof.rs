@@ -1,6 +1,9 @@
-use crate::merkle_proof::tests::utils::{
-    abi_calls::verify_proof,
-    test_helpers::{build_tree, leaves_with_depth, merkle_proof_instance},
+use crate::{
+    benchmark::assert_within_budget,
+    merkle_proof::tests::utils::{
+        abi_calls::verify_proof,
+        test_helpers::{build_tree, leaves_with_depth, merkle_proof_instance},
+    },
 };
 
 mod success {
@@ -23,6 +26,61 @@ mod success {
         );
     }
 
+    #[tokio::test]
+    async fn fails_merkle_proof_verification_with_tampered_leaf() {
+        let instance = merkle_proof_instance().await;
+
+        let depth = 8;
+        let leaves = leaves_with_depth(depth).await;
+        let key = 0;
+
+        let (_tree, root, leaf, proof) = build_tree(leaves.clone(), key).await;
+
+        let mut tampered_leaf = leaf;
+        tampered_leaf.0[0] ^= 1;
+
+        assert_eq!(
+            verify_proof(
+                &instance,
+                key,
+                tampered_leaf,
+                root,
+                leaves.len() as u64,
+                proof
+            )
+            .await,
+            false
+        );
+    }
+
+    #[tokio::test]
+    async fn fails_merkle_proof_verification_with_tampered_proof() {
+        let instance = merkle_proof_instance().await;
+
+        let depth = 8;
+        let leaves = leaves_with_depth(depth).await;
+        let key = 0;
+
+        let (_tree, root, leaf, proof) = build_tree(leaves.clone(), key).await;
+
+        let mut tampered_proof = proof.clone();
+        let last = tampered_proof.len() - 1;
+        tampered_proof[last].0[31] ^= 1;
+
+        assert_eq!(
+            verify_proof(
+                &instance,
+                key,
+                leaf,
+                root,
+                leaves.len() as u64,
+                tampered_proof
+            )
+            .await,
+            false
+        );
+    }
+
     #[tokio::test]
     async fn verifies_merkle_proof() {
         let instance = merkle_proof_instance().await;
@@ -39,3 +97,36 @@ mod success {
         );
     }
 }
+
+mod benchmarks {
+
+    use super::*;
+
+    // Each level of the tree costs a node hash, so the budget scales with the depth
+    // This bound has not been measured yet, see `assert_within_budget` for how budgets are set
+    const BUDGET_PER_LEVEL: u64 = 10_000;
+
+    #[tokio::test]
+    async fn verify_proof_with_depth_8() {
+        let instance = merkle_proof_instance().await;
+
+        let depth = 8;
+        let leaves = leaves_with_depth(depth).await;
+        let key = 0;
+
+        let (_tree, root, leaf, proof) = build_tree(leaves.clone(), key).await;
+
+        let response = instance
+            .methods()
+            .verify_proof(key, leaf, root, leaves.len() as u64, proof)
+            .call()
+            .await
+            .unwrap();
+        assert!(response.value);
+        assert_within_budget(
+            "verify_proof with a depth of 8",
+            &response,
+            BUDGET_PER_LEVEL * depth as u64,
+        );
+    }
+}
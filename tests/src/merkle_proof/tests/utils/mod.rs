@@ -1,12 +1,10 @@
+use crate::common::{deploy_contract, get_wallet};
 use fuel_merkle::{
     binary::in_memory::MerkleTree,
     common::{empty_sum_sha256, Bytes32},
 };
 use fuels::{
-    prelude::{
-        abigen, launch_provider_and_get_wallet, Contract, LoadConfiguration, TxParameters,
-        WalletUnlocked,
-    },
+    prelude::{abigen, LoadConfiguration, WalletUnlocked},
     types::Bits256,
 };
 use sha2::{Digest, Sha256};
@@ -18,6 +16,7 @@ abigen!(Contract(
 
 pub const NODE: u8 = 0x01;
 pub const LEAF: u8 = 0x00;
+pub const SPARSE_DEPTH: usize = 256;
 
 pub mod abi_calls {
 
@@ -66,6 +65,52 @@ pub mod abi_calls {
             .value
     }
 
+    pub async fn root(
+        contract: &TestMerkleProofLib<WalletUnlocked>,
+        leaf_key: Bits256,
+        leaf_value: Bits256,
+        proof: Vec<(Bits256, bool)>,
+    ) -> Bits256 {
+        contract
+            .methods()
+            .root(leaf_key, leaf_value, proof)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn verify_membership(
+        contract: &TestMerkleProofLib<WalletUnlocked>,
+        root: Bits256,
+        key: Bits256,
+        value: Bits256,
+        proof: Vec<(Bits256, bool)>,
+    ) -> bool {
+        contract
+            .methods()
+            .verify_membership(root, key, value, proof)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn verify_non_membership(
+        contract: &TestMerkleProofLib<WalletUnlocked>,
+        root: Bits256,
+        key: Bits256,
+        proof: Vec<(Bits256, bool)>,
+    ) -> bool {
+        contract
+            .methods()
+            .verify_non_membership(root, key, proof)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
     pub async fn verify_proof(
         contract: &TestMerkleProofLib<WalletUnlocked>,
         key: u64,
@@ -230,6 +275,89 @@ pub mod test_helpers {
         )
     }
 
+    pub fn sparse_leaf_digest(key: &Bytes32, value: &Bytes32) -> Bytes32 {
+        let mut hasher = Sha256::new();
+        hasher.update(&[LEAF]);
+        hasher.update(key);
+        hasher.update(value);
+        hasher.finalize().try_into().unwrap()
+    }
+
+    pub fn sparse_node_digest(left: &Bytes32, right: &Bytes32) -> Bytes32 {
+        // Subtrees without any leaves are zero at every height
+        if *left == [0u8; 32] && *right == [0u8; 32] {
+            return [0u8; 32];
+        }
+
+        let mut hasher = Sha256::new();
+        hasher.update(&[NODE]);
+        hasher.update(left);
+        hasher.update(right);
+        hasher.finalize().try_into().unwrap()
+    }
+
+    /// Returns whether the path of `key` goes right at `depth`, counting from the root.
+    fn goes_right(key: &Bytes32, depth: usize) -> bool {
+        (key[depth / 8] >> (7 - depth % 8)) & 1 == 1
+    }
+
+    /// Returns the hash of the subtree at `depth` which holds all of `leaves`.
+    fn sparse_subtree(leaves: &[(Bytes32, Bytes32)], depth: usize) -> Bytes32 {
+        if leaves.is_empty() {
+            return [0u8; 32];
+        }
+        if depth == SPARSE_DEPTH {
+            return sparse_leaf_digest(&leaves[0].0, &leaves[0].1);
+        }
+
+        let (right, left): (Vec<_>, Vec<_>) = leaves
+            .iter()
+            .cloned()
+            .partition(|(key, _)| goes_right(key, depth));
+
+        sparse_node_digest(
+            &sparse_subtree(&left, depth + 1),
+            &sparse_subtree(&right, depth + 1),
+        )
+    }
+
+    /// Builds a Sparse Merkle Tree of depth 256 from `leaves` and returns its root with the
+    /// proof of `key`, which is a non-membership proof when `key` is not one of the leaves.
+    pub fn build_sparse_tree(
+        leaves: &[(Bytes32, Bytes32)],
+        key: Bytes32,
+    ) -> (Bits256, Vec<(Bits256, bool)>) {
+        let root = sparse_subtree(leaves, 0);
+
+        let mut proof: Vec<(Bits256, bool)> = Vec::new();
+        let mut path: Vec<(Bytes32, Bytes32)> = leaves.to_vec();
+        for depth in 0..SPARSE_DEPTH {
+            let right = goes_right(&key, depth);
+            let (on_path, sibling): (Vec<_>, Vec<_>) = path
+                .into_iter()
+                .partition(|(leaf_key, _)| goes_right(leaf_key, depth) == right);
+
+            // A sibling is on the left when the path goes right
+            proof.push((Bits256(sparse_subtree(&sibling, depth + 1)), right));
+            path = on_path;
+        }
+        proof.reverse();
+
+        (Bits256(root), proof)
+    }
+
+    pub fn sparse_leaves() -> Vec<(Bytes32, Bytes32)> {
+        let mut sharing_prefix = [0xAAu8; 32];
+        sharing_prefix[31] = 0xAB;
+
+        vec![
+            ([0xAAu8; 32], [1u8; 32]),
+            (sharing_prefix, [2u8; 32]),
+            ([0x01u8; 32], [3u8; 32]),
+            ([0xFFu8; 32], [4u8; 32]),
+        ]
+    }
+
     pub async fn leaves_with_depth(depth: u32) -> Vec<[u8; 1]> {
         let num_elements_in_tree = 2_i32.pow(depth);
         let mut return_vec: Vec<[u8; 1]> = Vec::new();
@@ -243,16 +371,14 @@ pub mod test_helpers {
     }
 
     pub async fn merkle_proof_instance() -> TestMerkleProofLib<WalletUnlocked> {
-        let wallet = launch_provider_and_get_wallet().await;
+        let wallet = get_wallet().await;
 
-        let contract_id = Contract::load_from(
+        let contract_id = deploy_contract(
+            &wallet,
             "./src/merkle_proof/out/debug/merkle_proof_test.bin",
             LoadConfiguration::default(),
         )
-        .unwrap()
-        .deploy(&wallet, TxParameters::default())
-        .await
-        .unwrap();
+        .await;
 
         let instance = TestMerkleProofLib::new(contract_id.clone(), wallet.clone());
 
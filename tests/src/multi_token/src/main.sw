@@ -0,0 +1,40 @@
+contract;
+
+use multi_token::Ledger;
+
+storage {
+    ledger: Ledger = Ledger {},
+}
+
+abi MultiTokenTest {
+    #[storage(read)]
+    fn balance_of(owner: Identity, asset: AssetId) -> u64;
+    #[storage(read, write)]
+    fn burn_from(from: Identity, asset: AssetId, amount: u64);
+    #[storage(read, write)]
+    fn mint_to(to: Identity, asset: AssetId, amount: u64);
+    #[storage(read, write)]
+    fn transfer(from: Identity, to: Identity, asset: AssetId, amount: u64);
+}
+
+impl MultiTokenTest for Contract {
+    #[storage(read)]
+    fn balance_of(owner: Identity, asset: AssetId) -> u64 {
+        storage.ledger.balance_of(owner, asset)
+    }
+
+    #[storage(read, write)]
+    fn burn_from(from: Identity, asset: AssetId, amount: u64) {
+        storage.ledger.burn_from(from, asset, amount);
+    }
+
+    #[storage(read, write)]
+    fn mint_to(to: Identity, asset: AssetId, amount: u64) {
+        storage.ledger.mint_to(to, asset, amount);
+    }
+
+    #[storage(read, write)]
+    fn transfer(from: Identity, to: Identity, asset: AssetId, amount: u64) {
+        storage.ledger.transfer(from, to, asset, amount);
+    }
+}
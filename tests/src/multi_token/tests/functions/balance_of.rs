@@ -0,0 +1,30 @@
+use crate::multi_token::tests::utils::{
+    abi_calls::{balance_of, mint_to},
+    test_helpers::{asset, identity, setup},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_zero_when_never_minted() {
+        let instance = setup().await;
+
+        assert_eq!(balance_of(&instance, identity(1), asset(1)).await, 0);
+    }
+
+    #[tokio::test]
+    async fn keeps_assets_and_owners_apart() {
+        let instance = setup().await;
+
+        mint_to(&instance, identity(1), asset(1), 10).await;
+        mint_to(&instance, identity(1), asset(2), 20).await;
+        mint_to(&instance, identity(2), asset(1), 30).await;
+
+        assert_eq!(balance_of(&instance, identity(1), asset(1)).await, 10);
+        assert_eq!(balance_of(&instance, identity(1), asset(2)).await, 20);
+        assert_eq!(balance_of(&instance, identity(2), asset(1)).await, 30);
+        assert_eq!(balance_of(&instance, identity(2), asset(2)).await, 0);
+    }
+}
@@ -0,0 +1,64 @@
+use crate::multi_token::tests::utils::{
+    abi_calls::{balance_of, burn_from, mint_to},
+    test_helpers::{asset, identity, setup},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn burns() {
+        let instance = setup().await;
+
+        mint_to(&instance, identity(1), asset(1), 10).await;
+        burn_from(&instance, identity(1), asset(1), 4).await;
+
+        assert_eq!(balance_of(&instance, identity(1), asset(1)).await, 6);
+    }
+
+    #[tokio::test]
+    async fn burns_entire_balance() {
+        let instance = setup().await;
+
+        mint_to(&instance, identity(1), asset(1), 10).await;
+        burn_from(&instance, identity(1), asset(1), 10).await;
+
+        assert_eq!(balance_of(&instance, identity(1), asset(1)).await, 0);
+    }
+
+    #[tokio::test]
+    async fn burns_only_the_given_asset() {
+        let instance = setup().await;
+
+        mint_to(&instance, identity(1), asset(1), 10).await;
+        mint_to(&instance, identity(1), asset(2), 20).await;
+        burn_from(&instance, identity(1), asset(2), 5).await;
+
+        assert_eq!(balance_of(&instance, identity(1), asset(1)).await, 10);
+        assert_eq!(balance_of(&instance, identity(1), asset(2)).await, 15);
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "InsufficientBalance")]
+    async fn when_balance_is_insufficient() {
+        let instance = setup().await;
+
+        mint_to(&instance, identity(1), asset(1), 10).await;
+        burn_from(&instance, identity(1), asset(1), 11).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InsufficientBalance")]
+    async fn when_only_another_asset_is_held() {
+        let instance = setup().await;
+
+        mint_to(&instance, identity(1), asset(1), 10).await;
+        burn_from(&instance, identity(1), asset(2), 1).await;
+    }
+}
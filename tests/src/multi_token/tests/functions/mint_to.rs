@@ -0,0 +1,63 @@
+use crate::multi_token::tests::utils::{
+    abi_calls::{balance_of, mint_to},
+    test_helpers::{asset, identity, setup},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn mints() {
+        let instance = setup().await;
+
+        mint_to(&instance, identity(1), asset(1), 10).await;
+
+        assert_eq!(balance_of(&instance, identity(1), asset(1)).await, 10);
+    }
+
+    #[tokio::test]
+    async fn mints_onto_existing_balance() {
+        let instance = setup().await;
+
+        mint_to(&instance, identity(1), asset(1), 10).await;
+        mint_to(&instance, identity(1), asset(1), 5).await;
+
+        assert_eq!(balance_of(&instance, identity(1), asset(1)).await, 15);
+    }
+
+    #[tokio::test]
+    async fn mints_two_assets() {
+        let instance = setup().await;
+
+        mint_to(&instance, identity(1), asset(1), 10).await;
+        mint_to(&instance, identity(1), asset(2), 20).await;
+
+        assert_eq!(balance_of(&instance, identity(1), asset(1)).await, 10);
+        assert_eq!(balance_of(&instance, identity(1), asset(2)).await, 20);
+    }
+
+    #[tokio::test]
+    async fn mints_up_to_max() {
+        let instance = setup().await;
+
+        mint_to(&instance, identity(1), asset(1), u64::MAX - 1).await;
+        mint_to(&instance, identity(1), asset(1), 1).await;
+
+        assert_eq!(balance_of(&instance, identity(1), asset(1)).await, u64::MAX);
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "Overflow")]
+    async fn when_balance_overflows() {
+        let instance = setup().await;
+
+        mint_to(&instance, identity(1), asset(1), u64::MAX).await;
+        mint_to(&instance, identity(1), asset(1), 1).await;
+    }
+}
@@ -0,0 +1,4 @@
+mod balance_of;
+mod burn_from;
+mod mint_to;
+mod transfer;
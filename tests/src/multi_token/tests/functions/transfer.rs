@@ -0,0 +1,87 @@
+use crate::multi_token::tests::utils::{
+    abi_calls::{balance_of, mint_to, transfer},
+    test_helpers::{asset, identity, setup},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn transfers() {
+        let instance = setup().await;
+
+        mint_to(&instance, identity(1), asset(1), 10).await;
+        transfer(&instance, identity(1), identity(2), asset(1), 4).await;
+
+        assert_eq!(balance_of(&instance, identity(1), asset(1)).await, 6);
+        assert_eq!(balance_of(&instance, identity(2), asset(1)).await, 4);
+    }
+
+    #[tokio::test]
+    async fn transfers_only_the_given_asset() {
+        let instance = setup().await;
+
+        mint_to(&instance, identity(1), asset(1), 10).await;
+        mint_to(&instance, identity(1), asset(2), 20).await;
+        transfer(&instance, identity(1), identity(2), asset(2), 20).await;
+
+        assert_eq!(balance_of(&instance, identity(1), asset(1)).await, 10);
+        assert_eq!(balance_of(&instance, identity(1), asset(2)).await, 0);
+        assert_eq!(balance_of(&instance, identity(2), asset(1)).await, 0);
+        assert_eq!(balance_of(&instance, identity(2), asset(2)).await, 20);
+    }
+
+    #[tokio::test]
+    async fn transfers_to_self() {
+        let instance = setup().await;
+
+        mint_to(&instance, identity(1), asset(1), 10).await;
+        transfer(&instance, identity(1), identity(1), asset(1), 10).await;
+
+        assert_eq!(balance_of(&instance, identity(1), asset(1)).await, 10);
+    }
+
+    #[tokio::test]
+    async fn transfers_zero() {
+        let instance = setup().await;
+
+        transfer(&instance, identity(1), identity(2), asset(1), 0).await;
+
+        assert_eq!(balance_of(&instance, identity(1), asset(1)).await, 0);
+        assert_eq!(balance_of(&instance, identity(2), asset(1)).await, 0);
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "InsufficientBalance")]
+    async fn when_balance_is_insufficient() {
+        let instance = setup().await;
+
+        mint_to(&instance, identity(1), asset(1), 10).await;
+        transfer(&instance, identity(1), identity(2), asset(1), 11).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InsufficientBalance")]
+    async fn when_only_another_asset_is_held() {
+        let instance = setup().await;
+
+        mint_to(&instance, identity(1), asset(1), 10).await;
+        transfer(&instance, identity(1), identity(2), asset(2), 1).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Overflow")]
+    async fn when_recipient_balance_overflows() {
+        let instance = setup().await;
+
+        mint_to(&instance, identity(1), asset(1), 1).await;
+        mint_to(&instance, identity(2), asset(1), u64::MAX).await;
+        transfer(&instance, identity(1), identity(2), asset(1), 1).await;
+    }
+}
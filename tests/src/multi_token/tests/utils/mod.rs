@@ -0,0 +1,101 @@
+use crate::common::{deploy_contract, get_wallet};
+use fuels::{
+    prelude::{abigen, Address, AssetId, LoadConfiguration, StorageConfiguration, WalletUnlocked},
+    types::Identity,
+};
+
+abigen!(Contract(
+    name = "MultiTokenLib",
+    abi = "src/multi_token/out/debug/multi_token_test-abi.json"
+));
+
+pub mod abi_calls {
+
+    use super::*;
+
+    pub async fn balance_of(
+        contract: &MultiTokenLib<WalletUnlocked>,
+        owner: Identity,
+        asset: AssetId,
+    ) -> u64 {
+        contract
+            .methods()
+            .balance_of(owner, asset)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn burn_from(
+        contract: &MultiTokenLib<WalletUnlocked>,
+        from: Identity,
+        asset: AssetId,
+        amount: u64,
+    ) {
+        contract
+            .methods()
+            .burn_from(from, asset, amount)
+            .call()
+            .await
+            .unwrap();
+    }
+
+    pub async fn mint_to(
+        contract: &MultiTokenLib<WalletUnlocked>,
+        to: Identity,
+        asset: AssetId,
+        amount: u64,
+    ) {
+        contract
+            .methods()
+            .mint_to(to, asset, amount)
+            .call()
+            .await
+            .unwrap();
+    }
+
+    pub async fn transfer(
+        contract: &MultiTokenLib<WalletUnlocked>,
+        from: Identity,
+        to: Identity,
+        asset: AssetId,
+        amount: u64,
+    ) {
+        contract
+            .methods()
+            .transfer(from, to, asset, amount)
+            .call()
+            .await
+            .unwrap();
+    }
+}
+
+pub mod test_helpers {
+
+    use super::*;
+
+    pub fn asset(byte: u8) -> AssetId {
+        AssetId::new([byte; 32])
+    }
+
+    pub fn identity(byte: u8) -> Identity {
+        Identity::Address(Address::new([byte; 32]))
+    }
+
+    pub async fn setup() -> MultiTokenLib<WalletUnlocked> {
+        let wallet = get_wallet().await;
+
+        let storage_configuration = StorageConfiguration::load_from(
+            "src/multi_token/out/debug/multi_token_test-storage_slots.json",
+        );
+        let id = deploy_contract(
+            &wallet,
+            "src/multi_token/out/debug/multi_token_test.bin",
+            LoadConfiguration::default().set_storage_configuration(storage_configuration.unwrap()),
+        )
+        .await;
+
+        MultiTokenLib::new(id, wallet)
+    }
+}
@@ -0,0 +1,107 @@
+contract;
+
+use nft::{*, nft_metadata::*};
+use ownership::*;
+use src_5::Ownership;
+use std::{storage::storage_string::*, string::String};
+
+storage {
+    owner: Ownership = Ownership::uninitialized(),
+    balances: StorageMap<Identity, u64> = StorageMap {},
+    owners: StorageMap<u64, Identity> = StorageMap {},
+    base_uri: StorageString = StorageString {},
+    contract_uri: StorageString = StorageString {},
+    name: StorageString = StorageString {},
+    symbol: StorageString = StorageString {},
+}
+
+abi NFTTest {
+    #[storage(read)]
+    fn balance_of(owner: Identity) -> u64;
+    #[storage(read, write)]
+    fn batch_mint(to: Identity, start_id: u64, count: u64);
+    #[storage(read, write)]
+    fn batch_transfer(from: Identity, to: Identity, token_ids: Vec<u64>);
+    #[storage(read)]
+    fn owner_of(token_id: u64) -> Option<Identity>;
+    #[storage(read, write)]
+    fn set_base_uri(base: String);
+    #[storage(read, write)]
+    fn set_contract_uri(uri: String);
+    #[storage(read, write)]
+    fn set_name(name: String);
+    #[storage(read, write)]
+    fn set_ownership(new_owner: Identity);
+    #[storage(read, write)]
+    fn set_symbol(symbol: String);
+}
+
+impl NFTMetadata for Contract {
+    #[storage(read)]
+    fn name() -> String {
+        _name(storage.name)
+    }
+
+    #[storage(read)]
+    fn symbol() -> String {
+        _symbol(storage.symbol)
+    }
+
+    #[storage(read)]
+    fn token_uri(token_id: u64) -> String {
+        _token_uri(storage.base_uri, token_id)
+    }
+
+    #[storage(read)]
+    fn contract_uri() -> String {
+        _contract_uri(storage.contract_uri)
+    }
+}
+
+impl NFTTest for Contract {
+    #[storage(read)]
+    fn balance_of(owner: Identity) -> u64 {
+        _balance_of(storage.balances, owner)
+    }
+
+    #[storage(read, write)]
+    fn batch_mint(to: Identity, start_id: u64, count: u64) {
+        storage.owner.only_owner();
+        _batch_mint(storage.owners, storage.balances, to, start_id, count);
+    }
+
+    #[storage(read, write)]
+    fn batch_transfer(from: Identity, to: Identity, token_ids: Vec<u64>) {
+        _batch_transfer(storage.owners, storage.balances, from, to, token_ids);
+    }
+
+    #[storage(read)]
+    fn owner_of(token_id: u64) -> Option<Identity> {
+        _owner_of(storage.owners, token_id)
+    }
+
+    #[storage(read, write)]
+    fn set_base_uri(base: String) {
+        _set_base_uri(storage.base_uri, storage.owner, base);
+    }
+
+    #[storage(read, write)]
+    fn set_contract_uri(uri: String) {
+        _set_contract_uri(storage.contract_uri, storage.owner, uri);
+    }
+
+    #[storage(read, write)]
+    fn set_name(name: String) {
+        _set_name(storage.name, storage.owner, name);
+    }
+
+    #[storage(read, write)]
+    fn set_ownership(new_owner: Identity) {
+        storage.owner.set_ownership(new_owner);
+    }
+
+    #[storage(read, write)]
+    fn set_symbol(symbol: String) {
+        _set_symbol(storage.symbol, storage.owner, symbol);
+    }
+}
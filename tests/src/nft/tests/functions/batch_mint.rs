@@ -0,0 +1,97 @@
+use crate::nft::tests::utils::{
+    abi_calls::{balance_of, batch_mint, owner_of},
+    test_helpers::setup,
+    Mint,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn mints_batch_to_recipient() {
+        let (admin, user) = setup().await;
+
+        batch_mint(&admin.contract, user.identity.clone(), 1, 10).await;
+
+        for token_id in 1..=10 {
+            assert_eq!(
+                owner_of(&admin.contract, token_id).await,
+                Some(user.identity.clone())
+            );
+        }
+        assert_eq!(owner_of(&admin.contract, 0).await, None);
+        assert_eq!(owner_of(&admin.contract, 11).await, None);
+        assert_eq!(balance_of(&admin.contract, user.identity.clone()).await, 10);
+    }
+
+    #[tokio::test]
+    async fn logs_event_per_token() {
+        let (admin, user) = setup().await;
+
+        let response = batch_mint(&admin.contract, user.identity.clone(), 5, 3).await;
+
+        let events = response.decode_logs_with_type::<Mint>().unwrap();
+        assert_eq!(
+            events,
+            (5..8)
+                .map(|token_id| Mint {
+                    to: user.identity.clone(),
+                    token_id
+                })
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn mints_consecutive_batches() {
+        let (admin, user) = setup().await;
+
+        batch_mint(&admin.contract, admin.identity.clone(), 0, 5).await;
+        batch_mint(&admin.contract, user.identity.clone(), 5, 5).await;
+
+        assert_eq!(balance_of(&admin.contract, admin.identity.clone()).await, 5);
+        assert_eq!(balance_of(&admin.contract, user.identity.clone()).await, 5);
+        assert_eq!(
+            owner_of(&admin.contract, 4).await,
+            Some(admin.identity.clone())
+        );
+        assert_eq!(
+            owner_of(&admin.contract, 5).await,
+            Some(user.identity.clone())
+        );
+    }
+
+    #[tokio::test]
+    async fn does_nothing_for_empty_batch() {
+        let (admin, user) = setup().await;
+
+        batch_mint(&admin.contract, user.identity.clone(), 1, 0).await;
+
+        assert_eq!(balance_of(&admin.contract, user.identity.clone()).await, 0);
+        assert_eq!(owner_of(&admin.contract, 1).await, None);
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "AlreadyMinted")]
+    async fn when_batch_overlaps_minted_token() {
+        let (admin, user) = setup().await;
+
+        batch_mint(&admin.contract, user.identity.clone(), 1, 10).await;
+
+        batch_mint(&admin.contract, user.identity.clone(), 10, 5).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_not_owner() {
+        let (_admin, user) = setup().await;
+
+        batch_mint(&user.contract, user.identity.clone(), 1, 10).await;
+    }
+}
@@ -0,0 +1,160 @@
+use crate::nft::tests::utils::{
+    abi_calls::{balance_of, batch_mint, batch_transfer, owner_of},
+    test_helpers::setup,
+    Transfer,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn transfers_half_of_batch() {
+        let (admin, user) = setup().await;
+
+        batch_mint(&admin.contract, admin.identity.clone(), 1, 10).await;
+        for token_id in 1..=10 {
+            assert_eq!(
+                owner_of(&admin.contract, token_id).await,
+                Some(admin.identity.clone())
+            );
+        }
+
+        batch_transfer(
+            &admin.contract,
+            admin.identity.clone(),
+            user.identity.clone(),
+            vec![1, 3, 5, 7, 9],
+        )
+        .await;
+
+        for token_id in 1..=10 {
+            let expected = if token_id % 2 == 1 {
+                user.identity.clone()
+            } else {
+                admin.identity.clone()
+            };
+            assert_eq!(owner_of(&admin.contract, token_id).await, Some(expected));
+        }
+        assert_eq!(balance_of(&admin.contract, admin.identity.clone()).await, 5);
+        assert_eq!(balance_of(&admin.contract, user.identity.clone()).await, 5);
+    }
+
+    #[tokio::test]
+    async fn logs_event_per_token() {
+        let (admin, user) = setup().await;
+
+        batch_mint(&admin.contract, admin.identity.clone(), 1, 10).await;
+
+        let response = batch_transfer(
+            &admin.contract,
+            admin.identity.clone(),
+            user.identity.clone(),
+            vec![2, 4],
+        )
+        .await;
+
+        let events = response.decode_logs_with_type::<Transfer>().unwrap();
+        assert_eq!(
+            events,
+            vec![
+                Transfer {
+                    from: admin.identity.clone(),
+                    to: user.identity.clone(),
+                    token_id: 2
+                },
+                Transfer {
+                    from: admin.identity.clone(),
+                    to: user.identity.clone(),
+                    token_id: 4
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn reverts_whole_batch_on_failure() {
+        let (admin, user) = setup().await;
+
+        batch_mint(&admin.contract, admin.identity.clone(), 1, 10).await;
+
+        // The last token has not been minted, so none of the tokens may move
+        let result = admin
+            .contract
+            .methods()
+            .batch_transfer(
+                admin.identity.clone(),
+                user.identity.clone(),
+                vec![1, 2, 11],
+            )
+            .call()
+            .await;
+        assert!(result.is_err());
+
+        assert_eq!(
+            owner_of(&admin.contract, 1).await,
+            Some(admin.identity.clone())
+        );
+        assert_eq!(
+            owner_of(&admin.contract, 2).await,
+            Some(admin.identity.clone())
+        );
+        assert_eq!(
+            balance_of(&admin.contract, admin.identity.clone()).await,
+            10
+        );
+        assert_eq!(balance_of(&admin.contract, user.identity.clone()).await, 0);
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotTokenOwner")]
+    async fn when_token_is_not_owned() {
+        let (admin, user) = setup().await;
+
+        batch_mint(&admin.contract, user.identity.clone(), 1, 5).await;
+        batch_mint(&admin.contract, admin.identity.clone(), 6, 5).await;
+
+        batch_transfer(
+            &admin.contract,
+            admin.identity.clone(),
+            user.identity.clone(),
+            vec![6, 7, 1],
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotTokenOwner")]
+    async fn when_token_is_not_minted() {
+        let (admin, user) = setup().await;
+
+        batch_transfer(
+            &admin.contract,
+            admin.identity.clone(),
+            user.identity.clone(),
+            vec![1],
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "SenderNotOwner")]
+    async fn when_sender_is_not_from() {
+        let (admin, user) = setup().await;
+
+        batch_mint(&admin.contract, admin.identity.clone(), 1, 10).await;
+
+        batch_transfer(
+            &user.contract,
+            admin.identity.clone(),
+            user.identity.clone(),
+            vec![1],
+        )
+        .await;
+    }
+}
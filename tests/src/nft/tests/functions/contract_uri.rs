@@ -0,0 +1,63 @@
+use crate::nft::tests::utils::{
+    abi_calls::{contract_uri, set_contract_uri},
+    test_helpers::setup,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn is_empty_by_default() {
+        let (admin, _user) = setup().await;
+
+        assert_eq!(contract_uri(&admin.contract).await, "");
+    }
+
+    #[tokio::test]
+    async fn returns_value_after_set() {
+        let (admin, _user) = setup().await;
+
+        set_contract_uri(
+            &admin.contract,
+            String::from("ipfs://collection/metadata.json"),
+        )
+        .await;
+
+        assert_eq!(
+            contract_uri(&admin.contract).await,
+            "ipfs://collection/metadata.json"
+        );
+    }
+
+    #[tokio::test]
+    async fn can_be_overwritten() {
+        let (admin, _user) = setup().await;
+
+        set_contract_uri(
+            &admin.contract,
+            String::from("ipfs://collection/metadata.json"),
+        )
+        .await;
+        set_contract_uri(&admin.contract, String::from("X")).await;
+
+        assert_eq!(contract_uri(&admin.contract).await, "X");
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_set_by_non_owner() {
+        let (_admin, user) = setup().await;
+
+        set_contract_uri(
+            &user.contract,
+            String::from("ipfs://collection/metadata.json"),
+        )
+        .await;
+    }
+}
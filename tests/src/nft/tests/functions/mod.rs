@@ -0,0 +1,6 @@
+mod batch_mint;
+mod batch_transfer;
+mod contract_uri;
+mod name;
+mod symbol;
+mod token_uri;
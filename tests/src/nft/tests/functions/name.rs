@@ -0,0 +1,48 @@
+use crate::nft::tests::utils::{
+    abi_calls::{name, set_name},
+    test_helpers::setup,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn is_empty_by_default() {
+        let (admin, _user) = setup().await;
+
+        assert_eq!(name(&admin.contract).await, "");
+    }
+
+    #[tokio::test]
+    async fn returns_value_after_set() {
+        let (admin, _user) = setup().await;
+
+        set_name(&admin.contract, String::from("Fuel Punks")).await;
+
+        assert_eq!(name(&admin.contract).await, "Fuel Punks");
+    }
+
+    #[tokio::test]
+    async fn can_be_overwritten() {
+        let (admin, _user) = setup().await;
+
+        set_name(&admin.contract, String::from("Fuel Punks")).await;
+        set_name(&admin.contract, String::from("X")).await;
+
+        assert_eq!(name(&admin.contract).await, "X");
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_set_by_non_owner() {
+        let (_admin, user) = setup().await;
+
+        set_name(&user.contract, String::from("Fuel Punks")).await;
+    }
+}
@@ -0,0 +1,48 @@
+use crate::nft::tests::utils::{
+    abi_calls::{set_symbol, symbol},
+    test_helpers::setup,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn is_empty_by_default() {
+        let (admin, _user) = setup().await;
+
+        assert_eq!(symbol(&admin.contract).await, "");
+    }
+
+    #[tokio::test]
+    async fn returns_value_after_set() {
+        let (admin, _user) = setup().await;
+
+        set_symbol(&admin.contract, String::from("PUNK")).await;
+
+        assert_eq!(symbol(&admin.contract).await, "PUNK");
+    }
+
+    #[tokio::test]
+    async fn can_be_overwritten() {
+        let (admin, _user) = setup().await;
+
+        set_symbol(&admin.contract, String::from("PUNK")).await;
+        set_symbol(&admin.contract, String::from("X")).await;
+
+        assert_eq!(symbol(&admin.contract).await, "X");
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_set_by_non_owner() {
+        let (_admin, user) = setup().await;
+
+        set_symbol(&user.contract, String::from("PUNK")).await;
+    }
+}
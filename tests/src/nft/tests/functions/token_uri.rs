@@ -0,0 +1,69 @@
+use crate::nft::tests::utils::{
+    abi_calls::{set_base_uri, token_uri},
+    test_helpers::setup,
+    BASE_URI,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn appends_token_id_to_base_uri() {
+        let (admin, _user) = setup().await;
+
+        set_base_uri(&admin.contract, String::from(BASE_URI)).await;
+
+        assert_eq!(
+            token_uri(&admin.contract, 42).await,
+            format!("{}{}", BASE_URI, 42)
+        );
+    }
+
+    #[tokio::test]
+    async fn formats_boundary_token_ids() {
+        let (admin, _user) = setup().await;
+
+        set_base_uri(&admin.contract, String::from(BASE_URI)).await;
+
+        for token_id in [0, 1, 10, u64::MAX] {
+            assert_eq!(
+                token_uri(&admin.contract, token_id).await,
+                format!("{}{}", BASE_URI, token_id)
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn returns_token_id_without_base_uri() {
+        let (admin, _user) = setup().await;
+
+        assert_eq!(token_uri(&admin.contract, 42).await, "42");
+    }
+
+    #[tokio::test]
+    async fn uses_updated_base_uri() {
+        let (admin, _user) = setup().await;
+
+        set_base_uri(&admin.contract, String::from(BASE_URI)).await;
+        set_base_uri(&admin.contract, String::from("https://example.com/")).await;
+
+        assert_eq!(
+            token_uri(&admin.contract, 42).await,
+            "https://example.com/42"
+        );
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_base_uri_is_set_by_non_owner() {
+        let (_admin, user) = setup().await;
+
+        set_base_uri(&user.contract, String::from(BASE_URI)).await;
+    }
+}
@@ -0,0 +1,181 @@
+use crate::common::{deploy_contract, get_wallets};
+use fuels::{
+    prelude::{abigen, LoadConfiguration, StorageConfiguration, WalletUnlocked},
+    programs::call_response::FuelCallResponse,
+    types::Identity,
+};
+
+abigen!(Contract(
+    name = "NFTLib",
+    abi = "src/nft/out/debug/nft_test-abi.json"
+));
+
+pub const BASE_URI: &str = "ipfs://collection/";
+
+pub struct Metadata {
+    pub contract: NFTLib<WalletUnlocked>,
+    pub identity: Identity,
+    pub wallet: WalletUnlocked,
+}
+
+pub mod abi_calls {
+
+    use super::*;
+
+    pub async fn balance_of(contract: &NFTLib<WalletUnlocked>, owner: Identity) -> u64 {
+        contract
+            .methods()
+            .balance_of(owner)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn batch_mint(
+        contract: &NFTLib<WalletUnlocked>,
+        to: Identity,
+        start_id: u64,
+        count: u64,
+    ) -> FuelCallResponse<()> {
+        contract
+            .methods()
+            .batch_mint(to, start_id, count)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn batch_transfer(
+        contract: &NFTLib<WalletUnlocked>,
+        from: Identity,
+        to: Identity,
+        token_ids: Vec<u64>,
+    ) -> FuelCallResponse<()> {
+        contract
+            .methods()
+            .batch_transfer(from, to, token_ids)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn contract_uri(contract: &NFTLib<WalletUnlocked>) -> String {
+        contract
+            .methods()
+            .contract_uri()
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn name(contract: &NFTLib<WalletUnlocked>) -> String {
+        contract.methods().name().call().await.unwrap().value
+    }
+
+    pub async fn owner_of(contract: &NFTLib<WalletUnlocked>, token_id: u64) -> Option<Identity> {
+        contract
+            .methods()
+            .owner_of(token_id)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn set_base_uri(
+        contract: &NFTLib<WalletUnlocked>,
+        base: String,
+    ) -> FuelCallResponse<()> {
+        contract.methods().set_base_uri(base).call().await.unwrap()
+    }
+
+    pub async fn set_contract_uri(
+        contract: &NFTLib<WalletUnlocked>,
+        uri: String,
+    ) -> FuelCallResponse<()> {
+        contract
+            .methods()
+            .set_contract_uri(uri)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn set_name(contract: &NFTLib<WalletUnlocked>, name: String) -> FuelCallResponse<()> {
+        contract.methods().set_name(name).call().await.unwrap()
+    }
+
+    pub async fn set_ownership(
+        contract: &NFTLib<WalletUnlocked>,
+        new_owner: Identity,
+    ) -> FuelCallResponse<()> {
+        contract
+            .methods()
+            .set_ownership(new_owner)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn set_symbol(
+        contract: &NFTLib<WalletUnlocked>,
+        symbol: String,
+    ) -> FuelCallResponse<()> {
+        contract.methods().set_symbol(symbol).call().await.unwrap()
+    }
+
+    pub async fn symbol(contract: &NFTLib<WalletUnlocked>) -> String {
+        contract.methods().symbol().call().await.unwrap().value
+    }
+
+    pub async fn token_uri(contract: &NFTLib<WalletUnlocked>, token_id: u64) -> String {
+        contract
+            .methods()
+            .token_uri(token_id)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+}
+
+pub mod test_helpers {
+
+    use super::abi_calls::set_ownership;
+    use super::*;
+
+    pub async fn setup() -> (Metadata, Metadata) {
+        let mut wallets = get_wallets(2).await;
+
+        // Get the wallets from that provider
+        let wallet1 = wallets.pop().unwrap();
+        let wallet2 = wallets.pop().unwrap();
+
+        let storage_configuration =
+            StorageConfiguration::load_from("src/nft/out/debug/nft_test-storage_slots.json");
+        let id = deploy_contract(
+            &wallet1,
+            "src/nft/out/debug/nft_test.bin",
+            LoadConfiguration::default().set_storage_configuration(storage_configuration.unwrap()),
+        )
+        .await;
+
+        let admin = Metadata {
+            contract: NFTLib::new(id.clone(), wallet1.clone()),
+            identity: Identity::Address(wallet1.address().into()),
+            wallet: wallet1.clone(),
+        };
+
+        let user = Metadata {
+            contract: NFTLib::new(id.clone(), wallet2.clone()),
+            identity: Identity::Address(wallet2.address().into()),
+            wallet: wallet2.clone(),
+        };
+
+        set_ownership(&admin.contract, admin.identity.clone()).await;
+
+        (admin, user)
+    }
+}
@@ -0,0 +1,54 @@
+contract;
+
+use oracle::*;
+use std::call_frames::contract_id;
+
+storage {
+    price: OraclePrice = OraclePrice {
+        price: 0,
+        decimals: 0,
+        timestamp: 0,
+    },
+}
+
+abi OracleTest {
+    #[storage(write)]
+    fn set_price(price: u64, decimals: u8, timestamp: u64);
+    fn to_u64_with_decimals(p: OraclePrice, target_decimals: u8) -> u64;
+    #[storage(read)]
+    fn validate_latest_price(max_staleness: u64) -> bool;
+    fn validate_price(p: OraclePrice, max_staleness: u64) -> bool;
+}
+
+impl PriceOracle for Contract {
+    #[storage(read)]
+    fn latest_price() -> OraclePrice {
+        storage.price.read()
+    }
+}
+
+impl OracleTest for Contract {
+    #[storage(write)]
+    fn set_price(price: u64, decimals: u8, timestamp: u64) {
+        storage.price.write(OraclePrice {
+            price,
+            decimals,
+            timestamp,
+        });
+    }
+
+    fn to_u64_with_decimals(p: OraclePrice, target_decimals: u8) -> u64 {
+        to_u64_with_decimals(p, target_decimals)
+    }
+
+    #[storage(read)]
+    fn validate_latest_price(max_staleness: u64) -> bool {
+        // Read the price through the oracle interface like a consumer would
+        let oracle = abi(PriceOracle, contract_id().value);
+        validate_price(oracle.latest_price(), max_staleness)
+    }
+
+    fn validate_price(p: OraclePrice, max_staleness: u64) -> bool {
+        validate_price(p, max_staleness)
+    }
+}
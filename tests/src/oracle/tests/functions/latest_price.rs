@@ -0,0 +1,33 @@
+use crate::oracle::tests::utils::{
+    abi_calls::{latest_price, set_price, validate_latest_price},
+    test_helpers::{current_height, oracle_price, produce_blocks, setup},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_reported_price() {
+        let oracle = setup().await;
+
+        set_price(&oracle.contract, 1500, 2, 7).await;
+
+        assert_eq!(
+            latest_price(&oracle.contract).await,
+            oracle_price(1500, 2, 7)
+        );
+    }
+
+    #[tokio::test]
+    async fn validates_price_read_through_interface() {
+        let oracle = setup().await;
+
+        let height = current_height(&oracle.wallet).await;
+        set_price(&oracle.contract, 1500, 2, height).await;
+        assert!(validate_latest_price(&oracle.contract, 5).await);
+
+        produce_blocks(&oracle.wallet, 10).await;
+        assert!(!validate_latest_price(&oracle.contract, 5).await);
+    }
+}
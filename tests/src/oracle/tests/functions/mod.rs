@@ -0,0 +1,3 @@
+mod latest_price;
+mod to_u64_with_decimals;
+mod validate_price;
@@ -0,0 +1,91 @@
+use crate::oracle::tests::utils::{
+    abi_calls::to_u64_with_decimals,
+    test_helpers::{oracle_price, setup},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn keeps_same_decimals() {
+        let oracle = setup().await;
+
+        let p = oracle_price(12345, 3, 0);
+
+        assert_eq!(to_u64_with_decimals(&oracle.contract, p, 3).await, 12345);
+    }
+
+    #[tokio::test]
+    async fn scales_up() {
+        let oracle = setup().await;
+
+        let p = oracle_price(150, 2, 0);
+
+        assert_eq!(
+            to_u64_with_decimals(&oracle.contract, p.clone(), 4).await,
+            15000
+        );
+        assert_eq!(
+            to_u64_with_decimals(&oracle.contract, p, 9).await,
+            1_500_000_000
+        );
+    }
+
+    #[tokio::test]
+    async fn scales_down() {
+        let oracle = setup().await;
+
+        let p = oracle_price(1_999_999_999, 9, 0);
+
+        assert_eq!(
+            to_u64_with_decimals(&oracle.contract, p.clone(), 6).await,
+            1_999_999
+        );
+        assert_eq!(
+            to_u64_with_decimals(&oracle.contract, p.clone(), 0).await,
+            1
+        );
+        assert_eq!(
+            to_u64_with_decimals(&oracle.contract, oracle_price(u64::MAX, 30, 0), 0).await,
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn scales_up_to_max() {
+        let oracle = setup().await;
+
+        let p = oracle_price(u64::MAX / 10, 0, 0);
+
+        assert_eq!(
+            to_u64_with_decimals(&oracle.contract, p, 1).await,
+            u64::MAX / 10 * 10
+        );
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "Overflow")]
+    async fn when_scaled_price_overflows() {
+        let oracle = setup().await;
+
+        let p = oracle_price(u64::MAX / 10 + 1, 0, 0);
+
+        to_u64_with_decimals(&oracle.contract, p, 1).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Overflow")]
+    async fn when_factor_overflows() {
+        let oracle = setup().await;
+
+        let p = oracle_price(1, 0, 0);
+
+        to_u64_with_decimals(&oracle.contract, p, 20).await;
+    }
+}
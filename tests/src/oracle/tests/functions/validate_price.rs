@@ -0,0 +1,60 @@
+use crate::oracle::tests::utils::{
+    abi_calls::validate_price,
+    test_helpers::{current_height, oracle_price, produce_blocks, setup},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn accepts_fresh_price() {
+        let oracle = setup().await;
+
+        let height = current_height(&oracle.wallet).await;
+
+        // The call is included in the next block, so a price of the latest block is one block old
+        assert!(!validate_price(&oracle.contract, oracle_price(100, 0, height), 0).await);
+        assert!(validate_price(&oracle.contract, oracle_price(100, 0, height), 1).await);
+        assert!(validate_price(&oracle.contract, oracle_price(100, 0, height + 1), 0).await);
+    }
+
+    #[tokio::test]
+    async fn accepts_price_at_max_staleness() {
+        let oracle = setup().await;
+
+        let height = current_height(&oracle.wallet).await;
+        produce_blocks(&oracle.wallet, 9).await;
+
+        // The call is included in the block after the produced blocks
+        assert!(validate_price(&oracle.contract, oracle_price(100, 0, height), 10).await);
+    }
+
+    #[tokio::test]
+    async fn rejects_stale_price() {
+        let oracle = setup().await;
+
+        let height = current_height(&oracle.wallet).await;
+        produce_blocks(&oracle.wallet, 10).await;
+
+        assert!(!validate_price(&oracle.contract, oracle_price(100, 0, height), 10).await);
+    }
+
+    #[tokio::test]
+    async fn rejects_zero_price() {
+        let oracle = setup().await;
+
+        let height = current_height(&oracle.wallet).await;
+
+        assert!(!validate_price(&oracle.contract, oracle_price(0, 0, height), 100).await);
+    }
+
+    #[tokio::test]
+    async fn rejects_future_price() {
+        let oracle = setup().await;
+
+        let height = current_height(&oracle.wallet).await;
+
+        assert!(!validate_price(&oracle.contract, oracle_price(100, 0, height + 10), 100).await);
+    }
+}
@@ -0,0 +1,135 @@
+use crate::common::{deploy_contract, get_isolated_wallets};
+use fuels::{
+    prelude::{abigen, LoadConfiguration, StorageConfiguration, WalletUnlocked},
+    programs::call_response::FuelCallResponse,
+};
+
+// Load abi from json
+abigen!(Contract(
+    name = "OracleLib",
+    abi = "src/oracle/out/debug/oracle_test-abi.json"
+));
+
+pub struct Metadata {
+    pub contract: OracleLib<WalletUnlocked>,
+    pub wallet: WalletUnlocked,
+}
+
+pub mod abi_calls {
+
+    use super::*;
+
+    pub async fn latest_price(contract: &OracleLib<WalletUnlocked>) -> OraclePrice {
+        contract
+            .methods()
+            .latest_price()
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn set_price(
+        contract: &OracleLib<WalletUnlocked>,
+        price: u64,
+        decimals: u8,
+        timestamp: u64,
+    ) -> FuelCallResponse<()> {
+        contract
+            .methods()
+            .set_price(price, decimals, timestamp)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn to_u64_with_decimals(
+        contract: &OracleLib<WalletUnlocked>,
+        p: OraclePrice,
+        target_decimals: u8,
+    ) -> u64 {
+        contract
+            .methods()
+            .to_u64_with_decimals(p, target_decimals)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn validate_latest_price(
+        contract: &OracleLib<WalletUnlocked>,
+        max_staleness: u64,
+    ) -> bool {
+        contract
+            .methods()
+            .validate_latest_price(max_staleness)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn validate_price(
+        contract: &OracleLib<WalletUnlocked>,
+        p: OraclePrice,
+        max_staleness: u64,
+    ) -> bool {
+        contract
+            .methods()
+            .validate_price(p, max_staleness)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+}
+
+pub mod test_helpers {
+
+    use super::*;
+
+    pub async fn current_height(wallet: &WalletUnlocked) -> u64 {
+        wallet
+            .provider()
+            .unwrap()
+            .latest_block_height()
+            .await
+            .unwrap() as u64
+    }
+
+    pub fn oracle_price(price: u64, decimals: u8, timestamp: u64) -> OraclePrice {
+        OraclePrice {
+            price,
+            decimals,
+            timestamp,
+        }
+    }
+
+    pub async fn produce_blocks(wallet: &WalletUnlocked, blocks: u64) {
+        wallet
+            .provider()
+            .unwrap()
+            .produce_blocks(blocks, None)
+            .await
+            .unwrap();
+    }
+
+    pub async fn setup() -> Metadata {
+        let wallet = get_isolated_wallets(1).await.pop().unwrap();
+
+        let storage_configuration =
+            StorageConfiguration::load_from("src/oracle/out/debug/oracle_test-storage_slots.json");
+        let id = deploy_contract(
+            &wallet,
+            "src/oracle/out/debug/oracle_test.bin",
+            LoadConfiguration::default().set_storage_configuration(storage_configuration.unwrap()),
+        )
+        .await;
+
+        Metadata {
+            contract: OracleLib::new(id, wallet.clone()),
+            wallet,
+        }
+    }
+}
@@ -8,19 +8,30 @@ storage {
 }
 
 abi OwnableTest {
+    #[storage(read, write)]
+    fn accept_ownership();
     #[storage(read)]
     fn only_owner();
     #[storage(read)]
     fn owner() -> State;
+    #[storage(read)]
+    fn pending_owner() -> Option<Identity>;
     #[storage(read, write)]
     fn renounce_ownership();
     #[storage(read, write)]
     fn set_ownership(new_owner: Identity);
     #[storage(read, write)]
+    fn start_ownership_transfer(new_owner: Identity);
+    #[storage(read, write)]
     fn transfer_ownership(new_owner: Identity);
 }
 
 impl OwnableTest for Contract {
+    #[storage(read, write)]
+    fn accept_ownership() {
+        storage.owner.accept_ownership();
+    }
+
     #[storage(read)]
     fn only_owner() {
         storage.owner.only_owner();
@@ -31,6 +42,11 @@ impl OwnableTest for Contract {
         storage.owner.owner()
     }
 
+    #[storage(read)]
+    fn pending_owner() -> Option<Identity> {
+        storage.owner.pending_owner()
+    }
+
     #[storage(read, write)]
     fn renounce_ownership() {
         storage.owner.renounce_ownership();
@@ -41,6 +57,11 @@ impl OwnableTest for Contract {
         storage.owner.set_ownership(new_owner);
     }
 
+    #[storage(read, write)]
+    fn start_ownership_transfer(new_owner: Identity) {
+        storage.owner.start_ownership_transfer(new_owner);
+    }
+
     #[storage(read, write)]
     fn transfer_ownership(new_owner: Identity) {
         storage.owner.transfer_ownership(new_owner);
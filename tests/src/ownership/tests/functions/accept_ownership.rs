@@ -0,0 +1,93 @@
+use crate::ownership::tests::utils::{
+    abi_calls::{
+        accept_ownership, only_owner, owner, pending_owner, renounce_ownership, set_ownership,
+        start_ownership_transfer, transfer_ownership,
+    },
+    abigen_bindings::ownership_lib_mod::State,
+    test_helpers::setup,
+};
+use fuels::types::Identity;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn accepts_ownership() {
+        let (_deployer, owner1, owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity.clone()).await;
+        start_ownership_transfer(&owner1.contract, owner2_identity.clone()).await;
+
+        accept_ownership(&owner2.contract).await;
+
+        let owner_enum = match owner(&owner1.contract).await {
+            State::Initialized(owner) => Some(owner),
+            _ => None,
+        };
+        assert_eq!(owner_enum.unwrap(), owner2_identity);
+        assert_eq!(pending_owner(&owner1.contract).await, None);
+
+        only_owner(&owner2.contract).await;
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotPendingOwner")]
+    async fn when_no_transfer_is_pending() {
+        let (_deployer, owner1, owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity).await;
+
+        accept_ownership(&owner2.contract).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotPendingOwner")]
+    async fn when_not_pending_owner() {
+        let (deployer, owner1, owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity).await;
+        start_ownership_transfer(&owner1.contract, owner2_identity).await;
+
+        accept_ownership(&deployer.contract).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotPendingOwner")]
+    async fn when_transfer_was_replaced_by_direct_transfer() {
+        let (deployer, owner1, owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        let deployer_identity = Identity::Address(deployer.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity).await;
+        start_ownership_transfer(&owner1.contract, owner2_identity).await;
+        transfer_ownership(&owner1.contract, deployer_identity).await;
+
+        accept_ownership(&owner2.contract).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotPendingOwner")]
+    async fn when_ownership_was_renounced() {
+        let (_deployer, owner1, owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity).await;
+        start_ownership_transfer(&owner1.contract, owner2_identity).await;
+        renounce_ownership(&owner1.contract).await;
+
+        accept_ownership(&owner2.contract).await;
+    }
+}
@@ -1,5 +1,8 @@
+mod accept_ownership;
 mod only_owner;
 mod owner;
+mod pending_owner;
 mod renounce_ownership;
 mod set_ownership;
+mod start_ownership_transfer;
 mod transfer_ownership;
@@ -0,0 +1,34 @@
+use crate::ownership::tests::utils::{
+    abi_calls::{pending_owner, set_ownership, start_ownership_transfer},
+    test_helpers::setup,
+};
+use fuels::types::Identity;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn gets_no_pending_owner() {
+        let (_deployer, owner1, _owner2) = setup().await;
+
+        assert_eq!(pending_owner(&owner1.contract).await, None);
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity).await;
+
+        assert_eq!(pending_owner(&owner1.contract).await, None);
+    }
+
+    #[tokio::test]
+    async fn gets_pending_owner() {
+        let (_deployer, owner1, owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity).await;
+        start_ownership_transfer(&owner1.contract, owner2_identity.clone()).await;
+
+        assert_eq!(pending_owner(&owner1.contract).await, Some(owner2_identity));
+    }
+}
@@ -0,0 +1,64 @@
+use crate::ownership::tests::utils::{
+    abi_calls::{owner, pending_owner, set_ownership, start_ownership_transfer},
+    abigen_bindings::ownership_lib_mod::State,
+    test_helpers::setup,
+};
+use fuels::types::Identity;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn starts_ownership_transfer() {
+        let (_deployer, owner1, owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity.clone()).await;
+
+        start_ownership_transfer(&owner1.contract, owner2_identity.clone()).await;
+
+        let owner_enum = match owner(&owner1.contract).await {
+            State::Initialized(owner) => Some(owner),
+            _ => None,
+        };
+        assert_eq!(owner_enum.unwrap(), owner1_identity);
+        assert_eq!(pending_owner(&owner1.contract).await, Some(owner2_identity));
+    }
+
+    #[tokio::test]
+    async fn replaces_pending_owner() {
+        let (deployer, owner1, owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        let deployer_identity = Identity::Address(deployer.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity).await;
+
+        start_ownership_transfer(&owner1.contract, owner2_identity).await;
+        start_ownership_transfer(&owner1.contract, deployer_identity.clone()).await;
+
+        assert_eq!(
+            pending_owner(&owner1.contract).await,
+            Some(deployer_identity)
+        );
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_not_owner() {
+        let (_deployer, owner1, owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity).await;
+
+        start_ownership_transfer(&owner2.contract, owner2_identity).await;
+    }
+}
@@ -1,8 +1,6 @@
+use crate::common::{deploy_contract, get_wallets};
 use fuels::{
-    prelude::{
-        abigen, launch_custom_provider_and_get_wallets, Contract, LoadConfiguration,
-        StorageConfiguration, TxParameters, WalletUnlocked, WalletsConfig,
-    },
+    prelude::{abigen, LoadConfiguration, StorageConfiguration, WalletUnlocked},
     programs::call_response::FuelCallResponse,
     types::Identity,
 };
@@ -22,6 +20,10 @@ pub mod abi_calls {
 
     use super::*;
 
+    pub async fn accept_ownership(contract: &OwnershipLib<WalletUnlocked>) -> FuelCallResponse<()> {
+        contract.methods().accept_ownership().call().await.unwrap()
+    }
+
     pub async fn only_owner(contract: &OwnershipLib<WalletUnlocked>) -> FuelCallResponse<()> {
         contract.methods().only_owner().call().await.unwrap()
     }
@@ -30,6 +32,16 @@ pub mod abi_calls {
         contract.methods().owner().call().await.unwrap().value
     }
 
+    pub async fn pending_owner(contract: &OwnershipLib<WalletUnlocked>) -> Option<Identity> {
+        contract
+            .methods()
+            .pending_owner()
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
     pub async fn renounce_ownership(
         contract: &OwnershipLib<WalletUnlocked>,
     ) -> FuelCallResponse<()> {
@@ -53,6 +65,18 @@ pub mod abi_calls {
             .unwrap()
     }
 
+    pub async fn start_ownership_transfer(
+        contract: &OwnershipLib<WalletUnlocked>,
+        new_owner: Identity,
+    ) -> FuelCallResponse<()> {
+        contract
+            .methods()
+            .start_ownership_transfer(new_owner)
+            .call()
+            .await
+            .unwrap()
+    }
+
     pub async fn transfer_ownership(
         contract: &OwnershipLib<WalletUnlocked>,
         new_owner: Identity,
@@ -71,15 +95,7 @@ pub mod test_helpers {
     use super::*;
 
     pub async fn setup() -> (Metadata, Metadata, Metadata) {
-        let num_wallets = 4;
-        let coins_per_wallet = 1;
-        let coin_amount = 1000000;
-        let mut wallets = launch_custom_provider_and_get_wallets(
-            WalletsConfig::new(Some(num_wallets), Some(coins_per_wallet), Some(coin_amount)),
-            None,
-            None,
-        )
-        .await;
+        let mut wallets = get_wallets(4).await;
 
         // Get the wallets from that provider
         let wallet1 = wallets.pop().unwrap();
@@ -89,14 +105,12 @@ pub mod test_helpers {
         let storage_configuration = StorageConfiguration::load_from(
             "src/ownership/out/debug/ownership_test-storage_slots.json",
         );
-        let id = Contract::load_from(
+        let id = deploy_contract(
+            &wallet1,
             "src/ownership/out/debug/ownership_test.bin",
             LoadConfiguration::default().set_storage_configuration(storage_configuration.unwrap()),
         )
-        .unwrap()
-        .deploy(&wallet1, TxParameters::default())
-        .await
-        .unwrap();
+        .await;
 
         let deploy_wallet = Metadata {
             contract: OwnershipLib::new(id.clone(), wallet1.clone()),
@@ -0,0 +1,50 @@
+contract;
+
+use ownership::*;
+use pausable::*;
+use src_5::Ownership;
+
+storage {
+    owner: Ownership = Ownership::uninitialized(),
+    pause_state: PauseState = PauseState::Unpaused,
+}
+
+abi PausableTest {
+    #[storage(read)]
+    fn guarded();
+    #[storage(read)]
+    fn is_paused() -> bool;
+    #[storage(read, write)]
+    fn pause();
+    #[storage(read, write)]
+    fn set_ownership(new_owner: Identity);
+    #[storage(read, write)]
+    fn unpause();
+}
+
+impl PausableTest for Contract {
+    #[storage(read)]
+    fn guarded() {
+        storage.pause_state.require_not_paused();
+    }
+
+    #[storage(read)]
+    fn is_paused() -> bool {
+        storage.pause_state.is_paused()
+    }
+
+    #[storage(read, write)]
+    fn pause() {
+        storage.pause_state.pause(storage.owner);
+    }
+
+    #[storage(read, write)]
+    fn set_ownership(new_owner: Identity) {
+        storage.owner.set_ownership(new_owner);
+    }
+
+    #[storage(read, write)]
+    fn unpause() {
+        storage.pause_state.unpause(storage.owner);
+    }
+}
@@ -0,0 +1,41 @@
+use crate::pausable::tests::utils::{
+    abi_calls::{guarded, pause, unpause},
+    test_helpers::setup,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn can_call_when_not_paused() {
+        let (_owner, user) = setup().await;
+
+        guarded(&user.contract).await;
+    }
+
+    #[tokio::test]
+    async fn can_call_after_unpause() {
+        let (owner, user) = setup().await;
+
+        pause(&owner.contract).await;
+        unpause(&owner.contract).await;
+
+        guarded(&user.contract).await;
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "Paused")]
+    async fn when_paused() {
+        let (owner, user) = setup().await;
+
+        pause(&owner.contract).await;
+
+        guarded(&user.contract).await;
+    }
+}
@@ -0,0 +1,27 @@
+use crate::pausable::tests::utils::{
+    abi_calls::{is_paused, pause, unpause},
+    test_helpers::setup,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn is_unpaused_on_deployment() {
+        let (_owner, user) = setup().await;
+
+        assert!(!is_paused(&user.contract).await);
+    }
+
+    #[tokio::test]
+    async fn returns_pause_state() {
+        let (owner, user) = setup().await;
+
+        pause(&owner.contract).await;
+        assert!(is_paused(&user.contract).await);
+
+        unpause(&owner.contract).await;
+        assert!(!is_paused(&user.contract).await);
+    }
+}
@@ -0,0 +1,4 @@
+mod guarded;
+mod is_paused;
+mod pause;
+mod unpause;
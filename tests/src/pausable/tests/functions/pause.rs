@@ -0,0 +1,42 @@
+use crate::pausable::tests::utils::{
+    abi_calls::{is_paused, pause},
+    test_helpers::setup,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn owner_can_pause() {
+        let (owner, _user) = setup().await;
+
+        assert!(!is_paused(&owner.contract).await);
+
+        pause(&owner.contract).await;
+
+        assert!(is_paused(&owner.contract).await);
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_not_owner() {
+        let (_owner, user) = setup().await;
+
+        pause(&user.contract).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Paused")]
+    async fn when_already_paused() {
+        let (owner, _user) = setup().await;
+
+        pause(&owner.contract).await;
+        pause(&owner.contract).await;
+    }
+}
@@ -0,0 +1,44 @@
+use crate::pausable::tests::utils::{
+    abi_calls::{is_paused, pause, unpause},
+    test_helpers::setup,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn owner_can_unpause() {
+        let (owner, _user) = setup().await;
+
+        pause(&owner.contract).await;
+        assert!(is_paused(&owner.contract).await);
+
+        unpause(&owner.contract).await;
+
+        assert!(!is_paused(&owner.contract).await);
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_not_owner() {
+        let (owner, user) = setup().await;
+
+        pause(&owner.contract).await;
+
+        unpause(&user.contract).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotPaused")]
+    async fn when_not_paused() {
+        let (owner, _user) = setup().await;
+
+        unpause(&owner.contract).await;
+    }
+}
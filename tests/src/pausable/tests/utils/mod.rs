@@ -0,0 +1,89 @@
+use crate::common::{deploy_contract, get_wallets};
+use fuels::{
+    prelude::{abigen, LoadConfiguration, StorageConfiguration, WalletUnlocked},
+    programs::call_response::FuelCallResponse,
+    types::Identity,
+};
+
+// Load abi from json
+abigen!(Contract(
+    name = "PausableLib",
+    abi = "src/pausable/out/debug/pausable_test-abi.json"
+));
+
+pub struct Metadata {
+    pub contract: PausableLib<WalletUnlocked>,
+    pub wallet: WalletUnlocked,
+}
+
+pub mod abi_calls {
+
+    use super::*;
+
+    pub async fn guarded(contract: &PausableLib<WalletUnlocked>) -> FuelCallResponse<()> {
+        contract.methods().guarded().call().await.unwrap()
+    }
+
+    pub async fn is_paused(contract: &PausableLib<WalletUnlocked>) -> bool {
+        contract.methods().is_paused().call().await.unwrap().value
+    }
+
+    pub async fn pause(contract: &PausableLib<WalletUnlocked>) -> FuelCallResponse<()> {
+        contract.methods().pause().call().await.unwrap()
+    }
+
+    pub async fn set_ownership(
+        contract: &PausableLib<WalletUnlocked>,
+        new_owner: Identity,
+    ) -> FuelCallResponse<()> {
+        contract
+            .methods()
+            .set_ownership(new_owner)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn unpause(contract: &PausableLib<WalletUnlocked>) -> FuelCallResponse<()> {
+        contract.methods().unpause().call().await.unwrap()
+    }
+}
+
+pub mod test_helpers {
+
+    use super::abi_calls::set_ownership;
+    use super::*;
+
+    pub async fn setup() -> (Metadata, Metadata) {
+        let mut wallets = get_wallets(3).await;
+
+        // Get the wallets from that provider
+        let wallet1 = wallets.pop().unwrap();
+        let wallet2 = wallets.pop().unwrap();
+
+        let storage_configuration = StorageConfiguration::load_from(
+            "src/pausable/out/debug/pausable_test-storage_slots.json",
+        );
+        let id = deploy_contract(
+            &wallet1,
+            "src/pausable/out/debug/pausable_test.bin",
+            LoadConfiguration::default().set_storage_configuration(storage_configuration.unwrap()),
+        )
+        .await;
+
+        let owner = Metadata {
+            contract: PausableLib::new(id.clone(), wallet1.clone()),
+            wallet: wallet1.clone(),
+        };
+
+        let user = Metadata {
+            contract: PausableLib::new(id.clone(), wallet2.clone()),
+            wallet: wallet2.clone(),
+        };
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        set_ownership(&owner.contract, owner_identity).await;
+
+        (owner, user)
+    }
+}
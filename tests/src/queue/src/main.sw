@@ -0,0 +1,51 @@
+contract;
+
+use collection_backend::StorageBackend;
+use queue::backend_queue::BackendQueue;
+
+storage {
+    items: StorageBackend<u64> = StorageBackend {},
+    head: StorageBackend<u64> = StorageBackend {},
+}
+
+abi QueueTest {
+    #[storage(read, write)]
+    fn dequeue() -> Option<u64>;
+    #[storage(read, write)]
+    fn enqueue(item: u64);
+    #[storage(read)]
+    fn is_empty() -> bool;
+    #[storage(read)]
+    fn len() -> u64;
+    #[storage(read)]
+    fn peek() -> Option<u64>;
+}
+
+impl QueueTest for Contract {
+    #[storage(read, write)]
+    fn dequeue() -> Option<u64> {
+        let mut queue = BackendQueue::new(storage.items, storage.head);
+        queue.dequeue()
+    }
+
+    #[storage(read, write)]
+    fn enqueue(item: u64) {
+        let mut queue = BackendQueue::new(storage.items, storage.head);
+        queue.enqueue(item);
+    }
+
+    #[storage(read)]
+    fn is_empty() -> bool {
+        BackendQueue::new(storage.items, storage.head).is_empty()
+    }
+
+    #[storage(read)]
+    fn len() -> u64 {
+        BackendQueue::new(storage.items, storage.head).len()
+    }
+
+    #[storage(read)]
+    fn peek() -> Option<u64> {
+        BackendQueue::new(storage.items, storage.head).peek()
+    }
+}
@@ -0,0 +1,54 @@
+use crate::queue::tests::utils::{
+    abi_calls::{dequeue, enqueue, len},
+    test_helpers::setup,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_none_when_empty() {
+        let instance = setup().await;
+
+        assert_eq!(dequeue(&instance).await, None);
+        assert_eq!(len(&instance).await, 0);
+    }
+
+    #[tokio::test]
+    async fn dequeues_in_fifo_order() {
+        let instance = setup().await;
+
+        for item in 1..=5 {
+            enqueue(&instance, item).await;
+        }
+
+        assert_eq!(dequeue(&instance).await, Some(1));
+        assert_eq!(dequeue(&instance).await, Some(2));
+        assert_eq!(len(&instance).await, 3);
+
+        for item in 6..=8 {
+            enqueue(&instance, item).await;
+        }
+        assert_eq!(len(&instance).await, 6);
+
+        for item in 3..=8 {
+            assert_eq!(dequeue(&instance).await, Some(item));
+        }
+        assert_eq!(len(&instance).await, 0);
+        assert_eq!(dequeue(&instance).await, None);
+    }
+
+    #[tokio::test]
+    async fn enqueues_after_being_emptied() {
+        let instance = setup().await;
+
+        enqueue(&instance, 1).await;
+        assert_eq!(dequeue(&instance).await, Some(1));
+        assert_eq!(dequeue(&instance).await, None);
+
+        enqueue(&instance, 2).await;
+        assert_eq!(len(&instance).await, 1);
+        assert_eq!(dequeue(&instance).await, Some(2));
+    }
+}
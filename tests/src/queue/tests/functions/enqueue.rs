@@ -0,0 +1,20 @@
+use crate::queue::tests::utils::{
+    abi_calls::{enqueue, len, peek},
+    test_helpers::setup,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn adds_to_the_end() {
+        let instance = setup().await;
+
+        enqueue(&instance, 1).await;
+        enqueue(&instance, 2).await;
+
+        assert_eq!(len(&instance).await, 2);
+        assert_eq!(peek(&instance).await, Some(1));
+    }
+}
@@ -0,0 +1,27 @@
+use crate::queue::tests::utils::{
+    abi_calls::{dequeue, enqueue, is_empty},
+    test_helpers::setup,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn starts_empty() {
+        let instance = setup().await;
+
+        assert!(is_empty(&instance).await);
+    }
+
+    #[tokio::test]
+    async fn tracks_enqueue_and_dequeue() {
+        let instance = setup().await;
+
+        enqueue(&instance, 1).await;
+        assert!(!is_empty(&instance).await);
+
+        dequeue(&instance).await;
+        assert!(is_empty(&instance).await);
+    }
+}
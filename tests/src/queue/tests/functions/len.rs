@@ -0,0 +1,23 @@
+use crate::queue::tests::utils::{
+    abi_calls::{dequeue, enqueue, len},
+    test_helpers::setup,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn counts_remaining_elements() {
+        let instance = setup().await;
+
+        assert_eq!(len(&instance).await, 0);
+
+        enqueue(&instance, 1).await;
+        enqueue(&instance, 2).await;
+        assert_eq!(len(&instance).await, 2);
+
+        dequeue(&instance).await;
+        assert_eq!(len(&instance).await, 1);
+    }
+}
@@ -0,0 +1,5 @@
+mod dequeue;
+mod enqueue;
+mod is_empty;
+mod len;
+mod peek;
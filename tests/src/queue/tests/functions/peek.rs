@@ -0,0 +1,31 @@
+use crate::queue::tests::utils::{
+    abi_calls::{dequeue, enqueue, len, peek},
+    test_helpers::setup,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_none_when_empty() {
+        let instance = setup().await;
+
+        assert_eq!(peek(&instance).await, None);
+    }
+
+    #[tokio::test]
+    async fn does_not_remove_the_head() {
+        let instance = setup().await;
+
+        enqueue(&instance, 1).await;
+        enqueue(&instance, 2).await;
+
+        assert_eq!(peek(&instance).await, Some(1));
+        assert_eq!(peek(&instance).await, Some(1));
+        assert_eq!(len(&instance).await, 2);
+
+        dequeue(&instance).await;
+        assert_eq!(peek(&instance).await, Some(2));
+    }
+}
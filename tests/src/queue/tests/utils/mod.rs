@@ -0,0 +1,55 @@
+use crate::common::{deploy_contract, get_wallet};
+use fuels::{
+    prelude::{abigen, LoadConfiguration, StorageConfiguration, WalletUnlocked},
+    programs::call_response::FuelCallResponse,
+};
+
+abigen!(Contract(
+    name = "QueueLib",
+    abi = "src/queue/out/debug/queue_test-abi.json"
+));
+
+pub mod abi_calls {
+
+    use super::*;
+
+    pub async fn dequeue(contract: &QueueLib<WalletUnlocked>) -> Option<u64> {
+        contract.methods().dequeue().call().await.unwrap().value
+    }
+
+    pub async fn enqueue(contract: &QueueLib<WalletUnlocked>, item: u64) -> FuelCallResponse<()> {
+        contract.methods().enqueue(item).call().await.unwrap()
+    }
+
+    pub async fn is_empty(contract: &QueueLib<WalletUnlocked>) -> bool {
+        contract.methods().is_empty().call().await.unwrap().value
+    }
+
+    pub async fn len(contract: &QueueLib<WalletUnlocked>) -> u64 {
+        contract.methods().len().call().await.unwrap().value
+    }
+
+    pub async fn peek(contract: &QueueLib<WalletUnlocked>) -> Option<u64> {
+        contract.methods().peek().call().await.unwrap().value
+    }
+}
+
+pub mod test_helpers {
+
+    use super::*;
+
+    pub async fn setup() -> QueueLib<WalletUnlocked> {
+        let wallet = get_wallet().await;
+
+        let storage_configuration =
+            StorageConfiguration::load_from("src/queue/out/debug/queue_test-storage_slots.json");
+        let id = deploy_contract(
+            &wallet,
+            "src/queue/out/debug/queue_test.bin",
+            LoadConfiguration::default().set_storage_configuration(storage_configuration.unwrap()),
+        )
+        .await;
+
+        QueueLib::new(id, wallet)
+    }
+}
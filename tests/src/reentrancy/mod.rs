@@ -1,8 +1,6 @@
+use crate::common::{deploy_contract, get_wallet};
 use fuels::{
-    prelude::{
-        abigen, launch_provider_and_get_wallet, Contract, LoadConfiguration, StorageConfiguration,
-        TxParameters, WalletUnlocked,
-    },
+    prelude::{abigen, LoadConfiguration, StorageConfiguration, WalletUnlocked},
     types::ContractId,
 };
 
@@ -26,14 +24,12 @@ pub async fn get_attacker_instance(
     wallet: WalletUnlocked,
 ) -> (AttackerContract<WalletUnlocked>, ContractId) {
     let storage_configuration = StorageConfiguration::load_from(REENTRANCY_ATTACKER_STORAGE);
-    let id = Contract::load_from(
+    let id = deploy_contract(
+        &wallet,
         REENTRANCY_ATTACKER_BIN,
         LoadConfiguration::default().set_storage_configuration(storage_configuration.unwrap()),
     )
-    .unwrap()
-    .deploy(&wallet, TxParameters::default())
-    .await
-    .unwrap();
+    .await;
 
     let instance = AttackerContract::new(id.clone(), wallet);
 
@@ -44,14 +40,12 @@ pub async fn get_target_instance(
     wallet: WalletUnlocked,
 ) -> (TargetContract<WalletUnlocked>, ContractId) {
     let storage_configuration = StorageConfiguration::load_from(REENTRANCY_TARGET_STORAGE);
-    let id = Contract::load_from(
+    let id = deploy_contract(
+        &wallet,
         REENTRANCY_TARGET_BIN,
         LoadConfiguration::default().set_storage_configuration(storage_configuration.unwrap()),
     )
-    .unwrap()
-    .deploy(&wallet, TxParameters::default())
-    .await
-    .unwrap();
+    .await;
 
     let instance = TargetContract::new(id.clone(), wallet);
 
@@ -61,11 +55,12 @@ pub async fn get_target_instance(
 pub async fn get_attack_helper_id(
     wallet: WalletUnlocked,
 ) -> (AttackHelperContract<WalletUnlocked>, ContractId) {
-    let id = Contract::load_from(REENTRANCY_ATTACK_HELPER_BIN, LoadConfiguration::default())
-        .unwrap()
-        .deploy(&wallet, TxParameters::default())
-        .await
-        .unwrap();
+    let id = deploy_contract(
+        &wallet,
+        REENTRANCY_ATTACK_HELPER_BIN,
+        LoadConfiguration::default(),
+    )
+    .await;
 
     let instance = AttackHelperContract::new(id.clone(), wallet);
 
@@ -77,7 +72,7 @@ mod success {
 
     #[tokio::test]
     async fn can_detect_reentrancy() {
-        let wallet = launch_provider_and_get_wallet().await;
+        let wallet = get_wallet().await;
         let (attacker_instance, _) = get_attacker_instance(wallet.clone()).await;
         let (instance, target_id) = get_target_instance(wallet).await;
 
@@ -94,7 +89,7 @@ mod success {
 
     #[tokio::test]
     async fn can_call_guarded_function() {
-        let wallet = launch_provider_and_get_wallet().await;
+        let wallet = get_wallet().await;
         let (attacker_instance, _) = get_attacker_instance(wallet.clone()).await;
         let (instance, target_id) = get_target_instance(wallet).await;
 
@@ -106,6 +101,21 @@ mod success {
             .await
             .unwrap();
     }
+
+    #[tokio::test]
+    async fn can_call_locked_function_after_unlock() {
+        let wallet = get_wallet().await;
+        let (attacker_instance, _) = get_attacker_instance(wallet.clone()).await;
+        let (instance, target_id) = get_target_instance(wallet).await;
+
+        attacker_instance
+            .methods()
+            .innocent_locked_call(target_id)
+            .set_contracts(&[&instance])
+            .call()
+            .await
+            .unwrap();
+    }
 }
 
 mod revert {
@@ -114,7 +124,7 @@ mod revert {
     #[tokio::test]
     #[should_panic(expected = "NonReentrant")]
     async fn can_block_reentrancy() {
-        let wallet = launch_provider_and_get_wallet().await;
+        let wallet = get_wallet().await;
         let (attacker_instance, _) = get_attacker_instance(wallet.clone()).await;
         let (instance, target_id) = get_target_instance(wallet).await;
 
@@ -130,7 +140,7 @@ mod revert {
     #[tokio::test]
     #[should_panic(expected = "NonReentrant")]
     async fn can_block_cross_function_reentrancy() {
-        let wallet = launch_provider_and_get_wallet().await;
+        let wallet = get_wallet().await;
         let (attacker_instance, _) = get_attacker_instance(wallet.clone()).await;
         let (instance, target_id) = get_target_instance(wallet).await;
 
@@ -146,7 +156,7 @@ mod revert {
     #[tokio::test]
     #[should_panic(expected = "NonReentrant")]
     async fn can_block_cross_contract_reentrancy() {
-        let wallet = launch_provider_and_get_wallet().await;
+        let wallet = get_wallet().await;
         let (attacker_instance, _) = get_attacker_instance(wallet.clone()).await;
         let (helper_instance, helper_id) = get_attack_helper_id(wallet.clone()).await;
         let (target_instance, target_id) = get_target_instance(wallet).await;
@@ -159,4 +169,36 @@ mod revert {
             .await
             .unwrap();
     }
+
+    #[tokio::test]
+    #[should_panic(expected = "NonReentrant")]
+    async fn can_block_reentrancy_with_lock() {
+        let wallet = get_wallet().await;
+        let (attacker_instance, _) = get_attacker_instance(wallet.clone()).await;
+        let (instance, target_id) = get_target_instance(wallet).await;
+
+        attacker_instance
+            .methods()
+            .launch_locked_attack(target_id)
+            .set_contracts(&[&instance])
+            .call()
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NonReentrant")]
+    async fn can_block_entering_while_locked() {
+        let wallet = get_wallet().await;
+        let (attacker_instance, _) = get_attacker_instance(wallet.clone()).await;
+        let (instance, target_id) = get_target_instance(wallet).await;
+
+        attacker_instance
+            .methods()
+            .launch_locked_cross_function_attack(target_id)
+            .set_contracts(&[&instance])
+            .call()
+            .await
+            .unwrap();
+    }
 }
@@ -13,4 +13,9 @@ abi Attacker {
     #[storage(read)]
     fn evil_callback_4();
     fn innocent_callback();
+    fn launch_locked_attack(target: ContractId);
+    fn launch_locked_cross_function_attack(target: ContractId);
+    fn innocent_locked_call(target: ContractId);
+    fn evil_callback_5();
+    fn evil_callback_6();
 }
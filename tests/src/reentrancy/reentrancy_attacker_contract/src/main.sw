@@ -62,4 +62,28 @@ impl Attacker for Contract {
     }
 
     fn innocent_callback() {}
+
+    fn launch_locked_attack(target: ContractId) {
+        abi(Target, target.value).locked_reentrance_denied();
+    }
+
+    fn launch_locked_cross_function_attack(target: ContractId) {
+        abi(Target, target.value).locked_cross_function_reentrance_denied();
+    }
+
+    fn innocent_locked_call(target: ContractId) {
+        let target = abi(Target, target.value);
+        // The lock is released on return, so the guarded functions may be called again
+        target.locked_function_is_callable();
+        target.locked_function_is_callable();
+        target.not_entered_function_is_callable();
+    }
+
+    fn evil_callback_5() {
+        abi(Attacker, contract_id().value).launch_locked_attack(get_msg_sender_id_or_panic());
+    }
+
+    fn evil_callback_6() {
+        abi(Target, get_msg_sender_id_or_panic().value).not_entered_function_is_callable();
+    }
 }
@@ -7,4 +7,12 @@ abi Target {
     fn intra_contract_call();
     fn guarded_function_is_callable();
     fn cross_contract_reentrancy_denied();
+    #[storage(read, write)]
+    fn locked_reentrance_denied();
+    #[storage(read, write)]
+    fn locked_cross_function_reentrance_denied();
+    #[storage(read)]
+    fn not_entered_function_is_callable();
+    #[storage(read, write)]
+    fn locked_function_is_callable();
 }
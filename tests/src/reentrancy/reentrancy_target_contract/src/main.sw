@@ -56,4 +56,39 @@ impl Target for Contract {
         // this call transfers control to the attacker contract, allowing it to execute arbitrary code.
         abi(Attacker, get_msg_sender_id_or_panic().value).evil_callback_4();
     }
+
+    #[storage(read, write)]
+    fn locked_reentrance_denied() {
+        // panic if the lock is already set
+        lock();
+
+        // this call transfers control to the attacker contract, allowing it to execute arbitrary code.
+        abi(Attacker, get_msg_sender_id_or_panic().value).evil_callback_5();
+
+        unlock();
+    }
+
+    #[storage(read, write)]
+    fn locked_cross_function_reentrance_denied() {
+        // panic if the lock is already set
+        lock();
+
+        // this call transfers control to the attacker contract, allowing it to execute arbitrary code.
+        abi(Attacker, get_msg_sender_id_or_panic().value).evil_callback_6();
+
+        unlock();
+    }
+
+    #[storage(read)]
+    fn not_entered_function_is_callable() {
+        // panic if the lock is set
+        require_not_entered();
+    }
+
+    #[storage(read, write)]
+    fn locked_function_is_callable() {
+        // panic if the lock is already set
+        lock();
+        unlock();
+    }
 }
@@ -0,0 +1,2 @@
+mod recover_test;
+mod structured_hash_test;
@@ -0,0 +1,11 @@
+script;
+
+use signature::{recover_address, verify_signature};
+use std::b512::B512;
+
+fn main(message_hash: b256, sig: B512, expected: Address) -> (Option<Address>, bool) {
+    (
+        recover_address(message_hash, sig),
+        verify_signature(message_hash, sig, expected),
+    )
+}
@@ -0,0 +1,127 @@
+use crate::common::script_instance;
+use fuels::{
+    accounts::fuel_crypto::{Message, SecretKey, Signature},
+    prelude::{abigen, Signer, WalletUnlocked},
+    types::{Address, Bits256, B512},
+};
+use std::str::FromStr;
+
+abigen!(Script(
+    name = "TestRecover",
+    abi = "src/signature/recover_test/out/debug/recover_test-abi.json"
+),);
+
+const PATH_TO_BIN: &str = "src/signature/recover_test/out/debug/recover_test.bin";
+
+const PRIVATE_KEY: &str = "0x862512a2363db2b3a375c0d4bbbd27172180d89f23f2e259bac850ab02619301";
+const MESSAGE: &str = "Approve 100 tokens";
+
+async fn setup() -> TestRecover<WalletUnlocked> {
+    script_instance!(TestRecover, PATH_TO_BIN)
+}
+
+async fn sign(message: &str) -> (Address, Bits256, Signature) {
+    let signer =
+        WalletUnlocked::new_from_private_key(SecretKey::from_str(PRIVATE_KEY).unwrap(), None);
+    let signature = signer.sign_message(message).await.unwrap();
+
+    (
+        Address::from(signer.address()),
+        Bits256(*Message::new(message)),
+        signature,
+    )
+}
+
+fn b512(signature: &Signature) -> B512 {
+    let mut r = [0u8; 32];
+    let mut s = [0u8; 32];
+    r.copy_from_slice(&signature[..32]);
+    s.copy_from_slice(&signature[32..]);
+    B512::from((Bits256(r), Bits256(s)))
+}
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn recovers_the_signer() {
+        let instance = setup().await;
+        let (signer, message_hash, signature) = sign(MESSAGE).await;
+
+        let result = instance
+            .main(message_hash, b512(&signature), signer)
+            .call()
+            .await
+            .unwrap();
+
+        assert_eq!(result.value, (Some(signer), true));
+    }
+
+    #[tokio::test]
+    async fn does_not_verify_another_signer() {
+        let instance = setup().await;
+        let (_, message_hash, signature) = sign(MESSAGE).await;
+        let other = Address::new([1u8; 32]);
+
+        let result = instance
+            .main(message_hash, b512(&signature), other)
+            .call()
+            .await
+            .unwrap();
+
+        assert_ne!(result.value.0, Some(other));
+        assert!(!result.value.1);
+    }
+
+    #[tokio::test]
+    async fn does_not_verify_a_tampered_message() {
+        let instance = setup().await;
+        let (signer, _, signature) = sign(MESSAGE).await;
+        let tampered = Bits256(*Message::new("Approve 900 tokens"));
+
+        let result = instance
+            .main(tampered, b512(&signature), signer)
+            .call()
+            .await
+            .unwrap();
+
+        assert_ne!(result.value.0, Some(signer));
+        assert!(!result.value.1);
+    }
+
+    #[tokio::test]
+    async fn does_not_verify_a_tampered_signature() {
+        let instance = setup().await;
+        let (signer, message_hash, signature) = sign(MESSAGE).await;
+
+        let mut tampered = b512(&signature);
+        tampered.bytes[0].0[31] ^= 1;
+
+        let result = instance
+            .main(message_hash, tampered, signer)
+            .call()
+            .await
+            .unwrap();
+
+        assert_ne!(result.value.0, Some(signer));
+        assert!(!result.value.1);
+    }
+
+    #[tokio::test]
+    async fn returns_none_for_an_invalid_signature() {
+        let instance = setup().await;
+        let (signer, message_hash, _) = sign(MESSAGE).await;
+
+        // A signature with zero for `r` does not lie on the curve
+        let invalid = B512::from((Bits256([0u8; 32]), Bits256([0u8; 32])));
+
+        let result = instance
+            .main(message_hash, invalid, signer)
+            .call()
+            .await
+            .unwrap();
+
+        assert_eq!(result.value, (None, false));
+    }
+}
@@ -0,0 +1,14 @@
+script;
+
+use signature::structured_hash::{domain_separator, hash_typed_data};
+
+fn main(
+    name: b256,
+    version: b256,
+    chain_id: u64,
+    contract_addr: Address,
+    message_hash: b256,
+) -> (b256, b256) {
+    let separator = domain_separator(name, version, chain_id, contract_addr);
+    (separator, hash_typed_data(separator, message_hash))
+}
@@ -0,0 +1,154 @@
+use crate::common::script_instance;
+use ethers_core::{
+    abi::{encode, ParamType, Token},
+    types::{
+        transaction::eip712::{make_type_hash, EIP712Domain, Eip712, Eip712Error},
+        H160, U256,
+    },
+    utils::keccak256,
+};
+use fuels::{
+    prelude::{abigen, WalletUnlocked},
+    types::{Address, Bits256},
+};
+
+abigen!(Script(
+    name = "TestStructuredHash",
+    abi = "src/signature/structured_hash_test/out/debug/structured_hash_test-abi.json"
+),);
+
+const PATH_TO_BIN: &str = "src/signature/structured_hash_test/out/debug/structured_hash_test.bin";
+
+const NAME: &str = "Fuel Permit";
+const VERSION: &str = "1";
+
+async fn setup() -> TestStructuredHash<WalletUnlocked> {
+    script_instance!(TestStructuredHash, PATH_TO_BIN)
+}
+
+// An EIP-2612 permit, hashed by the `ethers` typed data utilities
+struct Permit {
+    chain_id: u64,
+    verifying_contract: H160,
+    owner: H160,
+    spender: H160,
+    value: U256,
+    nonce: U256,
+    deadline: U256,
+}
+
+impl Eip712 for Permit {
+    type Error = Eip712Error;
+
+    fn domain(&self) -> Result<EIP712Domain, Self::Error> {
+        Ok(EIP712Domain {
+            name: Some(NAME.to_string()),
+            version: Some(VERSION.to_string()),
+            chain_id: Some(U256::from(self.chain_id)),
+            verifying_contract: Some(self.verifying_contract),
+            salt: None,
+        })
+    }
+
+    fn type_hash() -> Result<[u8; 32], Self::Error> {
+        Ok(make_type_hash(
+            "Permit".to_string(),
+            &[
+                ("owner".to_string(), ParamType::Address),
+                ("spender".to_string(), ParamType::Address),
+                ("value".to_string(), ParamType::Uint(256)),
+                ("nonce".to_string(), ParamType::Uint(256)),
+                ("deadline".to_string(), ParamType::Uint(256)),
+            ],
+        ))
+    }
+
+    fn struct_hash(&self) -> Result<[u8; 32], Self::Error> {
+        Ok(keccak256(encode(&[
+            Token::FixedBytes(Self::type_hash()?.to_vec()),
+            Token::Address(self.owner),
+            Token::Address(self.spender),
+            Token::Uint(self.value),
+            Token::Uint(self.nonce),
+            Token::Uint(self.deadline),
+        ])))
+    }
+}
+
+fn permit(chain_id: u64, verifying_contract: u8) -> Permit {
+    Permit {
+        chain_id,
+        verifying_contract: H160::repeat_byte(verifying_contract),
+        owner: H160::repeat_byte(0xaa),
+        spender: H160::repeat_byte(0xbb),
+        value: U256::from(1_000_000u64),
+        nonce: U256::zero(),
+        deadline: U256::MAX,
+    }
+}
+
+// Pads an EVM address into a Fuel address, which is how it is encoded in EIP-712
+fn fuel_address(address: H160) -> Address {
+    let mut bytes = [0u8; 32];
+    bytes[12..].copy_from_slice(address.as_bytes());
+    Address::new(bytes)
+}
+
+async fn hash(
+    instance: &TestStructuredHash<WalletUnlocked>,
+    permit: &Permit,
+) -> (Bits256, Bits256) {
+    instance
+        .main(
+            Bits256(keccak256(NAME)),
+            Bits256(keccak256(VERSION)),
+            permit.chain_id,
+            fuel_address(permit.verifying_contract),
+            Bits256(permit.struct_hash().unwrap()),
+        )
+        .call()
+        .await
+        .unwrap()
+        .value
+}
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn matches_ethers_domain_separator() {
+        let instance = setup().await;
+        let permit = permit(1, 0xcc);
+
+        let (separator, _) = hash(&instance, &permit).await;
+
+        assert_eq!(separator, Bits256(permit.domain_separator().unwrap()));
+    }
+
+    #[tokio::test]
+    async fn matches_ethers_typed_data_hash() {
+        let instance = setup().await;
+        let permit = permit(1, 0xcc);
+
+        let (_, digest) = hash(&instance, &permit).await;
+
+        assert_eq!(digest, Bits256(permit.encode_eip712().unwrap()));
+    }
+
+    #[tokio::test]
+    async fn changes_with_the_domain() {
+        let instance = setup().await;
+
+        for permit in [permit(0, 0xcc), permit(9889, 0xcc), permit(1, 0xdd)] {
+            let (separator, digest) = hash(&instance, &permit).await;
+
+            assert_eq!(separator, Bits256(permit.domain_separator().unwrap()));
+            assert_eq!(digest, Bits256(permit.encode_eip712().unwrap()));
+        }
+
+        let (first, _) = hash(&instance, &permit(1, 0xcc)).await;
+        let (second, _) = hash(&instance, &permit(2, 0xcc)).await;
+        assert_ne!(first, second);
+    }
+}
@@ -5,7 +5,78 @@ mod signed_i32;
 mod signed_i64;
 mod signed_i8;
 
+mod signed_i128_twos_complement;
 mod signed_i16_twos_complement;
+mod signed_i256_twos_complement;
 mod signed_i32_twos_complement;
 mod signed_i64_twos_complement;
 mod signed_i8_twos_complement;
+
+mod signed_i16_wrapping;
+mod signed_i32_wrapping;
+mod signed_i64_wrapping;
+mod signed_i8_wrapping;
+
+mod signed_i16_saturating;
+mod signed_i32_saturating;
+mod signed_i64_saturating;
+mod signed_i8_saturating;
+
+mod signed_i16_checked;
+mod signed_i32_checked;
+mod signed_i64_checked;
+mod signed_i8_checked;
+
+mod signed_i128_shift;
+mod signed_i16_shift;
+mod signed_i256_shift;
+mod signed_i32_shift;
+mod signed_i64_shift;
+mod signed_i8_shift;
+
+mod signed_conversions;
+
+mod signed_i16_abs;
+mod signed_i32_abs;
+mod signed_i64_abs;
+mod signed_i8_abs;
+
+mod signed_abs_reverts;
+
+mod signed_min_max;
+
+mod signed_i32_pow;
+mod signed_i64_pow;
+
+mod signed_pow_reverts;
+
+mod signed_i32_rem_euclid;
+mod signed_i64_rem_euclid;
+mod signed_i8_rem_euclid;
+
+mod signed_rem_euclid_reverts;
+
+mod signed_clamp;
+
+mod signed_clamp_reverts;
+
+mod signed_i32_mul_div;
+mod signed_i64_mul_div;
+
+mod signed_mul_div_reverts;
+
+mod signed_compare;
+
+mod signed_i64_arithmetic;
+
+mod signed_i64_wide_mul;
+
+mod signed_try_from_i64;
+mod signed_try_from_u64;
+
+mod signed_minimum_maximum;
+
+mod signed_i16_operations;
+mod signed_i32_operations;
+mod signed_i64_operations;
+mod signed_i8_operations;
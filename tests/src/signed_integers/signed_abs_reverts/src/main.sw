@@ -0,0 +1,17 @@
+script;
+
+use signed_integers::{i16::I16, i32::I32, i64::I64, i8::I8};
+
+fn main(bits: u64) -> bool {
+    if bits == 8 {
+        let _ = I8::min().abs();
+    } else if bits == 16 {
+        let _ = I16::min().abs();
+    } else if bits == 32 {
+        let _ = I32::min().abs();
+    } else {
+        let _ = I64::min().abs();
+    }
+
+    true
+}
@@ -0,0 +1,51 @@
+use crate::common::script_instance;
+use fuels::prelude::{abigen, WalletUnlocked};
+
+abigen!(Script(
+    name = "TestSignedAbsReverts",
+    abi = "src/signed_integers/signed_abs_reverts/out/debug/signed_abs_reverts_test-abi.json"
+),);
+
+const PATH_TO_BIN: &str =
+    "src/signed_integers/signed_abs_reverts/out/debug/signed_abs_reverts_test.bin";
+
+async fn setup() -> TestSignedAbsReverts<WalletUnlocked> {
+    script_instance!(TestSignedAbsReverts, PATH_TO_BIN)
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "Overflow")]
+    async fn when_taking_abs_of_i8_min() {
+        let instance = setup().await;
+
+        instance.main(8).call().await.unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Overflow")]
+    async fn when_taking_abs_of_i16_min() {
+        let instance = setup().await;
+
+        instance.main(16).call().await.unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Overflow")]
+    async fn when_taking_abs_of_i32_min() {
+        let instance = setup().await;
+
+        instance.main(32).call().await.unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Overflow")]
+    async fn when_taking_abs_of_i64_min() {
+        let instance = setup().await;
+
+        instance.main(64).call().await.unwrap();
+    }
+}
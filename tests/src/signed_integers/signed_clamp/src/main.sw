@@ -0,0 +1,59 @@
+script;
+
+use signed_integers::{i16::I16, i32::I32, i64::I64, i8::I8};
+
+fn main() -> bool {
+    // I8
+    assert(I8::neg_from(10u8).clamp(I8::neg_from(5u8), I8::from(5u8)) == I8::neg_from(5u8));
+    assert(I8::min().clamp(I8::neg_from(5u8), I8::from(5u8)) == I8::neg_from(5u8));
+    assert(I8::neg_from(5u8).clamp(I8::neg_from(5u8), I8::from(5u8)) == I8::neg_from(5u8));
+    assert(I8::neg_from(3u8).clamp(I8::neg_from(5u8), I8::from(5u8)) == I8::neg_from(3u8));
+    assert(I8::new().clamp(I8::neg_from(5u8), I8::from(5u8)) == I8::new());
+    assert(I8::from(5u8).clamp(I8::neg_from(5u8), I8::from(5u8)) == I8::from(5u8));
+    assert(I8::from(6u8).clamp(I8::neg_from(5u8), I8::from(5u8)) == I8::from(5u8));
+    assert(I8::max().clamp(I8::neg_from(5u8), I8::from(5u8)) == I8::from(5u8));
+    assert(I8::from(1u8).clamp(I8::neg_from(7u8), I8::neg_from(7u8)) == I8::neg_from(7u8));
+    assert(I8::max().clamp(I8::min(), I8::max()) == I8::max());
+    assert(I8::min().clamp(I8::min(), I8::max()) == I8::min());
+
+    // I16
+    assert(I16::neg_from(10u16).clamp(I16::neg_from(5u16), I16::from(5u16)) == I16::neg_from(5u16));
+    assert(I16::min().clamp(I16::neg_from(5u16), I16::from(5u16)) == I16::neg_from(5u16));
+    assert(I16::neg_from(5u16).clamp(I16::neg_from(5u16), I16::from(5u16)) == I16::neg_from(5u16));
+    assert(I16::neg_from(3u16).clamp(I16::neg_from(5u16), I16::from(5u16)) == I16::neg_from(3u16));
+    assert(I16::new().clamp(I16::neg_from(5u16), I16::from(5u16)) == I16::new());
+    assert(I16::from(5u16).clamp(I16::neg_from(5u16), I16::from(5u16)) == I16::from(5u16));
+    assert(I16::from(6u16).clamp(I16::neg_from(5u16), I16::from(5u16)) == I16::from(5u16));
+    assert(I16::max().clamp(I16::neg_from(5u16), I16::from(5u16)) == I16::from(5u16));
+    assert(I16::from(1u16).clamp(I16::neg_from(7u16), I16::neg_from(7u16)) == I16::neg_from(7u16));
+    assert(I16::max().clamp(I16::min(), I16::max()) == I16::max());
+    assert(I16::min().clamp(I16::min(), I16::max()) == I16::min());
+
+    // I32
+    assert(I32::neg_from(10u32).clamp(I32::neg_from(5u32), I32::from(5u32)) == I32::neg_from(5u32));
+    assert(I32::min().clamp(I32::neg_from(5u32), I32::from(5u32)) == I32::neg_from(5u32));
+    assert(I32::neg_from(5u32).clamp(I32::neg_from(5u32), I32::from(5u32)) == I32::neg_from(5u32));
+    assert(I32::neg_from(3u32).clamp(I32::neg_from(5u32), I32::from(5u32)) == I32::neg_from(3u32));
+    assert(I32::new().clamp(I32::neg_from(5u32), I32::from(5u32)) == I32::new());
+    assert(I32::from(5u32).clamp(I32::neg_from(5u32), I32::from(5u32)) == I32::from(5u32));
+    assert(I32::from(6u32).clamp(I32::neg_from(5u32), I32::from(5u32)) == I32::from(5u32));
+    assert(I32::max().clamp(I32::neg_from(5u32), I32::from(5u32)) == I32::from(5u32));
+    assert(I32::from(1u32).clamp(I32::neg_from(7u32), I32::neg_from(7u32)) == I32::neg_from(7u32));
+    assert(I32::max().clamp(I32::min(), I32::max()) == I32::max());
+    assert(I32::min().clamp(I32::min(), I32::max()) == I32::min());
+
+    // I64
+    assert(I64::neg_from(10u64).clamp(I64::neg_from(5u64), I64::from(5u64)) == I64::neg_from(5u64));
+    assert(I64::min().clamp(I64::neg_from(5u64), I64::from(5u64)) == I64::neg_from(5u64));
+    assert(I64::neg_from(5u64).clamp(I64::neg_from(5u64), I64::from(5u64)) == I64::neg_from(5u64));
+    assert(I64::neg_from(3u64).clamp(I64::neg_from(5u64), I64::from(5u64)) == I64::neg_from(3u64));
+    assert(I64::new().clamp(I64::neg_from(5u64), I64::from(5u64)) == I64::new());
+    assert(I64::from(5u64).clamp(I64::neg_from(5u64), I64::from(5u64)) == I64::from(5u64));
+    assert(I64::from(6u64).clamp(I64::neg_from(5u64), I64::from(5u64)) == I64::from(5u64));
+    assert(I64::max().clamp(I64::neg_from(5u64), I64::from(5u64)) == I64::from(5u64));
+    assert(I64::from(1u64).clamp(I64::neg_from(7u64), I64::neg_from(7u64)) == I64::neg_from(7u64));
+    assert(I64::max().clamp(I64::min(), I64::max()) == I64::max());
+    assert(I64::min().clamp(I64::min(), I64::max()) == I64::min());
+
+    true
+}
@@ -0,0 +1,19 @@
+use crate::common::run_script;
+use fuels::prelude::abigen;
+
+abigen!(Script(
+    name = "TestSignedClamp",
+    abi = "src/signed_integers/signed_clamp/out/debug/signed_clamp_test-abi.json"
+),);
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn runs_signed_clamp_test_script() {
+        let path_to_bin = "src/signed_integers/signed_clamp/out/debug/signed_clamp_test.bin";
+
+        assert!(run_script!(TestSignedClamp, path_to_bin));
+    }
+}
@@ -0,0 +1,17 @@
+script;
+
+use signed_integers::{i16::I16, i32::I32, i64::I64, i8::I8};
+
+fn main(bits: u64) -> bool {
+    if bits == 8 {
+        let _ = I8::new().clamp(I8::from(1u8), I8::neg_from(1u8));
+    } else if bits == 16 {
+        let _ = I16::new().clamp(I16::from(1u16), I16::neg_from(1u16));
+    } else if bits == 32 {
+        let _ = I32::new().clamp(I32::from(1u32), I32::neg_from(1u32));
+    } else {
+        let _ = I64::new().clamp(I64::from(1u64), I64::neg_from(1u64));
+    }
+
+    true
+}
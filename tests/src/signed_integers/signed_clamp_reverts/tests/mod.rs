@@ -0,0 +1,51 @@
+use crate::common::script_instance;
+use fuels::prelude::{abigen, WalletUnlocked};
+
+abigen!(Script(
+    name = "TestSignedClampReverts",
+    abi = "src/signed_integers/signed_clamp_reverts/out/debug/signed_clamp_reverts_test-abi.json"
+),);
+
+const PATH_TO_BIN: &str =
+    "src/signed_integers/signed_clamp_reverts/out/debug/signed_clamp_reverts_test.bin";
+
+async fn setup() -> TestSignedClampReverts<WalletUnlocked> {
+    script_instance!(TestSignedClampReverts, PATH_TO_BIN)
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidRange")]
+    async fn when_i8_min_exceeds_max() {
+        let instance = setup().await;
+
+        instance.main(8).call().await.unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidRange")]
+    async fn when_i16_min_exceeds_max() {
+        let instance = setup().await;
+
+        instance.main(16).call().await.unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidRange")]
+    async fn when_i32_min_exceeds_max() {
+        let instance = setup().await;
+
+        instance.main(32).call().await.unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidRange")]
+    async fn when_i64_min_exceeds_max() {
+        let instance = setup().await;
+
+        instance.main(64).call().await.unwrap();
+    }
+}
@@ -0,0 +1,13 @@
+script;
+
+use signed_integers::{common::Ordering, i64::I64};
+
+/// Compares the values at every width. The values must fit in an `I8`.
+fn main(lhs: I64, rhs: I64) -> (Ordering, Ordering, Ordering, Ordering) {
+    (
+        lhs.try_into_i8().unwrap().compare(rhs.try_into_i8().unwrap()),
+        lhs.try_into_i16().unwrap().compare(rhs.try_into_i16().unwrap()),
+        lhs.try_into_i32().unwrap().compare(rhs.try_into_i32().unwrap()),
+        lhs.compare(rhs),
+    )
+}
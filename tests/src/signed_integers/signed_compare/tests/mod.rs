@@ -0,0 +1,89 @@
+use crate::common::script_instance;
+use fuels::prelude::{abigen, WalletUnlocked};
+
+abigen!(Script(
+    name = "TestSignedCompare",
+    abi = "src/signed_integers/signed_compare/out/debug/signed_compare_test-abi.json"
+),);
+
+const PATH_TO_BIN: &str = "src/signed_integers/signed_compare/out/debug/signed_compare_test.bin";
+
+async fn setup() -> TestSignedCompare<WalletUnlocked> {
+    script_instance!(TestSignedCompare, PATH_TO_BIN)
+}
+
+fn i64(value: i64) -> I64 {
+    // The underlying value is biased by 2 ^ 63, which only flips the sign bit
+    I64 {
+        underlying: (value as u64) ^ (1 << 63),
+    }
+}
+
+async fn compare(
+    instance: &TestSignedCompare<WalletUnlocked>,
+    lhs: i64,
+    rhs: i64,
+) -> (Ordering, Ordering, Ordering, Ordering) {
+    instance
+        .main(i64(lhs), i64(rhs))
+        .call()
+        .await
+        .unwrap()
+        .value
+}
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_less() {
+        let instance = setup().await;
+
+        for (lhs, rhs) in [(1, 2), (-2, -1), (-1, 1), (-128, 127), (0, 1), (-1, 0)] {
+            assert_eq!(
+                compare(&instance, lhs, rhs).await,
+                (
+                    Ordering::Less,
+                    Ordering::Less,
+                    Ordering::Less,
+                    Ordering::Less
+                )
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn returns_equal() {
+        let instance = setup().await;
+
+        for value in [0, 1, -1, 127, -128] {
+            assert_eq!(
+                compare(&instance, value, value).await,
+                (
+                    Ordering::Equal,
+                    Ordering::Equal,
+                    Ordering::Equal,
+                    Ordering::Equal
+                )
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn returns_greater() {
+        let instance = setup().await;
+
+        for (lhs, rhs) in [(2, 1), (-1, -2), (1, -1), (127, -128), (1, 0), (0, -1)] {
+            assert_eq!(
+                compare(&instance, lhs, rhs).await,
+                (
+                    Ordering::Greater,
+                    Ordering::Greater,
+                    Ordering::Greater,
+                    Ordering::Greater
+                )
+            );
+        }
+    }
+}
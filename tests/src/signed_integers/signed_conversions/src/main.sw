@@ -0,0 +1,97 @@
+script;
+
+use signed_integers::{i16::I16, i32::I32, i64::I64, i8::I8};
+
+fn main() -> bool {
+    // I8 <-> I16
+    assert(I16::from_i8(I8::from(5u8)) == I16::from(5u16));
+    assert(I16::from_i8(I8::neg_from(5u8)) == I16::neg_from(5u16));
+    assert(I16::from_i8(I8::new()) == I16::new());
+    assert(I16::from_i8(I8::max()) == I16::from(127u16));
+    assert(I16::from_i8(I8::min()) == I16::neg_from(128u16));
+    assert(I16::from(5u16).try_into_i8().unwrap() == I8::from(5u8));
+    assert(I16::neg_from(5u16).try_into_i8().unwrap() == I8::neg_from(5u8));
+    assert(I16::from_i8(I8::max()).try_into_i8().unwrap() == I8::max());
+    assert(I16::from_i8(I8::min()).try_into_i8().unwrap() == I8::min());
+    assert(I16::from(128u16).try_into_i8().is_none());
+    assert(I16::neg_from(129u16).try_into_i8().is_none());
+    assert(I16::max().try_into_i8().is_none());
+    assert(I16::min().try_into_i8().is_none());
+
+    // I8 <-> I32
+    assert(I32::from_i8(I8::from(5u8)) == I32::from(5u32));
+    assert(I32::from_i8(I8::neg_from(5u8)) == I32::neg_from(5u32));
+    assert(I32::from_i8(I8::new()) == I32::new());
+    assert(I32::from_i8(I8::max()) == I32::from(127u32));
+    assert(I32::from_i8(I8::min()) == I32::neg_from(128u32));
+    assert(I32::from(5u32).try_into_i8().unwrap() == I8::from(5u8));
+    assert(I32::neg_from(5u32).try_into_i8().unwrap() == I8::neg_from(5u8));
+    assert(I32::from_i8(I8::max()).try_into_i8().unwrap() == I8::max());
+    assert(I32::from_i8(I8::min()).try_into_i8().unwrap() == I8::min());
+    assert(I32::from(128u32).try_into_i8().is_none());
+    assert(I32::neg_from(129u32).try_into_i8().is_none());
+    assert(I32::max().try_into_i8().is_none());
+    assert(I32::min().try_into_i8().is_none());
+
+    // I16 <-> I32
+    assert(I32::from_i16(I16::from(5u16)) == I32::from(5u32));
+    assert(I32::from_i16(I16::neg_from(5u16)) == I32::neg_from(5u32));
+    assert(I32::from_i16(I16::new()) == I32::new());
+    assert(I32::from_i16(I16::max()) == I32::from(32767u32));
+    assert(I32::from_i16(I16::min()) == I32::neg_from(32768u32));
+    assert(I32::from(5u32).try_into_i16().unwrap() == I16::from(5u16));
+    assert(I32::neg_from(5u32).try_into_i16().unwrap() == I16::neg_from(5u16));
+    assert(I32::from_i16(I16::max()).try_into_i16().unwrap() == I16::max());
+    assert(I32::from_i16(I16::min()).try_into_i16().unwrap() == I16::min());
+    assert(I32::from(32768u32).try_into_i16().is_none());
+    assert(I32::neg_from(32769u32).try_into_i16().is_none());
+    assert(I32::max().try_into_i16().is_none());
+    assert(I32::min().try_into_i16().is_none());
+
+    // I8 <-> I64
+    assert(I64::from_i8(I8::from(5u8)) == I64::from(5u64));
+    assert(I64::from_i8(I8::neg_from(5u8)) == I64::neg_from(5u64));
+    assert(I64::from_i8(I8::new()) == I64::new());
+    assert(I64::from_i8(I8::max()) == I64::from(127u64));
+    assert(I64::from_i8(I8::min()) == I64::neg_from(128u64));
+    assert(I64::from(5u64).try_into_i8().unwrap() == I8::from(5u8));
+    assert(I64::neg_from(5u64).try_into_i8().unwrap() == I8::neg_from(5u8));
+    assert(I64::from_i8(I8::max()).try_into_i8().unwrap() == I8::max());
+    assert(I64::from_i8(I8::min()).try_into_i8().unwrap() == I8::min());
+    assert(I64::from(128u64).try_into_i8().is_none());
+    assert(I64::neg_from(129u64).try_into_i8().is_none());
+    assert(I64::max().try_into_i8().is_none());
+    assert(I64::min().try_into_i8().is_none());
+
+    // I16 <-> I64
+    assert(I64::from_i16(I16::from(5u16)) == I64::from(5u64));
+    assert(I64::from_i16(I16::neg_from(5u16)) == I64::neg_from(5u64));
+    assert(I64::from_i16(I16::new()) == I64::new());
+    assert(I64::from_i16(I16::max()) == I64::from(32767u64));
+    assert(I64::from_i16(I16::min()) == I64::neg_from(32768u64));
+    assert(I64::from(5u64).try_into_i16().unwrap() == I16::from(5u16));
+    assert(I64::neg_from(5u64).try_into_i16().unwrap() == I16::neg_from(5u16));
+    assert(I64::from_i16(I16::max()).try_into_i16().unwrap() == I16::max());
+    assert(I64::from_i16(I16::min()).try_into_i16().unwrap() == I16::min());
+    assert(I64::from(32768u64).try_into_i16().is_none());
+    assert(I64::neg_from(32769u64).try_into_i16().is_none());
+    assert(I64::max().try_into_i16().is_none());
+    assert(I64::min().try_into_i16().is_none());
+
+    // I32 <-> I64
+    assert(I64::from_i32(I32::from(5u32)) == I64::from(5u64));
+    assert(I64::from_i32(I32::neg_from(5u32)) == I64::neg_from(5u64));
+    assert(I64::from_i32(I32::new()) == I64::new());
+    assert(I64::from_i32(I32::max()) == I64::from(2147483647u64));
+    assert(I64::from_i32(I32::min()) == I64::neg_from(2147483648u64));
+    assert(I64::from(5u64).try_into_i32().unwrap() == I32::from(5u32));
+    assert(I64::neg_from(5u64).try_into_i32().unwrap() == I32::neg_from(5u32));
+    assert(I64::from_i32(I32::max()).try_into_i32().unwrap() == I32::max());
+    assert(I64::from_i32(I32::min()).try_into_i32().unwrap() == I32::min());
+    assert(I64::from(2147483648u64).try_into_i32().is_none());
+    assert(I64::neg_from(2147483649u64).try_into_i32().is_none());
+    assert(I64::max().try_into_i32().is_none());
+    assert(I64::min().try_into_i32().is_none());
+
+    true
+}
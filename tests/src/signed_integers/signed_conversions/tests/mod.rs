@@ -0,0 +1,20 @@
+use crate::common::run_script;
+use fuels::prelude::abigen;
+
+abigen!(Script(
+    name = "TestSignedConversions",
+    abi = "src/signed_integers/signed_conversions/out/debug/signed_conversions_test-abi.json"
+),);
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn runs_signed_conversions_test_script() {
+        let path_to_bin =
+            "src/signed_integers/signed_conversions/out/debug/signed_conversions_test.bin";
+
+        assert!(run_script!(TestSignedConversions, path_to_bin));
+    }
+}
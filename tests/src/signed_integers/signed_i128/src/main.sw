@@ -37,14 +37,7 @@ fn main() -> bool {
     };
     assert(res == I128::from(u128_100));
 
-    let u128_lower_max_u64 = U128 {
-        upper: 0,
-        lower: u64::max(),
-    };
-
-    res = I128::from(u128_10) / I128 {
-        underlying: u128_lower_max_u64,
-    };
+    res = I128::from(u128_10) / I128::neg_from(u128_one);
     assert(res == I128::neg_from(u128_10));
 
     let u128_5 = U128 {
@@ -60,5 +53,51 @@ fn main() -> bool {
     res = I128::from(u128_10) / I128::from(u128_5);
     assert(res == I128::from(u128_2));
 
+    // boundaries
+    let max = I128::max();
+    let min = I128::min();
+    let zero = I128::new();
+    let neg_one = I128::neg_from(u128_one);
+    assert(max == I128::from(U128::from((9223372036854775807, u64::max()))));
+    assert(min == I128::neg_from(U128::from((9223372036854775808, 0))));
+
+    assert(min < zero);
+    assert(zero < max);
+    assert(neg_one < one);
+    assert(max > min);
+
+    assert(min + max == neg_one);
+    assert(max - one + one == max);
+    assert(min + one - one == min);
+    assert(max - max == zero);
+
+    // subtracting negative values
+    assert(I128::from(U128::from((0, 5))) - I128::neg_from(U128::from((0, 3))) == I128::from(U128::from((0, 8))));
+    assert(I128::neg_from(U128::from((0, 5))) - I128::neg_from(U128::from((0, 3))) == I128::neg_from(U128::from((0, 2))));
+    assert(I128::neg_from(U128::from((0, 3))) - I128::neg_from(U128::from((0, 5))) == I128::from(U128::from((0, 2))));
+    assert(I128::neg_from(U128::from((0, 3))) - I128::from(U128::from((0, 5))) == I128::neg_from(U128::from((0, 8))));
+    assert(min - neg_one == min + one);
+
+    // checked arithmetic
+    assert(max.checked_add(one).is_none());
+    assert(min.checked_add(neg_one).is_none());
+    assert(min.checked_sub(one).is_none());
+    assert(max.checked_sub(neg_one).is_none());
+    assert(zero.checked_sub(min).is_none());
+    assert(max.checked_mul(I128::from(U128::from((0, 2)))).is_none());
+    assert(min.checked_mul(neg_one).is_none());
+    assert(max.checked_mul(neg_one).unwrap() == min + one);
+    assert(I128::neg_from(U128::from((0, 2))).checked_mul(I128::neg_from(U128::from((0, 3)))).unwrap() == I128::from(U128::from((0, 6))));
+    assert(min.checked_div(neg_one).is_none());
+    assert(one.checked_div(zero).is_none());
+    assert(I128::from(U128::from((0, 6))).checked_div(I128::neg_from(U128::from((0, 3)))).unwrap() == I128::neg_from(U128::from((0, 2))));
+
+    assert(max * one == max);
+    assert(min * one == min);
+    assert(max * neg_one == min + one);
+
+    assert(max / one == max);
+    assert(min / one == min);
+
     true
 }
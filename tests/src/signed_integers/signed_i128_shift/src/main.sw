@@ -0,0 +1,54 @@
+script;
+
+use signed_integers::i128::I128;
+use std::u128::U128;
+
+fn main() -> bool {
+    let one = I128::from(U128::from((0, 1)));
+    let two = I128::from(U128::from((0, 2)));
+    let three = I128::from(U128::from((0, 3)));
+    let four = I128::from(U128::from((0, 4)));
+    let five = I128::from(U128::from((0, 5)));
+    let twelve = I128::from(U128::from((0, 12)));
+    let neg_one = I128::new() - one;
+
+    // lsh
+    let mut res = three << 2;
+    assert(res == twelve);
+
+    res = (I128::new() - three) << 2;
+    assert(res == I128::new() - twelve);
+
+    res = one << (I128::bits() - 1);
+    assert(res == I128::min());
+
+    res = I128::max() << 1;
+    assert(res == I128::new() - two);
+
+    res = neg_one << I128::bits();
+    assert(res == I128::new());
+
+    // rsh
+    res = twelve >> 2;
+    assert(res == three);
+
+    res = (I128::new() - four) >> 1;
+    assert(res == I128::new() - two);
+
+    res = (I128::new() - five) >> 1;
+    assert(res == I128::new() - three);
+
+    res = neg_one >> 5;
+    assert(res == neg_one);
+
+    res = I128::min() >> (I128::bits() - 1);
+    assert(res == neg_one);
+
+    res = I128::max() >> I128::bits();
+    assert(res == I128::new());
+
+    res = I128::min() >> I128::bits();
+    assert(res == neg_one);
+
+    true
+}
@@ -1,4 +1,5 @@
-use fuels::prelude::{abigen, launch_provider_and_get_wallet};
+use crate::common::run_script;
+use fuels::prelude::abigen;
 
 abigen!(Script(
     name = "Testi128TwosComplement",
@@ -11,12 +12,8 @@ mod success {
 
     #[tokio::test]
     async fn runs_i128_twos_complement_test_script() {
-        let path_to_bin =
-            "src/signed_integers/signed_i128_twos_complement/out/debug/i128_twos_complement_test.bin";
-        let wallet = launch_provider_and_get_wallet().await;
+        let path_to_bin = "src/signed_integers/signed_i128_twos_complement/out/debug/i128_twos_complement_test.bin";
 
-        let instance = Testi128TwosComplement::new(wallet, path_to_bin);
-
-        let _result = instance.main().call().await;
+        assert!(run_script!(Testi128TwosComplement, path_to_bin));
     }
 }
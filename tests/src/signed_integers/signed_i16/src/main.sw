@@ -12,6 +12,15 @@ fn main() -> bool {
         underlying: 32767u16,
     });
 
+    res = I16::from(5u16) - I16::neg_from(3u16);
+    assert(res == I16::from(8u16));
+
+    res = I16::neg_from(5u16) - I16::neg_from(3u16);
+    assert(res == I16::neg_from(2u16));
+
+    res = I16::neg_from(3u16) - I16::neg_from(5u16);
+    assert(res == I16::from(2u16));
+
     res = I16::from(10u16) * I16::neg_from(1u16);
     assert(res == I16::neg_from(10u16));
 
@@ -0,0 +1,39 @@
+script;
+
+use signed_integers::i16::I16;
+
+fn main() -> bool {
+    // abs
+    let mut res = I16::from(5u16).abs();
+    assert(res == I16::from(5u16));
+
+    res = I16::neg_from(5u16).abs();
+    assert(res == I16::from(5u16));
+
+    res = I16::new().abs();
+    assert(res == I16::new());
+
+    res = I16::max().abs();
+    assert(res == I16::max());
+
+    res = (I16::min() + I16::from(1u16)).abs();
+    assert(res == I16::max());
+
+    // wrapping_neg
+    res = I16::from(5u16).wrapping_neg();
+    assert(res == I16::neg_from(5u16));
+
+    res = I16::neg_from(5u16).wrapping_neg();
+    assert(res == I16::from(5u16));
+
+    res = I16::new().wrapping_neg();
+    assert(res == I16::new());
+
+    res = I16::max().wrapping_neg();
+    assert(res == I16::min() + I16::from(1u16));
+
+    res = I16::min().wrapping_neg();
+    assert(res == I16::min());
+
+    true
+}
@@ -0,0 +1,19 @@
+use crate::common::run_script;
+use fuels::prelude::abigen;
+
+abigen!(Script(
+    name = "Testi16Abs",
+    abi = "src/signed_integers/signed_i16_abs/out/debug/i16_abs_test-abi.json"
+),);
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn runs_i16_abs_test_script() {
+        let path_to_bin = "src/signed_integers/signed_i16_abs/out/debug/i16_abs_test.bin";
+
+        assert!(run_script!(Testi16Abs, path_to_bin));
+    }
+}
@@ -0,0 +1,48 @@
+script;
+
+use signed_integers::i16::I16;
+
+fn main() -> bool {
+    let zero = I16::new();
+    let one = I16::from(1u16);
+    let neg_one = I16::neg_from(1u16);
+
+    // checked_add
+    assert(I16::from(10u16).checked_add(I16::neg_from(20u16)).unwrap() == I16::neg_from(10u16));
+    assert(I16::max().checked_add(zero).unwrap() == I16::max());
+    assert(I16::max().checked_add(one).is_none());
+    assert(I16::min().checked_add(neg_one).is_none());
+    assert(I16::min().checked_add(I16::max()).unwrap() == neg_one);
+
+    // checked_sub
+    assert(I16::from(10u16).checked_sub(I16::neg_from(20u16)).unwrap() == I16::from(30u16));
+    assert(I16::min().checked_sub(zero).unwrap() == I16::min());
+    assert(I16::min().checked_sub(one).is_none());
+    assert(I16::max().checked_sub(neg_one).is_none());
+    assert(I16::max().checked_sub(I16::max()).unwrap() == zero);
+
+    // checked_mul
+    assert(I16::from(10u16).checked_mul(I16::neg_from(3u16)).unwrap() == I16::neg_from(30u16));
+    assert(I16::neg_from(10u16).checked_mul(I16::neg_from(3u16)).unwrap() == I16::from(30u16));
+    assert(I16::max().checked_mul(one).unwrap() == I16::max());
+    assert(I16::max().checked_mul(neg_one).unwrap() == I16::min() + one);
+    assert(I16::min().checked_mul(one).unwrap() == I16::min());
+    assert(I16::min().checked_mul(neg_one).is_none());
+    assert(I16::max().checked_mul(I16::from(2u16)).is_none());
+    assert(I16::min().checked_mul(I16::from(2u16)).is_none());
+    assert(I16::neg_from(128u16).checked_mul(I16::from(256u16)).unwrap() == I16::min());
+    assert(I16::from(128u16).checked_mul(I16::from(256u16)).is_none());
+    assert(zero.checked_mul(I16::min()).unwrap() == zero);
+
+    // checked_div
+    assert(I16::from(30u16).checked_div(I16::neg_from(3u16)).unwrap() == I16::neg_from(10u16));
+    assert(I16::neg_from(30u16).checked_div(I16::neg_from(3u16)).unwrap() == I16::from(10u16));
+    assert(I16::max().checked_div(neg_one).unwrap() == I16::min() + one);
+    assert(I16::min().checked_div(one).unwrap() == I16::min());
+    assert(I16::min().checked_div(neg_one).is_none());
+    assert(I16::max().checked_div(zero).is_none());
+    assert(I16::min().checked_div(zero).is_none());
+    assert(zero.checked_div(zero).is_none());
+
+    true
+}
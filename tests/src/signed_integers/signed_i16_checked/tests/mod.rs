@@ -0,0 +1,19 @@
+use crate::common::run_script;
+use fuels::prelude::abigen;
+
+abigen!(Script(
+    name = "Testi16Checked",
+    abi = "src/signed_integers/signed_i16_checked/out/debug/i16_checked_test-abi.json"
+),);
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn runs_i16_checked_test_script() {
+        let path_to_bin = "src/signed_integers/signed_i16_checked/out/debug/i16_checked_test.bin";
+
+        assert!(run_script!(Testi16Checked, path_to_bin));
+    }
+}
@@ -0,0 +1,21 @@
+script;
+
+use signed_integers::i16::I16;
+
+enum Operation {
+    Add: (),
+    Subtract: (),
+    Multiply: (),
+    Negate: (),
+    Abs: (),
+}
+
+fn main(operation: Operation, lhs: I16, rhs: I16) -> I16 {
+    match operation {
+        Operation::Add => lhs + rhs,
+        Operation::Subtract => lhs - rhs,
+        Operation::Multiply => lhs * rhs,
+        Operation::Negate => lhs.neg(),
+        Operation::Abs => lhs.abs(),
+    }
+}
@@ -0,0 +1,23 @@
+use crate::common::{script_instance, signed_operations_tests};
+use fuels::prelude::{abigen, WalletUnlocked};
+
+abigen!(Script(
+    name = "Testi16Operations",
+    abi = "src/signed_integers/signed_i16_operations/out/debug/i16_operations_test-abi.json"
+),);
+
+const PATH_TO_BIN: &str =
+    "src/signed_integers/signed_i16_operations/out/debug/i16_operations_test.bin";
+
+async fn setup() -> Testi16Operations<WalletUnlocked> {
+    script_instance!(Testi16Operations, PATH_TO_BIN)
+}
+
+fn i16(value: i16) -> I16 {
+    // The underlying value is biased by 2 ^ 15, which only flips the sign bit
+    I16 {
+        underlying: (value as u16) ^ (1 << 15),
+    }
+}
+
+signed_operations_tests!(i16, i16);
@@ -0,0 +1,47 @@
+script;
+
+use signed_integers::i16::I16;
+
+fn main() -> bool {
+    let one = I16::from(1u16);
+
+    // saturating_add
+    let mut res = I16::from(10u16).saturating_add(I16::from(20u16));
+    assert(res == I16::from(30u16));
+
+    res = I16::from(10u16).saturating_add(I16::neg_from(20u16));
+    assert(res == I16::neg_from(10u16));
+
+    res = I16::max().saturating_add(one);
+    assert(res == I16::max());
+
+    res = I16::max().saturating_add(I16::max());
+    assert(res == I16::max());
+
+    res = I16::min().saturating_add(I16::neg_from(1u16));
+    assert(res == I16::min());
+
+    res = I16::min().saturating_add(I16::max());
+    assert(res == I16::neg_from(1u16));
+
+    // saturating_sub
+    res = I16::from(10u16).saturating_sub(I16::from(20u16));
+    assert(res == I16::neg_from(10u16));
+
+    res = I16::from(10u16).saturating_sub(I16::neg_from(20u16));
+    assert(res == I16::from(30u16));
+
+    res = I16::min().saturating_sub(one);
+    assert(res == I16::min());
+
+    res = I16::min().saturating_sub(I16::max());
+    assert(res == I16::min());
+
+    res = I16::max().saturating_sub(I16::neg_from(1u16));
+    assert(res == I16::max());
+
+    res = I16::max().saturating_sub(I16::max());
+    assert(res == I16::new());
+
+    true
+}
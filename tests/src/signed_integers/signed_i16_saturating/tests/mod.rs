@@ -0,0 +1,20 @@
+use crate::common::run_script;
+use fuels::prelude::abigen;
+
+abigen!(Script(
+    name = "Testi16Saturating",
+    abi = "src/signed_integers/signed_i16_saturating/out/debug/i16_saturating_test-abi.json"
+),);
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn runs_i16_saturating_test_script() {
+        let path_to_bin =
+            "src/signed_integers/signed_i16_saturating/out/debug/i16_saturating_test.bin";
+
+        assert!(run_script!(Testi16Saturating, path_to_bin));
+    }
+}
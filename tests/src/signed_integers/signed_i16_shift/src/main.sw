@@ -0,0 +1,48 @@
+script;
+
+use signed_integers::i16::I16;
+
+fn main() -> bool {
+    // lsh
+    let mut res = I16::from(3u16) << 2;
+    assert(res == I16::from(12u16));
+
+    res = I16::neg_from(3u16) << 2;
+    assert(res == I16::neg_from(12u16));
+
+    res = I16::from(1u16) << (I16::bits() - 1);
+    assert(res == I16::min());
+
+    res = I16::max() << 1;
+    assert(res == I16::neg_from(2u16));
+
+    res = I16::neg_from(1u16) << I16::bits();
+    assert(res == I16::new());
+
+    // rsh
+    res = I16::from(12u16) >> 2;
+    assert(res == I16::from(3u16));
+
+    res = I16::from(13u16) >> 2;
+    assert(res == I16::from(3u16));
+
+    res = I16::neg_from(4u16) >> 1;
+    assert(res == I16::neg_from(2u16));
+
+    res = I16::neg_from(5u16) >> 1;
+    assert(res == I16::neg_from(3u16));
+
+    res = I16::neg_from(1u16) >> 5;
+    assert(res == I16::neg_from(1u16));
+
+    res = I16::min() >> (I16::bits() - 1);
+    assert(res == I16::neg_from(1u16));
+
+    res = I16::max() >> I16::bits();
+    assert(res == I16::new());
+
+    res = I16::min() >> I16::bits();
+    assert(res == I16::neg_from(1u16));
+
+    true
+}
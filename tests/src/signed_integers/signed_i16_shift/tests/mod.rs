@@ -0,0 +1,19 @@
+use crate::common::run_script;
+use fuels::prelude::abigen;
+
+abigen!(Script(
+    name = "Testi16Shift",
+    abi = "src/signed_integers/signed_i16_shift/out/debug/i16_shift_test-abi.json"
+),);
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn runs_i16_shift_test_script() {
+        let path_to_bin = "src/signed_integers/signed_i16_shift/out/debug/i16_shift_test.bin";
+
+        assert!(run_script!(Testi16Shift, path_to_bin));
+    }
+}
@@ -0,0 +1,45 @@
+script;
+
+use signed_integers::i16::I16;
+
+fn main() -> bool {
+    let one = I16::from(1u16);
+
+    // wrapping_add
+    let mut res = I16::from(10u16).wrapping_add(I16::from(20u16));
+    assert(res == I16::from(30u16));
+
+    res = I16::neg_from(10u16).wrapping_add(I16::from(5u16));
+    assert(res == I16::neg_from(5u16));
+
+    res = I16::max().wrapping_add(one);
+    assert(res == I16::min());
+
+    res = I16::min().wrapping_add(I16::neg_from(1u16));
+    assert(res == I16::max());
+
+    // wrapping_sub
+    res = I16::from(10u16).wrapping_sub(I16::from(20u16));
+    assert(res == I16::neg_from(10u16));
+
+    res = I16::min().wrapping_sub(one);
+    assert(res == I16::max());
+
+    res = I16::max().wrapping_sub(I16::neg_from(1u16));
+    assert(res == I16::min());
+
+    // wrapping_mul
+    res = I16::from(10u16).wrapping_mul(I16::neg_from(3u16));
+    assert(res == I16::neg_from(30u16));
+
+    res = I16::from(256u16).wrapping_mul(I16::from(256u16));
+    assert(res == I16::new());
+
+    res = I16::from(200u16).wrapping_mul(I16::from(200u16));
+    assert(res == I16::neg_from(25536u16));
+
+    res = I16::min().wrapping_mul(I16::neg_from(1u16));
+    assert(res == I16::min());
+
+    true
+}
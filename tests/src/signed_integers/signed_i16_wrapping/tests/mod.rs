@@ -0,0 +1,19 @@
+use crate::common::run_script;
+use fuels::prelude::abigen;
+
+abigen!(Script(
+    name = "Testi16Wrapping",
+    abi = "src/signed_integers/signed_i16_wrapping/out/debug/i16_wrapping_test-abi.json"
+),);
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn runs_i16_wrapping_test_script() {
+        let path_to_bin = "src/signed_integers/signed_i16_wrapping/out/debug/i16_wrapping_test.bin";
+
+        assert!(run_script!(Testi16Wrapping, path_to_bin));
+    }
+}
@@ -48,16 +48,7 @@ fn main() -> bool {
     };
     assert(res == I256::from(u128_100));
 
-    let u128_lower_max_u64 = U256 {
-        a: 0,
-        b: 0,
-        c: 0,
-        d: u64::max(),
-    };
-
-    res = I256::from(u128_10) / I256 {
-        underlying: u128_lower_max_u64,
-    };
+    res = I256::from(u128_10) / I256::neg_from(u128_one);
     assert(res == I256::neg_from(u128_10));
 
     let u128_5 = U256 {
@@ -81,5 +72,51 @@ fn main() -> bool {
     res = i256_10 / i256_5;
     assert(res == i256_2);
 
+    // boundaries
+    let max = I256::max();
+    let min = I256::min();
+    let zero = I256::new();
+    let neg_one = I256::neg_from(u128_one);
+    assert(max == I256::from(U256::from((9223372036854775807, u64::max(), u64::max(), u64::max()))));
+    assert(min == I256::neg_from(U256::from((9223372036854775808, 0, 0, 0))));
+
+    assert(min < zero);
+    assert(zero < max);
+    assert(neg_one < one);
+    assert(max > min);
+
+    assert(min + max == neg_one);
+    assert(max - one + one == max);
+    assert(min + one - one == min);
+    assert(max - max == zero);
+
+    // subtracting negative values
+    assert(I256::from(U256::from((0, 0, 0, 5))) - I256::neg_from(U256::from((0, 0, 0, 3))) == I256::from(U256::from((0, 0, 0, 8))));
+    assert(I256::neg_from(U256::from((0, 0, 0, 5))) - I256::neg_from(U256::from((0, 0, 0, 3))) == I256::neg_from(U256::from((0, 0, 0, 2))));
+    assert(I256::neg_from(U256::from((0, 0, 0, 3))) - I256::neg_from(U256::from((0, 0, 0, 5))) == I256::from(U256::from((0, 0, 0, 2))));
+    assert(I256::neg_from(U256::from((0, 0, 0, 3))) - I256::from(U256::from((0, 0, 0, 5))) == I256::neg_from(U256::from((0, 0, 0, 8))));
+    assert(min - neg_one == min + one);
+
+    // checked arithmetic
+    assert(max.checked_add(one).is_none());
+    assert(min.checked_add(neg_one).is_none());
+    assert(min.checked_sub(one).is_none());
+    assert(max.checked_sub(neg_one).is_none());
+    assert(zero.checked_sub(min).is_none());
+    assert(max.checked_mul(I256::from(U256::from((0, 0, 0, 2)))).is_none());
+    assert(min.checked_mul(neg_one).is_none());
+    assert(max.checked_mul(neg_one).unwrap() == min + one);
+    assert(I256::neg_from(U256::from((0, 0, 0, 2))).checked_mul(I256::neg_from(U256::from((0, 0, 0, 3)))).unwrap() == I256::from(U256::from((0, 0, 0, 6))));
+    assert(min.checked_div(neg_one).is_none());
+    assert(one.checked_div(zero).is_none());
+    assert(I256::from(U256::from((0, 0, 0, 6))).checked_div(I256::neg_from(U256::from((0, 0, 0, 3)))).unwrap() == I256::neg_from(U256::from((0, 0, 0, 2))));
+
+    assert(max * one == max);
+    assert(min * one == min);
+    assert(max * neg_one == min + one);
+
+    assert(max / one == max);
+    assert(min / one == min);
+
     true
 }
@@ -1,4 +1,5 @@
-use fuels::prelude::{abigen, launch_provider_and_get_wallet};
+use crate::common::run_script;
+use fuels::prelude::abigen;
 
 abigen!(Script(
     name = "Testi256",
@@ -12,10 +13,7 @@ mod success {
     #[tokio::test]
     async fn runs_i256_test_script() {
         let path_to_bin = "src/signed_integers/signed_i256/out/debug/i256_test.bin";
-        let wallet = launch_provider_and_get_wallet().await;
 
-        let instance = Testi256::new(wallet, path_to_bin);
-
-        let _result = instance.main().call().await;
+        assert!(run_script!(Testi256, path_to_bin));
     }
 }
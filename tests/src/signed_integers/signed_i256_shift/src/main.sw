@@ -0,0 +1,54 @@
+script;
+
+use signed_integers::i256::I256;
+use std::u256::U256;
+
+fn main() -> bool {
+    let one = I256::from(U256::from((0, 0, 0, 1)));
+    let two = I256::from(U256::from((0, 0, 0, 2)));
+    let three = I256::from(U256::from((0, 0, 0, 3)));
+    let four = I256::from(U256::from((0, 0, 0, 4)));
+    let five = I256::from(U256::from((0, 0, 0, 5)));
+    let twelve = I256::from(U256::from((0, 0, 0, 12)));
+    let neg_one = I256::new() - one;
+
+    // lsh
+    let mut res = three << 2;
+    assert(res == twelve);
+
+    res = (I256::new() - three) << 2;
+    assert(res == I256::new() - twelve);
+
+    res = one << (I256::bits() - 1);
+    assert(res == I256::min());
+
+    res = I256::max() << 1;
+    assert(res == I256::new() - two);
+
+    res = neg_one << I256::bits();
+    assert(res == I256::new());
+
+    // rsh
+    res = twelve >> 2;
+    assert(res == three);
+
+    res = (I256::new() - four) >> 1;
+    assert(res == I256::new() - two);
+
+    res = (I256::new() - five) >> 1;
+    assert(res == I256::new() - three);
+
+    res = neg_one >> 5;
+    assert(res == neg_one);
+
+    res = I256::min() >> (I256::bits() - 1);
+    assert(res == neg_one);
+
+    res = I256::max() >> I256::bits();
+    assert(res == I256::new());
+
+    res = I256::min() >> I256::bits();
+    assert(res == neg_one);
+
+    true
+}
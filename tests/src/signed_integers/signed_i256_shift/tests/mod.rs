@@ -0,0 +1,19 @@
+use crate::common::run_script;
+use fuels::prelude::abigen;
+
+abigen!(Script(
+    name = "Testi256Shift",
+    abi = "src/signed_integers/signed_i256_shift/out/debug/i256_shift_test-abi.json"
+),);
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn runs_i256_shift_test_script() {
+        let path_to_bin = "src/signed_integers/signed_i256_shift/out/debug/i256_shift_test.bin";
+
+        assert!(run_script!(Testi256Shift, path_to_bin));
+    }
+}
@@ -1,4 +1,5 @@
-use fuels::prelude::{abigen, launch_provider_and_get_wallet};
+use crate::common::script_instance;
+use fuels::prelude::abigen;
 
 abigen!(Script(
     name = "Testi32",
@@ -12,9 +13,8 @@ mod success {
     #[tokio::test]
     async fn runs_i32_test_script() {
         let path_to_bin = "src/signed_integers/signed_i32/out/debug/i32_test.bin";
-        let wallet = launch_provider_and_get_wallet().await;
 
-        let instance = Testi32::new(wallet, path_to_bin);
+        let instance = script_instance!(Testi32, path_to_bin);
 
         let _result = instance.main().call().await;
     }
@@ -0,0 +1,39 @@
+script;
+
+use signed_integers::i32::I32;
+
+fn main() -> bool {
+    // abs
+    let mut res = I32::from(5u32).abs();
+    assert(res == I32::from(5u32));
+
+    res = I32::neg_from(5u32).abs();
+    assert(res == I32::from(5u32));
+
+    res = I32::new().abs();
+    assert(res == I32::new());
+
+    res = I32::max().abs();
+    assert(res == I32::max());
+
+    res = (I32::min() + I32::from(1u32)).abs();
+    assert(res == I32::max());
+
+    // wrapping_neg
+    res = I32::from(5u32).wrapping_neg();
+    assert(res == I32::neg_from(5u32));
+
+    res = I32::neg_from(5u32).wrapping_neg();
+    assert(res == I32::from(5u32));
+
+    res = I32::new().wrapping_neg();
+    assert(res == I32::new());
+
+    res = I32::max().wrapping_neg();
+    assert(res == I32::min() + I32::from(1u32));
+
+    res = I32::min().wrapping_neg();
+    assert(res == I32::min());
+
+    true
+}
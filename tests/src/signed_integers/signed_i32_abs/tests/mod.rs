@@ -0,0 +1,19 @@
+use crate::common::run_script;
+use fuels::prelude::abigen;
+
+abigen!(Script(
+    name = "Testi32Abs",
+    abi = "src/signed_integers/signed_i32_abs/out/debug/i32_abs_test-abi.json"
+),);
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn runs_i32_abs_test_script() {
+        let path_to_bin = "src/signed_integers/signed_i32_abs/out/debug/i32_abs_test.bin";
+
+        assert!(run_script!(Testi32Abs, path_to_bin));
+    }
+}
@@ -0,0 +1,48 @@
+script;
+
+use signed_integers::i32::I32;
+
+fn main() -> bool {
+    let zero = I32::new();
+    let one = I32::from(1u32);
+    let neg_one = I32::neg_from(1u32);
+
+    // checked_add
+    assert(I32::from(10u32).checked_add(I32::neg_from(20u32)).unwrap() == I32::neg_from(10u32));
+    assert(I32::max().checked_add(zero).unwrap() == I32::max());
+    assert(I32::max().checked_add(one).is_none());
+    assert(I32::min().checked_add(neg_one).is_none());
+    assert(I32::min().checked_add(I32::max()).unwrap() == neg_one);
+
+    // checked_sub
+    assert(I32::from(10u32).checked_sub(I32::neg_from(20u32)).unwrap() == I32::from(30u32));
+    assert(I32::min().checked_sub(zero).unwrap() == I32::min());
+    assert(I32::min().checked_sub(one).is_none());
+    assert(I32::max().checked_sub(neg_one).is_none());
+    assert(I32::max().checked_sub(I32::max()).unwrap() == zero);
+
+    // checked_mul
+    assert(I32::from(10u32).checked_mul(I32::neg_from(3u32)).unwrap() == I32::neg_from(30u32));
+    assert(I32::neg_from(10u32).checked_mul(I32::neg_from(3u32)).unwrap() == I32::from(30u32));
+    assert(I32::max().checked_mul(one).unwrap() == I32::max());
+    assert(I32::max().checked_mul(neg_one).unwrap() == I32::min() + one);
+    assert(I32::min().checked_mul(one).unwrap() == I32::min());
+    assert(I32::min().checked_mul(neg_one).is_none());
+    assert(I32::max().checked_mul(I32::from(2u32)).is_none());
+    assert(I32::min().checked_mul(I32::from(2u32)).is_none());
+    assert(I32::neg_from(32768u32).checked_mul(I32::from(65536u32)).unwrap() == I32::min());
+    assert(I32::from(32768u32).checked_mul(I32::from(65536u32)).is_none());
+    assert(zero.checked_mul(I32::min()).unwrap() == zero);
+
+    // checked_div
+    assert(I32::from(30u32).checked_div(I32::neg_from(3u32)).unwrap() == I32::neg_from(10u32));
+    assert(I32::neg_from(30u32).checked_div(I32::neg_from(3u32)).unwrap() == I32::from(10u32));
+    assert(I32::max().checked_div(neg_one).unwrap() == I32::min() + one);
+    assert(I32::min().checked_div(one).unwrap() == I32::min());
+    assert(I32::min().checked_div(neg_one).is_none());
+    assert(I32::max().checked_div(zero).is_none());
+    assert(I32::min().checked_div(zero).is_none());
+    assert(zero.checked_div(zero).is_none());
+
+    true
+}
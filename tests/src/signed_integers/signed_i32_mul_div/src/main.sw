@@ -0,0 +1,46 @@
+script;
+
+use signed_integers::i32::I32;
+
+fn main() -> bool {
+    // positive * positive
+    assert(I32::from(6u32) * I32::from(7u32) == I32::from(42u32));
+    // positive * negative
+    assert(I32::from(6u32) * I32::neg_from(7u32) == I32::neg_from(42u32));
+    assert(I32::neg_from(6u32) * I32::from(7u32) == I32::neg_from(42u32));
+    // negative * negative
+    assert(I32::neg_from(6u32) * I32::neg_from(7u32) == I32::from(42u32));
+    // multiplying by zero and one
+    assert(I32::neg_from(6u32) * I32::new() == I32::new());
+    assert(I32::min() * I32::from(1u32) == I32::min());
+    // result at MAX
+    assert(I32::max() * I32::from(1u32) == I32::max());
+    assert(I32::max() * I32::neg_from(1u32) == I32::neg_from(2147483647u32));
+    assert(I32::neg_from(2147483647u32) * I32::neg_from(1u32) == I32::max());
+    // result at MIN
+    assert(I32::neg_from(1073741824u32) * I32::from(2u32) == I32::min());
+    assert(I32::from(1073741824u32) * I32::neg_from(2u32) == I32::min());
+
+    // positive / positive
+    assert(I32::from(42u32) / I32::from(7u32) == I32::from(6u32));
+    assert(I32::from(43u32) / I32::from(7u32) == I32::from(6u32));
+    // positive / negative, rounding towards zero
+    assert(I32::from(42u32) / I32::neg_from(7u32) == I32::neg_from(6u32));
+    assert(I32::from(43u32) / I32::neg_from(7u32) == I32::neg_from(6u32));
+    assert(I32::neg_from(43u32) / I32::from(7u32) == I32::neg_from(6u32));
+    // negative / negative
+    assert(I32::neg_from(42u32) / I32::neg_from(7u32) == I32::from(6u32));
+    assert(I32::neg_from(43u32) / I32::neg_from(7u32) == I32::from(6u32));
+    // result at MAX
+    assert(I32::max() / I32::from(1u32) == I32::max());
+    assert(I32::neg_from(2147483647u32) / I32::neg_from(1u32) == I32::max());
+    // result at MIN
+    assert(I32::min() / I32::from(1u32) == I32::min());
+    assert(I32::min() / I32::from(2u32) == I32::neg_from(1073741824u32));
+    assert(I32::min() / I32::min() == I32::from(1u32));
+
+    // `min() / -1` is rejected by the checked variant
+    assert(I32::min().checked_div(I32::neg_from(1u32)).is_none());
+
+    true
+}
@@ -0,0 +1,19 @@
+use crate::common::run_script;
+use fuels::prelude::abigen;
+
+abigen!(Script(
+    name = "Testi32MulDiv",
+    abi = "src/signed_integers/signed_i32_mul_div/out/debug/i32_mul_div_test-abi.json"
+),);
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn runs_i32_mul_div_test_script() {
+        let path_to_bin = "src/signed_integers/signed_i32_mul_div/out/debug/i32_mul_div_test.bin";
+
+        assert!(run_script!(Testi32MulDiv, path_to_bin));
+    }
+}
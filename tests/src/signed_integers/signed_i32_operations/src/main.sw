@@ -0,0 +1,21 @@
+script;
+
+use signed_integers::i32::I32;
+
+enum Operation {
+    Add: (),
+    Subtract: (),
+    Multiply: (),
+    Negate: (),
+    Abs: (),
+}
+
+fn main(operation: Operation, lhs: I32, rhs: I32) -> I32 {
+    match operation {
+        Operation::Add => lhs + rhs,
+        Operation::Subtract => lhs - rhs,
+        Operation::Multiply => lhs * rhs,
+        Operation::Negate => lhs.neg(),
+        Operation::Abs => lhs.abs(),
+    }
+}
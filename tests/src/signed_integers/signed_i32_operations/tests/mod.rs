@@ -0,0 +1,23 @@
+use crate::common::{script_instance, signed_operations_tests};
+use fuels::prelude::{abigen, WalletUnlocked};
+
+abigen!(Script(
+    name = "Testi32Operations",
+    abi = "src/signed_integers/signed_i32_operations/out/debug/i32_operations_test-abi.json"
+),);
+
+const PATH_TO_BIN: &str =
+    "src/signed_integers/signed_i32_operations/out/debug/i32_operations_test.bin";
+
+async fn setup() -> Testi32Operations<WalletUnlocked> {
+    script_instance!(Testi32Operations, PATH_TO_BIN)
+}
+
+fn i32(value: i32) -> I32 {
+    // The underlying value is biased by 2 ^ 31, which only flips the sign bit
+    I32 {
+        underlying: (value as u32) ^ (1 << 31),
+    }
+}
+
+signed_operations_tests!(i32, i32);
@@ -0,0 +1,26 @@
+script;
+
+use signed_integers::i32::I32;
+
+fn main() -> bool {
+    // zero exponent and zero base
+    assert(I32::new().pow(0) == I32::from(1u32));
+    assert(I32::neg_from(5u32).pow(0) == I32::from(1u32));
+    assert(I32::min().pow(0) == I32::from(1u32));
+    assert(I32::new().pow(3) == I32::new());
+
+    // positive bases
+    assert(I32::from(2u32).pow(10) == I32::from(1024u32));
+    assert(I32::from(3u32).pow(4) == I32::from(81u32));
+    assert(I32::from(2u32).pow(30) == I32::from(1073741824u32));
+    assert(I32::max().pow(1) == I32::max());
+
+    // negative bases
+    assert(I32::neg_from(3u32).pow(3) == I32::neg_from(27u32));
+    assert(I32::neg_from(3u32).pow(4) == I32::from(81u32));
+    assert(I32::neg_from(1u32).pow(4294967295) == I32::neg_from(1u32));
+    assert(I32::neg_from(2u32).pow(31) == I32::min());
+    assert(I32::min().pow(1) == I32::min());
+
+    true
+}
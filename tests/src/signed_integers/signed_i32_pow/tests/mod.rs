@@ -0,0 +1,19 @@
+use crate::common::run_script;
+use fuels::prelude::abigen;
+
+abigen!(Script(
+    name = "Testi32Pow",
+    abi = "src/signed_integers/signed_i32_pow/out/debug/i32_pow_test-abi.json"
+),);
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn runs_i32_pow_test_script() {
+        let path_to_bin = "src/signed_integers/signed_i32_pow/out/debug/i32_pow_test.bin";
+
+        assert!(run_script!(Testi32Pow, path_to_bin));
+    }
+}
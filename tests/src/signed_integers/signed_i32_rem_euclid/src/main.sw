@@ -0,0 +1,42 @@
+script;
+
+use signed_integers::i32::I32;
+
+fn main() -> bool {
+    // negative dividend and positive divisor
+    assert(I32::neg_from(7u32).rem_euclid(I32::from(3u32)) == I32::from(2u32));
+    assert(I32::neg_from(6u32).rem_euclid(I32::from(3u32)) == I32::from(0u32));
+    assert(I32::neg_from(1u32).rem_euclid(I32::from(5u32)) == I32::from(4u32));
+    assert(I32::neg_from(100u32).rem_euclid(I32::from(7u32)) == I32::from(5u32));
+    assert(I32::min().rem_euclid(I32::from(3u32)) == I32::from(1u32));
+    assert(I32::min().rem_euclid(I32::max()) == I32::from(2147483646u32));
+
+    // positive dividend and negative divisor
+    assert(I32::from(7u32).rem_euclid(I32::neg_from(3u32)) == I32::from(1u32));
+    assert(I32::from(6u32).rem_euclid(I32::neg_from(3u32)) == I32::from(0u32));
+    assert(I32::from(1u32).rem_euclid(I32::neg_from(5u32)) == I32::from(1u32));
+    assert(I32::from(100u32).rem_euclid(I32::neg_from(7u32)) == I32::from(2u32));
+    assert(I32::max().rem_euclid(I32::neg_from(2u32)) == I32::from(1u32));
+    assert(I32::max().rem_euclid(I32::min()) == I32::max());
+
+    // both negative
+    assert(I32::neg_from(7u32).rem_euclid(I32::neg_from(3u32)) == I32::from(2u32));
+    assert(I32::neg_from(6u32).rem_euclid(I32::neg_from(3u32)) == I32::from(0u32));
+    assert(I32::neg_from(1u32).rem_euclid(I32::neg_from(5u32)) == I32::from(4u32));
+    assert(I32::min().rem_euclid(I32::neg_from(1u32)) == I32::from(0u32));
+    assert(I32::min().rem_euclid(I32::min()) == I32::from(0u32));
+    assert(I32::neg_from(5u32).rem_euclid(I32::min()) == I32::from(2147483643u32));
+
+    // both positive
+    assert(I32::from(7u32).rem_euclid(I32::from(3u32)) == I32::from(1u32));
+    assert(I32::from(6u32).rem_euclid(I32::from(3u32)) == I32::from(0u32));
+    assert(I32::from(1u32).rem_euclid(I32::from(5u32)) == I32::from(1u32));
+    assert(I32::from(0u32).rem_euclid(I32::from(5u32)) == I32::from(0u32));
+    assert(I32::max().rem_euclid(I32::max()) == I32::from(0u32));
+    assert(I32::max().rem_euclid(I32::from(2u32)) == I32::from(1u32));
+
+    // zero dividend
+    assert(I32::new().rem_euclid(I32::neg_from(3u32)) == I32::new());
+
+    true
+}
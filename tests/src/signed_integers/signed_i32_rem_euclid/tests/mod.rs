@@ -0,0 +1,20 @@
+use crate::common::run_script;
+use fuels::prelude::abigen;
+
+abigen!(Script(
+    name = "Testi32RemEuclid",
+    abi = "src/signed_integers/signed_i32_rem_euclid/out/debug/i32_rem_euclid_test-abi.json"
+),);
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn runs_i32_rem_euclid_test_script() {
+        let path_to_bin =
+            "src/signed_integers/signed_i32_rem_euclid/out/debug/i32_rem_euclid_test.bin";
+
+        assert!(run_script!(Testi32RemEuclid, path_to_bin));
+    }
+}
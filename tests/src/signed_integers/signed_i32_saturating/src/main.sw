@@ -0,0 +1,47 @@
+script;
+
+use signed_integers::i32::I32;
+
+fn main() -> bool {
+    let one = I32::from(1u32);
+
+    // saturating_add
+    let mut res = I32::from(10u32).saturating_add(I32::from(20u32));
+    assert(res == I32::from(30u32));
+
+    res = I32::from(10u32).saturating_add(I32::neg_from(20u32));
+    assert(res == I32::neg_from(10u32));
+
+    res = I32::max().saturating_add(one);
+    assert(res == I32::max());
+
+    res = I32::max().saturating_add(I32::max());
+    assert(res == I32::max());
+
+    res = I32::min().saturating_add(I32::neg_from(1u32));
+    assert(res == I32::min());
+
+    res = I32::min().saturating_add(I32::max());
+    assert(res == I32::neg_from(1u32));
+
+    // saturating_sub
+    res = I32::from(10u32).saturating_sub(I32::from(20u32));
+    assert(res == I32::neg_from(10u32));
+
+    res = I32::from(10u32).saturating_sub(I32::neg_from(20u32));
+    assert(res == I32::from(30u32));
+
+    res = I32::min().saturating_sub(one);
+    assert(res == I32::min());
+
+    res = I32::min().saturating_sub(I32::max());
+    assert(res == I32::min());
+
+    res = I32::max().saturating_sub(I32::neg_from(1u32));
+    assert(res == I32::max());
+
+    res = I32::max().saturating_sub(I32::max());
+    assert(res == I32::new());
+
+    true
+}
@@ -0,0 +1,20 @@
+use crate::common::run_script;
+use fuels::prelude::abigen;
+
+abigen!(Script(
+    name = "Testi32Saturating",
+    abi = "src/signed_integers/signed_i32_saturating/out/debug/i32_saturating_test-abi.json"
+),);
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn runs_i32_saturating_test_script() {
+        let path_to_bin =
+            "src/signed_integers/signed_i32_saturating/out/debug/i32_saturating_test.bin";
+
+        assert!(run_script!(Testi32Saturating, path_to_bin));
+    }
+}
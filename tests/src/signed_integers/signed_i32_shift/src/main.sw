@@ -0,0 +1,48 @@
+script;
+
+use signed_integers::i32::I32;
+
+fn main() -> bool {
+    // lsh
+    let mut res = I32::from(3u32) << 2;
+    assert(res == I32::from(12u32));
+
+    res = I32::neg_from(3u32) << 2;
+    assert(res == I32::neg_from(12u32));
+
+    res = I32::from(1u32) << (I32::bits() - 1);
+    assert(res == I32::min());
+
+    res = I32::max() << 1;
+    assert(res == I32::neg_from(2u32));
+
+    res = I32::neg_from(1u32) << I32::bits();
+    assert(res == I32::new());
+
+    // rsh
+    res = I32::from(12u32) >> 2;
+    assert(res == I32::from(3u32));
+
+    res = I32::from(13u32) >> 2;
+    assert(res == I32::from(3u32));
+
+    res = I32::neg_from(4u32) >> 1;
+    assert(res == I32::neg_from(2u32));
+
+    res = I32::neg_from(5u32) >> 1;
+    assert(res == I32::neg_from(3u32));
+
+    res = I32::neg_from(1u32) >> 5;
+    assert(res == I32::neg_from(1u32));
+
+    res = I32::min() >> (I32::bits() - 1);
+    assert(res == I32::neg_from(1u32));
+
+    res = I32::max() >> I32::bits();
+    assert(res == I32::new());
+
+    res = I32::min() >> I32::bits();
+    assert(res == I32::neg_from(1u32));
+
+    true
+}
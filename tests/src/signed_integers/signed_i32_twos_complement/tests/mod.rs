@@ -1,22 +1,95 @@
-use fuels::prelude::{abigen, launch_provider_and_get_wallet};
+use crate::common::script_instance;
+use fuels::prelude::{abigen, WalletUnlocked};
 
 abigen!(Script(
     name = "Testi32TwosComplement",
     abi = "src/signed_integers/signed_i32_twos_complement/out/debug/i32_twos_complement_test-abi.json"
 ),);
 
+const PATH_TO_BIN: &str =
+    "src/signed_integers/signed_i32_twos_complement/out/debug/i32_twos_complement_test.bin";
+
+async fn setup() -> Testi32TwosComplement<WalletUnlocked> {
+    script_instance!(Testi32TwosComplement, PATH_TO_BIN)
+}
+
+fn i32(value: i32) -> I32 {
+    // The underlying value is biased by 2 ^ 31, which only flips the sign bit
+    I32 {
+        underlying: (value as u32) ^ (1 << 31),
+    }
+}
+
 mod success {
 
     use super::*;
 
     #[tokio::test]
-    async fn runs_i32_twos_complement_test_script() {
-        let path_to_bin =
-            "src/signed_integers/signed_i32_twos_complement/out/debug/i32_twos_complement_test.bin";
-        let wallet = launch_provider_and_get_wallet().await;
+    async fn negates_zero() {
+        let instance = setup().await;
+
+        let result = instance.main(i32(0), true).call().await;
+        assert_eq!(result.unwrap().value, i32(0));
+    }
 
-        let instance = Testi32TwosComplement::new(wallet, path_to_bin);
+    #[tokio::test]
+    async fn negates_positive_values() {
+        let instance = setup().await;
+
+        for value in [1, 2, 10, i32::MAX] {
+            let result = instance.main(i32(value), true).call().await;
+            assert_eq!(result.unwrap().value, i32(-value));
+        }
+    }
+
+    #[tokio::test]
+    async fn negates_negative_values() {
+        let instance = setup().await;
+
+        for value in [-1, -2, -10, i32::MIN + 1] {
+            let result = instance.main(i32(value), true).call().await;
+            assert_eq!(result.unwrap().value, i32(-value));
+        }
+    }
+
+    #[tokio::test]
+    async fn complements_negative_values_into_their_magnitude() {
+        let instance = setup().await;
+
+        for value in [-1, -5, -27, -78, i32::MIN + 1] {
+            let result = instance.main(i32(value), false).call().await;
+            assert_eq!(result.unwrap().value, i32(-value));
+        }
+    }
+
+    #[tokio::test]
+    async fn complements_non_negative_values_unchanged() {
+        let instance = setup().await;
+
+        for value in [0, 1, 10, i32::MAX] {
+            let result = instance.main(i32(value), false).call().await;
+            assert_eq!(result.unwrap().value, i32(value));
+        }
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn when_min_is_negated() {
+        let instance = setup().await;
+
+        let result = instance.main(i32(i32::MIN), true).call().await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn when_min_is_complemented() {
+        let instance = setup().await;
 
-        let _result = instance.main().call().await;
+        let result = instance.main(i32(i32::MIN), false).call().await;
+        assert!(result.is_err());
     }
 }
@@ -0,0 +1,45 @@
+script;
+
+use signed_integers::i32::I32;
+
+fn main() -> bool {
+    let one = I32::from(1u32);
+
+    // wrapping_add
+    let mut res = I32::from(10u32).wrapping_add(I32::from(20u32));
+    assert(res == I32::from(30u32));
+
+    res = I32::neg_from(10u32).wrapping_add(I32::from(5u32));
+    assert(res == I32::neg_from(5u32));
+
+    res = I32::max().wrapping_add(one);
+    assert(res == I32::min());
+
+    res = I32::min().wrapping_add(I32::neg_from(1u32));
+    assert(res == I32::max());
+
+    // wrapping_sub
+    res = I32::from(10u32).wrapping_sub(I32::from(20u32));
+    assert(res == I32::neg_from(10u32));
+
+    res = I32::min().wrapping_sub(one);
+    assert(res == I32::max());
+
+    res = I32::max().wrapping_sub(I32::neg_from(1u32));
+    assert(res == I32::min());
+
+    // wrapping_mul
+    res = I32::from(10u32).wrapping_mul(I32::neg_from(3u32));
+    assert(res == I32::neg_from(30u32));
+
+    res = I32::from(65536u32).wrapping_mul(I32::from(65536u32));
+    assert(res == I32::new());
+
+    res = I32::from(50000u32).wrapping_mul(I32::from(50000u32));
+    assert(res == I32::neg_from(1794967296u32));
+
+    res = I32::min().wrapping_mul(I32::neg_from(1u32));
+    assert(res == I32::min());
+
+    true
+}
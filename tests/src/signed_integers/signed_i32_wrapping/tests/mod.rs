@@ -0,0 +1,19 @@
+use crate::common::run_script;
+use fuels::prelude::abigen;
+
+abigen!(Script(
+    name = "Testi32Wrapping",
+    abi = "src/signed_integers/signed_i32_wrapping/out/debug/i32_wrapping_test-abi.json"
+),);
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn runs_i32_wrapping_test_script() {
+        let path_to_bin = "src/signed_integers/signed_i32_wrapping/out/debug/i32_wrapping_test.bin";
+
+        assert!(run_script!(Testi32Wrapping, path_to_bin));
+    }
+}
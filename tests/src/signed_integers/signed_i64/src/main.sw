@@ -11,6 +11,18 @@ fn main() -> bool {
     assert(res == I64 {
         underlying: 9223372036854775807u64,
     });
+    res = I64::from(10u64) - I64::neg_from(3u64);
+    assert(res == I64::from(13u64));
+    res = I64::neg_from(5u64) - I64::neg_from(3u64);
+    assert(res == I64::neg_from(2u64));
+    res = I64::neg_from(3u64) - I64::neg_from(5u64);
+    assert(res == I64::from(2u64));
+    res = I64::neg_from(3u64) - I64::from(5u64);
+    assert(res == I64::neg_from(8u64));
+    res = I64::min() - I64::neg_from(1u64);
+    assert(res == I64::neg_from(9223372036854775807u64));
+    res = I64::max() - I64::max();
+    assert(res == I64::new());
     res = I64::from(10u64) * I64::neg_from(1);
     assert(res == I64::neg_from(10));
 
@@ -0,0 +1,39 @@
+script;
+
+use signed_integers::i64::I64;
+
+fn main() -> bool {
+    // abs
+    let mut res = I64::from(5u64).abs();
+    assert(res == I64::from(5u64));
+
+    res = I64::neg_from(5u64).abs();
+    assert(res == I64::from(5u64));
+
+    res = I64::new().abs();
+    assert(res == I64::new());
+
+    res = I64::max().abs();
+    assert(res == I64::max());
+
+    res = (I64::min() + I64::from(1u64)).abs();
+    assert(res == I64::max());
+
+    // wrapping_neg
+    res = I64::from(5u64).wrapping_neg();
+    assert(res == I64::neg_from(5u64));
+
+    res = I64::neg_from(5u64).wrapping_neg();
+    assert(res == I64::from(5u64));
+
+    res = I64::new().wrapping_neg();
+    assert(res == I64::new());
+
+    res = I64::max().wrapping_neg();
+    assert(res == I64::min() + I64::from(1u64));
+
+    res = I64::min().wrapping_neg();
+    assert(res == I64::min());
+
+    true
+}
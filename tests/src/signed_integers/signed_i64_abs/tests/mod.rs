@@ -0,0 +1,19 @@
+use crate::common::run_script;
+use fuels::prelude::abigen;
+
+abigen!(Script(
+    name = "Testi64Abs",
+    abi = "src/signed_integers/signed_i64_abs/out/debug/i64_abs_test-abi.json"
+),);
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn runs_i64_abs_test_script() {
+        let path_to_bin = "src/signed_integers/signed_i64_abs/out/debug/i64_abs_test.bin";
+
+        assert!(run_script!(Testi64Abs, path_to_bin));
+    }
+}
@@ -0,0 +1,19 @@
+script;
+
+use signed_integers::i64::I64;
+
+enum Operation {
+    Add: (),
+    Subtract: (),
+    Multiply: (),
+    Divide: (),
+}
+
+fn main(operation: Operation, lhs: I64, rhs: I64) -> I64 {
+    match operation {
+        Operation::Add => lhs + rhs,
+        Operation::Subtract => lhs - rhs,
+        Operation::Multiply => lhs * rhs,
+        Operation::Divide => lhs / rhs,
+    }
+}
@@ -0,0 +1,136 @@
+use crate::{benchmark::assert_within_budget, common::script_instance};
+use fuels::prelude::{abigen, WalletUnlocked};
+
+abigen!(Script(
+    name = "Testi64Arithmetic",
+    abi = "src/signed_integers/signed_i64_arithmetic/out/debug/i64_arithmetic_test-abi.json"
+),);
+
+const PATH_TO_BIN: &str =
+    "src/signed_integers/signed_i64_arithmetic/out/debug/i64_arithmetic_test.bin";
+
+// Each operation is a handful of comparisons and a single arithmetic instruction.
+// This bound has not been measured yet, see `assert_within_budget` for how budgets are set.
+const BUDGET: u64 = 20_000;
+
+async fn setup() -> Testi64Arithmetic<WalletUnlocked> {
+    script_instance!(Testi64Arithmetic, PATH_TO_BIN)
+}
+
+fn i64(value: i64) -> I64 {
+    // The underlying value is biased by 2 ^ 63, which only flips the sign bit
+    I64 {
+        underlying: (value as u64) ^ (1 << 63),
+    }
+}
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn computes_operations() {
+        let instance = setup().await;
+
+        for (operation, lhs, rhs, expected) in [
+            (Operation::Add, 1000, -10, 990),
+            (Operation::Subtract, 1000, 10, 990),
+            (Operation::Subtract, 1000, -10, 1010),
+            (Operation::Subtract, -1000, -10, -990),
+            (Operation::Subtract, -10, -1000, 990),
+            (Operation::Multiply, -1000, 10, -10000),
+            (Operation::Divide, -1000, 10, -100),
+        ] {
+            let result = instance
+                .main(operation, i64(lhs), i64(rhs))
+                .call()
+                .await
+                .unwrap();
+            assert_eq!(result.value, i64(expected));
+        }
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn when_add_overflows() {
+        let instance = setup().await;
+
+        for (lhs, rhs) in [(i64::MAX, 1), (i64::MIN, -1)] {
+            let result = instance
+                .main(Operation::Add, i64(lhs), i64(rhs))
+                .call()
+                .await;
+            assert!(result.is_err());
+        }
+    }
+
+    #[tokio::test]
+    async fn when_subtract_overflows() {
+        let instance = setup().await;
+
+        for (lhs, rhs) in [(i64::MIN, 1), (i64::MAX, -1), (0, i64::MIN)] {
+            let result = instance
+                .main(Operation::Subtract, i64(lhs), i64(rhs))
+                .call()
+                .await;
+            assert!(result.is_err());
+        }
+    }
+}
+
+mod benchmarks {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn i64_add() {
+        let instance = setup().await;
+
+        let response = instance
+            .main(Operation::Add, i64(-123456789), i64(987654321))
+            .call()
+            .await
+            .unwrap();
+        assert_within_budget("I64 add", &response, BUDGET);
+    }
+
+    #[tokio::test]
+    async fn i64_subtract() {
+        let instance = setup().await;
+
+        let response = instance
+            .main(Operation::Subtract, i64(987654321), i64(123456789))
+            .call()
+            .await
+            .unwrap();
+        assert_within_budget("I64 subtract", &response, BUDGET);
+    }
+
+    #[tokio::test]
+    async fn i64_multiply() {
+        let instance = setup().await;
+
+        let response = instance
+            .main(Operation::Multiply, i64(-123456789), i64(987654321))
+            .call()
+            .await
+            .unwrap();
+        assert_within_budget("I64 multiply", &response, BUDGET);
+    }
+
+    #[tokio::test]
+    async fn i64_divide() {
+        let instance = setup().await;
+
+        let response = instance
+            .main(Operation::Divide, i64(-987654321), i64(123456789))
+            .call()
+            .await
+            .unwrap();
+        assert_within_budget("I64 divide", &response, BUDGET);
+    }
+}
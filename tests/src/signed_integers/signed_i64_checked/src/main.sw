@@ -0,0 +1,48 @@
+script;
+
+use signed_integers::i64::I64;
+
+fn main() -> bool {
+    let zero = I64::new();
+    let one = I64::from(1u64);
+    let neg_one = I64::neg_from(1u64);
+
+    // checked_add
+    assert(I64::from(10u64).checked_add(I64::neg_from(20u64)).unwrap() == I64::neg_from(10u64));
+    assert(I64::max().checked_add(zero).unwrap() == I64::max());
+    assert(I64::max().checked_add(one).is_none());
+    assert(I64::min().checked_add(neg_one).is_none());
+    assert(I64::min().checked_add(I64::max()).unwrap() == neg_one);
+
+    // checked_sub
+    assert(I64::from(10u64).checked_sub(I64::neg_from(20u64)).unwrap() == I64::from(30u64));
+    assert(I64::min().checked_sub(zero).unwrap() == I64::min());
+    assert(I64::min().checked_sub(one).is_none());
+    assert(I64::max().checked_sub(neg_one).is_none());
+    assert(I64::max().checked_sub(I64::max()).unwrap() == zero);
+
+    // checked_mul
+    assert(I64::from(10u64).checked_mul(I64::neg_from(3u64)).unwrap() == I64::neg_from(30u64));
+    assert(I64::neg_from(10u64).checked_mul(I64::neg_from(3u64)).unwrap() == I64::from(30u64));
+    assert(I64::max().checked_mul(one).unwrap() == I64::max());
+    assert(I64::max().checked_mul(neg_one).unwrap() == I64::min() + one);
+    assert(I64::min().checked_mul(one).unwrap() == I64::min());
+    assert(I64::min().checked_mul(neg_one).is_none());
+    assert(I64::max().checked_mul(I64::from(2u64)).is_none());
+    assert(I64::min().checked_mul(I64::from(2u64)).is_none());
+    assert(I64::neg_from(2147483648u64).checked_mul(I64::from(4294967296u64)).unwrap() == I64::min());
+    assert(I64::from(2147483648u64).checked_mul(I64::from(4294967296u64)).is_none());
+    assert(zero.checked_mul(I64::min()).unwrap() == zero);
+
+    // checked_div
+    assert(I64::from(30u64).checked_div(I64::neg_from(3u64)).unwrap() == I64::neg_from(10u64));
+    assert(I64::neg_from(30u64).checked_div(I64::neg_from(3u64)).unwrap() == I64::from(10u64));
+    assert(I64::max().checked_div(neg_one).unwrap() == I64::min() + one);
+    assert(I64::min().checked_div(one).unwrap() == I64::min());
+    assert(I64::min().checked_div(neg_one).is_none());
+    assert(I64::max().checked_div(zero).is_none());
+    assert(I64::min().checked_div(zero).is_none());
+    assert(zero.checked_div(zero).is_none());
+
+    true
+}
@@ -0,0 +1,46 @@
+script;
+
+use signed_integers::i64::I64;
+
+fn main() -> bool {
+    // positive * positive
+    assert(I64::from(6u64) * I64::from(7u64) == I64::from(42u64));
+    // positive * negative
+    assert(I64::from(6u64) * I64::neg_from(7u64) == I64::neg_from(42u64));
+    assert(I64::neg_from(6u64) * I64::from(7u64) == I64::neg_from(42u64));
+    // negative * negative
+    assert(I64::neg_from(6u64) * I64::neg_from(7u64) == I64::from(42u64));
+    // multiplying by zero and one
+    assert(I64::neg_from(6u64) * I64::new() == I64::new());
+    assert(I64::min() * I64::from(1u64) == I64::min());
+    // result at MAX
+    assert(I64::max() * I64::from(1u64) == I64::max());
+    assert(I64::max() * I64::neg_from(1u64) == I64::neg_from(2147483647u64));
+    assert(I64::neg_from(2147483647u64) * I64::neg_from(1u64) == I64::max());
+    // result at MIN
+    assert(I64::neg_from(1073741824u64) * I64::from(2u64) == I64::min());
+    assert(I64::from(1073741824u64) * I64::neg_from(2u64) == I64::min());
+
+    // positive / positive
+    assert(I64::from(42u64) / I64::from(7u64) == I64::from(6u64));
+    assert(I64::from(43u64) / I64::from(7u64) == I64::from(6u64));
+    // positive / negative, rounding towards zero
+    assert(I64::from(42u64) / I64::neg_from(7u64) == I64::neg_from(6u64));
+    assert(I64::from(43u64) / I64::neg_from(7u64) == I64::neg_from(6u64));
+    assert(I64::neg_from(43u64) / I64::from(7u64) == I64::neg_from(6u64));
+    // negative / negative
+    assert(I64::neg_from(42u64) / I64::neg_from(7u64) == I64::from(6u64));
+    assert(I64::neg_from(43u64) / I64::neg_from(7u64) == I64::from(6u64));
+    // result at MAX
+    assert(I64::max() / I64::from(1u64) == I64::max());
+    assert(I64::neg_from(2147483647u64) / I64::neg_from(1u64) == I64::max());
+    // result at MIN
+    assert(I64::min() / I64::from(1u64) == I64::min());
+    assert(I64::min() / I64::from(2u64) == I64::neg_from(1073741824u64));
+    assert(I64::min() / I64::min() == I64::from(1u64));
+
+    // `min() / -1` is rejected by the checked variant
+    assert(I64::min().checked_div(I64::neg_from(1u64)).is_none());
+
+    true
+}
@@ -0,0 +1,19 @@
+use crate::common::run_script;
+use fuels::prelude::abigen;
+
+abigen!(Script(
+    name = "Testi64MulDiv",
+    abi = "src/signed_integers/signed_i64_mul_div/out/debug/i64_mul_div_test-abi.json"
+),);
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn runs_i64_mul_div_test_script() {
+        let path_to_bin = "src/signed_integers/signed_i64_mul_div/out/debug/i64_mul_div_test.bin";
+
+        assert!(run_script!(Testi64MulDiv, path_to_bin));
+    }
+}
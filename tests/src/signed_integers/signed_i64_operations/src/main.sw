@@ -0,0 +1,21 @@
+script;
+
+use signed_integers::i64::I64;
+
+enum Operation {
+    Add: (),
+    Subtract: (),
+    Multiply: (),
+    Negate: (),
+    Abs: (),
+}
+
+fn main(operation: Operation, lhs: I64, rhs: I64) -> I64 {
+    match operation {
+        Operation::Add => lhs + rhs,
+        Operation::Subtract => lhs - rhs,
+        Operation::Multiply => lhs * rhs,
+        Operation::Negate => lhs.neg(),
+        Operation::Abs => lhs.abs(),
+    }
+}
@@ -0,0 +1,23 @@
+use crate::common::{script_instance, signed_operations_tests};
+use fuels::prelude::{abigen, WalletUnlocked};
+
+abigen!(Script(
+    name = "Testi64Operations",
+    abi = "src/signed_integers/signed_i64_operations/out/debug/i64_operations_test-abi.json"
+),);
+
+const PATH_TO_BIN: &str =
+    "src/signed_integers/signed_i64_operations/out/debug/i64_operations_test.bin";
+
+async fn setup() -> Testi64Operations<WalletUnlocked> {
+    script_instance!(Testi64Operations, PATH_TO_BIN)
+}
+
+fn i64(value: i64) -> I64 {
+    // The underlying value is biased by 2 ^ 63, which only flips the sign bit
+    I64 {
+        underlying: (value as u64) ^ (1 << 63),
+    }
+}
+
+signed_operations_tests!(i64, i64);
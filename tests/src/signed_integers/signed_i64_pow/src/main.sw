@@ -0,0 +1,26 @@
+script;
+
+use signed_integers::i64::I64;
+
+fn main() -> bool {
+    // zero exponent and zero base
+    assert(I64::new().pow(0) == I64::from(1u64));
+    assert(I64::neg_from(5u64).pow(0) == I64::from(1u64));
+    assert(I64::min().pow(0) == I64::from(1u64));
+    assert(I64::new().pow(3) == I64::new());
+
+    // positive bases
+    assert(I64::from(2u64).pow(10) == I64::from(1024u64));
+    assert(I64::from(3u64).pow(4) == I64::from(81u64));
+    assert(I64::from(2u64).pow(62) == I64::from(4611686018427387904u64));
+    assert(I64::max().pow(1) == I64::max());
+
+    // negative bases
+    assert(I64::neg_from(3u64).pow(3) == I64::neg_from(27u64));
+    assert(I64::neg_from(3u64).pow(4) == I64::from(81u64));
+    assert(I64::neg_from(1u64).pow(4294967295) == I64::neg_from(1u64));
+    assert(I64::neg_from(2u64).pow(63) == I64::min());
+    assert(I64::min().pow(1) == I64::min());
+
+    true
+}
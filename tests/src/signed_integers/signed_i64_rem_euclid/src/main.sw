@@ -0,0 +1,42 @@
+script;
+
+use signed_integers::i64::I64;
+
+fn main() -> bool {
+    // negative dividend and positive divisor
+    assert(I64::neg_from(7u64).rem_euclid(I64::from(3u64)) == I64::from(2u64));
+    assert(I64::neg_from(6u64).rem_euclid(I64::from(3u64)) == I64::from(0u64));
+    assert(I64::neg_from(1u64).rem_euclid(I64::from(5u64)) == I64::from(4u64));
+    assert(I64::neg_from(100u64).rem_euclid(I64::from(7u64)) == I64::from(5u64));
+    assert(I64::min().rem_euclid(I64::from(3u64)) == I64::from(1u64));
+    assert(I64::min().rem_euclid(I64::max()) == I64::from(9223372036854775806u64));
+
+    // positive dividend and negative divisor
+    assert(I64::from(7u64).rem_euclid(I64::neg_from(3u64)) == I64::from(1u64));
+    assert(I64::from(6u64).rem_euclid(I64::neg_from(3u64)) == I64::from(0u64));
+    assert(I64::from(1u64).rem_euclid(I64::neg_from(5u64)) == I64::from(1u64));
+    assert(I64::from(100u64).rem_euclid(I64::neg_from(7u64)) == I64::from(2u64));
+    assert(I64::max().rem_euclid(I64::neg_from(2u64)) == I64::from(1u64));
+    assert(I64::max().rem_euclid(I64::min()) == I64::max());
+
+    // both negative
+    assert(I64::neg_from(7u64).rem_euclid(I64::neg_from(3u64)) == I64::from(2u64));
+    assert(I64::neg_from(6u64).rem_euclid(I64::neg_from(3u64)) == I64::from(0u64));
+    assert(I64::neg_from(1u64).rem_euclid(I64::neg_from(5u64)) == I64::from(4u64));
+    assert(I64::min().rem_euclid(I64::neg_from(1u64)) == I64::from(0u64));
+    assert(I64::min().rem_euclid(I64::min()) == I64::from(0u64));
+    assert(I64::neg_from(5u64).rem_euclid(I64::min()) == I64::from(9223372036854775803u64));
+
+    // both positive
+    assert(I64::from(7u64).rem_euclid(I64::from(3u64)) == I64::from(1u64));
+    assert(I64::from(6u64).rem_euclid(I64::from(3u64)) == I64::from(0u64));
+    assert(I64::from(1u64).rem_euclid(I64::from(5u64)) == I64::from(1u64));
+    assert(I64::from(0u64).rem_euclid(I64::from(5u64)) == I64::from(0u64));
+    assert(I64::max().rem_euclid(I64::max()) == I64::from(0u64));
+    assert(I64::max().rem_euclid(I64::from(2u64)) == I64::from(1u64));
+
+    // zero dividend
+    assert(I64::new().rem_euclid(I64::neg_from(3u64)) == I64::new());
+
+    true
+}
@@ -0,0 +1,20 @@
+use crate::common::run_script;
+use fuels::prelude::abigen;
+
+abigen!(Script(
+    name = "Testi64RemEuclid",
+    abi = "src/signed_integers/signed_i64_rem_euclid/out/debug/i64_rem_euclid_test-abi.json"
+),);
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn runs_i64_rem_euclid_test_script() {
+        let path_to_bin =
+            "src/signed_integers/signed_i64_rem_euclid/out/debug/i64_rem_euclid_test.bin";
+
+        assert!(run_script!(Testi64RemEuclid, path_to_bin));
+    }
+}
@@ -0,0 +1,47 @@
+script;
+
+use signed_integers::i64::I64;
+
+fn main() -> bool {
+    let one = I64::from(1u64);
+
+    // saturating_add
+    let mut res = I64::from(10u64).saturating_add(I64::from(20u64));
+    assert(res == I64::from(30u64));
+
+    res = I64::from(10u64).saturating_add(I64::neg_from(20u64));
+    assert(res == I64::neg_from(10u64));
+
+    res = I64::max().saturating_add(one);
+    assert(res == I64::max());
+
+    res = I64::max().saturating_add(I64::max());
+    assert(res == I64::max());
+
+    res = I64::min().saturating_add(I64::neg_from(1u64));
+    assert(res == I64::min());
+
+    res = I64::min().saturating_add(I64::max());
+    assert(res == I64::neg_from(1u64));
+
+    // saturating_sub
+    res = I64::from(10u64).saturating_sub(I64::from(20u64));
+    assert(res == I64::neg_from(10u64));
+
+    res = I64::from(10u64).saturating_sub(I64::neg_from(20u64));
+    assert(res == I64::from(30u64));
+
+    res = I64::min().saturating_sub(one);
+    assert(res == I64::min());
+
+    res = I64::min().saturating_sub(I64::max());
+    assert(res == I64::min());
+
+    res = I64::max().saturating_sub(I64::neg_from(1u64));
+    assert(res == I64::max());
+
+    res = I64::max().saturating_sub(I64::max());
+    assert(res == I64::new());
+
+    true
+}
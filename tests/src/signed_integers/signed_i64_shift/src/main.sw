@@ -0,0 +1,48 @@
+script;
+
+use signed_integers::i64::I64;
+
+fn main() -> bool {
+    // lsh
+    let mut res = I64::from(3u64) << 2;
+    assert(res == I64::from(12u64));
+
+    res = I64::neg_from(3u64) << 2;
+    assert(res == I64::neg_from(12u64));
+
+    res = I64::from(1u64) << (I64::bits() - 1);
+    assert(res == I64::min());
+
+    res = I64::max() << 1;
+    assert(res == I64::neg_from(2u64));
+
+    res = I64::neg_from(1u64) << I64::bits();
+    assert(res == I64::new());
+
+    // rsh
+    res = I64::from(12u64) >> 2;
+    assert(res == I64::from(3u64));
+
+    res = I64::from(13u64) >> 2;
+    assert(res == I64::from(3u64));
+
+    res = I64::neg_from(4u64) >> 1;
+    assert(res == I64::neg_from(2u64));
+
+    res = I64::neg_from(5u64) >> 1;
+    assert(res == I64::neg_from(3u64));
+
+    res = I64::neg_from(1u64) >> 5;
+    assert(res == I64::neg_from(1u64));
+
+    res = I64::min() >> (I64::bits() - 1);
+    assert(res == I64::neg_from(1u64));
+
+    res = I64::max() >> I64::bits();
+    assert(res == I64::new());
+
+    res = I64::min() >> I64::bits();
+    assert(res == I64::neg_from(1u64));
+
+    true
+}
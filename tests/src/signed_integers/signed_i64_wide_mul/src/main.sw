@@ -0,0 +1,7 @@
+script;
+
+use signed_integers::i64::I64;
+
+fn main(a: I64, b: I64) -> (I64, u64) {
+    a.wide_mul(b)
+}
@@ -0,0 +1,89 @@
+use crate::common::script_instance;
+use fuels::prelude::{abigen, WalletUnlocked};
+
+abigen!(Script(
+    name = "Testi64WideMul",
+    abi = "src/signed_integers/signed_i64_wide_mul/out/debug/i64_wide_mul_test-abi.json"
+),);
+
+const PATH_TO_BIN: &str = "src/signed_integers/signed_i64_wide_mul/out/debug/i64_wide_mul_test.bin";
+
+async fn setup() -> Testi64WideMul<WalletUnlocked> {
+    script_instance!(Testi64WideMul, PATH_TO_BIN)
+}
+
+fn i64(value: i64) -> I64 {
+    // The underlying value is biased by 2 ^ 63, which only flips the sign bit
+    I64 {
+        underlying: (value as u64) ^ (1 << 63),
+    }
+}
+
+fn wide_mul(a: i64, b: i64) -> (I64, u64) {
+    let product = a as i128 * b as i128;
+    (i64((product >> 64) as i64), product as u64)
+}
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn multiplies_max_by_max() {
+        let instance = setup().await;
+
+        let result = instance.main(i64(i64::MAX), i64(i64::MAX)).call().await;
+        // (2 ^ 63 - 1) ^ 2 = 2 ^ 126 - 2 ^ 64 + 1
+        assert_eq!(result.unwrap().value, (i64((1 << 62) - 1), 1));
+    }
+
+    #[tokio::test]
+    async fn multiplies_min_by_min() {
+        let instance = setup().await;
+
+        let result = instance.main(i64(i64::MIN), i64(i64::MIN)).call().await;
+        // (- 2 ^ 63) ^ 2 = 2 ^ 126
+        assert_eq!(result.unwrap().value, (i64(1 << 62), 0));
+    }
+
+    #[tokio::test]
+    async fn multiplies_mixed_signs() {
+        let instance = setup().await;
+
+        for (a, b) in [
+            (i64::MIN, i64::MAX),
+            (i64::MAX, i64::MIN),
+            (-1, 1),
+            (-1, i64::MAX),
+            (i64::MIN, 1),
+            (-3, 1 << 62),
+            (123_456_789, -987_654_321_000),
+        ] {
+            let result = instance.main(i64(a), i64(b)).call().await;
+            let (high, low) = result.unwrap().value;
+            assert_eq!((high.clone(), low), wide_mul(a, b));
+            // The high word is negative exactly when the product is
+            assert!(high.underlying < 1 << 63);
+        }
+    }
+
+    #[tokio::test]
+    async fn multiplies_by_zero() {
+        let instance = setup().await;
+
+        for (a, b) in [(0, 0), (0, i64::MIN), (i64::MIN, 0), (-1, 0)] {
+            let result = instance.main(i64(a), i64(b)).call().await;
+            assert_eq!(result.unwrap().value, (i64(0), 0));
+        }
+    }
+
+    #[tokio::test]
+    async fn matches_products_which_fit() {
+        let instance = setup().await;
+
+        for (a, b) in [(2, 3), (-2, 3), (-2, -3), (i64::MAX, 2), (i64::MIN, -1)] {
+            let result = instance.main(i64(a), i64(b)).call().await;
+            assert_eq!(result.unwrap().value, wide_mul(a, b));
+        }
+    }
+}
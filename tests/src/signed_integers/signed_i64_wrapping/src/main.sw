@@ -0,0 +1,45 @@
+script;
+
+use signed_integers::i64::I64;
+
+fn main() -> bool {
+    let one = I64::from(1u64);
+
+    // wrapping_add
+    let mut res = I64::from(10u64).wrapping_add(I64::from(20u64));
+    assert(res == I64::from(30u64));
+
+    res = I64::neg_from(10u64).wrapping_add(I64::from(5u64));
+    assert(res == I64::neg_from(5u64));
+
+    res = I64::max().wrapping_add(one);
+    assert(res == I64::min());
+
+    res = I64::min().wrapping_add(I64::neg_from(1u64));
+    assert(res == I64::max());
+
+    // wrapping_sub
+    res = I64::from(10u64).wrapping_sub(I64::from(20u64));
+    assert(res == I64::neg_from(10u64));
+
+    res = I64::min().wrapping_sub(one);
+    assert(res == I64::max());
+
+    res = I64::max().wrapping_sub(I64::neg_from(1u64));
+    assert(res == I64::min());
+
+    // wrapping_mul
+    res = I64::from(10u64).wrapping_mul(I64::neg_from(3u64));
+    assert(res == I64::neg_from(30u64));
+
+    res = I64::from(4294967296u64).wrapping_mul(I64::from(4294967296u64));
+    assert(res == I64::new());
+
+    res = I64::from(5000000000u64).wrapping_mul(I64::from(5000000000u64));
+    assert(res == I64::from(6553255926290448384u64));
+
+    res = I64::min().wrapping_mul(I64::neg_from(1u64));
+    assert(res == I64::min());
+
+    true
+}
@@ -0,0 +1,19 @@
+use crate::common::run_script;
+use fuels::prelude::abigen;
+
+abigen!(Script(
+    name = "Testi64Wrapping",
+    abi = "src/signed_integers/signed_i64_wrapping/out/debug/i64_wrapping_test-abi.json"
+),);
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn runs_i64_wrapping_test_script() {
+        let path_to_bin = "src/signed_integers/signed_i64_wrapping/out/debug/i64_wrapping_test.bin";
+
+        assert!(run_script!(Testi64Wrapping, path_to_bin));
+    }
+}
@@ -12,6 +12,15 @@ fn main() -> bool {
         underlying: 127u8,
     });
 
+    res = I8::from(5u8) - I8::neg_from(3u8);
+    assert(res == I8::from(8u8));
+
+    res = I8::neg_from(5u8) - I8::neg_from(3u8);
+    assert(res == I8::neg_from(2u8));
+
+    res = I8::neg_from(3u8) - I8::neg_from(5u8);
+    assert(res == I8::from(2u8));
+
     res = I8::from(10u8) * I8 {
         underlying: 127u8,
     };
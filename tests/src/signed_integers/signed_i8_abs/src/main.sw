@@ -0,0 +1,39 @@
+script;
+
+use signed_integers::i8::I8;
+
+fn main() -> bool {
+    // abs
+    let mut res = I8::from(5u8).abs();
+    assert(res == I8::from(5u8));
+
+    res = I8::neg_from(5u8).abs();
+    assert(res == I8::from(5u8));
+
+    res = I8::new().abs();
+    assert(res == I8::new());
+
+    res = I8::max().abs();
+    assert(res == I8::max());
+
+    res = (I8::min() + I8::from(1u8)).abs();
+    assert(res == I8::max());
+
+    // wrapping_neg
+    res = I8::from(5u8).wrapping_neg();
+    assert(res == I8::neg_from(5u8));
+
+    res = I8::neg_from(5u8).wrapping_neg();
+    assert(res == I8::from(5u8));
+
+    res = I8::new().wrapping_neg();
+    assert(res == I8::new());
+
+    res = I8::max().wrapping_neg();
+    assert(res == I8::min() + I8::from(1u8));
+
+    res = I8::min().wrapping_neg();
+    assert(res == I8::min());
+
+    true
+}
@@ -0,0 +1,48 @@
+script;
+
+use signed_integers::i8::I8;
+
+fn main() -> bool {
+    let zero = I8::new();
+    let one = I8::from(1u8);
+    let neg_one = I8::neg_from(1u8);
+
+    // checked_add
+    assert(I8::from(10u8).checked_add(I8::neg_from(20u8)).unwrap() == I8::neg_from(10u8));
+    assert(I8::max().checked_add(zero).unwrap() == I8::max());
+    assert(I8::max().checked_add(one).is_none());
+    assert(I8::min().checked_add(neg_one).is_none());
+    assert(I8::min().checked_add(I8::max()).unwrap() == neg_one);
+
+    // checked_sub
+    assert(I8::from(10u8).checked_sub(I8::neg_from(20u8)).unwrap() == I8::from(30u8));
+    assert(I8::min().checked_sub(zero).unwrap() == I8::min());
+    assert(I8::min().checked_sub(one).is_none());
+    assert(I8::max().checked_sub(neg_one).is_none());
+    assert(I8::max().checked_sub(I8::max()).unwrap() == zero);
+
+    // checked_mul
+    assert(I8::from(10u8).checked_mul(I8::neg_from(3u8)).unwrap() == I8::neg_from(30u8));
+    assert(I8::neg_from(10u8).checked_mul(I8::neg_from(3u8)).unwrap() == I8::from(30u8));
+    assert(I8::max().checked_mul(one).unwrap() == I8::max());
+    assert(I8::max().checked_mul(neg_one).unwrap() == I8::min() + one);
+    assert(I8::min().checked_mul(one).unwrap() == I8::min());
+    assert(I8::min().checked_mul(neg_one).is_none());
+    assert(I8::max().checked_mul(I8::from(2u8)).is_none());
+    assert(I8::min().checked_mul(I8::from(2u8)).is_none());
+    assert(I8::neg_from(8u8).checked_mul(I8::from(16u8)).unwrap() == I8::min());
+    assert(I8::from(8u8).checked_mul(I8::from(16u8)).is_none());
+    assert(zero.checked_mul(I8::min()).unwrap() == zero);
+
+    // checked_div
+    assert(I8::from(30u8).checked_div(I8::neg_from(3u8)).unwrap() == I8::neg_from(10u8));
+    assert(I8::neg_from(30u8).checked_div(I8::neg_from(3u8)).unwrap() == I8::from(10u8));
+    assert(I8::max().checked_div(neg_one).unwrap() == I8::min() + one);
+    assert(I8::min().checked_div(one).unwrap() == I8::min());
+    assert(I8::min().checked_div(neg_one).is_none());
+    assert(I8::max().checked_div(zero).is_none());
+    assert(I8::min().checked_div(zero).is_none());
+    assert(zero.checked_div(zero).is_none());
+
+    true
+}
@@ -0,0 +1,21 @@
+script;
+
+use signed_integers::i8::I8;
+
+enum Operation {
+    Add: (),
+    Subtract: (),
+    Multiply: (),
+    Negate: (),
+    Abs: (),
+}
+
+fn main(operation: Operation, lhs: I8, rhs: I8) -> I8 {
+    match operation {
+        Operation::Add => lhs + rhs,
+        Operation::Subtract => lhs - rhs,
+        Operation::Multiply => lhs * rhs,
+        Operation::Negate => lhs.neg(),
+        Operation::Abs => lhs.abs(),
+    }
+}
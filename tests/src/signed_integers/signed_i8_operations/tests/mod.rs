@@ -0,0 +1,23 @@
+use crate::common::{script_instance, signed_operations_tests};
+use fuels::prelude::{abigen, WalletUnlocked};
+
+abigen!(Script(
+    name = "Testi8Operations",
+    abi = "src/signed_integers/signed_i8_operations/out/debug/i8_operations_test-abi.json"
+),);
+
+const PATH_TO_BIN: &str =
+    "src/signed_integers/signed_i8_operations/out/debug/i8_operations_test.bin";
+
+async fn setup() -> Testi8Operations<WalletUnlocked> {
+    script_instance!(Testi8Operations, PATH_TO_BIN)
+}
+
+fn i8(value: i8) -> I8 {
+    // The underlying value is biased by 2 ^ 7, which only flips the sign bit
+    I8 {
+        underlying: (value as u8) ^ (1 << 7),
+    }
+}
+
+signed_operations_tests!(i8, i8);
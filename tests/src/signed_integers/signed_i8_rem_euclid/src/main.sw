@@ -0,0 +1,42 @@
+script;
+
+use signed_integers::i8::I8;
+
+fn main() -> bool {
+    // negative dividend and positive divisor
+    assert(I8::neg_from(7u8).rem_euclid(I8::from(3u8)) == I8::from(2u8));
+    assert(I8::neg_from(6u8).rem_euclid(I8::from(3u8)) == I8::from(0u8));
+    assert(I8::neg_from(1u8).rem_euclid(I8::from(5u8)) == I8::from(4u8));
+    assert(I8::neg_from(100u8).rem_euclid(I8::from(7u8)) == I8::from(5u8));
+    assert(I8::min().rem_euclid(I8::from(3u8)) == I8::from(1u8));
+    assert(I8::min().rem_euclid(I8::max()) == I8::from(126u8));
+
+    // positive dividend and negative divisor
+    assert(I8::from(7u8).rem_euclid(I8::neg_from(3u8)) == I8::from(1u8));
+    assert(I8::from(6u8).rem_euclid(I8::neg_from(3u8)) == I8::from(0u8));
+    assert(I8::from(1u8).rem_euclid(I8::neg_from(5u8)) == I8::from(1u8));
+    assert(I8::from(100u8).rem_euclid(I8::neg_from(7u8)) == I8::from(2u8));
+    assert(I8::max().rem_euclid(I8::neg_from(2u8)) == I8::from(1u8));
+    assert(I8::max().rem_euclid(I8::min()) == I8::max());
+
+    // both negative
+    assert(I8::neg_from(7u8).rem_euclid(I8::neg_from(3u8)) == I8::from(2u8));
+    assert(I8::neg_from(6u8).rem_euclid(I8::neg_from(3u8)) == I8::from(0u8));
+    assert(I8::neg_from(1u8).rem_euclid(I8::neg_from(5u8)) == I8::from(4u8));
+    assert(I8::min().rem_euclid(I8::neg_from(1u8)) == I8::from(0u8));
+    assert(I8::min().rem_euclid(I8::min()) == I8::from(0u8));
+    assert(I8::neg_from(5u8).rem_euclid(I8::min()) == I8::from(123u8));
+
+    // both positive
+    assert(I8::from(7u8).rem_euclid(I8::from(3u8)) == I8::from(1u8));
+    assert(I8::from(6u8).rem_euclid(I8::from(3u8)) == I8::from(0u8));
+    assert(I8::from(1u8).rem_euclid(I8::from(5u8)) == I8::from(1u8));
+    assert(I8::from(0u8).rem_euclid(I8::from(5u8)) == I8::from(0u8));
+    assert(I8::max().rem_euclid(I8::max()) == I8::from(0u8));
+    assert(I8::max().rem_euclid(I8::from(2u8)) == I8::from(1u8));
+
+    // zero dividend
+    assert(I8::new().rem_euclid(I8::neg_from(3u8)) == I8::new());
+
+    true
+}
@@ -0,0 +1,47 @@
+script;
+
+use signed_integers::i8::I8;
+
+fn main() -> bool {
+    let one = I8::from(1u8);
+
+    // saturating_add
+    let mut res = I8::from(10u8).saturating_add(I8::from(20u8));
+    assert(res == I8::from(30u8));
+
+    res = I8::from(10u8).saturating_add(I8::neg_from(20u8));
+    assert(res == I8::neg_from(10u8));
+
+    res = I8::max().saturating_add(one);
+    assert(res == I8::max());
+
+    res = I8::max().saturating_add(I8::max());
+    assert(res == I8::max());
+
+    res = I8::min().saturating_add(I8::neg_from(1u8));
+    assert(res == I8::min());
+
+    res = I8::min().saturating_add(I8::max());
+    assert(res == I8::neg_from(1u8));
+
+    // saturating_sub
+    res = I8::from(10u8).saturating_sub(I8::from(20u8));
+    assert(res == I8::neg_from(10u8));
+
+    res = I8::from(10u8).saturating_sub(I8::neg_from(20u8));
+    assert(res == I8::from(30u8));
+
+    res = I8::min().saturating_sub(one);
+    assert(res == I8::min());
+
+    res = I8::min().saturating_sub(I8::max());
+    assert(res == I8::min());
+
+    res = I8::max().saturating_sub(I8::neg_from(1u8));
+    assert(res == I8::max());
+
+    res = I8::max().saturating_sub(I8::max());
+    assert(res == I8::new());
+
+    true
+}
@@ -0,0 +1,20 @@
+use crate::common::run_script;
+use fuels::prelude::abigen;
+
+abigen!(Script(
+    name = "Testi8Saturating",
+    abi = "src/signed_integers/signed_i8_saturating/out/debug/i8_saturating_test-abi.json"
+),);
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn runs_i8_saturating_test_script() {
+        let path_to_bin =
+            "src/signed_integers/signed_i8_saturating/out/debug/i8_saturating_test.bin";
+
+        assert!(run_script!(Testi8Saturating, path_to_bin));
+    }
+}
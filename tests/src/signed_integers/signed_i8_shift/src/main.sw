@@ -0,0 +1,48 @@
+script;
+
+use signed_integers::i8::I8;
+
+fn main() -> bool {
+    // lsh
+    let mut res = I8::from(3u8) << 2;
+    assert(res == I8::from(12u8));
+
+    res = I8::neg_from(3u8) << 2;
+    assert(res == I8::neg_from(12u8));
+
+    res = I8::from(1u8) << (I8::bits() - 1);
+    assert(res == I8::min());
+
+    res = I8::max() << 1;
+    assert(res == I8::neg_from(2u8));
+
+    res = I8::neg_from(1u8) << I8::bits();
+    assert(res == I8::new());
+
+    // rsh
+    res = I8::from(12u8) >> 2;
+    assert(res == I8::from(3u8));
+
+    res = I8::from(13u8) >> 2;
+    assert(res == I8::from(3u8));
+
+    res = I8::neg_from(4u8) >> 1;
+    assert(res == I8::neg_from(2u8));
+
+    res = I8::neg_from(5u8) >> 1;
+    assert(res == I8::neg_from(3u8));
+
+    res = I8::neg_from(1u8) >> 5;
+    assert(res == I8::neg_from(1u8));
+
+    res = I8::min() >> (I8::bits() - 1);
+    assert(res == I8::neg_from(1u8));
+
+    res = I8::max() >> I8::bits();
+    assert(res == I8::new());
+
+    res = I8::min() >> I8::bits();
+    assert(res == I8::neg_from(1u8));
+
+    true
+}
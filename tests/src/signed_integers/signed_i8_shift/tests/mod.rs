@@ -0,0 +1,19 @@
+use crate::common::run_script;
+use fuels::prelude::abigen;
+
+abigen!(Script(
+    name = "Testi8Shift",
+    abi = "src/signed_integers/signed_i8_shift/out/debug/i8_shift_test-abi.json"
+),);
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn runs_i8_shift_test_script() {
+        let path_to_bin = "src/signed_integers/signed_i8_shift/out/debug/i8_shift_test.bin";
+
+        assert!(run_script!(Testi8Shift, path_to_bin));
+    }
+}
@@ -0,0 +1,45 @@
+script;
+
+use signed_integers::i8::I8;
+
+fn main() -> bool {
+    let one = I8::from(1u8);
+
+    // wrapping_add
+    let mut res = I8::from(10u8).wrapping_add(I8::from(20u8));
+    assert(res == I8::from(30u8));
+
+    res = I8::neg_from(10u8).wrapping_add(I8::from(5u8));
+    assert(res == I8::neg_from(5u8));
+
+    res = I8::max().wrapping_add(one);
+    assert(res == I8::min());
+
+    res = I8::min().wrapping_add(I8::neg_from(1u8));
+    assert(res == I8::max());
+
+    // wrapping_sub
+    res = I8::from(10u8).wrapping_sub(I8::from(20u8));
+    assert(res == I8::neg_from(10u8));
+
+    res = I8::min().wrapping_sub(one);
+    assert(res == I8::max());
+
+    res = I8::max().wrapping_sub(I8::neg_from(1u8));
+    assert(res == I8::min());
+
+    // wrapping_mul
+    res = I8::from(10u8).wrapping_mul(I8::neg_from(3u8));
+    assert(res == I8::neg_from(30u8));
+
+    res = I8::from(16u8).wrapping_mul(I8::from(16u8));
+    assert(res == I8::new());
+
+    res = I8::from(10u8).wrapping_mul(I8::from(13u8));
+    assert(res == I8::neg_from(126u8));
+
+    res = I8::min().wrapping_mul(I8::neg_from(1u8));
+    assert(res == I8::min());
+
+    true
+}
@@ -0,0 +1,37 @@
+script;
+
+use signed_integers::{
+    i128::I128,
+    i16::I16,
+    i256::I256,
+    i32::I32,
+    i64::I64,
+    i8::I8,
+};
+use std::{u128::U128, u256::U256};
+
+fn main() -> bool {
+    assert(I8::min() == I8::neg_from(128u8));
+    assert(I8::max() == I8::from(127u8));
+    assert(I8::max().wrapping_add(I8::from(1u8)) == I8::min());
+
+    assert(I16::min() == I16::neg_from(32768u16));
+    assert(I16::max() == I16::from(32767u16));
+    assert(I16::max().wrapping_add(I16::from(1u16)) == I16::min());
+
+    assert(I32::min() == I32::neg_from(2147483648u32));
+    assert(I32::max() == I32::from(2147483647u32));
+    assert(I32::max().wrapping_add(I32::from(1u32)) == I32::min());
+
+    assert(I64::min() == I64::neg_from(9223372036854775808u64));
+    assert(I64::max() == I64::from(9223372036854775807u64));
+    assert(I64::max().wrapping_add(I64::from(1u64)) == I64::min());
+
+    let u128_one = U128::from((0, 1));
+    assert(I128::min() + I128::max() == I128::new() - I128::from(u128_one));
+
+    let u256_one = U256::from((0, 0, 0, 1));
+    assert(I256::min() + I256::max() == I256::new() - I256::from(u256_one));
+
+    true
+}
@@ -0,0 +1,19 @@
+use crate::common::run_script;
+use fuels::prelude::abigen;
+
+abigen!(Script(
+    name = "TestSignedMinMax",
+    abi = "src/signed_integers/signed_min_max/out/debug/signed_min_max_test-abi.json"
+),);
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn runs_signed_min_max_test_script() {
+        let path_to_bin = "src/signed_integers/signed_min_max/out/debug/signed_min_max_test.bin";
+
+        assert!(run_script!(TestSignedMinMax, path_to_bin));
+    }
+}
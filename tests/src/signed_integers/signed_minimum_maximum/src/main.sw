@@ -0,0 +1,63 @@
+script;
+
+use signed_integers::{i16::I16, i32::I32, i64::I64, i8::I8};
+
+fn main() -> bool {
+    // I8
+    assert(I8::from(3u8).minimum(I8::from(5u8)) == I8::from(3u8));
+    assert(I8::from(5u8).maximum(I8::from(3u8)) == I8::from(5u8));
+    assert(I8::from(3u8).minimum(I8::neg_from(5u8)) == I8::neg_from(5u8));
+    assert(I8::from(3u8).maximum(I8::neg_from(5u8)) == I8::from(3u8));
+    assert(I8::neg_from(3u8).minimum(I8::from(5u8)) == I8::neg_from(3u8));
+    assert(I8::neg_from(3u8).maximum(I8::from(5u8)) == I8::from(5u8));
+    assert(I8::neg_from(3u8).minimum(I8::neg_from(5u8)) == I8::neg_from(5u8));
+    assert(I8::neg_from(3u8).maximum(I8::neg_from(5u8)) == I8::neg_from(3u8));
+    assert(I8::neg_from(3u8).minimum(I8::neg_from(3u8)) == I8::neg_from(3u8));
+    assert(I8::neg_from(3u8).maximum(I8::neg_from(3u8)) == I8::neg_from(3u8));
+    assert(I8::min().minimum(I8::max()) == I8::min());
+    assert(I8::min().maximum(I8::max()) == I8::max());
+
+    // I16
+    assert(I16::from(3u16).minimum(I16::from(5u16)) == I16::from(3u16));
+    assert(I16::from(5u16).maximum(I16::from(3u16)) == I16::from(5u16));
+    assert(I16::from(3u16).minimum(I16::neg_from(5u16)) == I16::neg_from(5u16));
+    assert(I16::from(3u16).maximum(I16::neg_from(5u16)) == I16::from(3u16));
+    assert(I16::neg_from(3u16).minimum(I16::from(5u16)) == I16::neg_from(3u16));
+    assert(I16::neg_from(3u16).maximum(I16::from(5u16)) == I16::from(5u16));
+    assert(I16::neg_from(3u16).minimum(I16::neg_from(5u16)) == I16::neg_from(5u16));
+    assert(I16::neg_from(3u16).maximum(I16::neg_from(5u16)) == I16::neg_from(3u16));
+    assert(I16::neg_from(3u16).minimum(I16::neg_from(3u16)) == I16::neg_from(3u16));
+    assert(I16::neg_from(3u16).maximum(I16::neg_from(3u16)) == I16::neg_from(3u16));
+    assert(I16::min().minimum(I16::max()) == I16::min());
+    assert(I16::min().maximum(I16::max()) == I16::max());
+
+    // I32
+    assert(I32::from(3u32).minimum(I32::from(5u32)) == I32::from(3u32));
+    assert(I32::from(5u32).maximum(I32::from(3u32)) == I32::from(5u32));
+    assert(I32::from(3u32).minimum(I32::neg_from(5u32)) == I32::neg_from(5u32));
+    assert(I32::from(3u32).maximum(I32::neg_from(5u32)) == I32::from(3u32));
+    assert(I32::neg_from(3u32).minimum(I32::from(5u32)) == I32::neg_from(3u32));
+    assert(I32::neg_from(3u32).maximum(I32::from(5u32)) == I32::from(5u32));
+    assert(I32::neg_from(3u32).minimum(I32::neg_from(5u32)) == I32::neg_from(5u32));
+    assert(I32::neg_from(3u32).maximum(I32::neg_from(5u32)) == I32::neg_from(3u32));
+    assert(I32::neg_from(3u32).minimum(I32::neg_from(3u32)) == I32::neg_from(3u32));
+    assert(I32::neg_from(3u32).maximum(I32::neg_from(3u32)) == I32::neg_from(3u32));
+    assert(I32::min().minimum(I32::max()) == I32::min());
+    assert(I32::min().maximum(I32::max()) == I32::max());
+
+    // I64
+    assert(I64::from(3u64).minimum(I64::from(5u64)) == I64::from(3u64));
+    assert(I64::from(5u64).maximum(I64::from(3u64)) == I64::from(5u64));
+    assert(I64::from(3u64).minimum(I64::neg_from(5u64)) == I64::neg_from(5u64));
+    assert(I64::from(3u64).maximum(I64::neg_from(5u64)) == I64::from(3u64));
+    assert(I64::neg_from(3u64).minimum(I64::from(5u64)) == I64::neg_from(3u64));
+    assert(I64::neg_from(3u64).maximum(I64::from(5u64)) == I64::from(5u64));
+    assert(I64::neg_from(3u64).minimum(I64::neg_from(5u64)) == I64::neg_from(5u64));
+    assert(I64::neg_from(3u64).maximum(I64::neg_from(5u64)) == I64::neg_from(3u64));
+    assert(I64::neg_from(3u64).minimum(I64::neg_from(3u64)) == I64::neg_from(3u64));
+    assert(I64::neg_from(3u64).maximum(I64::neg_from(3u64)) == I64::neg_from(3u64));
+    assert(I64::min().minimum(I64::max()) == I64::min());
+    assert(I64::min().maximum(I64::max()) == I64::max());
+
+    true
+}
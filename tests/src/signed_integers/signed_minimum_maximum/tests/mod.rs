@@ -0,0 +1,21 @@
+use crate::common::run_script;
+use fuels::prelude::abigen;
+
+abigen!(Script(
+    name = "TestSignedMinimumMaximum",
+    abi =
+        "src/signed_integers/signed_minimum_maximum/out/debug/signed_minimum_maximum_test-abi.json"
+),);
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn runs_signed_minimum_maximum_test_script() {
+        let path_to_bin =
+            "src/signed_integers/signed_minimum_maximum/out/debug/signed_minimum_maximum_test.bin";
+
+        assert!(run_script!(TestSignedMinimumMaximum, path_to_bin));
+    }
+}
@@ -0,0 +1,21 @@
+script;
+
+use signed_integers::{i32::I32, i64::I64};
+
+fn main(bits: u64, multiply: bool) -> bool {
+    if bits == 32 {
+        if multiply {
+            let _ = I32::max() * I32::from(2u32);
+        } else {
+            let _ = I32::min() / I32::neg_from(1u32);
+        }
+    } else {
+        if multiply {
+            let _ = I64::max() * I64::from(2u64);
+        } else {
+            let _ = I64::min() / I64::neg_from(1u64);
+        }
+    }
+
+    true
+}
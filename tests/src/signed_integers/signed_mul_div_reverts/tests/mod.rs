@@ -0,0 +1,52 @@
+use crate::common::script_instance;
+use fuels::prelude::{abigen, WalletUnlocked};
+
+abigen!(Script(
+    name = "TestSignedMulDivReverts",
+    abi =
+        "src/signed_integers/signed_mul_div_reverts/out/debug/signed_mul_div_reverts_test-abi.json"
+),);
+
+const PATH_TO_BIN: &str =
+    "src/signed_integers/signed_mul_div_reverts/out/debug/signed_mul_div_reverts_test.bin";
+
+async fn setup() -> TestSignedMulDivReverts<WalletUnlocked> {
+    script_instance!(TestSignedMulDivReverts, PATH_TO_BIN)
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "Overflow")]
+    async fn when_i32_multiplication_overflows() {
+        let instance = setup().await;
+
+        instance.main(32, true).call().await.unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Overflow")]
+    async fn when_i64_multiplication_overflows() {
+        let instance = setup().await;
+
+        instance.main(64, true).call().await.unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Overflow")]
+    async fn when_i32_min_is_divided_by_negative_one() {
+        let instance = setup().await;
+
+        instance.main(32, false).call().await.unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Overflow")]
+    async fn when_i64_min_is_divided_by_negative_one() {
+        let instance = setup().await;
+
+        instance.main(64, false).call().await.unwrap();
+    }
+}
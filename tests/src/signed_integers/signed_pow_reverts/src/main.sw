@@ -0,0 +1,13 @@
+script;
+
+use signed_integers::{i32::I32, i64::I64};
+
+fn main(bits: u64, exponent: u32) -> bool {
+    if bits == 32 {
+        let _ = I32::from(2u32).pow(exponent);
+    } else {
+        let _ = I64::from(2u64).pow(exponent);
+    }
+
+    true
+}
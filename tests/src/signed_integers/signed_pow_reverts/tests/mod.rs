@@ -0,0 +1,48 @@
+use crate::common::script_instance;
+use fuels::prelude::{abigen, WalletUnlocked};
+
+abigen!(Script(
+    name = "TestSignedPowReverts",
+    abi = "src/signed_integers/signed_pow_reverts/out/debug/signed_pow_reverts_test-abi.json"
+),);
+
+const PATH_TO_BIN: &str =
+    "src/signed_integers/signed_pow_reverts/out/debug/signed_pow_reverts_test.bin";
+
+async fn setup() -> TestSignedPowReverts<WalletUnlocked> {
+    script_instance!(TestSignedPowReverts, PATH_TO_BIN)
+}
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn raises_to_largest_power() {
+        let instance = setup().await;
+
+        instance.main(32, 30).call().await.unwrap();
+        instance.main(64, 62).call().await.unwrap();
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "Overflow")]
+    async fn when_i32_overflows() {
+        let instance = setup().await;
+
+        instance.main(32, 31).call().await.unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Overflow")]
+    async fn when_i64_overflows() {
+        let instance = setup().await;
+
+        instance.main(64, 63).call().await.unwrap();
+    }
+}
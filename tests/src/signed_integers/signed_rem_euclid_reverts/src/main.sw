@@ -0,0 +1,15 @@
+script;
+
+use signed_integers::{i32::I32, i64::I64, i8::I8};
+
+fn main(bits: u64) -> bool {
+    if bits == 8 {
+        let _ = I8::neg_from(7u8).rem_euclid(I8::new());
+    } else if bits == 32 {
+        let _ = I32::neg_from(7u32).rem_euclid(I32::new());
+    } else {
+        let _ = I64::neg_from(7u64).rem_euclid(I64::new());
+    }
+
+    true
+}
@@ -0,0 +1,43 @@
+use crate::common::script_instance;
+use fuels::prelude::{abigen, WalletUnlocked};
+
+abigen!(Script(
+    name = "TestSignedRemEuclidReverts",
+    abi = "src/signed_integers/signed_rem_euclid_reverts/out/debug/signed_rem_euclid_reverts_test-abi.json"
+),);
+
+const PATH_TO_BIN: &str =
+    "src/signed_integers/signed_rem_euclid_reverts/out/debug/signed_rem_euclid_reverts_test.bin";
+
+async fn setup() -> TestSignedRemEuclidReverts<WalletUnlocked> {
+    script_instance!(TestSignedRemEuclidReverts, PATH_TO_BIN)
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "ZeroDivisor")]
+    async fn when_i8_divisor_is_zero() {
+        let instance = setup().await;
+
+        instance.main(8).call().await.unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "ZeroDivisor")]
+    async fn when_i32_divisor_is_zero() {
+        let instance = setup().await;
+
+        instance.main(32).call().await.unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "ZeroDivisor")]
+    async fn when_i64_divisor_is_zero() {
+        let instance = setup().await;
+
+        instance.main(64).call().await.unwrap();
+    }
+}
@@ -0,0 +1,166 @@
+use crate::common::script_instance;
+use fuels::prelude::{abigen, WalletUnlocked};
+
+abigen!(Script(
+    name = "TestTryFromI64",
+    abi = "src/signed_integers/signed_try_from_i64/out/debug/try_from_i64_test-abi.json"
+),);
+
+const PATH_TO_BIN: &str = "src/signed_integers/signed_try_from_i64/out/debug/try_from_i64_test.bin";
+
+async fn setup() -> TestTryFromI64<WalletUnlocked> {
+    script_instance!(TestTryFromI64, PATH_TO_BIN)
+}
+
+// The underlying values are biased by half of their range, which only flips the sign bit
+fn i8(value: i8) -> I8 {
+    I8 {
+        underlying: (value as u8) ^ (1 << 7),
+    }
+}
+
+fn i16(value: i16) -> I16 {
+    I16 {
+        underlying: (value as u16) ^ (1 << 15),
+    }
+}
+
+fn i32(value: i32) -> I32 {
+    I32 {
+        underlying: (value as u32) ^ (1 << 31),
+    }
+}
+
+fn i64(value: i64) -> I64 {
+    I64 {
+        underlying: (value as u64) ^ (1 << 63),
+    }
+}
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn converts_values_in_range() {
+        let instance = setup().await;
+
+        for value in [0, 1, -1, 100, -100] {
+            let result = instance.main(i64(value)).call().await.unwrap().value;
+            assert_eq!(
+                result,
+                (
+                    Some(i8(value as i8)),
+                    Some(i16(value as i16)),
+                    Some(i32(value as i32))
+                )
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn converts_at_bounds() {
+        let instance = setup().await;
+
+        let result = instance
+            .main(i64(i8::MAX as i64))
+            .call()
+            .await
+            .unwrap()
+            .value;
+        assert_eq!(result.0, Some(i8(i8::MAX)));
+        let result = instance
+            .main(i64(i8::MIN as i64))
+            .call()
+            .await
+            .unwrap()
+            .value;
+        assert_eq!(result.0, Some(i8(i8::MIN)));
+
+        let result = instance
+            .main(i64(i16::MAX as i64))
+            .call()
+            .await
+            .unwrap()
+            .value;
+        assert_eq!(result.1, Some(i16(i16::MAX)));
+        let result = instance
+            .main(i64(i16::MIN as i64))
+            .call()
+            .await
+            .unwrap()
+            .value;
+        assert_eq!(result.1, Some(i16(i16::MIN)));
+
+        let result = instance
+            .main(i64(i32::MAX as i64))
+            .call()
+            .await
+            .unwrap()
+            .value;
+        assert_eq!(result.2, Some(i32(i32::MAX)));
+        let result = instance
+            .main(i64(i32::MIN as i64))
+            .call()
+            .await
+            .unwrap()
+            .value;
+        assert_eq!(result.2, Some(i32(i32::MIN)));
+    }
+
+    #[tokio::test]
+    async fn returns_none_out_of_range() {
+        let instance = setup().await;
+
+        let result = instance
+            .main(i64(i8::MAX as i64 + 1))
+            .call()
+            .await
+            .unwrap()
+            .value;
+        assert_eq!(result.0, None);
+        let result = instance
+            .main(i64(i8::MIN as i64 - 1))
+            .call()
+            .await
+            .unwrap()
+            .value;
+        assert_eq!(result.0, None);
+        assert_eq!(result.1, Some(i16(i8::MIN as i16 - 1)));
+
+        let result = instance
+            .main(i64(i16::MAX as i64 + 1))
+            .call()
+            .await
+            .unwrap()
+            .value;
+        assert_eq!(result.1, None);
+        let result = instance
+            .main(i64(i16::MIN as i64 - 1))
+            .call()
+            .await
+            .unwrap()
+            .value;
+        assert_eq!(result.1, None);
+
+        let result = instance
+            .main(i64(i32::MAX as i64 + 1))
+            .call()
+            .await
+            .unwrap()
+            .value;
+        assert_eq!(result.2, None);
+        let result = instance
+            .main(i64(i32::MIN as i64 - 1))
+            .call()
+            .await
+            .unwrap()
+            .value;
+        assert_eq!(result.2, None);
+
+        for value in [i64::MIN, i64::MAX] {
+            let result = instance.main(i64(value)).call().await.unwrap().value;
+            assert_eq!(result, (None, None, None));
+        }
+    }
+}
@@ -0,0 +1,12 @@
+script;
+
+use signed_integers::{i16::I16, i32::I32, i64::I64, i8::I8};
+
+fn main(value: u64) -> (Option<I8>, Option<I16>, Option<I32>, Option<I64>) {
+    (
+        I8::try_from_u64(value),
+        I16::try_from_u64(value),
+        I32::try_from_u64(value),
+        I64::try_from_u64(value),
+    )
+}
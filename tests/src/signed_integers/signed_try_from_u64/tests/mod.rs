@@ -0,0 +1,127 @@
+use crate::common::script_instance;
+use fuels::prelude::{abigen, WalletUnlocked};
+
+abigen!(Script(
+    name = "TestTryFromU64",
+    abi = "src/signed_integers/signed_try_from_u64/out/debug/try_from_u64_test-abi.json"
+),);
+
+const PATH_TO_BIN: &str = "src/signed_integers/signed_try_from_u64/out/debug/try_from_u64_test.bin";
+
+async fn setup() -> TestTryFromU64<WalletUnlocked> {
+    script_instance!(TestTryFromU64, PATH_TO_BIN)
+}
+
+// The underlying values are biased by half of their range, which only flips the sign bit
+fn i8(value: i8) -> I8 {
+    I8 {
+        underlying: (value as u8) ^ (1 << 7),
+    }
+}
+
+fn i16(value: i16) -> I16 {
+    I16 {
+        underlying: (value as u16) ^ (1 << 15),
+    }
+}
+
+fn i32(value: i32) -> I32 {
+    I32 {
+        underlying: (value as u32) ^ (1 << 31),
+    }
+}
+
+fn i64(value: i64) -> I64 {
+    I64 {
+        underlying: (value as u64) ^ (1 << 63),
+    }
+}
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn converts_values_in_range() {
+        let instance = setup().await;
+
+        for value in [0, 1, 100] {
+            let result = instance.main(value).call().await.unwrap().value;
+            assert_eq!(
+                result,
+                (
+                    Some(i8(value as i8)),
+                    Some(i16(value as i16)),
+                    Some(i32(value as i32)),
+                    Some(i64(value as i64))
+                )
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn converts_i8_max() {
+        let instance = setup().await;
+
+        let result = instance.main(i8::MAX as u64).call().await.unwrap().value;
+        assert_eq!(result.0, Some(i8(i8::MAX)));
+        assert_eq!(result.1, Some(i16(i8::MAX as i16)));
+    }
+
+    #[tokio::test]
+    async fn converts_at_max() {
+        let instance = setup().await;
+
+        let result = instance.main(i16::MAX as u64).call().await.unwrap().value;
+        assert_eq!(result.1, Some(i16(i16::MAX)));
+
+        let result = instance.main(i32::MAX as u64).call().await.unwrap().value;
+        assert_eq!(result.2, Some(i32(i32::MAX)));
+
+        let result = instance.main(i64::MAX as u64).call().await.unwrap().value;
+        assert_eq!(result.3, Some(i64(i64::MAX)));
+    }
+
+    #[tokio::test]
+    async fn returns_none_above_max() {
+        let instance = setup().await;
+
+        let result = instance
+            .main(i8::MAX as u64 + 1)
+            .call()
+            .await
+            .unwrap()
+            .value;
+        assert_eq!(result.0, None);
+        assert_eq!(result.1, Some(i16(i8::MAX as i16 + 1)));
+
+        let result = instance
+            .main(i16::MAX as u64 + 1)
+            .call()
+            .await
+            .unwrap()
+            .value;
+        assert_eq!(result.1, None);
+        assert_eq!(result.2, Some(i32(i16::MAX as i32 + 1)));
+
+        let result = instance
+            .main(i32::MAX as u64 + 1)
+            .call()
+            .await
+            .unwrap()
+            .value;
+        assert_eq!(result.2, None);
+        assert_eq!(result.3, Some(i64(i32::MAX as i64 + 1)));
+
+        let result = instance
+            .main(i64::MAX as u64 + 1)
+            .call()
+            .await
+            .unwrap()
+            .value;
+        assert_eq!(result.3, None);
+
+        let result = instance.main(u64::MAX).call().await.unwrap().value;
+        assert_eq!(result, (None, None, None, None));
+    }
+}
@@ -0,0 +1 @@
+mod string_test;
@@ -0,0 +1,27 @@
+script;
+
+use bytes::Bytes;
+use string::String;
+
+fn main(first: Vec<u8>, second: Vec<u8>, expected: Vec<u8>) -> bool {
+    let first = String::from_ascii(into_bytes(first));
+    let second = String::from_ascii(into_bytes(second));
+    let expected = String::from_ascii(into_bytes(expected));
+
+    let string = first.concat(second);
+    assert(string.len() == first.len() + second.len());
+    assert(string.is_empty() == (first.is_empty() && second.is_empty()));
+    assert(string.to_bytes().len() == string.len());
+
+    string == expected
+}
+
+fn into_bytes(data: Vec<u8>) -> Bytes {
+    let mut bytes = Bytes::new();
+    let mut index = 0;
+    while index < data.len() {
+        bytes.push(data.get(index).unwrap());
+        index += 1;
+    }
+    bytes
+}
@@ -0,0 +1,96 @@
+use crate::common::script_instance;
+use fuels::prelude::{abigen, WalletUnlocked};
+
+abigen!(Script(
+    name = "TestString",
+    abi = "src/string/string_test/out/debug/string_test-abi.json"
+),);
+
+const PATH_TO_BIN: &str = "src/string/string_test/out/debug/string_test.bin";
+
+async fn setup() -> TestString<WalletUnlocked> {
+    script_instance!(TestString, PATH_TO_BIN)
+}
+
+fn ascii(string: &str) -> Vec<u8> {
+    string.as_bytes().to_vec()
+}
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn concatenates_strings() {
+        let instance = setup().await;
+
+        let result = instance
+            .main(ascii("Fuel"), ascii(" Labs"), ascii("Fuel Labs"))
+            .call()
+            .await
+            .unwrap();
+        assert!(result.value);
+    }
+
+    #[tokio::test]
+    async fn compares_contents_of_differently_built_strings() {
+        let instance = setup().await;
+
+        for (first, second) in [("F", "uel Labs"), ("Fuel L", "abs"), ("Fuel Labs", "")] {
+            let result = instance
+                .main(ascii(first), ascii(second), ascii("Fuel Labs"))
+                .call()
+                .await
+                .unwrap();
+            assert!(result.value);
+        }
+    }
+
+    #[tokio::test]
+    async fn concatenates_empty_strings() {
+        let instance = setup().await;
+
+        let result = instance
+            .main(ascii(""), ascii(""), ascii(""))
+            .call()
+            .await
+            .unwrap();
+        assert!(result.value);
+    }
+
+    #[tokio::test]
+    async fn detects_different_strings() {
+        let instance = setup().await;
+
+        let result = instance
+            .main(ascii("Fuel"), ascii(" Labs"), ascii("Fuel Lab"))
+            .call()
+            .await
+            .unwrap();
+        assert!(!result.value);
+
+        let result = instance
+            .main(ascii("Fuel"), ascii(" Labs"), ascii("Fuel Labz"))
+            .call()
+            .await
+            .unwrap();
+        assert!(!result.value);
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidAscii")]
+    async fn when_not_ascii() {
+        let instance = setup().await;
+
+        instance
+            .main(vec![70, 200], ascii(""), ascii(""))
+            .call()
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,50 @@
+contract;
+
+use ownership::*;
+use src_5::Ownership;
+use timelock::*;
+
+storage {
+    owner: Ownership = Ownership::uninitialized(),
+    timelock: Timelock = Timelock {},
+}
+
+abi TimelockTest {
+    #[storage(read, write)]
+    fn cancel(call_hash: b256);
+    #[storage(read, write)]
+    fn execute(call_hash: b256);
+    #[storage(read, write)]
+    fn queue(call_hash: b256, delay: u64);
+    #[storage(read)]
+    fn scheduled_height(call_hash: b256) -> Option<u64>;
+    #[storage(read, write)]
+    fn set_ownership(new_owner: Identity);
+}
+
+impl TimelockTest for Contract {
+    #[storage(read, write)]
+    fn cancel(call_hash: b256) {
+        storage.timelock.cancel(storage.owner, call_hash);
+    }
+
+    #[storage(read, write)]
+    fn execute(call_hash: b256) {
+        storage.timelock.execute(call_hash);
+    }
+
+    #[storage(read, write)]
+    fn queue(call_hash: b256, delay: u64) {
+        storage.timelock.queue(storage.owner, call_hash, delay);
+    }
+
+    #[storage(read)]
+    fn scheduled_height(call_hash: b256) -> Option<u64> {
+        storage.timelock.scheduled_height(call_hash)
+    }
+
+    #[storage(read, write)]
+    fn set_ownership(new_owner: Identity) {
+        storage.owner.set_ownership(new_owner);
+    }
+}
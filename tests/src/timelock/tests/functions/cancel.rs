@@ -0,0 +1,68 @@
+use crate::timelock::tests::utils::{
+    abi_calls::{cancel, execute, queue, scheduled_height},
+    test_helpers::{produce_blocks, setup},
+    CALL_HASH, DELAY,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn removes_pending_call() {
+        let (admin, _user) = setup().await;
+
+        queue(&admin.contract, CALL_HASH, DELAY).await;
+
+        cancel(&admin.contract, CALL_HASH).await;
+
+        assert_eq!(scheduled_height(&admin.contract, CALL_HASH).await, None);
+    }
+
+    #[tokio::test]
+    async fn allows_queueing_again() {
+        let (admin, _user) = setup().await;
+
+        queue(&admin.contract, CALL_HASH, DELAY).await;
+        cancel(&admin.contract, CALL_HASH).await;
+
+        queue(&admin.contract, CALL_HASH, DELAY).await;
+
+        assert!(scheduled_height(&admin.contract, CALL_HASH).await.is_some());
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotQueued")]
+    async fn prevents_execution() {
+        let (admin, _user) = setup().await;
+
+        queue(&admin.contract, CALL_HASH, DELAY).await;
+        cancel(&admin.contract, CALL_HASH).await;
+        produce_blocks(&admin.wallet, DELAY).await;
+
+        execute(&admin.contract, CALL_HASH).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_not_owner() {
+        let (admin, user) = setup().await;
+
+        queue(&admin.contract, CALL_HASH, DELAY).await;
+
+        cancel(&user.contract, CALL_HASH).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotQueued")]
+    async fn when_not_queued() {
+        let (admin, _user) = setup().await;
+
+        cancel(&admin.contract, CALL_HASH).await;
+    }
+}
@@ -0,0 +1,81 @@
+use crate::timelock::tests::utils::{
+    abi_calls::{execute, queue, scheduled_height},
+    test_helpers::{produce_blocks, setup},
+    CALL_HASH, DELAY,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn executes_after_delay() {
+        let (admin, _user) = setup().await;
+
+        queue(&admin.contract, CALL_HASH, DELAY).await;
+        // The execution is included in the block after the produced blocks
+        produce_blocks(&admin.wallet, DELAY - 1).await;
+
+        execute(&admin.contract, CALL_HASH).await;
+
+        assert_eq!(scheduled_height(&admin.contract, CALL_HASH).await, None);
+    }
+
+    #[tokio::test]
+    async fn executes_by_anyone() {
+        let (admin, user) = setup().await;
+
+        queue(&admin.contract, CALL_HASH, DELAY).await;
+        produce_blocks(&admin.wallet, DELAY).await;
+
+        execute(&user.contract, CALL_HASH).await;
+
+        assert_eq!(scheduled_height(&admin.contract, CALL_HASH).await, None);
+    }
+
+    #[tokio::test]
+    async fn executes_without_delay() {
+        let (admin, _user) = setup().await;
+
+        queue(&admin.contract, CALL_HASH, 0).await;
+
+        execute(&admin.contract, CALL_HASH).await;
+
+        assert_eq!(scheduled_height(&admin.contract, CALL_HASH).await, None);
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotReady")]
+    async fn when_delay_has_not_passed() {
+        let (admin, _user) = setup().await;
+
+        queue(&admin.contract, CALL_HASH, DELAY).await;
+        produce_blocks(&admin.wallet, DELAY - 2).await;
+
+        execute(&admin.contract, CALL_HASH).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotQueued")]
+    async fn when_not_queued() {
+        let (admin, _user) = setup().await;
+
+        execute(&admin.contract, CALL_HASH).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotQueued")]
+    async fn when_already_executed() {
+        let (admin, _user) = setup().await;
+
+        queue(&admin.contract, CALL_HASH, 0).await;
+        execute(&admin.contract, CALL_HASH).await;
+
+        execute(&admin.contract, CALL_HASH).await;
+    }
+}
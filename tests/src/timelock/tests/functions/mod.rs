@@ -0,0 +1,4 @@
+mod cancel;
+mod execute;
+mod queue;
+mod scheduled_height;
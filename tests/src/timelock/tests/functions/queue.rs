@@ -0,0 +1,63 @@
+use crate::timelock::tests::utils::{
+    abi_calls::{queue, scheduled_height},
+    test_helpers::{current_height, setup},
+    CALL_HASH, DELAY,
+};
+use fuels::types::Bits256;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn sets_scheduled_height() {
+        let (admin, _user) = setup().await;
+
+        queue(&admin.contract, CALL_HASH, DELAY).await;
+
+        // The call is included in the latest block
+        let height = current_height(&admin.wallet).await;
+        assert_eq!(
+            scheduled_height(&admin.contract, CALL_HASH).await,
+            Some(height + DELAY)
+        );
+    }
+
+    #[tokio::test]
+    async fn queues_independent_calls() {
+        let (admin, _user) = setup().await;
+
+        let other_call_hash = Bits256([2u8; 32]);
+
+        queue(&admin.contract, CALL_HASH, DELAY).await;
+        queue(&admin.contract, other_call_hash, DELAY * 2).await;
+
+        let first = scheduled_height(&admin.contract, CALL_HASH).await.unwrap();
+        let second = scheduled_height(&admin.contract, other_call_hash)
+            .await
+            .unwrap();
+        assert!(second > first + DELAY);
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_not_owner() {
+        let (_admin, user) = setup().await;
+
+        queue(&user.contract, CALL_HASH, DELAY).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "AlreadyQueued")]
+    async fn when_already_queued() {
+        let (admin, _user) = setup().await;
+
+        queue(&admin.contract, CALL_HASH, DELAY).await;
+        queue(&admin.contract, CALL_HASH, DELAY).await;
+    }
+}
@@ -0,0 +1,26 @@
+use crate::timelock::tests::utils::{
+    abi_calls::{queue, scheduled_height},
+    test_helpers::setup,
+    CALL_HASH, DELAY,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_none_when_not_queued() {
+        let (admin, _user) = setup().await;
+
+        assert_eq!(scheduled_height(&admin.contract, CALL_HASH).await, None);
+    }
+
+    #[tokio::test]
+    async fn returns_height_when_queued() {
+        let (admin, user) = setup().await;
+
+        queue(&admin.contract, CALL_HASH, DELAY).await;
+
+        assert!(scheduled_height(&user.contract, CALL_HASH).await.is_some());
+    }
+}
@@ -0,0 +1,134 @@
+use crate::common::{deploy_contract, get_isolated_wallets};
+use fuels::{
+    prelude::{abigen, LoadConfiguration, StorageConfiguration, WalletUnlocked},
+    programs::call_response::FuelCallResponse,
+    types::{Bits256, Identity},
+};
+
+// Load abi from json
+abigen!(Contract(
+    name = "TimelockLib",
+    abi = "src/timelock/out/debug/timelock_test-abi.json"
+));
+
+pub const CALL_HASH: Bits256 = Bits256([1u8; 32]);
+pub const DELAY: u64 = 10;
+
+pub struct Metadata {
+    pub contract: TimelockLib<WalletUnlocked>,
+    pub wallet: WalletUnlocked,
+}
+
+pub mod abi_calls {
+
+    use super::*;
+
+    pub async fn cancel(
+        contract: &TimelockLib<WalletUnlocked>,
+        call_hash: Bits256,
+    ) -> FuelCallResponse<()> {
+        contract.methods().cancel(call_hash).call().await.unwrap()
+    }
+
+    pub async fn execute(
+        contract: &TimelockLib<WalletUnlocked>,
+        call_hash: Bits256,
+    ) -> FuelCallResponse<()> {
+        contract.methods().execute(call_hash).call().await.unwrap()
+    }
+
+    pub async fn queue(
+        contract: &TimelockLib<WalletUnlocked>,
+        call_hash: Bits256,
+        delay: u64,
+    ) -> FuelCallResponse<()> {
+        contract
+            .methods()
+            .queue(call_hash, delay)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn scheduled_height(
+        contract: &TimelockLib<WalletUnlocked>,
+        call_hash: Bits256,
+    ) -> Option<u64> {
+        contract
+            .methods()
+            .scheduled_height(call_hash)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn set_ownership(
+        contract: &TimelockLib<WalletUnlocked>,
+        new_owner: Identity,
+    ) -> FuelCallResponse<()> {
+        contract
+            .methods()
+            .set_ownership(new_owner)
+            .call()
+            .await
+            .unwrap()
+    }
+}
+
+pub mod test_helpers {
+
+    use super::abi_calls::set_ownership;
+    use super::*;
+
+    pub async fn current_height(wallet: &WalletUnlocked) -> u64 {
+        wallet
+            .provider()
+            .unwrap()
+            .latest_block_height()
+            .await
+            .unwrap() as u64
+    }
+
+    pub async fn produce_blocks(wallet: &WalletUnlocked, blocks: u64) {
+        wallet
+            .provider()
+            .unwrap()
+            .produce_blocks(blocks, None)
+            .await
+            .unwrap();
+    }
+
+    pub async fn setup() -> (Metadata, Metadata) {
+        let mut wallets = get_isolated_wallets(2).await;
+
+        // Get the wallets from that provider
+        let wallet1 = wallets.pop().unwrap();
+        let wallet2 = wallets.pop().unwrap();
+
+        let storage_configuration = StorageConfiguration::load_from(
+            "src/timelock/out/debug/timelock_test-storage_slots.json",
+        );
+        let id = deploy_contract(
+            &wallet1,
+            "src/timelock/out/debug/timelock_test.bin",
+            LoadConfiguration::default().set_storage_configuration(storage_configuration.unwrap()),
+        )
+        .await;
+
+        let admin = Metadata {
+            contract: TimelockLib::new(id.clone(), wallet1.clone()),
+            wallet: wallet1.clone(),
+        };
+
+        let user = Metadata {
+            contract: TimelockLib::new(id.clone(), wallet2.clone()),
+            wallet: wallet2.clone(),
+        };
+
+        let admin_identity = Identity::Address(admin.wallet.address().into());
+        set_ownership(&admin.contract, admin_identity).await;
+
+        (admin, user)
+    }
+}
@@ -0,0 +1,9 @@
+script;
+
+use timestamp::Tai64;
+
+fn main(unix_secs: u64, secs: u64) -> (u64, u64) {
+    let start = Tai64::from_unix(unix_secs);
+    let end = start.add_seconds(secs);
+    (end.to_unix(), end.seconds_since(start))
+}
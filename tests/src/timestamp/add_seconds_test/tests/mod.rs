@@ -0,0 +1,54 @@
+use crate::common::script_instance;
+use fuels::prelude::{abigen, WalletUnlocked};
+
+abigen!(Script(
+    name = "TestAddSeconds",
+    abi = "src/timestamp/add_seconds_test/out/debug/add_seconds_test-abi.json"
+),);
+
+const PATH_TO_BIN: &str = "src/timestamp/add_seconds_test/out/debug/add_seconds_test.bin";
+
+async fn setup() -> TestAddSeconds<WalletUnlocked> {
+    script_instance!(TestAddSeconds, PATH_TO_BIN)
+}
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn adds_no_seconds() {
+        let instance = setup().await;
+
+        let (unix_secs, elapsed) = instance.main(1_700_000_000, 0).call().await.unwrap().value;
+        assert_eq!(unix_secs, 1_700_000_000);
+        assert_eq!(elapsed, 0);
+    }
+
+    #[tokio::test]
+    async fn adds_one_day() {
+        let instance = setup().await;
+
+        let (unix_secs, elapsed) = instance
+            .main(1_700_000_000, 86_400)
+            .call()
+            .await
+            .unwrap()
+            .value;
+        assert_eq!(unix_secs, 1_700_086_400);
+        assert_eq!(elapsed, 86_400);
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn when_result_overflows() {
+        let instance = setup().await;
+
+        let result = instance.main(0, u64::MAX).call().await;
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,4 @@
+mod add_seconds_test;
+mod now_test;
+mod seconds_since_test;
+mod tai64_test;
@@ -0,0 +1,7 @@
+script;
+
+use timestamp::now;
+
+fn main() -> u64 {
+    now().to_unix()
+}
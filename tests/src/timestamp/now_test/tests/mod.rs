@@ -0,0 +1,31 @@
+use crate::common::get_wallet;
+use fuels::prelude::abigen;
+
+abigen!(Script(
+    name = "TestNow",
+    abi = "src/timestamp/now_test/out/debug/now_test-abi.json"
+),);
+
+const PATH_TO_BIN: &str = "src/timestamp/now_test/out/debug/now_test.bin";
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_block_time() {
+        let wallet = get_wallet().await;
+        let provider = wallet.provider().unwrap().clone();
+        let instance = TestNow::new(wallet, PATH_TO_BIN);
+
+        let latest = provider
+            .latest_block_time()
+            .await
+            .unwrap()
+            .unwrap()
+            .timestamp() as u64;
+
+        let result = instance.main().call().await.unwrap();
+        assert!(result.value >= latest);
+    }
+}
@@ -0,0 +1,7 @@
+script;
+
+use timestamp::Tai64;
+
+fn main(later_unix_secs: u64, earlier_unix_secs: u64) -> u64 {
+    Tai64::from_unix(later_unix_secs).seconds_since(Tai64::from_unix(earlier_unix_secs))
+}
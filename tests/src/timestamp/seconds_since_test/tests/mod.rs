@@ -0,0 +1,51 @@
+use crate::common::script_instance;
+use fuels::prelude::{abigen, WalletUnlocked};
+
+abigen!(Script(
+    name = "TestSecondsSince",
+    abi = "src/timestamp/seconds_since_test/out/debug/seconds_since_test-abi.json"
+),);
+
+const PATH_TO_BIN: &str = "src/timestamp/seconds_since_test/out/debug/seconds_since_test.bin";
+
+async fn setup() -> TestSecondsSince<WalletUnlocked> {
+    script_instance!(TestSecondsSince, PATH_TO_BIN)
+}
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_elapsed_seconds() {
+        let instance = setup().await;
+
+        let result = instance.main(1_700_000_060, 1_700_000_000).call().await;
+        assert_eq!(result.unwrap().value, 60);
+    }
+
+    #[tokio::test]
+    async fn returns_zero_for_same_timestamp() {
+        let instance = setup().await;
+
+        let result = instance.main(1_700_000_000, 1_700_000_000).call().await;
+        assert_eq!(result.unwrap().value, 0);
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "EarlierIsLater")]
+    async fn when_earlier_is_in_the_future() {
+        let instance = setup().await;
+
+        instance
+            .main(1_700_000_000, 1_700_000_001)
+            .call()
+            .await
+            .unwrap();
+    }
+}
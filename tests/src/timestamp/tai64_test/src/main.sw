@@ -0,0 +1,10 @@
+script;
+
+use timestamp::{Tai64, TAI64_UNIX_OFFSET};
+
+fn main(unix_secs: u64) -> (u64, u64) {
+    assert(Tai64::from_unix(0).value == TAI64_UNIX_OFFSET);
+
+    let time = Tai64::from_unix(unix_secs);
+    (time.value, time.to_unix())
+}
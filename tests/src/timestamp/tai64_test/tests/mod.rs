@@ -0,0 +1,39 @@
+use crate::common::script_instance;
+use fuels::prelude::{abigen, WalletUnlocked};
+use tai64::Tai64;
+
+abigen!(Script(
+    name = "TestTai64",
+    abi = "src/timestamp/tai64_test/out/debug/tai64_test-abi.json"
+),);
+
+const PATH_TO_BIN: &str = "src/timestamp/tai64_test/out/debug/tai64_test.bin";
+
+async fn setup() -> TestTai64<WalletUnlocked> {
+    script_instance!(TestTai64, PATH_TO_BIN)
+}
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn unix_epoch_is_the_offset() {
+        let instance = setup().await;
+
+        let (tai64, unix_secs) = instance.main(0).call().await.unwrap().value;
+        assert_eq!(tai64, Tai64::UNIX_EPOCH.0);
+        assert_eq!(unix_secs, 0);
+    }
+
+    #[tokio::test]
+    async fn round_trips_unix_timestamp() {
+        let instance = setup().await;
+        // 2023-11-14 22:13:20 UTC
+        let timestamp = 1_700_000_000;
+
+        let (tai64, unix_secs) = instance.main(timestamp).call().await.unwrap().value;
+        assert_eq!(tai64, Tai64::from_unix(timestamp as i64).0);
+        assert_eq!(unix_secs, timestamp);
+    }
+}
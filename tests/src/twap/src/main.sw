@@ -0,0 +1,55 @@
+contract;
+
+use std::u128::U128;
+use twap::TwapAccumulator;
+
+storage {
+    twap: TwapAccumulator = TwapAccumulator::new(),
+}
+
+abi TwapTest {
+    #[storage(read)]
+    fn cumulative_price() -> U128;
+    #[storage(read)]
+    fn last_price() -> u64;
+    #[storage(read)]
+    fn last_update_block() -> u64;
+    #[storage(read)]
+    fn read_twap(window_blocks: u64) -> u64;
+    #[storage(read)]
+    fn snapshot(block: u64) -> Option<U128>;
+    #[storage(read, write)]
+    fn update(current_price: u64);
+}
+
+impl TwapTest for Contract {
+    #[storage(read)]
+    fn cumulative_price() -> U128 {
+        storage.twap.cumulative_price()
+    }
+
+    #[storage(read)]
+    fn last_price() -> u64 {
+        storage.twap.last_price()
+    }
+
+    #[storage(read)]
+    fn last_update_block() -> u64 {
+        storage.twap.last_update_block()
+    }
+
+    #[storage(read)]
+    fn read_twap(window_blocks: u64) -> u64 {
+        storage.twap.read_twap(window_blocks)
+    }
+
+    #[storage(read)]
+    fn snapshot(block: u64) -> Option<U128> {
+        storage.twap.snapshot(block)
+    }
+
+    #[storage(read, write)]
+    fn update(current_price: u64) {
+        storage.twap.update(current_price);
+    }
+}
@@ -0,0 +1,40 @@
+use crate::twap::tests::utils::{
+    abi_calls::{cumulative_price, update},
+    test_helpers::{produce_blocks, setup, update_prices},
+    PRICES,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn is_zero_before_first_update() {
+        let (contract, _wallet) = setup().await;
+
+        assert_eq!(cumulative_price(&contract).await, 0);
+    }
+
+    #[tokio::test]
+    async fn is_zero_after_first_update() {
+        let (contract, _wallet) = setup().await;
+
+        update(&contract, PRICES[0]).await;
+
+        assert_eq!(cumulative_price(&contract).await, 0);
+    }
+
+    #[tokio::test]
+    async fn sums_price_of_every_block_up_to_last_update() {
+        let (contract, wallet) = setup().await;
+
+        update_prices(&contract, &wallet, &PRICES[..3]).await;
+        // The last price is only added for the blocks up to the last update
+        produce_blocks(&wallet, 2).await;
+
+        assert_eq!(
+            cumulative_price(&contract).await,
+            (PRICES[0] + PRICES[1]) as u128
+        );
+    }
+}
@@ -0,0 +1,36 @@
+use crate::twap::tests::utils::{
+    abi_calls::{last_price, update},
+    test_helpers::{setup, update_prices},
+    PRICES,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn is_zero_before_first_update() {
+        let (contract, _wallet) = setup().await;
+
+        assert_eq!(last_price(&contract).await, 0);
+    }
+
+    #[tokio::test]
+    async fn returns_price_of_last_update() {
+        let (contract, wallet) = setup().await;
+
+        update_prices(&contract, &wallet, &PRICES).await;
+
+        assert_eq!(last_price(&contract).await, PRICES[9]);
+    }
+
+    #[tokio::test]
+    async fn returns_zero_price() {
+        let (contract, _wallet) = setup().await;
+
+        update(&contract, PRICES[0]).await;
+        update(&contract, 0).await;
+
+        assert_eq!(last_price(&contract).await, 0);
+    }
+}
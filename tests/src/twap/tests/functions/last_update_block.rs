@@ -0,0 +1,27 @@
+use crate::twap::tests::utils::{
+    abi_calls::last_update_block,
+    test_helpers::{produce_blocks, setup, update_prices},
+    PRICES,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn is_zero_before_first_update() {
+        let (contract, _wallet) = setup().await;
+
+        assert_eq!(last_update_block(&contract).await, 0);
+    }
+
+    #[tokio::test]
+    async fn returns_block_of_last_update() {
+        let (contract, wallet) = setup().await;
+
+        let blocks = update_prices(&contract, &wallet, &PRICES).await;
+        produce_blocks(&wallet, 3).await;
+
+        assert_eq!(last_update_block(&contract).await, blocks[9]);
+    }
+}
@@ -0,0 +1,6 @@
+mod cumulative_price;
+mod last_price;
+mod last_update_block;
+mod read_twap;
+mod snapshot;
+mod update;
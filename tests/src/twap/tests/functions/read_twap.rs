@@ -0,0 +1,116 @@
+use crate::twap::tests::utils::{
+    abi_calls::{read_twap, update},
+    test_helpers::{current_height, expected_twap, produce_blocks, setup, update_prices},
+    PRICES,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn averages_ten_blocks_of_updates() {
+        let (contract, wallet) = setup().await;
+
+        let blocks = update_prices(&contract, &wallet, &PRICES).await;
+        // Every update is included in its own block and the read in the block after the last update
+        assert_eq!(blocks[9] - blocks[0], 9);
+
+        assert_eq!(read_twap(&contract, 10).await, 112);
+    }
+
+    #[tokio::test]
+    async fn averages_most_recent_blocks() {
+        let (contract, wallet) = setup().await;
+
+        let blocks = update_prices(&contract, &wallet, &PRICES).await;
+        let updates: Vec<(u64, u64)> = blocks.into_iter().zip(PRICES).collect();
+
+        for window_blocks in [1, 3, 5] {
+            let current_block = current_height(&wallet).await + 1;
+            assert_eq!(
+                read_twap(&contract, window_blocks).await,
+                expected_twap(&updates, current_block, window_blocks)
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn counts_blocks_after_last_update_at_last_price() {
+        let (contract, wallet) = setup().await;
+
+        let blocks = update_prices(&contract, &wallet, &PRICES).await;
+        let updates: Vec<(u64, u64)> = blocks.into_iter().zip(PRICES).collect();
+        produce_blocks(&wallet, 5).await;
+
+        let current_block = current_height(&wallet).await + 1;
+        assert_eq!(
+            read_twap(&contract, 10).await,
+            expected_twap(&updates, current_block, 10)
+        );
+    }
+
+    #[tokio::test]
+    async fn averages_constant_price() {
+        let (contract, wallet) = setup().await;
+
+        update_prices(&contract, &wallet, &[PRICES[0]; 4]).await;
+
+        assert_eq!(read_twap(&contract, 4).await, PRICES[0]);
+    }
+
+    #[tokio::test]
+    async fn averages_maximum_price() {
+        let (contract, wallet) = setup().await;
+
+        update_prices(&contract, &wallet, &[u64::MAX; 3]).await;
+
+        assert_eq!(read_twap(&contract, 3).await, u64::MAX);
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidWindow")]
+    async fn when_window_is_zero() {
+        let (contract, wallet) = setup().await;
+
+        update_prices(&contract, &wallet, &PRICES).await;
+
+        read_twap(&contract, 0).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidWindow")]
+    async fn when_window_starts_before_first_block() {
+        let (contract, wallet) = setup().await;
+
+        update_prices(&contract, &wallet, &PRICES).await;
+
+        read_twap(&contract, u64::MAX).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "SnapshotNotFound")]
+    async fn when_window_starts_before_first_update() {
+        let (contract, wallet) = setup().await;
+
+        update_prices(&contract, &wallet, &PRICES).await;
+
+        read_twap(&contract, 11).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "SnapshotNotFound")]
+    async fn when_window_starts_at_block_without_update() {
+        let (contract, wallet) = setup().await;
+
+        update(&contract, PRICES[0]).await;
+        produce_blocks(&wallet, 2).await;
+
+        read_twap(&contract, 2).await;
+    }
+}
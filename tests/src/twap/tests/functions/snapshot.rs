@@ -0,0 +1,40 @@
+use crate::twap::tests::utils::{
+    abi_calls::snapshot,
+    test_helpers::{current_height, setup, update_prices},
+    PRICES,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn records_cumulative_price_at_every_update() {
+        let (contract, wallet) = setup().await;
+
+        let blocks = update_prices(&contract, &wallet, &PRICES).await;
+
+        let mut cumulative = 0u128;
+        for (index, block) in blocks.iter().enumerate() {
+            if index > 0 {
+                cumulative += (PRICES[index - 1] * (block - blocks[index - 1])) as u128;
+            }
+            assert_eq!(snapshot(&contract, *block).await, Some(cumulative));
+        }
+    }
+
+    #[tokio::test]
+    async fn returns_none_for_block_without_update() {
+        let (contract, wallet) = setup().await;
+
+        assert_eq!(
+            snapshot(&contract, current_height(&wallet).await).await,
+            None
+        );
+
+        let blocks = update_prices(&contract, &wallet, &PRICES[..1]).await;
+
+        assert_eq!(snapshot(&contract, blocks[0] - 1).await, None);
+        assert_eq!(snapshot(&contract, blocks[0] + 1).await, None);
+    }
+}
@@ -0,0 +1,55 @@
+use crate::twap::tests::utils::{
+    abi_calls::{cumulative_price, last_price, last_update_block, snapshot, update},
+    test_helpers::{current_height, produce_blocks, setup},
+    PRICES,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn sets_price_of_current_block() {
+        let (contract, wallet) = setup().await;
+
+        update(&contract, PRICES[0]).await;
+        let block = current_height(&wallet).await;
+
+        assert_eq!(last_price(&contract).await, PRICES[0]);
+        assert_eq!(last_update_block(&contract).await, block);
+        assert_eq!(snapshot(&contract, block).await, Some(0));
+    }
+
+    #[tokio::test]
+    async fn accumulates_last_price_for_every_block_since_last_update() {
+        let (contract, wallet) = setup().await;
+
+        update(&contract, PRICES[0]).await;
+        let first_block = current_height(&wallet).await;
+        produce_blocks(&wallet, 4).await;
+        update(&contract, PRICES[1]).await;
+        let second_block = current_height(&wallet).await;
+
+        let cumulative = (PRICES[0] * (second_block - first_block)) as u128;
+        assert_eq!(cumulative_price(&contract).await, cumulative);
+        assert_eq!(snapshot(&contract, second_block).await, Some(cumulative));
+        assert_eq!(last_price(&contract).await, PRICES[1]);
+        assert_eq!(last_update_block(&contract).await, second_block);
+    }
+
+    #[tokio::test]
+    async fn accumulates_maximum_price() {
+        let (contract, wallet) = setup().await;
+
+        update(&contract, u64::MAX).await;
+        let first_block = current_height(&wallet).await;
+        produce_blocks(&wallet, 2).await;
+        update(&contract, u64::MAX).await;
+        let second_block = current_height(&wallet).await;
+
+        assert_eq!(
+            cumulative_price(&contract).await,
+            u64::MAX as u128 * (second_block - first_block) as u128
+        );
+    }
+}
@@ -0,0 +1,135 @@
+use crate::common::{deploy_contract, get_isolated_wallets};
+use fuels::prelude::{abigen, LoadConfiguration, StorageConfiguration, WalletUnlocked};
+
+abigen!(Contract(
+    name = "TwapLib",
+    abi = "src/twap/out/debug/twap_test-abi.json"
+));
+
+/// The spot prices of ten consecutive blocks, whose average of `1125 / 10` is rounded down to `112`.
+pub const PRICES: [u64; 10] = [100, 110, 120, 90, 80, 130, 150, 140, 100, 105];
+
+pub mod abi_calls {
+
+    use super::*;
+
+    pub async fn cumulative_price(contract: &TwapLib<WalletUnlocked>) -> u128 {
+        contract
+            .methods()
+            .cumulative_price()
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn last_price(contract: &TwapLib<WalletUnlocked>) -> u64 {
+        contract.methods().last_price().call().await.unwrap().value
+    }
+
+    pub async fn last_update_block(contract: &TwapLib<WalletUnlocked>) -> u64 {
+        contract
+            .methods()
+            .last_update_block()
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn read_twap(contract: &TwapLib<WalletUnlocked>, window_blocks: u64) -> u64 {
+        contract
+            .methods()
+            .read_twap(window_blocks)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn snapshot(contract: &TwapLib<WalletUnlocked>, block: u64) -> Option<u128> {
+        contract
+            .methods()
+            .snapshot(block)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn update(contract: &TwapLib<WalletUnlocked>, current_price: u64) {
+        contract
+            .methods()
+            .update(current_price)
+            .call()
+            .await
+            .unwrap();
+    }
+}
+
+pub mod test_helpers {
+
+    use super::abi_calls::update;
+    use super::*;
+
+    pub async fn current_height(wallet: &WalletUnlocked) -> u64 {
+        wallet
+            .provider()
+            .unwrap()
+            .latest_block_height()
+            .await
+            .unwrap() as u64
+    }
+
+    pub async fn produce_blocks(wallet: &WalletUnlocked, blocks: u64) {
+        wallet
+            .provider()
+            .unwrap()
+            .produce_blocks(blocks, None)
+            .await
+            .unwrap();
+    }
+
+    /// Updates the accumulator with each price in its own block and returns the block height of every update.
+    pub async fn update_prices(
+        contract: &TwapLib<WalletUnlocked>,
+        wallet: &WalletUnlocked,
+        prices: &[u64],
+    ) -> Vec<u64> {
+        let mut blocks = vec![];
+        for price in prices {
+            update(contract, *price).await;
+            blocks.push(current_height(wallet).await);
+        }
+        blocks
+    }
+
+    /// Computes the rounded down average off-chain from the block height and price of every update.
+    pub fn expected_twap(updates: &[(u64, u64)], current_block: u64, window_blocks: u64) -> u64 {
+        let total: u128 = (current_block - window_blocks..current_block)
+            .map(|block| {
+                updates
+                    .iter()
+                    .rev()
+                    .find(|(update_block, _)| *update_block <= block)
+                    .map_or(0, |(_, price)| *price as u128)
+            })
+            .sum();
+        (total / window_blocks as u128) as u64
+    }
+
+    pub async fn setup() -> (TwapLib<WalletUnlocked>, WalletUnlocked) {
+        let wallet = get_isolated_wallets(1).await.pop().unwrap();
+
+        let storage_configuration =
+            StorageConfiguration::load_from("src/twap/out/debug/twap_test-storage_slots.json");
+        let id = deploy_contract(
+            &wallet,
+            "src/twap/out/debug/twap_test.bin",
+            LoadConfiguration::default().set_storage_configuration(storage_configuration.unwrap()),
+        )
+        .await;
+
+        (TwapLib::new(id, wallet.clone()), wallet)
+    }
+}
@@ -0,0 +1,48 @@
+contract;
+
+use vec_utils::{contains, dedup, index_of, remove_unordered};
+
+abi VecUtilsTest {
+    fn contains(v: Vec<u64>, target: u64) -> bool;
+    fn contains_b256(v: Vec<b256>, target: b256) -> bool;
+    fn dedup(v: Vec<u64>) -> Vec<u64>;
+    fn dedup_b256(v: Vec<b256>) -> Vec<b256>;
+    fn index_of(v: Vec<u64>, target: u64) -> Option<u64>;
+    fn index_of_b256(v: Vec<b256>, target: b256) -> Option<u64>;
+    fn remove_unordered(v: Vec<u64>, index: u64) -> Vec<u64>;
+    fn remove_unordered_b256(v: Vec<b256>, index: u64) -> Vec<b256>;
+}
+
+impl VecUtilsTest for Contract {
+    fn contains(v: Vec<u64>, target: u64) -> bool {
+        contains(v, target)
+    }
+
+    fn contains_b256(v: Vec<b256>, target: b256) -> bool {
+        contains(v, target)
+    }
+
+    fn dedup(v: Vec<u64>) -> Vec<u64> {
+        dedup(v)
+    }
+
+    fn dedup_b256(v: Vec<b256>) -> Vec<b256> {
+        dedup(v)
+    }
+
+    fn index_of(v: Vec<u64>, target: u64) -> Option<u64> {
+        index_of(v, target)
+    }
+
+    fn index_of_b256(v: Vec<b256>, target: b256) -> Option<u64> {
+        index_of(v, target)
+    }
+
+    fn remove_unordered(v: Vec<u64>, index: u64) -> Vec<u64> {
+        remove_unordered(v, index)
+    }
+
+    fn remove_unordered_b256(v: Vec<b256>, index: u64) -> Vec<b256> {
+        remove_unordered(v, index)
+    }
+}
@@ -0,0 +1,35 @@
+use crate::vec_utils::tests::utils::{
+    abi_calls::{contains, contains_b256},
+    test_helpers::{b256s, setup},
+};
+use fuels::types::Bits256;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn finds_present_element() {
+        let instance = setup().await;
+
+        assert!(contains(&instance, vec![1, 2, 3], 1).await);
+        assert!(contains(&instance, vec![1, 2, 3], 2).await);
+        assert!(contains(&instance, vec![1, 2, 3], 3).await);
+    }
+
+    #[tokio::test]
+    async fn does_not_find_absent_element() {
+        let instance = setup().await;
+
+        assert!(!contains(&instance, vec![1, 2, 3], 4).await);
+        assert!(!contains(&instance, vec![], 0).await);
+    }
+
+    #[tokio::test]
+    async fn finds_b256_element() {
+        let instance = setup().await;
+
+        assert!(contains_b256(&instance, b256s(&[1, 2, 3]), Bits256([2; 32])).await);
+        assert!(!contains_b256(&instance, b256s(&[1, 2, 3]), Bits256([4; 32])).await);
+    }
+}
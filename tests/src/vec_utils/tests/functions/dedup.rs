@@ -0,0 +1,44 @@
+use crate::vec_utils::tests::utils::{
+    abi_calls::{dedup, dedup_b256},
+    test_helpers::{b256s, setup},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn removes_duplicates_from_sorted_input() {
+        let instance = setup().await;
+
+        assert_eq!(
+            dedup(&instance, vec![1, 1, 2, 3, 3, 3, 4]).await,
+            vec![1, 2, 3, 4]
+        );
+    }
+
+    #[tokio::test]
+    async fn only_removes_consecutive_duplicates() {
+        let instance = setup().await;
+
+        assert_eq!(dedup(&instance, vec![1, 1, 2, 1]).await, vec![1, 2, 1]);
+    }
+
+    #[tokio::test]
+    async fn keeps_unique_input() {
+        let instance = setup().await;
+
+        assert_eq!(dedup(&instance, vec![1, 2, 3]).await, vec![1, 2, 3]);
+        assert_eq!(dedup(&instance, vec![]).await, vec![]);
+    }
+
+    #[tokio::test]
+    async fn removes_b256_duplicates() {
+        let instance = setup().await;
+
+        assert_eq!(
+            dedup_b256(&instance, b256s(&[1, 1, 2, 2])).await,
+            b256s(&[1, 2])
+        );
+    }
+}
@@ -0,0 +1,48 @@
+use crate::vec_utils::tests::utils::{
+    abi_calls::{index_of, index_of_b256},
+    test_helpers::{b256s, setup},
+};
+use fuels::types::Bits256;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_index_of_present_element() {
+        let instance = setup().await;
+
+        assert_eq!(index_of(&instance, vec![5, 6, 7], 5).await, Some(0));
+        assert_eq!(index_of(&instance, vec![5, 6, 7], 6).await, Some(1));
+        assert_eq!(index_of(&instance, vec![5, 6, 7], 7).await, Some(2));
+    }
+
+    #[tokio::test]
+    async fn returns_first_index_of_repeated_element() {
+        let instance = setup().await;
+
+        assert_eq!(index_of(&instance, vec![5, 6, 6, 6], 6).await, Some(1));
+    }
+
+    #[tokio::test]
+    async fn returns_none_for_absent_element() {
+        let instance = setup().await;
+
+        assert_eq!(index_of(&instance, vec![5, 6, 7], 8).await, None);
+        assert_eq!(index_of(&instance, vec![], 0).await, None);
+    }
+
+    #[tokio::test]
+    async fn returns_index_of_b256_element() {
+        let instance = setup().await;
+
+        assert_eq!(
+            index_of_b256(&instance, b256s(&[1, 2, 3]), Bits256([3; 32])).await,
+            Some(2)
+        );
+        assert_eq!(
+            index_of_b256(&instance, b256s(&[1, 2, 3]), Bits256([4; 32])).await,
+            None
+        );
+    }
+}
@@ -0,0 +1,4 @@
+mod contains;
+mod dedup;
+mod index_of;
+mod remove_unordered;
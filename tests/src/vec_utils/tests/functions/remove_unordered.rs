@@ -0,0 +1,71 @@
+use crate::vec_utils::tests::utils::{
+    abi_calls::{remove_unordered, remove_unordered_b256},
+    test_helpers::{b256s, setup},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn removes_first_element() {
+        let instance = setup().await;
+
+        assert_eq!(
+            remove_unordered(&instance, vec![1, 2, 3, 4], 0).await,
+            vec![4, 2, 3]
+        );
+    }
+
+    #[tokio::test]
+    async fn removes_middle_element() {
+        let instance = setup().await;
+
+        assert_eq!(
+            remove_unordered(&instance, vec![1, 2, 3, 4], 1).await,
+            vec![1, 4, 3]
+        );
+    }
+
+    #[tokio::test]
+    async fn removes_last_element() {
+        let instance = setup().await;
+
+        assert_eq!(
+            remove_unordered(&instance, vec![1, 2, 3, 4], 3).await,
+            vec![1, 2, 3]
+        );
+        assert_eq!(remove_unordered(&instance, vec![1], 0).await, vec![]);
+    }
+
+    #[tokio::test]
+    async fn removes_b256_element() {
+        let instance = setup().await;
+
+        assert_eq!(
+            remove_unordered_b256(&instance, b256s(&[1, 2, 3]), 0).await,
+            b256s(&[3, 2])
+        );
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "IndexOutOfBounds")]
+    async fn when_index_is_out_of_bounds() {
+        let instance = setup().await;
+
+        remove_unordered(&instance, vec![1, 2, 3], 3).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "IndexOutOfBounds")]
+    async fn when_vec_is_empty() {
+        let instance = setup().await;
+
+        remove_unordered(&instance, vec![], 0).await;
+    }
+}
@@ -0,0 +1,132 @@
+use crate::common::{deploy_contract, get_wallet};
+use fuels::{
+    prelude::{abigen, LoadConfiguration, WalletUnlocked},
+    types::Bits256,
+};
+
+abigen!(Contract(
+    name = "VecUtilsLib",
+    abi = "src/vec_utils/out/debug/vec_utils_test-abi.json"
+));
+
+pub mod abi_calls {
+
+    use super::*;
+
+    pub async fn contains(
+        contract: &VecUtilsLib<WalletUnlocked>,
+        v: Vec<u64>,
+        target: u64,
+    ) -> bool {
+        contract
+            .methods()
+            .contains(v, target)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn contains_b256(
+        contract: &VecUtilsLib<WalletUnlocked>,
+        v: Vec<Bits256>,
+        target: Bits256,
+    ) -> bool {
+        contract
+            .methods()
+            .contains_b256(v, target)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn dedup(contract: &VecUtilsLib<WalletUnlocked>, v: Vec<u64>) -> Vec<u64> {
+        contract.methods().dedup(v).call().await.unwrap().value
+    }
+
+    pub async fn dedup_b256(
+        contract: &VecUtilsLib<WalletUnlocked>,
+        v: Vec<Bits256>,
+    ) -> Vec<Bits256> {
+        contract.methods().dedup_b256(v).call().await.unwrap().value
+    }
+
+    pub async fn index_of(
+        contract: &VecUtilsLib<WalletUnlocked>,
+        v: Vec<u64>,
+        target: u64,
+    ) -> Option<u64> {
+        contract
+            .methods()
+            .index_of(v, target)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn index_of_b256(
+        contract: &VecUtilsLib<WalletUnlocked>,
+        v: Vec<Bits256>,
+        target: Bits256,
+    ) -> Option<u64> {
+        contract
+            .methods()
+            .index_of_b256(v, target)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn remove_unordered(
+        contract: &VecUtilsLib<WalletUnlocked>,
+        v: Vec<u64>,
+        index: u64,
+    ) -> Vec<u64> {
+        contract
+            .methods()
+            .remove_unordered(v, index)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn remove_unordered_b256(
+        contract: &VecUtilsLib<WalletUnlocked>,
+        v: Vec<Bits256>,
+        index: u64,
+    ) -> Vec<Bits256> {
+        contract
+            .methods()
+            .remove_unordered_b256(v, index)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+}
+
+pub mod test_helpers {
+
+    use super::*;
+
+    pub fn b256s(values: &[u8]) -> Vec<Bits256> {
+        values.iter().map(|value| Bits256([*value; 32])).collect()
+    }
+
+    pub async fn setup() -> VecUtilsLib<WalletUnlocked> {
+        let wallet = get_wallet().await;
+
+        let id = deploy_contract(
+            &wallet,
+            "src/vec_utils/out/debug/vec_utils_test.bin",
+            LoadConfiguration::default(),
+        )
+        .await;
+
+        VecUtilsLib::new(id, wallet)
+    }
+}